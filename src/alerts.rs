@@ -2,16 +2,20 @@
 //!
 //! Tracks block production performance and generates alerts when
 //! validators are underperforming their expected block production.
+//! Also debounces the peer-count floor alert used by `status` and `view`,
+//! tracks GRANDPA voter transitions, and gates webhook notifications during
+//! configured quiet hours.
 //!
 //! Note: This module is kept for future integration with the sync command.
 //! See BACKLOG.md "Pending Integration" section.
 
 #![allow(dead_code)]
 
-use crate::config::AlertConfig;
+use crate::config::{AlertConfig, DisplayTimeZone};
 use anyhow::Result;
+use chrono::{DateTime, NaiveTime, Utc};
 use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{error, info, warn};
 
 /// Alert state for a validator
@@ -37,15 +41,19 @@ pub struct AlertManager {
     validator_states: HashMap<String, ValidatorAlertState>,
     /// Current sidechain epoch being tracked
     current_epoch: u64,
+    /// Holds back warnings during quiet hours
+    quiet_gate: QuietHoursGate,
 }
 
 impl AlertManager {
-    /// Create a new alert manager
-    pub fn new(config: AlertConfig) -> Self {
+    /// Create a new alert manager (quiet hours are read in `time_zone`)
+    pub fn new(config: AlertConfig, time_zone: DisplayTimeZone) -> Self {
+        let quiet_gate = QuietHoursGate::new(&config, time_zone);
         Self {
             config,
             validator_states: HashMap::new(),
             current_epoch: 0,
+            quiet_gate,
         }
     }
 
@@ -93,6 +101,16 @@ impl AlertManager {
             return Vec::new();
         }
 
+        // Send warnings held during quiet hours once the window has passed
+        if let (Some(url), Some(digest)) = (
+            self.config.webhook_url.as_deref(),
+            self.quiet_gate.take_digest(Utc::now()),
+        ) {
+            if let Err(e) = send_digest_webhook(url, &digest).await {
+                error!("Failed to send alert digest webhook: {}", e);
+            }
+        }
+
         let mut alerts = Vec::new();
         let threshold = self.config.threshold_percent as f64 / 100.0;
         let min_expected = self.config.min_expected_blocks as f64;
@@ -141,10 +159,15 @@ impl AlertManager {
                         threshold * 100.0
                     );
 
-                    // Send webhook if configured
+                    // Send webhook if configured (held for the digest during quiet hours)
                     if let Some(ref url) = self.config.webhook_url {
-                        if let Err(e) = send_webhook_alert(url, &alert).await {
-                            error!("Failed to send webhook alert: {}", e);
+                        if self
+                            .quiet_gate
+                            .admit(alert.severity(), &alert.message(), Utc::now())
+                        {
+                            if let Err(e) = send_webhook_alert(url, &alert).await {
+                                error!("Failed to send webhook alert: {}", e);
+                            }
                         }
                     }
 
@@ -175,6 +198,24 @@ pub struct BlockProductionAlert {
     pub threshold: f64,
 }
 
+impl BlockProductionAlert {
+    /// Underperformance is worth knowing about, but not worth a page at night
+    pub fn severity(&self) -> AlertSeverity {
+        AlertSeverity::Warning
+    }
+
+    /// Human-readable alert message
+    pub fn message(&self) -> String {
+        format!(
+            "Validator {} is underperforming: {} blocks vs {:.1} expected ({:.1}%)",
+            truncate_key(&self.sidechain_key),
+            self.blocks_produced,
+            self.expected_blocks,
+            self.production_ratio * 100.0
+        )
+    }
+}
+
 /// Send an alert via webhook
 async fn send_webhook_alert(url: &str, alert: &BlockProductionAlert) -> Result<()> {
     let client = reqwest::Client::new();
@@ -188,13 +229,8 @@ async fn send_webhook_alert(url: &str, alert: &BlockProductionAlert) -> Result<(
         "committee_seats": alert.committee_seats,
         "production_ratio": alert.production_ratio,
         "threshold": alert.threshold,
-        "message": format!(
-            "Validator {} is underperforming: {} blocks vs {:.1} expected ({:.1}%)",
-            truncate_key(&alert.sidechain_key),
-            alert.blocks_produced,
-            alert.expected_blocks,
-            alert.production_ratio * 100.0
-        )
+        "severity": alert.severity().label(),
+        "message": alert.message(),
     });
 
     let response = client.post(url).json(&payload).send().await?;
@@ -207,6 +243,314 @@ async fn send_webhook_alert(url: &str, alert: &BlockProductionAlert) -> Result<(
     Ok(())
 }
 
+/// Debounced alert for the peer count staying below the critical floor
+#[derive(Debug, Default)]
+pub struct PeerFloorMonitor {
+    /// When the peer count first dropped below the floor
+    below_since: Option<Instant>,
+    /// Whether an alert has fired for the current low period
+    alerted: bool,
+}
+
+/// Peer floor transition worth notifying about
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerFloorAlert {
+    /// Peer count has been below the floor for at least the debounce period
+    Below {
+        peers: u64,
+        floor: u64,
+        duration_secs: u64,
+    },
+    /// Peer count is back at or above the floor after an alert
+    Recovered { peers: u64, floor: u64 },
+}
+
+impl PeerFloorAlert {
+    /// Losing peers risks isolation and pages; the recovery can wait for morning
+    pub fn severity(&self) -> AlertSeverity {
+        match self {
+            PeerFloorAlert::Below { .. } => AlertSeverity::Critical,
+            PeerFloorAlert::Recovered { .. } => AlertSeverity::Warning,
+        }
+    }
+
+    /// Human-readable alert message
+    pub fn message(&self) -> String {
+        match self {
+            PeerFloorAlert::Below {
+                peers,
+                floor,
+                duration_secs,
+            } => format!(
+                "Peer count {} below critical floor {} for {}s - check firewall/port 30333",
+                peers, floor, duration_secs
+            ),
+            PeerFloorAlert::Recovered { peers, floor } => {
+                format!("Peer count recovered to {} (floor {})", peers, floor)
+            }
+        }
+    }
+}
+
+impl PeerFloorMonitor {
+    /// Record a peer count sample, returning an alert on a debounced transition
+    ///
+    /// Fires once when the count has stayed below `floor` for `debounce`, and
+    /// once more when it recovers. Brief dips shorter than `debounce` are ignored.
+    pub fn observe(
+        &mut self,
+        peers: u64,
+        floor: u64,
+        debounce: Duration,
+        now: Instant,
+    ) -> Option<PeerFloorAlert> {
+        if peers >= floor {
+            self.below_since = None;
+            if std::mem::take(&mut self.alerted) {
+                return Some(PeerFloorAlert::Recovered { peers, floor });
+            }
+            return None;
+        }
+
+        let since = *self.below_since.get_or_insert(now);
+        let elapsed = now.duration_since(since);
+        if !self.alerted && elapsed >= debounce {
+            self.alerted = true;
+            return Some(PeerFloorAlert::Below {
+                peers,
+                floor,
+                duration_secs: elapsed.as_secs(),
+            });
+        }
+        None
+    }
+}
+
+/// Send a peer floor alert via webhook
+pub async fn send_peer_floor_webhook(url: &str, alert: &PeerFloorAlert) -> Result<()> {
+    let alert_type = match alert {
+        PeerFloorAlert::Below { .. } => "peer_count_low",
+        PeerFloorAlert::Recovered { .. } => "peer_count_recovered",
+    };
+    send_alert_webhook(url, alert_type, alert.severity(), &alert.message()).await?;
+    info!("Sent peer count alert to webhook");
+    Ok(())
+}
+
+/// Tracks the node's GRANDPA voter flag across refreshes
+#[derive(Debug, Default)]
+pub struct GrandpaVoterMonitor {
+    /// Voter flag at the previous sample (None before the first)
+    last: Option<bool>,
+    /// Whether a stop alert has fired and not yet recovered
+    alerted: bool,
+}
+
+/// GRANDPA voter transition worth notifying about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrandpaVoterAlert {
+    /// The node was voting and no longer is
+    Stopped,
+    /// The node is voting again after an alert
+    Resumed,
+}
+
+impl GrandpaVoterAlert {
+    /// A validator that stops voting still imports and produces blocks, so it pages
+    pub fn severity(self) -> AlertSeverity {
+        match self {
+            GrandpaVoterAlert::Stopped => AlertSeverity::Critical,
+            GrandpaVoterAlert::Resumed => AlertSeverity::Warning,
+        }
+    }
+
+    /// Human-readable alert message
+    pub fn message(self) -> String {
+        match self {
+            GrandpaVoterAlert::Stopped => {
+                "Node stopped voting in GRANDPA - out of the authority set or finality stalled locally"
+                    .to_string()
+            }
+            GrandpaVoterAlert::Resumed => "Node is a GRANDPA voter again".to_string(),
+        }
+    }
+}
+
+impl GrandpaVoterMonitor {
+    /// Record the voter flag, returning an alert when it flips
+    ///
+    /// Only a change from voting to not voting alerts, so a node that was never
+    /// a voter (not in the authority set) stays quiet. Recovery is reported
+    /// once after a stop alert.
+    pub fn observe(&mut self, is_voter: bool) -> Option<GrandpaVoterAlert> {
+        let was_voter = self.last.replace(is_voter);
+        if is_voter {
+            return std::mem::take(&mut self.alerted).then_some(GrandpaVoterAlert::Resumed);
+        }
+        if was_voter == Some(true) {
+            self.alerted = true;
+            return Some(GrandpaVoterAlert::Stopped);
+        }
+        None
+    }
+}
+
+/// Send a GRANDPA voter alert via webhook
+pub async fn send_grandpa_voter_webhook(url: &str, alert: GrandpaVoterAlert) -> Result<()> {
+    let alert_type = match alert {
+        GrandpaVoterAlert::Stopped => "grandpa_voter_stopped",
+        GrandpaVoterAlert::Resumed => "grandpa_voter_resumed",
+    };
+    send_alert_webhook(url, alert_type, alert.severity(), &alert.message()).await?;
+    info!("Sent GRANDPA voter alert to webhook");
+    Ok(())
+}
+
+/// POST an alert payload to the webhook
+async fn send_alert_webhook(
+    url: &str,
+    alert_type: &str,
+    severity: AlertSeverity,
+    message: &str,
+) -> Result<()> {
+    let payload = serde_json::json!({
+        "alert_type": alert_type,
+        "severity": severity.label(),
+        "message": message,
+    });
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&payload)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("Webhook returned status: {}", response.status());
+    }
+    Ok(())
+}
+
+/// How urgently an alert should reach the operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertSeverity {
+    /// Held back during quiet hours and sent in the next digest
+    Warning,
+    /// Always sent immediately (node down, finalization stalled, no peers)
+    Critical,
+}
+
+impl AlertSeverity {
+    /// Label used in webhook payloads
+    pub fn label(self) -> &'static str {
+        match self {
+            AlertSeverity::Warning => "warning",
+            AlertSeverity::Critical => "critical",
+        }
+    }
+}
+
+/// Daily window during which only critical alerts are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// Parse "HH:MM-HH:MM"; the window may wrap past midnight ("23:00-07:00")
+    pub fn from_str(s: &str) -> Option<Self> {
+        let (start, end) = s.trim().split_once('-')?;
+        let parse = |t: &str| NaiveTime::parse_from_str(t.trim(), "%H:%M").ok();
+        let (start, end) = (parse(start)?, parse(end)?);
+        (start != end).then_some(Self { start, end })
+    }
+
+    /// Whether a time of day falls inside the window (start inclusive, end exclusive)
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Quiet-hours filter in front of webhook dispatch
+///
+/// Critical alerts always go out. Warnings raised during quiet hours are
+/// held and handed back as a single digest once the window has ended.
+#[derive(Debug, Default)]
+pub struct QuietHoursGate {
+    quiet_hours: Option<QuietHours>,
+    time_zone: DisplayTimeZone,
+    held: Vec<String>,
+}
+
+impl QuietHoursGate {
+    /// Build from `[alerts] quiet_hours` (no window when unset or invalid)
+    pub fn new(config: &AlertConfig, time_zone: DisplayTimeZone) -> Self {
+        Self {
+            quiet_hours: config.quiet_hours.as_deref().and_then(QuietHours::from_str),
+            time_zone,
+            held: Vec::new(),
+        }
+    }
+
+    /// Apply new settings (e.g. after a config reload), keeping held warnings
+    pub fn reconfigure(&mut self, config: &AlertConfig, time_zone: DisplayTimeZone) {
+        let held = std::mem::take(&mut self.held);
+        *self = Self {
+            held,
+            ..Self::new(config, time_zone)
+        };
+    }
+
+    fn is_quiet(&self, now: DateTime<Utc>) -> bool {
+        self.quiet_hours
+            .is_some_and(|window| window.contains(self.time_zone.time_of_day(now)))
+    }
+
+    /// Whether an alert should be sent now; warnings in quiet hours are held
+    pub fn admit(&mut self, severity: AlertSeverity, message: &str, now: DateTime<Utc>) -> bool {
+        if severity == AlertSeverity::Critical || !self.is_quiet(now) {
+            return true;
+        }
+        self.held.push(message.to_string());
+        false
+    }
+
+    /// Take the held warnings once quiet hours are over (None if nothing is due)
+    pub fn take_digest(&mut self, now: DateTime<Utc>) -> Option<Vec<String>> {
+        if self.held.is_empty() || self.is_quiet(now) {
+            return None;
+        }
+        Some(std::mem::take(&mut self.held))
+    }
+}
+
+/// Send the warnings held during quiet hours as one webhook
+pub async fn send_digest_webhook(url: &str, messages: &[String]) -> Result<()> {
+    let client = reqwest::Client::new();
+
+    let payload = serde_json::json!({
+        "alert_type": "alert_digest",
+        "severity": AlertSeverity::Warning.label(),
+        "count": messages.len(),
+        "messages": messages,
+        "message": format!("{} alert(s) held during quiet hours", messages.len()),
+    });
+
+    let response = client.post(url).json(&payload).send().await?;
+
+    if !response.status().is_success() {
+        anyhow::bail!("Webhook returned status: {}", response.status());
+    }
+
+    info!("Sent alert digest ({} held) to webhook", messages.len());
+    Ok(())
+}
+
 /// Truncate a key for display
 fn truncate_key(key: &str) -> String {
     if key.len() > 16 {
@@ -228,9 +572,11 @@ mod tests {
             min_expected_blocks: 5,
             webhook_url: None,
             cooldown_secs: 0,
+            quiet_hours: None,
+            grandpa_voter: true,
         };
 
-        let mut manager = AlertManager::new(config);
+        let mut manager = AlertManager::new(config, DisplayTimeZone::Utc);
         manager.current_epoch = 100;
 
         // Validator producing well above threshold (90%)
@@ -242,4 +588,120 @@ mod tests {
         // Validator with too few expected blocks (should not alert)
         manager.update_validator("0xccc", 1, 3.0, 3);
     }
+
+    #[test]
+    fn test_peer_floor_debounce() {
+        let mut monitor = PeerFloorMonitor::default();
+        let debounce = Duration::from_secs(120);
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        // Brief dip shorter than the debounce period is ignored
+        assert_eq!(monitor.observe(1, 3, debounce, at(0)), None);
+        assert_eq!(monitor.observe(2, 3, debounce, at(60)), None);
+        assert_eq!(monitor.observe(5, 3, debounce, at(90)), None);
+
+        // Sustained drop alerts once
+        assert_eq!(monitor.observe(0, 3, debounce, at(100)), None);
+        assert_eq!(
+            monitor.observe(1, 3, debounce, at(220)),
+            Some(PeerFloorAlert::Below {
+                peers: 1,
+                floor: 3,
+                duration_secs: 120,
+            })
+        );
+        assert_eq!(monitor.observe(1, 3, debounce, at(400)), None);
+
+        // Recovery is reported once
+        assert_eq!(
+            monitor.observe(4, 3, debounce, at(460)),
+            Some(PeerFloorAlert::Recovered { peers: 4, floor: 3 })
+        );
+        assert_eq!(monitor.observe(4, 3, debounce, at(520)), None);
+    }
+
+    #[test]
+    fn test_grandpa_voter_transitions() {
+        let mut monitor = GrandpaVoterMonitor::default();
+
+        // Not a voter yet, then joining the authority set: nothing to report
+        assert_eq!(monitor.observe(false), None);
+        assert_eq!(monitor.observe(false), None);
+        assert_eq!(monitor.observe(true), None);
+
+        assert_eq!(monitor.observe(false), Some(GrandpaVoterAlert::Stopped));
+        assert_eq!(monitor.observe(false), None);
+        assert_eq!(monitor.observe(true), Some(GrandpaVoterAlert::Resumed));
+        assert_eq!(monitor.observe(true), None);
+
+        // A voter from the first sample is not a transition
+        let mut monitor = GrandpaVoterMonitor::default();
+        assert_eq!(monitor.observe(true), None);
+        assert_eq!(
+            GrandpaVoterAlert::Stopped.severity(),
+            AlertSeverity::Critical
+        );
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        let overnight = QuietHours::from_str("23:00-07:00").unwrap();
+        assert!(overnight.contains(at(23, 0)));
+        assert!(overnight.contains(at(3, 0)));
+        assert!(!overnight.contains(at(7, 0)));
+        assert!(!overnight.contains(at(12, 0)));
+
+        let midday = QuietHours::from_str(" 12:00 - 13:30 ").unwrap();
+        assert!(midday.contains(at(13, 0)));
+        assert!(!midday.contains(at(23, 0)));
+
+        assert_eq!(QuietHours::from_str("23:00"), None);
+        assert_eq!(QuietHours::from_str("25:00-07:00"), None);
+        assert_eq!(QuietHours::from_str("07:00-07:00"), None);
+    }
+
+    #[test]
+    fn test_quiet_hours_gate() {
+        let config = AlertConfig {
+            quiet_hours: Some("23:00-07:00".to_string()),
+            ..AlertConfig::default()
+        };
+        let mut gate = QuietHoursGate::new(&config, DisplayTimeZone::Utc);
+        let at = |h| {
+            DateTime::parse_from_rfc3339(&format!("2025-06-01T{:02}:00:00Z", h))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        // At night only critical alerts go out; warnings are held
+        assert!(gate.admit(AlertSeverity::Critical, "no peers", at(3)));
+        assert!(!gate.admit(AlertSeverity::Warning, "low blocks", at(3)));
+        assert!(!gate.admit(AlertSeverity::Warning, "low blocks again", at(4)));
+        assert_eq!(gate.take_digest(at(5)), None);
+
+        // Morning: the held warnings come back once as a digest
+        assert_eq!(
+            gate.take_digest(at(8)),
+            Some(vec![
+                "low blocks".to_string(),
+                "low blocks again".to_string()
+            ])
+        );
+        assert_eq!(gate.take_digest(at(9)), None);
+        assert!(gate.admit(AlertSeverity::Warning, "low blocks", at(9)));
+
+        // Named zone: 23:30 UTC is 00:30 in London (BST), still quiet
+        let mut gate = QuietHoursGate::new(
+            &AlertConfig {
+                quiet_hours: Some("00:00-06:00".to_string()),
+                ..AlertConfig::default()
+            },
+            DisplayTimeZone::from_str("Europe/London").unwrap(),
+        );
+        let late = at(23) + chrono::Duration::minutes(30);
+        assert!(!gate.admit(AlertSeverity::Warning, "low blocks", late));
+    }
 }
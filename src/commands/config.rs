@@ -1,7 +1,8 @@
 //! Configuration management command
 
 use anyhow::Result;
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
 
 #[derive(Args, Debug)]
 pub struct ConfigArgs {
@@ -9,13 +10,27 @@ pub struct ConfigArgs {
     pub command: ConfigCommands,
 }
 
+impl ConfigArgs {
+    /// Whether this command writes machine-readable output to stdout
+    pub fn is_machine_output(&self) -> bool {
+        matches!(self.command, ConfigCommands::Show { .. })
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ConfigCommands {
     /// Show current configuration (after applying all overrides)
-    Show,
-
-    /// Validate configuration file
-    Validate,
+    Show {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ConfigFormat::Toml)]
+        format: ConfigFormat,
+    },
+
+    /// Validate a configuration file (default: the file MVM would load)
+    Validate {
+        /// Config file to check instead of the search paths
+        path: Option<PathBuf>,
+    },
 
     /// Print example configuration file
     Example,
@@ -24,10 +39,17 @@ pub enum ConfigCommands {
     Paths,
 }
 
+/// Output format for `config show`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
 pub async fn run(args: ConfigArgs) -> Result<()> {
     match args.command {
-        ConfigCommands::Show => run_show().await?,
-        ConfigCommands::Validate => run_validate().await?,
+        ConfigCommands::Show { format } => run_show(format).await?,
+        ConfigCommands::Validate { path } => run_validate(path.as_deref()).await?,
         ConfigCommands::Example => run_example().await?,
         ConfigCommands::Paths => run_paths().await?,
     }
@@ -35,62 +57,70 @@ pub async fn run(args: ConfigArgs) -> Result<()> {
     Ok(())
 }
 
-async fn run_show() -> Result<()> {
+async fn run_show(format: ConfigFormat) -> Result<()> {
     let config = crate::config::Config::load()?;
     config.validate()?;
 
-    println!("Current Configuration:");
-    println!("=====================\n");
-
-    let toml_str = toml::to_string_pretty(&config)?;
-    println!("{}", toml_str);
-
-    println!("\nConfiguration loaded successfully.");
-    println!("Priority: CLI flags > Environment variables > Config file > Defaults");
+    match format {
+        ConfigFormat::Toml => {
+            // Header as comments so the output can be saved as a config file
+            println!("# Effective configuration (defaults + config file + environment)");
+            println!("# Priority: CLI flags > Environment variables > Config file > Defaults\n");
+            print!("{}", toml::to_string_pretty(&config)?);
+        }
+        ConfigFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&config)?);
+        }
+    }
 
     Ok(())
 }
 
-async fn run_validate() -> Result<()> {
+async fn run_validate(path: Option<&Path>) -> Result<()> {
     println!("Validating configuration...\n");
 
+    if let Some(path) = path {
+        return validate_file(path);
+    }
+
     let paths = crate::config::Config::config_file_paths();
-    let mut found = false;
-
-    for path in &paths {
-        if path.exists() {
-            found = true;
-            println!("Found config file: {}", path.display());
-
-            match crate::config::Config::load() {
-                Ok(config) => match config.validate() {
-                    Ok(_) => {
-                        println!("✓ Configuration is valid");
-                    }
-                    Err(e) => {
-                        println!("✗ Configuration validation failed: {}", e);
-                        return Err(e);
-                    }
-                },
-                Err(e) => {
-                    println!("✗ Failed to load configuration: {}", e);
-                    return Err(e);
-                }
-            }
-            break;
+    match paths.iter().find(|p| p.exists()) {
+        Some(path) => validate_file(path),
+        None => {
+            println!("{}", crate::config::Config::config_not_found_help());
+            println!();
+            println!("Using defaults...");
+            let config = crate::config::Config::default();
+            config.validate()?;
+            println!("✓ Default configuration is valid");
+            Ok(())
         }
     }
+}
 
-    if !found {
-        println!("{}", crate::config::Config::config_not_found_help());
-        println!();
-        println!("Using defaults...");
-        let config = crate::config::Config::default();
-        config.validate()?;
-        println!("✓ Default configuration is valid");
-    }
+/// Parse and check a single config file, printing parse errors with line context
+fn validate_file(path: &Path) -> Result<()> {
+    println!("Config file: {}", path.display());
 
-    Ok(())
+    let config = match crate::config::Config::load_file(path) {
+        Ok(config) => config,
+        Err(e) => {
+            // The root cause carries the TOML line/column snippet
+            println!("✗ Failed to load configuration:\n{}", e.root_cause());
+            return Err(e);
+        }
+    };
+
+    match config.validate() {
+        Ok(_) => {
+            println!("✓ Configuration is valid");
+            Ok(())
+        }
+        Err(e) => {
+            println!("✗ Configuration validation failed: {}", e);
+            Err(e)
+        }
+    }
 }
 
 async fn run_example() -> Result<()> {
@@ -106,10 +136,11 @@ async fn run_example() -> Result<()> {
 #
 # Environment variables: MVM_RPC_URL, MVM_METRICS_URL, MVM_DB_PATH,
 #   MVM_KEYSTORE_PATH, MVM_VALIDATOR_LABEL, MVM_BATCH_SIZE, MVM_POLL_INTERVAL,
-#   MVM_PID_FILE, MVM_EXPECTED_IP, MVM_NETWORK
+#   MVM_PID_FILE, MVM_EXPECTED_IP, MVM_NETWORK, MVM_LISTEN_ADDR, MVM_TIMEZONE
 
 [rpc]
-# Midnight node JSON-RPC endpoint
+# Midnight node JSON-RPC endpoint (http://, https://, ws://, wss://, or unix:///path/to/socket)
+# With ws:// or wss://, `mvm sync --daemon` subscribes to new heads instead of polling
 url = "http://localhost:9944"
 # Prometheus metrics endpoint (for bandwidth, uptime stats)
 metrics_url = "http://localhost:9615/metrics"
@@ -119,6 +150,16 @@ timeout_ms = 30000
 max_retries = 3
 retry_initial_delay_ms = 1000
 retry_max_delay_ms = 30000
+# Connection reuse: idle connections kept open, keep-alive seconds (0 = no reuse),
+# and connect timeout (timeout_ms above bounds the whole request)
+pool_size = 8
+keepalive_secs = 60
+connect_timeout_ms = 5000
+# `mvm view` flags any RPC call slower than this in the status bar (0 = off);
+# --verbose logs every call's method and duration
+slow_call_ms = 2000
+# Block to check committee election at: finalized, tip, or offset:N (N blocks behind tip)
+committee_ref = "finalized"
 
 [database]
 # SQLite database path for block and validator data
@@ -135,19 +176,46 @@ path = "/opt/midnight/mvm/data/mvm.db"
 [sync]
 # Blocks to fetch per batch during sync
 batch_size = 100
-# Seconds between polling for new blocks
+# Seconds between polling for new blocks (1-3600; above 5 slots, i.e. 30s,
+# new blocks show up late and a warning is logged)
 poll_interval_secs = 6
 # Only sync finalized blocks (safer but slightly delayed)
 finalized_only = false
 # Block number to start sync from (0 = continue from last synced)
 start_block = 0
+# Seconds between peer count / bandwidth samples while watching for new blocks
+# (0 = off); export with `mvm query network-history`
+network_sample_secs = 60
+# Days of network samples to keep (7 days at 60s is ~10k rows)
+network_history_days = 7
+# Seconds between sync throughput samples (blocks/s, time per block, lag, DB size)
+# (0 = off); see `mvm query sync-history`
+sync_sample_secs = 300
+# Days of sync throughput samples to keep (30 days at 300s is ~9k rows)
+sync_history_days = 30
+# Seconds between recalibrations of slot timing (genesis time, slot duration)
+# from recent block timestamps (0 = at startup only)
+calibration_interval_secs = 3600
 
 [view]
-# TUI refresh interval in milliseconds
+# TUI refresh interval in milliseconds (100-3600000)
 refresh_interval_ms = 6000
-# Filter external IPs to only show addresses matching this prefix
+# Filter external IPs to addresses matching this IP or CIDR block
 # Useful when node reports multiple addresses from peer discovery
-# expected_ip = "203.0.113.1"
+# expected_ip = "203.0.113.0/24"
+# Finalization lag samples shown in the dashboard sparkline (one per refresh)
+finality_history_len = 30
+# Epochs loaded in the validator detail popup, most recent first (mvm view --tail N);
+# scrolling past the last one loads this many more
+history_epochs = 50
+# Seconds between snapshots in headless mode (mvm view --output-dir <dir>)
+snapshot_interval_secs = 60
+# Sparkline and progress-bar characters: "unicode" or "ascii" (for terminals or
+# fonts that render block characters poorly). Unset: ascii on a non-UTF-8 locale
+# charset = "ascii"
+# Show each block's calls in the block detail popup (e.g. "1 timestamp.set"),
+# decoded with runtime metadata fetched from the node (mvm view --decode-extrinsics)
+decode_extrinsics = false
 
 [daemon]
 # PID file for daemon mode (optional)
@@ -163,9 +231,98 @@ enable_syslog = false
 #   preview: 2h sidechain epochs, 24h mainchain epochs
 #   preprod/mainnet: TBD sidechain, 5d mainchain epochs
 network = "preview"
+# Built-in timing profile (preview, preprod, mainnet); takes precedence over
+# network. `mvm --profile <name>` overrides both for one run
+# profile = "mainnet"
+# Override individual profile values (the resolved timing is logged at startup)
+# slot_duration_ms = 6000
+# sidechain_epoch_ms = 7200000        # or: blocks_per_sidechain_epoch = 1200
+# mainchain_epoch_ms = 86400000
 # Override genesis timestamp (milliseconds since Unix epoch)
-# Normally auto-calculated from current slot; only set if you know the exact value
+# Normally calibrated by `mvm sync` from block timestamps; only set if you know the exact value
 # genesis_timestamp_ms = 1700000000000
+
+[server]
+# Serve /healthz and /readyz for Kubernetes / load-balancer probes, and MVM's own
+# stats on /metrics for Prometheus (disabled when unset). Runs alongside `mvm sync`
+# (database lag) and `mvm view` (seats, blocks vs expected, selection rate, lag)
+# listen_addr = "127.0.0.1:9101"
+# /readyz fails if the last successful RPC fetch is older than this many seconds
+ready_max_age_secs = 120
+# Serve only /metrics on a separate address for Prometheus (also `--exporter-bind`)
+# exporter_addr = "127.0.0.1:9900"
+
+[alerts]
+# Webhook notifications (block production, sustained low peer count)
+enabled = false
+# webhook_url = "https://hooks.example.com/mvm"
+threshold_percent = 80
+min_expected_blocks = 5
+cooldown_secs = 300
+# Only critical alerts are sent during this daily window (display.timezone);
+# warnings are held and sent as one digest when it ends
+# quiet_hours = "23:00-07:00"
+# Warn when the node stops being a GRANDPA voter (TUI; webhook when enabled)
+grandpa_voter = true
+
+[thresholds]
+# Node counts as unhealthy (status bar, `mvm status --check`, /readyz) when the
+# finalized block trails the best block by more than this many blocks
+max_finalized_lag = 100
+
+# Peer count floors: below peers_warn is a caution, below peers_crit is critical.
+# `mvm status` and `mvm view` alert once the count stays under peers_crit for
+# peers_alert_after_secs (webhook via [alerts] when enabled).
+peers_warn = 8
+peers_crit = 3
+peers_alert_after_secs = 120
+
+# Host memory usage (percent, from node_exporter) that shows a dashboard warning
+memory_warn_percent = 85.0
+
+# Dashboard warns when the host clock is this many seconds off chain time
+# (measured from the tip block's slot); usually an NTP problem on the host
+max_clock_skew_secs = 5
+
+# Status bar turns red ("MVM sync lagging") when MVM's database trails the chain
+# tip by more than this many blocks and the gap is growing (sync daemon stalled)
+max_db_lag_blocks = 20
+
+# Dashboard shows a red "no blocks this epoch" banner when our validator holds
+# seats but hasn't produced a block after this much of the epoch (percent)
+alert_missed_block_epoch_percent = 50.0
+
+[display]
+# Time zone for displayed timestamps (TUI popups, Events view, query output):
+# "utc", "local", or an IANA name such as "Europe/London". The zone is always shown.
+timezone = "utc"
+
+[network]
+# Address shown in the dashboard Identity row (skips auto-detection); the
+# node's other public addresses are listed in the Peers view
+# public_ip = "203.0.113.10"
+# P2P port the node listens on; when not pinned, addresses on it are preferred
+p2p_port = 30333
+
+[rewards]
+# Rough reward estimate in the validator identity popup. Rewards are assumed
+# proportional to blocks produced, averaged over the last window_epochs
+# completed sidechain epochs. Set reward_per_block to project rewards per day
+# in `unit`; leave it out to see block figures only.
+window_epochs = 12
+# reward_per_block = 0.5
+unit = "NIGHT"
+
+# Additional nodes for `mvm view` (press n/N to switch). The [rpc] node above
+# is always first; unset fields default to port 9615 metrics on the RPC host
+# and the [database]/[validator] settings.
+# [[nodes]]
+# name = "backup"
+# rpc_url = "http://10.0.0.2:9944"
+# metrics_url = "http://10.0.0.2:9615/metrics"
+# node_exporter_url = "http://10.0.0.2:9100/metrics"
+# db_path = "/opt/midnight/mvm/data/backup.db"
+# keystore_path = "/opt/midnight/backup/keystore"
 "#
     );
 
@@ -0,0 +1,436 @@
+//! Database maintenance command
+
+use crate::commands::sync::{record_block_author, replace_validator_epoch_snapshot};
+use crate::db::{audit_epoch, explain_busy, timestamped_backup_path, BlockRecord, Database};
+use crate::midnight::ValidatorSet;
+use crate::rpc::RpcClient;
+use anyhow::{bail, Result};
+use clap::{Args, Subcommand};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+/// Epochs checked by `db verify` when no epoch is given
+const VERIFY_RECENT_EPOCHS: usize = 3;
+
+/// Database command arguments
+#[derive(Args, Debug)]
+pub struct DbArgs {
+    /// SQLite database path
+    #[arg(short, long)]
+    pub db_path: Option<PathBuf>,
+
+    /// Skip the automatic backup before commands that modify data
+    #[arg(long, global = true)]
+    pub no_backup: bool,
+
+    #[command(subcommand)]
+    pub command: DbCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DbCommands {
+    /// Recount each validator's total_blocks from the blocks table and fix any drift
+    RecomputeTotals,
+
+    /// Cross-check committee seats and attributed blocks for consistency
+    Verify {
+        /// Sidechain epoch to check (default: the most recent epochs with a committee snapshot)
+        #[arg(long)]
+        epoch: Option<u64>,
+    },
+
+    /// Attribute authors for stored blocks that have none, using the historical committee
+    ///
+    /// For blocks synced while the node's state was pruned. Point at an archive
+    /// node so the committee at each block is still available.
+    Reattribute {
+        /// First block to repair (default: lowest stored block)
+        #[arg(long)]
+        from: Option<u64>,
+
+        /// Last block to repair (default: highest stored block)
+        #[arg(long)]
+        to: Option<u64>,
+
+        /// Archive node RPC endpoint URL (default: [rpc] url)
+        #[arg(short, long)]
+        rpc_url: Option<String>,
+    },
+
+    /// Re-capture a sidechain epoch's seats snapshot and committee from the node
+    ///
+    /// Replaces a snapshot stored from a partial or raced committee fetch.
+    /// Needs a stored block from the epoch; point at an archive node for old epochs.
+    Recapture {
+        /// Sidechain epoch to re-capture
+        #[arg(long)]
+        epoch: u64,
+
+        /// Archive node RPC endpoint URL (default: [rpc] url)
+        #[arg(short, long)]
+        rpc_url: Option<String>,
+    },
+}
+
+/// `mvm backfill-authors` arguments
+///
+/// Shorthand for `mvm db reattribute` over the whole database, for operators
+/// who synced against a pruned node and have since attached an archive node.
+#[derive(Args, Debug)]
+pub struct BackfillAuthorsArgs {
+    /// Archive node RPC endpoint URL
+    #[arg(short, long)]
+    pub rpc_url: String,
+
+    /// SQLite database path
+    #[arg(short, long)]
+    pub db_path: Option<PathBuf>,
+
+    /// First block to repair (default: lowest stored block)
+    #[arg(long)]
+    pub from: Option<u64>,
+
+    /// Last block to repair (default: highest stored block)
+    #[arg(long)]
+    pub to: Option<u64>,
+
+    /// Skip the automatic backup before updating blocks
+    #[arg(long)]
+    pub no_backup: bool,
+}
+
+/// Run the backfill-authors command
+pub async fn run_backfill_authors(args: BackfillAuthorsArgs) -> Result<()> {
+    run(DbArgs {
+        db_path: args.db_path,
+        no_backup: args.no_backup,
+        command: DbCommands::Reattribute {
+            from: args.from,
+            to: args.to,
+            rpc_url: Some(args.rpc_url),
+        },
+    })
+    .await
+}
+
+/// Run the db command
+pub async fn run(args: DbArgs) -> Result<()> {
+    // Load configuration
+    let config = crate::config::Config::load()?;
+
+    // Use args or fall back to config
+    let db_path = args
+        .db_path
+        .unwrap_or_else(|| PathBuf::from(&config.database.path));
+
+    let db = Database::open(&db_path)?;
+
+    let result = match args.command {
+        DbCommands::RecomputeTotals => backup_before_write(&db, &db_path, args.no_backup)
+            .and_then(|_| run_recompute_totals(&db)),
+        DbCommands::Verify { epoch } => run_verify(
+            &db,
+            epoch,
+            config.chain.timing().blocks_per_sidechain_epoch(),
+        ),
+        DbCommands::Reattribute { from, to, rpc_url } => {
+            let rpc = RpcClient::with_options(
+                &rpc_url.unwrap_or_else(|| config.rpc.url.clone()),
+                config.rpc.timeout_ms,
+                config.rpc.retry_config(),
+                config.rpc.pool_config(),
+            );
+            match backup_before_write(&db, &db_path, args.no_backup) {
+                Ok(()) => run_reattribute(&db, &rpc, from, to).await,
+                Err(e) => Err(e),
+            }
+        }
+        DbCommands::Recapture { epoch, rpc_url } => {
+            let rpc = RpcClient::with_options(
+                &rpc_url.unwrap_or_else(|| config.rpc.url.clone()),
+                config.rpc.timeout_ms,
+                config.rpc.retry_config(),
+                config.rpc.pool_config(),
+            );
+            match backup_before_write(&db, &db_path, args.no_backup) {
+                Ok(()) => run_recapture(&db, &rpc, epoch).await,
+                Err(e) => Err(e),
+            }
+        }
+    };
+
+    result.map_err(explain_busy)
+}
+
+/// Back up the database before a command that modifies data, unless `--no-backup`
+fn backup_before_write(db: &Database, db_path: &Path, no_backup: bool) -> Result<()> {
+    if no_backup {
+        info!("Skipping backup (--no-backup)");
+        return Ok(());
+    }
+    let backup_path = timestamped_backup_path(db_path);
+    db.backup_to(&backup_path)?;
+    info!("Backed up database to {}", backup_path.display());
+    Ok(())
+}
+
+fn run_recompute_totals(db: &Database) -> Result<()> {
+    let corrections = db.recompute_block_totals()?;
+
+    if corrections.is_empty() {
+        info!(
+            "All {} validator block totals match the blocks table",
+            db.count_validators()?
+        );
+        return Ok(());
+    }
+
+    info!("Corrected {} validator block total(s):", corrections.len());
+    info!("─────────────────────────────────────────");
+    for c in &corrections {
+        let diff = c.actual as i64 - c.stored as i64;
+        info!(
+            "{}  {} → {} ({:+})",
+            c.sidechain_key, c.stored, c.actual, diff
+        );
+    }
+
+    Ok(())
+}
+
+async fn run_reattribute(
+    db: &Database,
+    rpc: &RpcClient,
+    from: Option<u64>,
+    to: Option<u64>,
+) -> Result<()> {
+    let (Some(min), Some(max)) = (db.get_min_block_number()?, db.get_max_block_number()?) else {
+        info!("No blocks in the database");
+        return Ok(());
+    };
+    let (from, to) = (from.unwrap_or(min), to.unwrap_or(max));
+    if from > to {
+        bail!("--from {} is after --to {}", from, to);
+    }
+
+    let blocks = db.get_unattributed_blocks(from, to)?;
+    if blocks.is_empty() {
+        info!("All blocks in {} - {} already have an author", from, to);
+        return Ok(());
+    }
+    info!(
+        "Re-attributing {} block(s) without an author in {} - {}",
+        blocks.len(),
+        from,
+        to
+    );
+
+    // Committees rotate per sidechain epoch, so fetch one per epoch at one of its blocks
+    let mut by_epoch: BTreeMap<u64, Vec<&BlockRecord>> = BTreeMap::new();
+    for block in &blocks {
+        by_epoch
+            .entry(block.sidechain_epoch)
+            .or_default()
+            .push(block);
+    }
+
+    let mut attributed = 0u64;
+    let mut skipped = 0u64;
+    // Of the skipped blocks, those whose epoch's committee state the node couldn't serve
+    let mut unavailable = 0u64;
+    for (sidechain_epoch, epoch_blocks) in by_epoch {
+        let first = epoch_blocks[0];
+        if sidechain_epoch == 0 || first.epoch == 0 {
+            warn!(
+                "{} block(s) have no recorded epoch (from #{}); re-sync them instead",
+                epoch_blocks.len(),
+                first.block_number
+            );
+            skipped += epoch_blocks.len() as u64;
+            continue;
+        }
+
+        let vset = match ValidatorSet::fetch_with_committee_or_fallback(
+            rpc,
+            first.epoch,
+            &first.block_hash,
+        )
+        .await
+        {
+            Ok((_, true)) => {
+                warn!(
+                    "State still pruned for sidechain epoch {}; skipping {} block(s) (is this an archive node?)",
+                    sidechain_epoch,
+                    epoch_blocks.len()
+                );
+                skipped += epoch_blocks.len() as u64;
+                unavailable += epoch_blocks.len() as u64;
+                continue;
+            }
+            Ok((vset, false)) => vset,
+            Err(e) => {
+                warn!(
+                    "Failed to fetch committee for sidechain epoch {}: {}; skipping {} block(s)",
+                    sidechain_epoch,
+                    e,
+                    epoch_blocks.len()
+                );
+                skipped += epoch_blocks.len() as u64;
+                unavailable += epoch_blocks.len() as u64;
+                continue;
+            }
+        };
+
+        if db.get_committee_snapshot(sidechain_epoch)?.is_none() {
+            db.store_committee_snapshot(sidechain_epoch, &vset.committee)?;
+        }
+
+        let mut epoch_attributed = 0u64;
+        for block in &epoch_blocks {
+            let validator = (block.slot_number > 0)
+                .then(|| vset.get_author_or_placeholder(block.slot_number))
+                .flatten();
+            match validator {
+                Some(validator) => {
+                    if db.set_block_author(block.block_number, &validator.sidechain_key)? {
+                        record_block_author(db, &validator, block.epoch);
+                        epoch_attributed += 1;
+                    }
+                }
+                None => skipped += 1,
+            }
+        }
+        info!(
+            "Sidechain epoch {}: attributed {} of {} block(s)",
+            sidechain_epoch,
+            epoch_attributed,
+            epoch_blocks.len()
+        );
+        attributed += epoch_attributed;
+    }
+
+    info!("─────────────────────────────────────────");
+    info!("Newly attributed: {}", attributed);
+    if skipped > 0 {
+        info!(
+            "Still missing:    {} ({} with committee state unavailable)",
+            skipped, unavailable
+        );
+    }
+
+    Ok(())
+}
+
+async fn run_recapture(db: &Database, rpc: &RpcClient, sidechain_epoch: u64) -> Result<()> {
+    // The committee is read at one of the epoch's own blocks
+    let Some(block) = db.get_first_block_in_sidechain_epoch(sidechain_epoch)? else {
+        bail!(
+            "No stored blocks in sidechain epoch {}; sync the epoch first",
+            sidechain_epoch
+        );
+    };
+    if block.epoch == 0 {
+        bail!(
+            "Block #{} has no recorded mainchain epoch; re-sync it first",
+            block.block_number
+        );
+    }
+
+    let vset =
+        match ValidatorSet::fetch_with_committee_or_fallback(rpc, block.epoch, &block.block_hash)
+            .await?
+        {
+            (_, true) => bail!(
+                "State pruned at block #{}; re-capture epoch {} from an archive node (--rpc-url)",
+                block.block_number,
+                sidechain_epoch
+            ),
+            (vset, false) => vset,
+        };
+
+    let previous: u32 = db
+        .get_validators_for_epoch(sidechain_epoch)?
+        .iter()
+        .map(|v| v.committee_seats)
+        .sum();
+    replace_validator_epoch_snapshot(db, sidechain_epoch, &vset)?;
+
+    info!(
+        "Re-captured sidechain epoch {} at block #{}: {} candidates, {} of {} seats matched (previously {})",
+        sidechain_epoch,
+        block.block_number,
+        vset.candidate_count(),
+        vset.assigned_seats(),
+        vset.committee_size(),
+        previous
+    );
+    if vset.assigned_seats() != vset.committee_size() {
+        warn!("Some committee members are not in the candidate list for this epoch");
+    }
+
+    Ok(())
+}
+
+fn run_verify(db: &Database, epoch: Option<u64>, slots_per_epoch: u64) -> Result<()> {
+    let epochs = match epoch {
+        Some(epoch) => vec![epoch],
+        None => db
+            .list_committee_epochs()?
+            .into_iter()
+            .take(VERIFY_RECENT_EPOCHS)
+            .collect(),
+    };
+    if epochs.is_empty() {
+        info!("No committee snapshots stored yet - run `mvm sync` first");
+    }
+
+    let mut total_issues = 0;
+    for epoch in epochs {
+        let input = db.get_epoch_audit_input(epoch, slots_per_epoch)?;
+        let issues = audit_epoch(&input);
+        if issues.is_empty() {
+            info!(
+                "Epoch {}: ✓ {} seats / committee {}, ours {}, {} attributed blocks",
+                epoch,
+                input.seat_sum,
+                input.recorded_committee_size,
+                input.our_recorded_seats,
+                input.attributed_blocks
+            );
+        }
+        // A data gap rather than an inconsistency: their blocks go to placeholders
+        if input.unmatched_members > 0 {
+            warn!(
+                "Epoch {}: {} committee member(s) ({} seats) not in the candidate list, attributed to unknown:<aura key>",
+                epoch, input.unmatched_members, input.unmatched_seats
+            );
+        }
+        for issue in &issues {
+            error!("Epoch {}: ⚠ {}", epoch, issue);
+        }
+        total_issues += issues.len();
+    }
+
+    // Blocks whose reported epoch went backwards were stored with epoch 0
+    let anomalies = db.get_epoch_anomalies()?;
+    for anomaly in &anomalies {
+        error!(
+            "Block #{}: ⚠ node reported sidechain epoch {}, out of order with #{} in epoch {} (stored with unknown epoch)",
+            anomaly.block_number,
+            anomaly.reported_epoch,
+            anomaly.neighbor_block_number,
+            anomaly.neighbor_epoch
+        );
+    }
+
+    if total_issues > 0 || !anomalies.is_empty() {
+        bail!(
+            "{} seat/block inconsistencies and {} epoch anomalies found",
+            total_issues,
+            anomalies.len()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,226 @@
+//! Export command - dump stored blocks for offline analysis
+
+use super::output::csv_row;
+use crate::db::{BlockRecord, Database};
+use anyhow::{bail, Context, Result};
+use clap::{Args, ValueEnum};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Blocks read from the database per query, so memory stays flat on large exports
+const PAGE_SIZE: u32 = 10_000;
+
+/// Columns written for each block (CSV header, JSON keys)
+const COLUMNS: [&str; 8] = [
+    "block_number",
+    "block_hash",
+    "slot_number",
+    "sidechain_epoch",
+    "timestamp",
+    "is_finalized",
+    "author_key",
+    "extrinsics_count",
+];
+
+/// Export file format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// Newline-delimited JSON, one block per line
+    Json,
+}
+
+/// Export command arguments
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// SQLite database path
+    #[arg(short, long)]
+    pub db_path: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+    pub format: ExportFormat,
+
+    /// First block to export (default: the lowest stored)
+    #[arg(long)]
+    pub from: Option<u64>,
+
+    /// Last block to export (default: the highest stored)
+    #[arg(long)]
+    pub to: Option<u64>,
+
+    /// Write to this file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl ExportArgs {
+    /// Whether the export goes to stdout (console logging must stay off)
+    pub fn is_machine_output(&self) -> bool {
+        self.output.is_none()
+    }
+}
+
+/// Run the export command
+pub async fn run(args: ExportArgs) -> Result<()> {
+    let config = crate::config::Config::load()?;
+    let db_path = args
+        .db_path
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(&config.database.path));
+    let db = Database::open(&db_path)?;
+
+    let from = args.from.unwrap_or(0);
+    let to = match args.to {
+        Some(to) => to,
+        None => db.get_max_block_number()?.unwrap_or(0),
+    };
+    if from > to {
+        bail!("--from {} is after --to {}", from, to);
+    }
+
+    match &args.output {
+        Some(path) => {
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            let mut out = BufWriter::new(file);
+            let exported = export_blocks(&db, from, to, args.format, &mut out)?;
+            out.flush()
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            info!(
+                "Exported {} blocks ({}-{}) to {}",
+                exported,
+                from,
+                to,
+                path.display()
+            );
+        }
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            export_blocks(&db, from, to, args.format, &mut out)?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Write blocks `from..=to` page by page; returns how many were written
+fn export_blocks(
+    db: &Database,
+    from: u64,
+    to: u64,
+    format: ExportFormat,
+    out: &mut impl Write,
+) -> Result<u64> {
+    if format == ExportFormat::Csv {
+        out.write_all(csv_row(&COLUMNS).as_bytes())?;
+    }
+
+    let mut exported = 0;
+    let mut next = from;
+    while next <= to {
+        let page = db.get_blocks_in_range(next, to, Some(PAGE_SIZE))?;
+        let Some(last) = page.last() else {
+            break;
+        };
+        next = last.block_number + 1;
+        for block in &page {
+            match format {
+                ExportFormat::Csv => out.write_all(csv_row(&block_fields(block)).as_bytes())?,
+                ExportFormat::Json => {
+                    serde_json::to_writer(&mut *out, &block_json(block))?;
+                    out.write_all(b"\n")?;
+                }
+            }
+        }
+        exported += page.len() as u64;
+    }
+    Ok(exported)
+}
+
+/// A block's values in `COLUMNS` order
+fn block_fields(block: &BlockRecord) -> [String; 8] {
+    [
+        block.block_number.to_string(),
+        block.block_hash.clone(),
+        block.slot_number.to_string(),
+        block.sidechain_epoch.to_string(),
+        block.timestamp.to_string(),
+        block.is_finalized.to_string(),
+        block.author_key.clone().unwrap_or_default(),
+        block.extrinsics_count.to_string(),
+    ]
+}
+
+fn block_json(block: &BlockRecord) -> serde_json::Value {
+    serde_json::json!({
+        "block_number": block.block_number,
+        "block_hash": block.block_hash,
+        "slot_number": block.slot_number,
+        "sidechain_epoch": block.sidechain_epoch,
+        "timestamp": block.timestamp,
+        "is_finalized": block.is_finalized,
+        "author_key": block.author_key,
+        "extrinsics_count": block.extrinsics_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(number: u64, author_key: Option<&str>) -> BlockRecord {
+        BlockRecord {
+            block_number: number,
+            block_hash: format!("0x{:04x}", number),
+            parent_hash: "0x00".to_string(),
+            state_root: "0x00".to_string(),
+            extrinsics_root: "0x00".to_string(),
+            slot_number: 1000 + number,
+            epoch: 100,
+            sidechain_epoch: 500,
+            timestamp: 1_700_000_000 + number as i64 * 6,
+            is_finalized: number < 3,
+            author_key: author_key.map(String::from),
+            extrinsics_count: 2,
+        }
+    }
+
+    #[test]
+    fn test_export_blocks() {
+        let db = Database::open_in_memory().unwrap();
+        for number in 1..=4 {
+            db.insert_block(&block(number, (number != 2).then_some("0x0a")))
+                .unwrap();
+        }
+
+        let mut csv = Vec::new();
+        assert_eq!(
+            export_blocks(&db, 2, 3, ExportFormat::Csv, &mut csv).unwrap(),
+            2
+        );
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "block_number,block_hash,slot_number,sidechain_epoch,timestamp,is_finalized,author_key,extrinsics_count\n\
+             2,0x0002,1002,500,1700000012,true,,2\n\
+             3,0x0003,1003,500,1700000018,false,0x0a,2\n"
+        );
+
+        let mut json = Vec::new();
+        assert_eq!(
+            export_blocks(&db, 0, 10, ExportFormat::Json, &mut json).unwrap(),
+            4
+        );
+        let lines: Vec<serde_json::Value> = String::from_utf8(json)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1]["author_key"], serde_json::Value::Null);
+        assert_eq!(lines[3]["block_number"], 4);
+    }
+}
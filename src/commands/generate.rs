@@ -0,0 +1,223 @@
+//! Generate command - config artifacts for external tooling
+//!
+//! `mvm generate prometheus-rules` writes Prometheus alerting rules for the
+//! node metrics MVM reads (Substrate on :9615, node_exporter on :9100), using
+//! the `[thresholds]` config so external alerts fire at the same points as
+//! MVM's own health checks.
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Args, Debug)]
+pub struct GenerateArgs {
+    #[command(subcommand)]
+    pub command: GenerateCommands,
+}
+
+impl GenerateArgs {
+    /// Whether this command writes machine-readable output to stdout
+    pub fn is_machine_output(&self) -> bool {
+        match &self.command {
+            GenerateCommands::PrometheusRules { output, .. } => output.is_none(),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum GenerateCommands {
+    /// Prometheus alerting rules using the [thresholds] config
+    PrometheusRules {
+        /// Label matchers added to every metric selector (e.g. 'instance="val1:9615"')
+        #[arg(long)]
+        selector: Option<String>,
+
+        /// Write the rules to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+pub async fn run(args: GenerateArgs) -> Result<()> {
+    match args.command {
+        GenerateCommands::PrometheusRules { selector, output } => {
+            let config = Config::load()?;
+            config.validate()?;
+            let rules = prometheus_rules(&config, selector.as_deref());
+
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, rules)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    println!("Wrote Prometheus rules to {}", path.display());
+                    println!("Add it under rule_files in prometheus.yml and reload Prometheus.");
+                }
+                None => print!("{}", rules),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a metric selector from the metric name, its own matchers and the user's
+fn metric(name: &str, matchers: &[&str], selector: Option<&str>) -> String {
+    let all: Vec<&str> = matchers.iter().copied().chain(selector).collect();
+    if all.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}{{{}}}", name, all.join(","))
+    }
+}
+
+/// Quote a value as a YAML double-quoted string
+fn yaml_str(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Render the alerting rules file
+///
+/// Block production is checked as "no blocks constructed" over two sidechain
+/// epochs: expected blocks depend on committee seats, which Prometheus can't see,
+/// so MVM's percentage-based alert stays the precise check.
+pub fn prometheus_rules(config: &Config, selector: Option<&str>) -> String {
+    let thresholds = &config.thresholds;
+    let selector = selector.map(str::trim).filter(|s| !s.is_empty());
+
+    let node = config.validator.name.clone().unwrap_or_else(|| {
+        hostname::get()
+            .ok()
+            .and_then(|h| h.into_string().ok())
+            .unwrap_or_else(|| "unknown".to_string())
+    });
+    let mut labels = vec![
+        ("node", node),
+        ("network", config.chain.profile_name().to_string()),
+    ];
+    if let Some(label) = &config.validator.label {
+        labels.push(("validator", label.clone()));
+    }
+
+    let no_blocks_minutes = config.chain.timing().sidechain_epoch_ms * 2 / 60_000;
+    let best = metric("substrate_block_height", &[r#"status="best""#], selector);
+    let finalized = metric(
+        "substrate_block_height",
+        &[r#"status="finalized""#],
+        selector,
+    );
+    let peers = metric("substrate_sub_libp2p_peers_count", &[], selector);
+    let constructed = metric("substrate_proposer_block_constructed_count", &[], selector);
+    let mem_available = metric("node_memory_MemAvailable_bytes", &[], selector);
+    let mem_total = metric("node_memory_MemTotal_bytes", &[], selector);
+
+    let rules = [
+        (
+            "MidnightNoBlocksProduced",
+            format!("increase({}[{}m]) == 0", constructed, no_blocks_minutes),
+            "0m".to_string(),
+            "critical",
+            format!(
+                "No blocks produced in {}m (two sidechain epochs); check keys and registration",
+                no_blocks_minutes
+            ),
+        ),
+        (
+            "MidnightFinalizationStall",
+            format!(
+                "({} - ignoring(status) {}) > {}",
+                best, finalized, thresholds.max_finalized_lag
+            ),
+            "5m".to_string(),
+            "critical",
+            format!(
+                "Finalized block trails best block by more than {} blocks",
+                thresholds.max_finalized_lag
+            ),
+        ),
+        (
+            "MidnightPeersCritical",
+            format!("{} < {}", peers, thresholds.peers_crit),
+            format!("{}s", thresholds.peers_alert_after_secs),
+            "critical",
+            format!(
+                "Fewer than {} peers connected (isolation risk)",
+                thresholds.peers_crit
+            ),
+        ),
+        (
+            "MidnightPeersLow",
+            format!("{} < {}", peers, thresholds.peers_warn),
+            "10m".to_string(),
+            "warning",
+            format!("Fewer than {} peers connected", thresholds.peers_warn),
+        ),
+        (
+            "MidnightHighMemory",
+            format!(
+                "(1 - {} / {}) * 100 > {}",
+                mem_available, mem_total, thresholds.memory_warn_percent
+            ),
+            "10m".to_string(),
+            "warning",
+            format!(
+                "Host memory usage above {}%",
+                thresholds.memory_warn_percent
+            ),
+        ),
+    ];
+
+    let mut out = String::new();
+    out.push_str("# Prometheus alerting rules generated by `mvm generate prometheus-rules`\n");
+    out.push_str("# Thresholds come from the [thresholds] config; regenerate after changing it.\n");
+    out.push_str("groups:\n");
+    out.push_str("  - name: midnight-validator\n");
+    out.push_str("    rules:\n");
+    for (name, expr, for_duration, severity, summary) in rules {
+        out.push_str(&format!("      - alert: {}\n", name));
+        out.push_str(&format!("        expr: {}\n", yaml_str(&expr)));
+        out.push_str(&format!("        for: {}\n", for_duration));
+        out.push_str("        labels:\n");
+        out.push_str(&format!("          severity: {}\n", severity));
+        for (key, value) in &labels {
+            out.push_str(&format!("          {}: {}\n", key, yaml_str(value)));
+        }
+        out.push_str("        annotations:\n");
+        out.push_str(&format!("          summary: {}\n", yaml_str(&summary)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prometheus_rules() {
+        let mut config = Config::default();
+        config.thresholds.max_finalized_lag = 50;
+        config.thresholds.peers_crit = 2;
+        config.thresholds.peers_alert_after_secs = 300;
+        config.validator.name = Some("val-1".to_string());
+        config.validator.label = Some("My \"main\" validator".to_string());
+
+        let rules = prometheus_rules(&config, Some(r#"instance="val1:9615""#));
+        assert!(rules.contains(r#"ignoring(status) substrate_block_height{status=\"finalized\",instance=\"val1:9615\"}) > 50"#));
+        assert!(rules.contains(r#"substrate_sub_libp2p_peers_count{instance=\"val1:9615\"} < 2""#));
+        assert!(rules.contains("for: 300s"));
+        // Preview: two 2h sidechain epochs
+        assert!(rules.contains("[240m]) == 0"));
+        assert!(rules.contains(r#"node: "val-1""#));
+        assert!(rules.contains(r#"validator: "My \"main\" validator""#));
+        assert!(rules.contains(r#"network: "preview""#));
+        assert_eq!(rules.matches("- alert:").count(), 5);
+
+        // The chain profile names the network, not the legacy chain.network
+        config.chain.profile = Some("mainnet".to_string());
+        assert!(prometheus_rules(&config, None).contains(r#"network: "mainnet""#));
+
+        // No selector: bare metric names
+        let rules = prometheus_rules(&config, None);
+        assert!(rules.contains(r#"expr: "substrate_sub_libp2p_peers_count < 2""#));
+    }
+}
@@ -1,8 +1,17 @@
 //! Troubleshooting guide command
 //!
-//! Provides built-in documentation for common validator issues.
+//! Provides built-in documentation for common validator issues, plus an
+//! interactive first-run wizard (`mvm guide wizard`) that detects the node
+//! and writes a validated config file.
 
+use crate::config::Config;
+use crate::metrics::{MetricsClient, NodeExporterClient};
+use crate::midnight::ValidatorKeys;
+use crate::rpc::{PoolConfig, RetryConfig, RpcClient};
+use anyhow::{bail, Context, Result};
 use clap::{Args, Subcommand};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Args, Debug)]
 pub struct GuideArgs {
@@ -24,6 +33,8 @@ pub enum GuideTopic {
     Keys,
     /// General setup and configuration guide
     Setup,
+    /// Interactive first-run wizard that writes a config file
+    Wizard,
     /// List all available topics
     List,
 }
@@ -36,6 +47,7 @@ pub async fn run(args: GuideArgs) -> anyhow::Result<()> {
         Some(GuideTopic::Memory) => print_memory_guide(),
         Some(GuideTopic::Keys) => print_keys_guide(),
         Some(GuideTopic::Setup) => print_setup_guide(),
+        Some(GuideTopic::Wizard) => return run_wizard(&mut io::stdin().lock()).await,
         Some(GuideTopic::List) | None => print_topic_list(),
     }
     Ok(())
@@ -53,6 +65,7 @@ fn print_topic_list() {
     println!("  mvm guide memory          Memory leaks and high usage");
     println!("  mvm guide keys            Session key issues");
     println!("  mvm guide setup           Initial validator setup");
+    println!("  mvm guide wizard          Interactive first-run configuration");
     println!();
     println!("Use 'mvm guide <topic>' for detailed troubleshooting steps.");
 }
@@ -369,3 +382,296 @@ fn print_setup_guide() {
     println!("  - Keys: all loaded");
     println!("  - Node: synced");
 }
+
+/// Local RPC endpoints tried when detecting the node
+const RPC_CANDIDATES: &[&str] = &[
+    "http://localhost:9944",
+    "http://localhost:9933",
+    "http://localhost:9945",
+];
+
+/// Directories searched for `chains/<chain>/keystore`
+const NODE_BASE_DIRS: &[&str] = &["/opt/midnight/data", "/data", "/var/lib/midnight"];
+
+/// Per-endpoint timeout while probing, so a missing node fails fast
+const PROBE_TIMEOUT_MS: u64 = 2000;
+
+/// Interactive first-run wizard
+///
+/// Detects the node, locates the keystore, checks the metrics endpoints,
+/// then writes a config file that is validated and re-loaded before the
+/// wizard reports success.
+async fn run_wizard(input: &mut impl BufRead) -> Result<()> {
+    println!("MVM Setup Wizard");
+    println!("================");
+    println!("Press Enter to accept the [default] shown for each question.");
+    println!();
+
+    let mut config = Config::default();
+
+    // 1. Node RPC
+    println!("Step 1/4: Node RPC");
+    let mut detected = None;
+    for url in RPC_CANDIDATES {
+        match probe_rpc(url).await {
+            Ok(chain) => {
+                println!("  ✓ Found node at {} (chain: {})", url, chain);
+                detected = Some(url.to_string());
+                break;
+            }
+            Err(_) => println!("  ✗ No node at {}", url),
+        }
+    }
+    let default_url = detected.unwrap_or_else(|| config.rpc.url.clone());
+    loop {
+        let url = prompt(input, "  RPC URL", &default_url)?;
+        match probe_rpc(&url).await {
+            Ok(chain) => {
+                println!("  ✓ Connected (chain: {})", chain);
+                config.rpc.url = url;
+                break;
+            }
+            Err(e) => {
+                println!("  ✗ {}", e);
+                if confirm(input, "  Use it anyway?", false)? {
+                    config.rpc.url = url;
+                    break;
+                }
+            }
+        }
+    }
+    println!();
+
+    // 2. Keystore
+    println!("Step 2/4: Keystore (optional, for key and registration checks)");
+    let found = find_keystores(NODE_BASE_DIRS.iter().map(Path::new));
+    for path in &found {
+        println!("  Found keystore: {}", path.display());
+    }
+    let default_keystore = found
+        .first()
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+    loop {
+        let path = prompt(input, "  Keystore path (empty to skip)", &default_keystore)?;
+        if path.is_empty() {
+            break;
+        }
+        match ValidatorKeys::from_keystore(Path::new(&path)) {
+            Ok(keys) => {
+                println!("  ✓ Sidechain key {}", keys.sidechain_short());
+                config.validator.keystore_path = Some(path);
+                break;
+            }
+            Err(e) => println!("  ✗ {:#}", e),
+        }
+    }
+    println!();
+
+    // 3. Metrics endpoints
+    println!("Step 3/4: Metrics");
+    let metrics_url = prompt(
+        input,
+        "  Node Prometheus metrics URL",
+        &config.rpc.metrics_url,
+    )?;
+    match MetricsClient::new(&metrics_url).fetch_metrics().await {
+        Ok(_) => println!("  ✓ Metrics reachable"),
+        Err(e) => println!(
+            "  ✗ {:#} (bandwidth and uptime stats will be unavailable)",
+            e
+        ),
+    }
+    config.rpc.metrics_url = metrics_url;
+
+    let node_exporter_url = prompt(
+        input,
+        "  node_exporter URL (empty to skip)",
+        "http://localhost:9100/metrics",
+    )?;
+    if !node_exporter_url.is_empty() {
+        match NodeExporterClient::new(&node_exporter_url)
+            .fetch_metrics()
+            .await
+        {
+            Ok(_) => {
+                println!("  ✓ node_exporter reachable");
+                config.rpc.node_exporter_url = Some(node_exporter_url);
+            }
+            Err(e) => {
+                println!("  ✗ {:#}", e);
+                if confirm(input, "  Keep it in the config anyway?", false)? {
+                    config.rpc.node_exporter_url = Some(node_exporter_url);
+                }
+            }
+        }
+    }
+    println!();
+
+    // 4. Database and config file
+    println!("Step 4/4: Write configuration");
+    config.database.path = prompt(input, "  Database path", &config.database.path)?;
+    config.validate()?;
+
+    let default_path = default_config_path();
+    let path = PathBuf::from(prompt(
+        input,
+        "  Config file",
+        &default_path.display().to_string(),
+    )?);
+    if path.exists() && !confirm(input, "  File exists. Overwrite?", false)? {
+        bail!("Aborted: {} left unchanged", path.display());
+    }
+    write_config(&config, &path)?;
+    println!("  ✓ Wrote {}", path.display());
+    println!();
+
+    println!("Setup complete. Next steps:");
+    println!();
+    println!(
+        "  mvm sync --daemon       # sync blocks into the database (or: systemctl start mvm-sync)"
+    );
+    println!("  mvm view                # open the TUI");
+    if config.validator.keystore_path.is_some() {
+        println!("  mvm keys verify         # confirm keys are loaded and registered");
+    }
+    if Config::config_file_paths().first() == Some(&path) {
+        println!();
+        println!("Note: ./mvm.toml is only found when mvm runs from this directory.");
+    }
+
+    Ok(())
+}
+
+/// Check that a JSON-RPC endpoint answers, returning the chain name
+async fn probe_rpc(url: &str) -> Result<String> {
+    let rpc = RpcClient::with_options(
+        url,
+        PROBE_TIMEOUT_MS,
+        RetryConfig {
+            max_retries: 0,
+            ..RetryConfig::default()
+        },
+        PoolConfig::default(),
+    );
+    rpc.call("system_chain", Vec::<()>::new())
+        .await
+        .with_context(|| format!("No JSON-RPC response from {}", url))
+}
+
+/// Find `<base>/chains/<chain>/keystore` directories
+fn find_keystores<'a>(bases: impl Iterator<Item = &'a Path>) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for base in bases {
+        let Ok(chains) = std::fs::read_dir(base.join("chains")) else {
+            continue;
+        };
+        let mut keystores: Vec<PathBuf> = chains
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().join("keystore"))
+            .filter(|path| path.is_dir())
+            .collect();
+        keystores.sort();
+        found.extend(keystores);
+    }
+    found
+}
+
+/// First writable-looking location from the config search paths
+///
+/// Prefers the user config directory; falls back to ./mvm.toml.
+fn default_config_path() -> PathBuf {
+    let paths = Config::config_file_paths();
+    paths
+        .get(1)
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from("./mvm.toml"))
+}
+
+/// Write a config file and check it loads back
+fn write_config(config: &Config, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let contents = format!(
+        "# Generated by `mvm guide wizard`\n# See `mvm config example` for every option\n\n{}",
+        toml::to_string_pretty(config)?
+    );
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Config::load_file(path)?.validate()
+}
+
+/// Ask a question, returning the trimmed answer or `default` when empty
+fn prompt(input: &mut impl BufRead, question: &str, default: &str) -> Result<String> {
+    if default.is_empty() {
+        print!("{}: ", question);
+    } else {
+        print!("{} [{}]: ", question, default);
+    }
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        bail!("Input closed");
+    }
+    let answer = line.trim();
+    Ok(if answer.is_empty() {
+        default.to_string()
+    } else {
+        answer.to_string()
+    })
+}
+
+/// Ask a yes/no question
+fn confirm(input: &mut impl BufRead, question: &str, default_yes: bool) -> Result<bool> {
+    let default = if default_yes { "Y/n" } else { "y/N" };
+    let answer = prompt(input, question, default)?;
+    Ok(match answer.to_lowercase().as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default_yes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_prompt_defaults() {
+        let mut input = Cursor::new("\n  custom  \nyes\n\n");
+        assert_eq!(prompt(&mut input, "q", "def").unwrap(), "def");
+        assert_eq!(prompt(&mut input, "q", "def").unwrap(), "custom");
+        assert!(confirm(&mut input, "q", false).unwrap());
+        assert!(confirm(&mut input, "q", true).unwrap());
+        // EOF aborts instead of looping forever on defaults
+        assert!(prompt(&mut input, "q", "def").is_err());
+    }
+
+    #[test]
+    fn test_find_keystores_and_write_config() {
+        let dir = std::env::temp_dir().join(format!("mvm-wizard-{}", std::process::id()));
+        let keystore = dir.join("chains/testnet-02/keystore");
+        std::fs::create_dir_all(&keystore).unwrap();
+        std::fs::create_dir_all(dir.join("chains/other")).unwrap();
+
+        let found = find_keystores([dir.as_path(), Path::new("/nonexistent")].into_iter());
+        assert_eq!(found, vec![keystore.clone()]);
+
+        let mut config = Config::default();
+        config.validator.keystore_path = Some(keystore.display().to_string());
+        let path = dir.join("config/config.toml");
+        write_config(&config, &path).unwrap();
+        let loaded = Config::load_file(&path).unwrap();
+        assert_eq!(
+            loaded.validator.keystore_path,
+            config.validator.keystore_path
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -319,6 +319,7 @@ User={}
 WorkingDirectory={}
 Environment="MVM_DB_PATH={}/mvm.db"
 ExecStart={}/mvm sync --daemon --pid-file {}/mvm-sync.pid
+ExecReload=/bin/kill -HUP $MAINPID
 Restart=on-failure
 RestartSec=10s
 StandardOutput=journal
@@ -1,7 +1,7 @@
 //! Keys command - verify and manage session keys
 
 use crate::db::{Database, ValidatorRecord};
-use crate::midnight::{get_key_status, ValidatorKeys};
+use crate::midnight::{get_key_status, KeyMismatch, ValidatorKeys, ValidatorSet};
 use crate::rpc::RpcClient;
 use anyhow::Result;
 use clap::{Args, Subcommand};
@@ -221,6 +221,27 @@ async fn run_verify(
         }
     }
 
+    info!("");
+    info!("On-chain Key Match:");
+    info!("─────────────────────────────────────────────────────────────────────────────");
+
+    // Catches a botched rotation: node signs with keys the chain no longer expects
+    let mismatches = match ValidatorSet::fetch_candidates(&rpc, current_epoch).await {
+        Ok(candidates) => {
+            let mismatches = keys.registration_mismatches(&candidates);
+            if mismatches.is_empty() {
+                info!("  ✓ Keystore keys match the on-chain registration");
+            } else {
+                log_key_mismatches(&mismatches);
+            }
+            mismatches
+        }
+        Err(e) => {
+            info!("  ? Unable to fetch on-chain registration: {}", e);
+            Vec::new()
+        }
+    };
+
     info!("");
 
     // Summary
@@ -234,7 +255,9 @@ async fn run_verify(
             | Some(crate::midnight::RegistrationStatus::RegisteredValid)
     );
 
-    if all_loaded && is_registered {
+    if !mismatches.is_empty() {
+        error!("Summary: Keystore keys differ from on-chain registration - no blocks will be produced!");
+    } else if all_loaded && is_registered {
         info!("Summary: ✓ All keys loaded and registered");
     } else if all_loaded {
         warn!("Summary: Keys loaded but registration issue detected");
@@ -358,3 +381,14 @@ async fn run_verify(
 
     Ok(())
 }
+
+/// Log each keystore key that differs from the on-chain registration
+fn log_key_mismatches(mismatches: &[KeyMismatch]) {
+    for m in mismatches {
+        error!("  ✗ {} key differs from on-chain registration", m.key_type);
+        error!("    Keystore: {}", m.local);
+        error!("    On-chain: {}", m.on_chain);
+    }
+    error!("  The node will sign with keys the chain does not expect.");
+    error!("  Finish the key rotation (re-register) or restore the registered keys.");
+}
@@ -1,15 +1,22 @@
 //! MVM commands
 
 pub mod config;
+pub mod db;
+pub mod export;
+pub mod generate;
 pub mod guide;
 pub mod install;
 pub mod keys;
+pub mod output;
 pub mod query;
 pub mod status;
 pub mod sync;
 pub mod view;
 
 pub use config::ConfigArgs;
+pub use db::{BackfillAuthorsArgs, DbArgs};
+pub use export::ExportArgs;
+pub use generate::GenerateArgs;
 pub use guide::GuideArgs;
 pub use install::InstallArgs;
 pub use keys::KeysArgs;
@@ -0,0 +1,198 @@
+//! Shared output formats for CLI commands
+//!
+//! Commands with a `--format` flag build a report implementing [`Render`] and
+//! hand it to an [`OutputWriter`], which picks the representation and the
+//! destination. Text printed to the terminal goes through the log like the
+//! rest of MVM's console output; JSON and CSV go to stdout bare so they can be
+//! piped. With `--out <file>` every format is written plain to the file.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use std::path::PathBuf;
+use tracing::info;
+
+/// Output format for command results
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    Text,
+    /// Machine-readable JSON
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+impl OutputFormat {
+    /// Whether this format is meant for other tools (console logging must stay off)
+    pub fn is_machine(self) -> bool {
+        self != OutputFormat::Text
+    }
+}
+
+/// A command result that can be rendered in every [`OutputFormat`]
+pub trait Render {
+    /// Human-readable lines; `color` allows ANSI styling
+    fn text(&self, color: bool) -> Vec<String>;
+
+    /// JSON document
+    fn json(&self) -> serde_json::Value;
+
+    /// CSV header and rows
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>);
+}
+
+/// Writes rendered results to stdout or the `--out` file
+#[derive(Debug, Clone)]
+pub struct OutputWriter {
+    format: OutputFormat,
+    out: Option<PathBuf>,
+    color: bool,
+}
+
+impl OutputWriter {
+    pub fn new(format: OutputFormat, out: Option<PathBuf>, color: bool) -> Self {
+        Self { format, out, color }
+    }
+
+    /// Render `output` in the writer's format (files never get color)
+    pub fn render(&self, output: &impl Render) -> Result<String> {
+        Ok(match self.format {
+            OutputFormat::Text => {
+                let mut text = output.text(self.color && self.out.is_none()).join("\n");
+                text.push('\n');
+                text
+            }
+            OutputFormat::Json => {
+                let mut json = serde_json::to_string_pretty(&output.json())?;
+                json.push('\n');
+                json
+            }
+            OutputFormat::Csv => {
+                let (header, rows) = output.csv();
+                csv_table(&header, &rows)
+            }
+        })
+    }
+
+    /// Render `output` and write it out
+    pub fn write(&self, output: &impl Render) -> Result<()> {
+        match (&self.out, self.format) {
+            (Some(path), _) => {
+                std::fs::write(path, self.render(output)?)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                info!("Wrote {}", path.display());
+            }
+            (None, OutputFormat::Text) => {
+                for line in output.text(self.color) {
+                    info!("{}", line);
+                }
+            }
+            (None, _) => print!("{}", self.render(output)?),
+        }
+        Ok(())
+    }
+}
+
+/// CSV document with a header row
+pub fn csv_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&csv_row(row));
+    }
+    csv
+}
+
+/// One CSV line, fields quoted as needed
+pub fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    let fields: Vec<String> = fields.iter().map(|f| csv_field(f.as_ref())).collect();
+    let mut line = fields.join(",");
+    line.push('\n');
+    line
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Optional value as a CSV field (empty when missing)
+pub fn opt_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Pairs(Vec<(&'static str, u64)>);
+
+    impl Render for Pairs {
+        fn text(&self, color: bool) -> Vec<String> {
+            let mark = if color { "\x1b[1m*\x1b[0m" } else { "*" };
+            self.0
+                .iter()
+                .map(|(name, value)| format!("{} {}: {}", mark, name, value))
+                .collect()
+        }
+
+        fn json(&self) -> serde_json::Value {
+            serde_json::json!(self
+                .0
+                .iter()
+                .map(|(name, value)| serde_json::json!({"name": name, "value": value}))
+                .collect::<Vec<_>>())
+        }
+
+        fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+            let rows = self
+                .0
+                .iter()
+                .map(|(name, value)| vec![name.to_string(), value.to_string()])
+                .collect();
+            (vec!["name", "value"], rows)
+        }
+    }
+
+    #[test]
+    fn test_output_writer_render() {
+        let pairs = Pairs(vec![("a", 1), ("b, \"c\"", 2)]);
+        let render = |format, out: Option<&str>, color| {
+            OutputWriter::new(format, out.map(PathBuf::from), color)
+                .render(&pairs)
+                .unwrap()
+        };
+
+        assert_eq!(
+            render(OutputFormat::Text, None, false),
+            "* a: 1\n* b, \"c\": 2\n"
+        );
+        assert!(render(OutputFormat::Text, None, true).contains("\x1b[1m"));
+        // Never color into files
+        assert!(!render(OutputFormat::Text, Some("out.txt"), true).contains('\x1b'));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(OutputFormat::Json, None, true)).unwrap();
+        assert_eq!(json[1]["value"], 2);
+
+        assert_eq!(
+            render(OutputFormat::Csv, None, true),
+            "name,value\na,1\n\"b, \"\"c\"\"\",2\n"
+        );
+        assert!(OutputFormat::Csv.is_machine() && !OutputFormat::Text.is_machine());
+    }
+
+    #[test]
+    fn test_output_writer_out_file() {
+        let path = std::env::temp_dir().join(format!("mvm-output-{}.csv", std::process::id()));
+        OutputWriter::new(OutputFormat::Csv, Some(path.clone()), true)
+            .write(&Pairs(vec![("a", 1)]))
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "name,value\na,1\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,10 +1,17 @@
 //! Query command - query stored block data
 
-use crate::db::Database;
+use super::output::{opt_field, OutputFormat, OutputWriter, Render};
+use crate::config::DisplayTimeZone;
+use crate::db::{
+    explain_busy, BlockRecord, Database, NetworkSample, SyncSample, ValidatorEpochRecord,
+    ValidatorRecord,
+};
 use crate::midnight::known_validators::KnownValidators;
+use crate::midnight::{project_seats, SeatProjection};
 use anyhow::{bail, Result};
 use clap::{Args, Subcommand};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
 /// Query command arguments
@@ -14,14 +21,29 @@ pub struct QueryArgs {
     #[arg(short, long)]
     pub db_path: Option<PathBuf>,
 
+    /// Write results to a file instead of stdout
+    #[arg(long, global = true)]
+    pub out: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: QueryCommands,
 }
 
+impl QueryArgs {
+    /// Whether this query writes machine-readable output to stdout
+    pub fn is_machine_output(&self) -> bool {
+        self.out.is_none() && self.command.format().is_some_and(OutputFormat::is_machine)
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum QueryCommands {
     /// Show database statistics
-    Stats,
+    Stats {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
 
     /// List blocks in a range
     Blocks {
@@ -33,13 +55,70 @@ pub enum QueryCommands {
         #[arg(short, long)]
         to: Option<u64>,
 
+        /// Start time instead of a block number: RFC3339 or relative (-24h, -30m, -7d)
+        #[arg(long, conflicts_with_all = ["from", "to"], allow_hyphen_values = true)]
+        from_time: Option<String>,
+
+        /// End time (default: now), same formats as --from-time
+        #[arg(long, requires = "from_time", allow_hyphen_values = true)]
+        to_time: Option<String>,
+
         /// Number of blocks to show (default: 10)
         #[arg(short, long, default_value_t = 10)]
         limit: u64,
     },
 
     /// Find gaps in synced blocks
-    Gaps,
+    Gaps {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Show a stored committee snapshot
+    Committee {
+        /// Sidechain epoch (default: latest snapshot)
+        #[arg(short, long)]
+        epoch: Option<u64>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Compare two stored committee snapshots: who joined, left or changed seats
+    CommitteeDiff {
+        /// Earlier sidechain epoch
+        #[arg(long)]
+        from: u64,
+
+        /// Later sidechain epoch
+        #[arg(long)]
+        to: u64,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Estimate committee seats a given stake would earn (what-if)
+    Project {
+        /// Stake to project, in ADA
+        #[arg(long)]
+        stake: f64,
+
+        /// Validator sidechain key whose current stake the projection replaces
+        #[arg(long)]
+        key: Option<String>,
+
+        /// Sidechain epoch whose stake distribution to use (default: latest snapshot)
+        #[arg(short, long)]
+        epoch: Option<u64>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
 
     /// List all validators with statistics
     Validators {
@@ -68,10 +147,48 @@ pub enum QueryCommands {
         #[arg(short, long, default_value_t = 10)]
         limit: u64,
     },
+
+    /// Show peer count and bandwidth history recorded by `mvm sync`
+    NetworkHistory {
+        /// Hours of history to show (default: 24)
+        #[arg(long, default_value_t = 24)]
+        hours: u64,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Show sync throughput history (blocks/s, time per block, lag) recorded by `mvm sync`
+    SyncHistory {
+        /// Days of history to show (default: 7)
+        #[arg(long, default_value_t = 7)]
+        days: u64,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+}
+
+impl QueryCommands {
+    /// The `--format` of subcommands that have one
+    fn format(&self) -> Option<OutputFormat> {
+        match self {
+            QueryCommands::Stats { format }
+            | QueryCommands::Gaps { format }
+            | QueryCommands::Committee { format, .. }
+            | QueryCommands::CommitteeDiff { format, .. }
+            | QueryCommands::Project { format, .. }
+            | QueryCommands::NetworkHistory { format, .. }
+            | QueryCommands::SyncHistory { format, .. } => Some(*format),
+            _ => None,
+        }
+    }
 }
 
 /// Run the query command
-pub async fn run(args: QueryArgs) -> Result<()> {
+pub async fn run(args: QueryArgs, color: bool) -> Result<()> {
     // Load configuration
     let config = crate::config::Config::load()?;
 
@@ -85,111 +202,275 @@ pub async fn run(args: QueryArgs) -> Result<()> {
     // Load known validators for labels
     let known_validators = KnownValidators::load();
 
-    match args.command {
-        QueryCommands::Stats => run_stats(&db)?,
-        QueryCommands::Blocks { from, to, limit } => run_blocks(&db, from, to, limit)?,
-        QueryCommands::Gaps => run_gaps(&db)?,
+    let writer = |format| OutputWriter::new(format, args.out.clone(), color);
+    let result = match args.command {
+        QueryCommands::Stats { format } => {
+            run_stats(&db, &db_path, config.display.time_zone(), &writer(format))
+        }
+        QueryCommands::Blocks {
+            from,
+            to,
+            from_time,
+            to_time,
+            limit,
+        } => {
+            let time_range = from_time.as_deref().map(|f| (f, to_time.as_deref()));
+            run_blocks(&db, from, to, time_range, config.display.time_zone(), limit)
+        }
+        QueryCommands::Gaps { format } => run_gaps(&db, &writer(format)),
+        QueryCommands::Committee { epoch, format } => {
+            run_committee(&db, epoch, &writer(format), &known_validators)
+        }
+        QueryCommands::CommitteeDiff { from, to, format } => {
+            run_committee_diff(&db, from, to, &writer(format), &known_validators)
+        }
+        QueryCommands::Project {
+            stake,
+            key,
+            epoch,
+            format,
+        } => run_project(&db, stake, key.as_deref(), epoch, &writer(format)),
         QueryCommands::Validators { ours, limit } => {
-            run_validators(&db, ours, limit, &known_validators)?
+            run_validators(&db, ours, limit, &known_validators)
         }
-        QueryCommands::Validator { key } => run_validator(&db, &key, &known_validators)?,
+        QueryCommands::Validator { key } => run_validator(&db, &key, &known_validators),
         QueryCommands::Performance { ours, limit } => {
-            run_performance(&db, ours, limit, &known_validators)?
+            run_performance(&db, ours, limit, &known_validators)
         }
-    }
+        QueryCommands::NetworkHistory { hours, format } => {
+            run_network_history(&db, hours, config.display.time_zone(), &writer(format))
+        }
+        QueryCommands::SyncHistory { days, format } => {
+            run_sync_history(&db, days, config.display.time_zone(), &writer(format))
+        }
+    };
 
-    Ok(())
+    result.map_err(explain_busy)
 }
 
-fn run_stats(db: &Database) -> Result<()> {
+fn run_stats(
+    db: &Database,
+    db_path: &Path,
+    time_zone: DisplayTimeZone,
+    writer: &OutputWriter,
+) -> Result<()> {
     let total_blocks = db.count_blocks()?;
-    let finalized_blocks = db.count_finalized_blocks()?;
-    let sync_status = db.get_sync_status()?;
-
-    info!("Database Statistics");
-    info!("─────────────────────────────────────────");
-    info!("Total blocks:     {}", total_blocks);
-    info!("Finalized blocks: {}", finalized_blocks);
-    info!("Unfinalized:      {}", total_blocks - finalized_blocks);
-
-    if let Some(min) = db.get_block(sync_status.last_synced_block)? {
-        info!("─────────────────────────────────────────");
-        info!("Latest synced:    Block #{}", min.block_number);
-        info!("  Slot:           {}", min.slot_number);
-        info!("  Epoch:          {}", min.epoch);
-    }
-
-    if let Some(max_block) = db.get_max_block_number()? {
-        if let Some(min_block) = db
-            .get_blocks_in_range(max_block.saturating_sub(1000), max_block, Some(1))?
-            .first()
-        {
-            info!("─────────────────────────────────────────");
-            info!(
-                "Block range:      {} - {}",
-                min_block.block_number, max_block
-            );
-        }
-    }
+    let attributed_blocks = db.count_attributed_blocks()?;
 
-    // Check for gaps
-    let gaps = db.find_gaps()?;
-    if gaps.is_empty() {
-        info!("Gaps:             None (continuous)");
-    } else {
-        info!("Gaps:             {} gap(s) detected", gaps.len());
-    }
+    // WAL contents count too: recent writes live there until checkpointed
+    let file_size = |path: &Path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
 
-    // Validator statistics
-    let total_validators = db.count_validators()?;
-    let our_validators = db.count_our_validators()?;
+    let last_block = db.get_max_block_number()?;
+    let newest = match last_block {
+        Some(number) => db.get_block(number)?,
+        None => None,
+    };
 
-    if total_validators > 0 {
-        info!("─────────────────────────────────────────");
-        info!("Validators:       {}", total_validators);
-        if our_validators > 0 {
-            info!("  Ours:           {}", our_validators);
-        }
+    writer.write(&StatsReport {
+        total_blocks,
+        finalized_blocks: db.count_finalized_blocks()?,
+        first_block: db.get_min_block_number()?,
+        last_block,
+        gap_count: db.find_gaps()?.len(),
+        validators: db.count_validators()?,
+        our_validators: db.count_our_validators()?,
+        snapshot_epochs: db.count_snapshot_epochs()?,
+        attributed_blocks,
+        attribution_pct: if total_blocks > 0 {
+            attributed_blocks as f64 / total_blocks as f64 * 100.0
+        } else {
+            0.0
+        },
+        db_size_bytes: file_size(db_path) + file_size(Path::new(&wal_path)),
+        newest_age_secs: newest
+            .as_ref()
+            .map(|b| (chrono::Utc::now().timestamp() - b.timestamp).max(0)),
+        newest,
+        time_zone,
+    })
+}
 
-        // Show blocks with author attribution
-        let blocks_with_authors = db
-            .get_blocks_in_range(0, u64::MAX, None)?
-            .iter()
-            .filter(|b| b.author_key.is_some())
-            .count();
+/// `query stats` result
+struct StatsReport {
+    total_blocks: u64,
+    finalized_blocks: u64,
+    first_block: Option<u64>,
+    last_block: Option<u64>,
+    gap_count: usize,
+    validators: u64,
+    our_validators: u64,
+    snapshot_epochs: u64,
+    attributed_blocks: u64,
+    attribution_pct: f64,
+    db_size_bytes: u64,
+    newest: Option<BlockRecord>,
+    newest_age_secs: Option<i64>,
+    time_zone: DisplayTimeZone,
+}
 
-        if blocks_with_authors > 0 {
-            let attribution_pct = (blocks_with_authors as f64 / total_blocks as f64) * 100.0;
-            info!(
-                "  Blocks with author: {} ({:.1}%)",
-                blocks_with_authors, attribution_pct
-            );
+impl Render for StatsReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        let mut lines = vec![
+            "Database Statistics".to_string(),
+            "─────────────────────────────────────────".to_string(),
+            format!("Total blocks:     {}", self.total_blocks),
+            format!("Finalized blocks: {}", self.finalized_blocks),
+            format!(
+                "Unfinalized:      {}",
+                self.total_blocks - self.finalized_blocks
+            ),
+            format!(
+                "DB size:          {:.1} MB",
+                self.db_size_bytes as f64 / (1024.0 * 1024.0)
+            ),
+        ];
+
+        if let (Some(first), Some(last)) = (self.first_block, self.last_block) {
+            lines.push("─────────────────────────────────────────".to_string());
+            lines.push(format!("Block range:      {} - {}", first, last));
+            if self.gap_count == 0 {
+                lines.push("Gaps:             None (continuous)".to_string());
+            } else {
+                lines.push(format!(
+                    "Gaps:             {} gap(s) detected",
+                    self.gap_count
+                ));
+            }
+        }
+
+        if let (Some(block), Some(age)) = (&self.newest, self.newest_age_secs) {
+            lines.push(format!("Newest block:     #{}", block.block_number));
+            lines.push(format!("  Slot:           {}", block.slot_number));
+            lines.push(format!("  Epoch:          {}", block.epoch));
+            lines.push(format!(
+                "  Time:           {}",
+                self.time_zone.format(block.timestamp)
+            ));
+            lines.push(format!("  Age:            {}", format_age(age)));
         }
+
+        lines.push("─────────────────────────────────────────".to_string());
+        lines.push(format!("Validators:       {}", self.validators));
+        lines.push(format!("  Ours:           {}", self.our_validators));
+        lines.push(format!("Snapshot epochs:  {}", self.snapshot_epochs));
+        lines.push(format!(
+            "Attributed:       {} ({:.1}%)",
+            self.attributed_blocks, self.attribution_pct
+        ));
+        lines
     }
 
-    Ok(())
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "total_blocks": self.total_blocks,
+            "finalized_blocks": self.finalized_blocks,
+            "first_block": self.first_block,
+            "last_block": self.last_block,
+            "gap_count": self.gap_count,
+            "validators": self.validators,
+            "our_validators": self.our_validators,
+            "snapshot_epochs": self.snapshot_epochs,
+            "attributed_blocks": self.attributed_blocks,
+            "attribution_percent": self.attribution_pct,
+            "db_size_bytes": self.db_size_bytes,
+            "newest_block_timestamp": self.newest.as_ref().map(|b| b.timestamp),
+            "newest_block_age_secs": self.newest_age_secs,
+        })
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let header = vec![
+            "total_blocks",
+            "finalized_blocks",
+            "first_block",
+            "last_block",
+            "gap_count",
+            "validators",
+            "our_validators",
+            "snapshot_epochs",
+            "attributed_blocks",
+            "attribution_percent",
+            "db_size_bytes",
+            "newest_block_timestamp",
+            "newest_block_age_secs",
+        ];
+        let row = vec![
+            self.total_blocks.to_string(),
+            self.finalized_blocks.to_string(),
+            opt_field(self.first_block),
+            opt_field(self.last_block),
+            self.gap_count.to_string(),
+            self.validators.to_string(),
+            self.our_validators.to_string(),
+            self.snapshot_epochs.to_string(),
+            self.attributed_blocks.to_string(),
+            format!("{:.1}", self.attribution_pct),
+            self.db_size_bytes.to_string(),
+            opt_field(self.newest.as_ref().map(|b| b.timestamp)),
+            opt_field(self.newest_age_secs),
+        ];
+        (header, vec![row])
+    }
 }
 
-fn run_blocks(db: &Database, from: Option<u64>, to: Option<u64>, limit: u64) -> Result<()> {
-    let (start, end) = match (from, to) {
-        (Some(f), Some(t)) => (f, t),
-        (Some(f), None) => (f, f + limit - 1),
-        (None, Some(t)) => (t.saturating_sub(limit - 1), t),
-        (None, None) => {
-            // Show most recent blocks
-            let max = db.get_max_block_number()?.unwrap_or(0);
-            (max.saturating_sub(limit - 1), max)
+/// Format an age in seconds as the largest whole unit (e.g. "42s ago", "3h ago")
+fn format_age(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("{}s ago", s),
+        s if s < 3600 => format!("{}m ago", s / 60),
+        s if s < 86400 => format!("{}h ago", s / 3600),
+        s => format!("{}d ago", s / 86400),
+    }
+}
+
+fn run_blocks(
+    db: &Database,
+    from: Option<u64>,
+    to: Option<u64>,
+    time_range: Option<(&str, Option<&str>)>,
+    time_zone: DisplayTimeZone,
+    limit: u64,
+) -> Result<()> {
+    let (blocks, range) = if let Some((from_time, to_time)) = time_range {
+        let now = chrono::Utc::now().timestamp();
+        let from_ts = parse_time(from_time, now)?;
+        let to_ts = to_time
+            .map(|t| parse_time(t, now))
+            .transpose()?
+            .unwrap_or(now);
+        if from_ts > to_ts {
+            bail!("--from-time must be before --to-time");
         }
+        let blocks = db.get_blocks_in_time_range(from_ts, to_ts, Some(limit as u32 + 1))?;
+        let range = format!(
+            "{} - {}",
+            time_zone.format(from_ts),
+            time_zone.format(to_ts)
+        );
+        (blocks, range)
+    } else {
+        let (start, end) = match (from, to) {
+            (Some(f), Some(t)) => (f, t),
+            (Some(f), None) => (f, f + limit - 1),
+            (None, Some(t)) => (t.saturating_sub(limit - 1), t),
+            (None, None) => {
+                // Show most recent blocks
+                let max = db.get_max_block_number()?.unwrap_or(0);
+                (max.saturating_sub(limit - 1), max)
+            }
+        };
+        let blocks = db.get_blocks_in_range(start, end, Some(limit as u32 + 1))?;
+        (blocks, format!("{} - {}", start, end))
     };
 
-    let blocks = db.get_blocks_in_range(start, end, Some(limit as u32 + 1))?;
-
     if blocks.is_empty() {
-        info!("No blocks found in range {} - {}", start, end);
+        info!("No blocks found in range {}", range);
         return Ok(());
     }
 
-    info!("Blocks {} - {} ({} found)", start, end, blocks.len());
+    info!("Blocks {} ({} found)", range, blocks.len());
     info!("─────────────────────────────────────────────────────────────────────────────");
     info!(
         "{:>10} {:>12} {:>8} {:>12} {:>6} {:>10}",
@@ -222,32 +503,782 @@ fn run_blocks(db: &Database, from: Option<u64>, to: Option<u64>, limit: u64) ->
     Ok(())
 }
 
-fn run_gaps(db: &Database) -> Result<()> {
-    let gaps = db.find_gaps()?;
+/// Parse an RFC3339 time, or an offset before `now` like "-24h" (units s, m, h, d)
+fn parse_time(input: &str, now: i64) -> Result<i64> {
+    let input = input.trim();
+    if let Ok(time) = chrono::DateTime::parse_from_rfc3339(input) {
+        return Ok(time.timestamp());
+    }
+    if input == "now" {
+        return Ok(now);
+    }
 
-    if gaps.is_empty() {
-        info!("No gaps found - block data is continuous");
-        return Ok(());
+    let offset = input.strip_prefix('-').unwrap_or(input);
+    let unit_secs = match offset.chars().last() {
+        Some('s') => 1,
+        Some('m') => 60,
+        Some('h') => 3600,
+        Some('d') => 86_400,
+        _ => bail!(
+            "Invalid time '{}' (expected RFC3339 like 2026-01-15T00:00:00Z, or relative like -24h)",
+            input
+        ),
+    };
+    let amount: i64 = offset[..offset.len() - 1]
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid relative time '{}' (e.g. -30m, -24h, -7d)", input))?;
+    Ok(now - amount * unit_secs)
+}
+
+fn run_gaps(db: &Database, writer: &OutputWriter) -> Result<()> {
+    let chain_tip = db
+        .get_sync_status()?
+        .chain_tip_block
+        .max(db.get_max_block_number()?.unwrap_or(0));
+
+    writer.write(&GapsReport::new(
+        db.find_gaps()?,
+        db.count_blocks()?,
+        db.get_min_block_number()?.zip(db.get_max_block_number()?),
+        chain_tip,
+    ))
+}
+
+/// `query gaps` result
+struct GapsReport {
+    /// Inclusive missing ranges, including any before the first and after the
+    /// last stored block
+    gaps: Vec<(u64, u64)>,
+    synced: u64,
+    first_block: Option<u64>,
+    chain_tip: u64,
+    total_missing: u64,
+    largest_gap: Option<u64>,
+    /// Blocks we are expected to hold: #1 up to the block before the tip
+    expected: u64,
+    completeness: f64,
+}
+
+impl GapsReport {
+    /// Build from the gaps between stored blocks and the stored (first, last) range
+    fn new(gaps: Vec<(u64, u64)>, synced: u64, stored: Option<(u64, u64)>, chain_tip: u64) -> Self {
+        // Completeness is synced / (tip - 1): genesis has no author and the
+        // tip itself may still be in flight
+        let expected = chain_tip.saturating_sub(1);
+
+        // Blocks missing before the first or after the last stored block are
+        // gaps too, so they show up in the list and in the totals
+        let mut missing = Vec::with_capacity(gaps.len() + 2);
+        match stored {
+            Some((first, last)) => {
+                if first > 1 && expected > 0 {
+                    missing.push((1, (first - 1).min(expected)));
+                }
+                missing.extend(gaps);
+                if last < expected {
+                    missing.push((last + 1, expected));
+                }
+            }
+            None if expected > 0 => missing.push((1, expected)),
+            None => {}
+        }
+        let gaps = missing;
+
+        // Gap ranges are inclusive on both ends
+        let total_missing: u64 = gaps.iter().map(|(start, end)| end - start + 1).sum();
+        let largest_gap = gaps.iter().map(|(start, end)| end - start + 1).max();
+
+        let completeness = if expected > 0 {
+            (synced as f64 / expected as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            gaps,
+            synced,
+            first_block: stored.map(|(first, _)| first),
+            chain_tip,
+            total_missing,
+            largest_gap,
+            expected,
+            completeness,
+        }
     }
+}
 
-    info!("Found {} gap(s) in block data:", gaps.len());
-    info!("─────────────────────────────────────────");
-    info!("{:>12} {:>12} {:>12}", "From", "To", "Missing");
-    info!("─────────────────────────────────────────");
+impl Render for GapsReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.gaps.is_empty() {
+            lines.push("No gaps found - block data is continuous".to_string());
+        } else {
+            lines.push(format!("Found {} gap(s) in block data:", self.gaps.len()));
+            lines.push("─────────────────────────────────────────".to_string());
+            lines.push(format!("{:>12} {:>12} {:>12}", "From", "To", "Missing"));
+            lines.push("─────────────────────────────────────────".to_string());
 
-    let mut total_missing = 0u64;
-    for (start, end) in &gaps {
-        let missing = end - start - 1;
-        total_missing += missing;
-        info!("{:>12} {:>12} {:>12}", start, end, missing);
+            for (start, end) in &self.gaps {
+                lines.push(format!("{:>12} {:>12} {:>12}", start, end, end - start + 1));
+            }
+
+            lines.push("─────────────────────────────────────────".to_string());
+            lines.push(format!("Total missing blocks: {}", self.total_missing));
+            if let Some(largest) = self.largest_gap {
+                lines.push(format!("Largest gap:          {} blocks", largest));
+            }
+        }
+
+        if self.expected > 0 {
+            lines.push(format!(
+                "Completeness:         {:.2}% ({} of {} blocks, #1 to #{} below tip #{})",
+                self.completeness, self.synced, self.expected, self.expected, self.chain_tip
+            ));
+        }
+
+        if !self.gaps.is_empty() {
+            lines.push(String::new());
+            lines.push("To resync gaps, run: mvm sync --start-block <from>".to_string());
+        }
+        lines
     }
 
-    info!("─────────────────────────────────────────");
-    info!("Total missing blocks: {}", total_missing);
-    info!("");
-    info!("To resync gaps, run: mvm sync --start-block <from>");
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "gaps": self
+                .gaps
+                .iter()
+                .map(|(start, end)| serde_json::json!({
+                    "from": start,
+                    "to": end,
+                    "missing": end - start + 1,
+                }))
+                .collect::<Vec<_>>(),
+            "gap_count": self.gaps.len(),
+            "total_missing": self.total_missing,
+            "largest_gap": self.largest_gap,
+            "synced_blocks": self.synced,
+            "first_block": self.first_block,
+            "chain_tip": self.chain_tip,
+            "completeness_percent": self.completeness,
+        })
+    }
 
-    Ok(())
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let rows = self
+            .gaps
+            .iter()
+            .map(|(start, end)| {
+                vec![
+                    start.to_string(),
+                    end.to_string(),
+                    (end - start + 1).to_string(),
+                ]
+            })
+            .collect();
+        (vec!["from", "to", "missing"], rows)
+    }
+}
+
+/// One committee seat resolved to the validator holding it
+#[derive(Debug, Clone, PartialEq)]
+struct CommitteeSeat {
+    position: usize,
+    aura_key: String,
+    sidechain_key: Option<String>,
+    label: Option<String>,
+    is_ours: bool,
+}
+
+/// Resolve committee AURA keys to validators
+///
+/// The epoch's own `validator_epochs` rows are preferred since AURA keys can
+/// rotate; the `validators` table fills in the rest.
+fn resolve_committee(
+    committee: &[String],
+    epoch_validators: &[ValidatorEpochRecord],
+    validators: &[ValidatorRecord],
+    known_validators: &KnownValidators,
+) -> Vec<CommitteeSeat> {
+    let mut by_aura: HashMap<String, &str> = HashMap::new();
+    for v in validators {
+        if let Some(aura) = &v.aura_key {
+            by_aura.insert(aura.to_lowercase(), &v.sidechain_key);
+        }
+    }
+    for v in epoch_validators {
+        by_aura.insert(v.aura_key.to_lowercase(), &v.sidechain_key);
+    }
+    let records: HashMap<&str, &ValidatorRecord> = validators
+        .iter()
+        .map(|v| (v.sidechain_key.as_str(), v))
+        .collect();
+
+    committee
+        .iter()
+        .enumerate()
+        .map(|(position, aura_key)| {
+            let sidechain_key = by_aura.get(&aura_key.to_lowercase()).copied();
+            let record = sidechain_key.and_then(|key| records.get(key));
+            CommitteeSeat {
+                position,
+                aura_key: aura_key.clone(),
+                sidechain_key: sidechain_key.map(String::from),
+                label: sidechain_key
+                    .and_then(|key| known_validators.get_label(key))
+                    .map(String::from)
+                    .or_else(|| record.and_then(|r| r.label.clone())),
+                is_ours: record.is_some_and(|r| r.is_ours),
+            }
+        })
+        .collect()
+}
+
+fn run_committee(
+    db: &Database,
+    epoch: Option<u64>,
+    writer: &OutputWriter,
+    known_validators: &KnownValidators,
+) -> Result<()> {
+    let Some(latest) = db.list_committee_epochs()?.into_iter().max() else {
+        bail!("No committee snapshots stored. Run 'mvm sync' to capture them.");
+    };
+    let epoch = epoch.unwrap_or(latest);
+    let Some(committee) = db.get_committee_snapshot(epoch)? else {
+        bail!(
+            "No committee snapshot for epoch {} (latest stored: {})",
+            epoch,
+            latest
+        );
+    };
+
+    let seats = resolve_committee(
+        &committee,
+        &db.get_validators_for_epoch(epoch)?,
+        &db.get_all_validators()?,
+        known_validators,
+    );
+    writer.write(&CommitteeReport { epoch, seats })
+}
+
+/// `query committee` result
+struct CommitteeReport {
+    epoch: u64,
+    seats: Vec<CommitteeSeat>,
+}
+
+impl CommitteeReport {
+    fn unique_validators(&self) -> usize {
+        self.seats
+            .iter()
+            .map(|s| s.aura_key.to_lowercase())
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    }
+
+    fn our_seats(&self) -> usize {
+        self.seats.iter().filter(|s| s.is_ours).count()
+    }
+}
+
+impl Render for CommitteeReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        let our_seats = self.our_seats();
+        let rule = "─".repeat(154);
+        let mut lines = vec![
+            format!(
+                "Committee for epoch {} ({} seats, {} unique validators, {} ours)",
+                self.epoch,
+                self.seats.len(),
+                self.unique_validators(),
+                our_seats
+            ),
+            rule.clone(),
+            format!(
+                "{:>5} {:<66} {:<68} {:<16}",
+                "Pos", "AURA Key", "Sidechain Key", "Label"
+            ),
+            rule,
+        ];
+        for seat in &self.seats {
+            lines.push(format!(
+                "{:>5} {:<66} {:<68} {:<16}{}",
+                seat.position,
+                seat.aura_key,
+                seat.sidechain_key.as_deref().unwrap_or("-"),
+                seat.label.as_deref().unwrap_or(""),
+                if seat.is_ours { " *" } else { "" }
+            ));
+        }
+
+        if our_seats > 0 {
+            lines.push(String::new());
+            lines.push("* = Our validator".to_string());
+        }
+        lines
+    }
+
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "epoch": self.epoch,
+            "size": self.seats.len(),
+            "unique_validators": self.unique_validators(),
+            "our_seats": self.our_seats(),
+            "seats": self
+                .seats
+                .iter()
+                .map(|s| serde_json::json!({
+                    "position": s.position,
+                    "aura_key": s.aura_key,
+                    "sidechain_key": s.sidechain_key,
+                    "label": s.label,
+                    "is_ours": s.is_ours,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let rows = self
+            .seats
+            .iter()
+            .map(|s| {
+                vec![
+                    self.epoch.to_string(),
+                    s.position.to_string(),
+                    s.aura_key.clone(),
+                    opt_field(s.sidechain_key.as_deref()),
+                    opt_field(s.label.as_deref()),
+                    s.is_ours.to_string(),
+                ]
+            })
+            .collect();
+        (
+            vec![
+                "epoch",
+                "position",
+                "aura_key",
+                "sidechain_key",
+                "label",
+                "is_ours",
+            ],
+            rows,
+        )
+    }
+}
+
+/// How one validator's seats changed between two committees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SeatChangeKind {
+    Joined,
+    Left,
+    Changed,
+}
+
+impl SeatChangeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            SeatChangeKind::Joined => "joined",
+            SeatChangeKind::Left => "left",
+            SeatChangeKind::Changed => "changed",
+        }
+    }
+}
+
+/// One AURA key whose seat count differs between the two committees
+#[derive(Debug, Clone, PartialEq)]
+struct SeatChange {
+    kind: SeatChangeKind,
+    aura_key: String,
+    sidechain_key: Option<String>,
+    label: Option<String>,
+    is_ours: bool,
+    from_seats: usize,
+    to_seats: usize,
+}
+
+impl SeatChange {
+    fn delta(&self) -> i64 {
+        self.to_seats as i64 - self.from_seats as i64
+    }
+}
+
+/// Seat changes per AURA key between two resolved committees
+///
+/// Sorted joined, left, changed; then ours first and by largest change.
+fn diff_committees(from: &[CommitteeSeat], to: &[CommitteeSeat]) -> Vec<SeatChange> {
+    // Per key: (from seats, to seats, a seat to take the resolution from)
+    let mut by_key: HashMap<String, (usize, usize, &CommitteeSeat)> = HashMap::new();
+    for seat in from {
+        by_key
+            .entry(seat.aura_key.to_lowercase())
+            .or_insert((0, 0, seat))
+            .0 += 1;
+    }
+    for seat in to {
+        let entry = by_key
+            .entry(seat.aura_key.to_lowercase())
+            .or_insert((0, 0, seat));
+        entry.1 += 1;
+        // The later epoch's resolution reflects current labels and keys
+        entry.2 = seat;
+    }
+
+    let mut changes: Vec<SeatChange> = by_key
+        .into_iter()
+        .filter(|(_, (from_seats, to_seats, _))| from_seats != to_seats)
+        .map(|(aura_key, (from_seats, to_seats, seat))| SeatChange {
+            kind: match (from_seats, to_seats) {
+                (0, _) => SeatChangeKind::Joined,
+                (_, 0) => SeatChangeKind::Left,
+                _ => SeatChangeKind::Changed,
+            },
+            aura_key,
+            sidechain_key: seat.sidechain_key.clone(),
+            label: seat.label.clone(),
+            is_ours: seat.is_ours,
+            from_seats,
+            to_seats,
+        })
+        .collect();
+    changes.sort_by(|a, b| {
+        a.kind
+            .cmp(&b.kind)
+            .then(b.is_ours.cmp(&a.is_ours))
+            .then(b.delta().abs().cmp(&a.delta().abs()))
+            .then(a.aura_key.cmp(&b.aura_key))
+    });
+    changes
+}
+
+fn run_committee_diff(
+    db: &Database,
+    from: u64,
+    to: u64,
+    writer: &OutputWriter,
+    known_validators: &KnownValidators,
+) -> Result<()> {
+    let validators = db.get_all_validators()?;
+    let resolve = |epoch: u64| -> Result<Vec<CommitteeSeat>> {
+        let Some(committee) = db.get_committee_snapshot(epoch)? else {
+            bail!(
+                "No committee snapshot for epoch {}. Run 'mvm query committee' for the latest stored epoch.",
+                epoch
+            );
+        };
+        Ok(resolve_committee(
+            &committee,
+            &db.get_validators_for_epoch(epoch)?,
+            &validators,
+            known_validators,
+        ))
+    };
+    let from_seats = resolve(from)?;
+    let to_seats = resolve(to)?;
+
+    writer.write(&CommitteeDiffReport {
+        from,
+        to,
+        from_size: from_seats.len(),
+        to_size: to_seats.len(),
+        unique_keys: from_seats
+            .iter()
+            .chain(&to_seats)
+            .map(|s| s.aura_key.to_lowercase())
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+        changes: diff_committees(&from_seats, &to_seats),
+    })
+}
+
+/// `query committee-diff` result
+struct CommitteeDiffReport {
+    from: u64,
+    to: u64,
+    from_size: usize,
+    to_size: usize,
+    /// Distinct AURA keys across both committees
+    unique_keys: usize,
+    changes: Vec<SeatChange>,
+}
+
+impl CommitteeDiffReport {
+    fn count(&self, kind: SeatChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.kind == kind).count()
+    }
+
+    /// Seats that changed hands (sum of per-key gains)
+    fn seats_reallocated(&self) -> usize {
+        self.changes
+            .iter()
+            .map(|c| c.to_seats.saturating_sub(c.from_seats))
+            .sum()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "{} joined, {} left, {} changed seat count, {} unchanged; {} seats reallocated",
+            self.count(SeatChangeKind::Joined),
+            self.count(SeatChangeKind::Left),
+            self.count(SeatChangeKind::Changed),
+            self.unique_keys - self.changes.len(),
+            self.seats_reallocated()
+        )
+    }
+}
+
+impl Render for CommitteeDiffReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        let rule = "─".repeat(112);
+        let mut lines = vec![
+            format!(
+                "Committee diff: epoch {} ({} seats) → epoch {} ({} seats)",
+                self.from, self.from_size, self.to, self.to_size
+            ),
+            self.summary(),
+        ];
+        if self.changes.is_empty() {
+            lines.push("No seat changes".to_string());
+            return lines;
+        }
+
+        lines.push(rule.clone());
+        lines.push(format!(
+            "{:<8} {:>5} {:>5} {:>6}  {:<66} {:<16}",
+            "Change", "From", "To", "Δ", "AURA Key", "Label"
+        ));
+        lines.push(rule);
+        for change in &self.changes {
+            lines.push(format!(
+                "{:<8} {:>5} {:>5} {:>+6}  {:<66} {:<16}{}",
+                change.kind.as_str(),
+                change.from_seats,
+                change.to_seats,
+                change.delta(),
+                change.aura_key,
+                change.label.as_deref().unwrap_or("-"),
+                if change.is_ours { " *" } else { "" }
+            ));
+        }
+
+        if self.changes.iter().any(|c| c.is_ours) {
+            lines.push(String::new());
+            lines.push("* = Our validator".to_string());
+        }
+        lines
+    }
+
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "from_epoch": self.from,
+            "to_epoch": self.to,
+            "from_size": self.from_size,
+            "to_size": self.to_size,
+            "joined": self.count(SeatChangeKind::Joined),
+            "left": self.count(SeatChangeKind::Left),
+            "changed": self.count(SeatChangeKind::Changed),
+            "unchanged": self.unique_keys - self.changes.len(),
+            "seats_reallocated": self.seats_reallocated(),
+            "changes": self
+                .changes
+                .iter()
+                .map(|c| serde_json::json!({
+                    "change": c.kind.as_str(),
+                    "aura_key": c.aura_key,
+                    "sidechain_key": c.sidechain_key,
+                    "label": c.label,
+                    "is_ours": c.is_ours,
+                    "from_seats": c.from_seats,
+                    "to_seats": c.to_seats,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let rows = self
+            .changes
+            .iter()
+            .map(|c| {
+                vec![
+                    self.from.to_string(),
+                    self.to.to_string(),
+                    c.kind.as_str().to_string(),
+                    c.aura_key.clone(),
+                    opt_field(c.sidechain_key.as_deref()),
+                    opt_field(c.label.as_deref()),
+                    c.is_ours.to_string(),
+                    c.from_seats.to_string(),
+                    c.to_seats.to_string(),
+                ]
+            })
+            .collect();
+        (
+            vec![
+                "from_epoch",
+                "to_epoch",
+                "change",
+                "aura_key",
+                "sidechain_key",
+                "label",
+                "is_ours",
+                "from_seats",
+                "to_seats",
+            ],
+            rows,
+        )
+    }
+}
+
+fn run_project(
+    db: &Database,
+    stake_ada: f64,
+    key: Option<&str>,
+    epoch: Option<u64>,
+    writer: &OutputWriter,
+) -> Result<()> {
+    if !(stake_ada.is_finite() && stake_ada > 0.0) {
+        bail!("--stake must be a positive amount of ADA");
+    }
+    let stake_lovelace = (stake_ada * 1_000_000.0).round() as u64;
+
+    let Some(latest) = db
+        .get_latest_validator_epochs()?
+        .iter()
+        .map(|r| r.sidechain_epoch)
+        .max()
+    else {
+        bail!("No validator epoch snapshots stored. Run 'mvm sync' to capture them.");
+    };
+    let epoch = epoch.unwrap_or(latest);
+    let records = db.get_validators_for_epoch(epoch)?;
+    if records.is_empty() {
+        bail!(
+            "No validator snapshot for epoch {} (latest stored: {})",
+            epoch,
+            latest
+        );
+    }
+
+    // A replaced validator's own stake leaves the pool; its seats stay dynamic
+    let key = key.map(|k| {
+        if k.starts_with("0x") {
+            k.to_lowercase()
+        } else {
+            format!("0x{}", k.to_lowercase())
+        }
+    });
+    let dynamic: Vec<&ValidatorEpochRecord> =
+        records.iter().filter(|r| !r.is_permissioned).collect();
+    let dynamic_seats: u64 = dynamic.iter().map(|r| r.committee_seats as u64).sum();
+    let other_stakes: Vec<u64> = dynamic
+        .iter()
+        .filter(|r| key.as_deref() != Some(r.sidechain_key.to_lowercase().as_str()))
+        .filter_map(|r| r.stake_lovelace)
+        .collect();
+
+    let Some(projection) = project_seats(stake_lovelace, &other_stakes, dynamic_seats) else {
+        bail!("Epoch {} has no dynamic committee seats to project", epoch);
+    };
+
+    writer.write(&ProjectionReport {
+        stake_ada,
+        epoch,
+        replaces: key,
+        projection,
+    })
+}
+
+/// `query project` result
+struct ProjectionReport {
+    stake_ada: f64,
+    epoch: u64,
+    /// Sidechain key whose stake the projection replaces
+    replaces: Option<String>,
+    projection: SeatProjection,
+}
+
+impl Render for ProjectionReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        let p = &self.projection;
+        let mut lines = vec![
+            format!(
+                "Seat projection for {} ADA (epoch {} stake distribution)",
+                self.stake_ada, self.epoch
+            ),
+            "─────────────────────────────────────────────────────────────────".to_string(),
+        ];
+        if let Some(k) = &self.replaces {
+            lines.push(format!("Replaces stake of: {}", k));
+        }
+        lines.extend([
+            format!(
+                "Dynamic pool:      {:.0} ADA across {} validators",
+                p.pool_stake_lovelace as f64 / 1_000_000.0,
+                p.dynamic_validators
+            ),
+            format!(
+                "Stake share:       {:.3}% (rank {} of {})",
+                p.stake_share_percent, p.stake_rank, p.dynamic_validators
+            ),
+            format!("Dynamic seats:     {}", p.dynamic_seats),
+            format!("Expected seats:    {:.2} per epoch", p.expected_seats),
+            format!(
+                "Selected at all:   {:.1}% of epochs",
+                p.selection_chance_percent
+            ),
+            String::new(),
+            "Estimate only: seats are drawn at random each epoch, weighted by stake,".to_string(),
+            "so actual seats vary around this average.".to_string(),
+        ]);
+        lines
+    }
+
+    fn json(&self) -> serde_json::Value {
+        let p = &self.projection;
+        serde_json::json!({
+            "epoch": self.epoch,
+            "estimate": true,
+            "stake_lovelace": p.stake_lovelace,
+            "replaces": self.replaces,
+            "pool_stake_lovelace": p.pool_stake_lovelace,
+            "stake_share_percent": p.stake_share_percent,
+            "dynamic_seats": p.dynamic_seats,
+            "expected_seats": p.expected_seats,
+            "selection_chance_percent": p.selection_chance_percent,
+            "stake_rank": p.stake_rank,
+            "dynamic_validators": p.dynamic_validators,
+        })
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let p = &self.projection;
+        let header = vec![
+            "epoch",
+            "stake_lovelace",
+            "replaces",
+            "pool_stake_lovelace",
+            "stake_share_percent",
+            "dynamic_seats",
+            "expected_seats",
+            "selection_chance_percent",
+            "stake_rank",
+            "dynamic_validators",
+        ];
+        let row = vec![
+            self.epoch.to_string(),
+            p.stake_lovelace.to_string(),
+            opt_field(self.replaces.as_deref()),
+            p.pool_stake_lovelace.to_string(),
+            format!("{:.3}", p.stake_share_percent),
+            p.dynamic_seats.to_string(),
+            format!("{:.2}", p.expected_seats),
+            format!("{:.1}", p.selection_chance_percent),
+            p.stake_rank.to_string(),
+            p.dynamic_validators.to_string(),
+        ];
+        (header, vec![row])
+    }
 }
 
 fn run_validators(
@@ -494,3 +1525,694 @@ fn run_performance(
 
     Ok(())
 }
+
+fn run_network_history(
+    db: &Database,
+    hours: u64,
+    time_zone: DisplayTimeZone,
+    writer: &OutputWriter,
+) -> Result<()> {
+    let since = chrono::Utc::now().timestamp() - (hours * 3600) as i64;
+    writer.write(&NetworkHistoryReport {
+        hours,
+        samples: db.get_network_samples(since)?,
+        time_zone,
+    })
+}
+
+/// `query network-history` result: summary and hourly table as text, raw samples otherwise
+struct NetworkHistoryReport {
+    hours: u64,
+    samples: Vec<NetworkSample>,
+    time_zone: DisplayTimeZone,
+}
+
+impl Render for NetworkHistoryReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        if self.samples.is_empty() {
+            return vec![
+                format!("No network samples in the last {} hours.", self.hours),
+                "Samples are recorded by 'mvm sync' every sync.network_sample_secs seconds."
+                    .to_string(),
+            ];
+        }
+
+        let overall = NetworkSummary::of(&self.samples);
+        let mut lines = vec![
+            format!(
+                "Network history: {} samples over the last {} hours",
+                self.samples.len(),
+                self.hours
+            ),
+            "─────────────────────────────────────────".to_string(),
+            format!(
+                "Peers:          min {} / avg {:.1} / max {}",
+                overall.peers_min, overall.peers_avg, overall.peers_max
+            ),
+        ];
+        if let (Some(inbound), Some(outbound)) = (overall.inbound_avg, overall.outbound_avg) {
+            lines.push(format!(
+                "Connections:    avg {:.1} in / {:.1} out",
+                inbound, outbound
+            ));
+        }
+        if let (Some(avg_in), Some(peak_in)) = (overall.bytes_in_avg, overall.bytes_in_peak) {
+            lines.push(format!(
+                "Bandwidth in:   avg {} / peak {}",
+                format_rate(avg_in),
+                format_rate(peak_in)
+            ));
+        }
+        if let (Some(avg_out), Some(peak_out)) = (overall.bytes_out_avg, overall.bytes_out_peak) {
+            lines.push(format!(
+                "Bandwidth out:  avg {} / peak {}",
+                format_rate(avg_out),
+                format_rate(peak_out)
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "{:<26} {:>6} {:>6} {:>6} {:>12} {:>12}",
+            "Hour", "Min", "Avg", "Max", "In (avg)", "Out (avg)"
+        ));
+        lines.push(
+            "─────────────────────────────────────────────────────────────────────────".to_string(),
+        );
+        for (hour_start, bucket) in hourly_buckets(&self.samples) {
+            let summary = NetworkSummary::of(bucket);
+            lines.push(format!(
+                "{:<26} {:>6} {:>6.1} {:>6} {:>12} {:>12}",
+                self.time_zone.format(hour_start),
+                summary.peers_min,
+                summary.peers_avg,
+                summary.peers_max,
+                summary
+                    .bytes_in_avg
+                    .map(format_rate)
+                    .unwrap_or_else(|| "-".to_string()),
+                summary
+                    .bytes_out_avg
+                    .map(format_rate)
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+        lines
+    }
+
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!(self
+            .samples
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "timestamp": s.timestamp,
+                    "peers": s.peers,
+                    "peers_inbound": s.peers_inbound,
+                    "peers_outbound": s.peers_outbound,
+                    "bytes_in_per_sec": s.bytes_in_per_sec,
+                    "bytes_out_per_sec": s.bytes_out_per_sec,
+                })
+            })
+            .collect::<Vec<_>>())
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let rows = self
+            .samples
+            .iter()
+            .map(|s| {
+                vec![
+                    s.timestamp.to_string(),
+                    s.peers.to_string(),
+                    opt_field(s.peers_inbound),
+                    opt_field(s.peers_outbound),
+                    opt_field(s.bytes_in_per_sec.map(|v| format!("{:.1}", v))),
+                    opt_field(s.bytes_out_per_sec.map(|v| format!("{:.1}", v))),
+                ]
+            })
+            .collect();
+        let header = vec![
+            "timestamp",
+            "peers",
+            "peers_inbound",
+            "peers_outbound",
+            "bytes_in_per_sec",
+            "bytes_out_per_sec",
+        ];
+        (header, rows)
+    }
+}
+
+/// Peer and bandwidth statistics over a run of samples
+#[derive(Debug, PartialEq)]
+struct NetworkSummary {
+    peers_min: u64,
+    peers_max: u64,
+    peers_avg: f64,
+    inbound_avg: Option<f64>,
+    outbound_avg: Option<f64>,
+    bytes_in_avg: Option<f64>,
+    bytes_in_peak: Option<f64>,
+    bytes_out_avg: Option<f64>,
+    bytes_out_peak: Option<f64>,
+}
+
+impl NetworkSummary {
+    /// Summarize a non-empty slice of samples (missing values are skipped)
+    fn of(samples: &[NetworkSample]) -> Self {
+        fn avg(values: impl Iterator<Item = f64>) -> Option<f64> {
+            let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+            (count > 0).then(|| sum / count as f64)
+        }
+        fn peak(values: impl Iterator<Item = f64>) -> Option<f64> {
+            values.fold(None, |max: Option<f64>, v| {
+                Some(max.map_or(v, |m| m.max(v)))
+            })
+        }
+
+        Self {
+            peers_min: samples.iter().map(|s| s.peers).min().unwrap_or(0),
+            peers_max: samples.iter().map(|s| s.peers).max().unwrap_or(0),
+            peers_avg: avg(samples.iter().map(|s| s.peers as f64)).unwrap_or(0.0),
+            inbound_avg: avg(samples
+                .iter()
+                .filter_map(|s| s.peers_inbound)
+                .map(|v| v as f64)),
+            outbound_avg: avg(samples
+                .iter()
+                .filter_map(|s| s.peers_outbound)
+                .map(|v| v as f64)),
+            bytes_in_avg: avg(samples.iter().filter_map(|s| s.bytes_in_per_sec)),
+            bytes_in_peak: peak(samples.iter().filter_map(|s| s.bytes_in_per_sec)),
+            bytes_out_avg: avg(samples.iter().filter_map(|s| s.bytes_out_per_sec)),
+            bytes_out_peak: peak(samples.iter().filter_map(|s| s.bytes_out_per_sec)),
+        }
+    }
+}
+
+/// Split time-ordered samples into runs by UTC hour, keyed by the hour's start
+fn hourly_buckets(samples: &[NetworkSample]) -> Vec<(i64, &[NetworkSample])> {
+    samples
+        .chunk_by(|a, b| a.timestamp.div_euclid(3600) == b.timestamp.div_euclid(3600))
+        .map(|bucket| (bucket[0].timestamp.div_euclid(3600) * 3600, bucket))
+        .collect()
+}
+
+fn run_sync_history(
+    db: &Database,
+    days: u64,
+    time_zone: DisplayTimeZone,
+    writer: &OutputWriter,
+) -> Result<()> {
+    let since = chrono::Utc::now().timestamp() - (days * 86_400) as i64;
+    writer.write(&SyncHistoryReport {
+        days,
+        samples: db.get_sync_samples(since)?,
+        time_zone,
+    })
+}
+
+/// `query sync-history` result: summary and daily table as text, raw samples otherwise
+struct SyncHistoryReport {
+    days: u64,
+    samples: Vec<SyncSample>,
+    time_zone: DisplayTimeZone,
+}
+
+impl Render for SyncHistoryReport {
+    fn text(&self, _color: bool) -> Vec<String> {
+        if self.samples.is_empty() {
+            return vec![
+                format!("No sync samples in the last {} days.", self.days),
+                "Samples are recorded by 'mvm sync' every sync.sync_sample_secs seconds."
+                    .to_string(),
+            ];
+        }
+
+        let overall = SyncSummary::of(&self.samples);
+        let daily: Vec<_> = daily_buckets(&self.samples)
+            .into_iter()
+            .map(|(day, bucket)| (day, SyncSummary::of(bucket)))
+            .collect();
+        let mut lines = vec![
+            format!(
+                "Sync history: {} samples over the last {} days",
+                self.samples.len(),
+                self.days
+            ),
+            "─────────────────────────────────────────".to_string(),
+            format!(
+                "Blocks synced:  {} ({:.2} blocks/s avg, {:.2} peak)",
+                overall.blocks, overall.blocks_per_sec_avg, overall.blocks_per_sec_peak
+            ),
+            format!(
+                "Lag:            avg {:.1} / max {} blocks",
+                overall.lag_avg, overall.lag_max
+            ),
+        ];
+        if let Some(ms) = overall.ms_per_block {
+            // First vs last day shows whether sync is getting slower
+            let first = daily.iter().find_map(|(_, s)| s.ms_per_block);
+            let last = daily.iter().rev().find_map(|(_, s)| s.ms_per_block);
+            let trend = match (first, last) {
+                (Some(first), Some(last)) if daily.len() > 1 => {
+                    format!(" (first day {:.1} ms → last day {:.1} ms)", first, last)
+                }
+                _ => String::new(),
+            };
+            lines.push(format!("Time per block: avg {:.1} ms{}", ms, trend));
+        }
+        let sizes: Vec<u64> = self
+            .samples
+            .iter()
+            .filter_map(|s| s.db_size_bytes)
+            .collect();
+        if let (Some(first), Some(last)) = (sizes.first(), sizes.last()) {
+            lines.push(format!(
+                "Database size:  {} → {}",
+                format_mb(*first),
+                format_mb(*last)
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!(
+            "{:<26} {:>8} {:>10} {:>9} {:>10} {:>8} {:>10}",
+            "Day", "Samples", "Blocks", "Blocks/s", "ms/block", "Max lag", "DB size"
+        ));
+        lines.push("─".repeat(87));
+        for (day_start, summary) in &daily {
+            lines.push(format!(
+                "{:<26} {:>8} {:>10} {:>9.2} {:>10} {:>8} {:>10}",
+                self.time_zone.format(*day_start),
+                summary.samples,
+                summary.blocks,
+                summary.blocks_per_sec_avg,
+                summary
+                    .ms_per_block
+                    .map(|ms| format!("{:.1}", ms))
+                    .unwrap_or_else(|| "-".to_string()),
+                summary.lag_max,
+                summary
+                    .db_size_bytes
+                    .map(format_mb)
+                    .unwrap_or_else(|| "-".to_string()),
+            ));
+        }
+        lines
+    }
+
+    fn json(&self) -> serde_json::Value {
+        serde_json::json!(self
+            .samples
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "timestamp": s.timestamp,
+                    "blocks": s.blocks,
+                    "blocks_per_sec": s.blocks_per_sec,
+                    "ms_per_block": s.ms_per_block,
+                    "lag_blocks": s.lag_blocks,
+                    "catching_up": s.catching_up,
+                    "db_size_bytes": s.db_size_bytes,
+                })
+            })
+            .collect::<Vec<_>>())
+    }
+
+    fn csv(&self) -> (Vec<&'static str>, Vec<Vec<String>>) {
+        let rows = self
+            .samples
+            .iter()
+            .map(|s| {
+                vec![
+                    s.timestamp.to_string(),
+                    s.blocks.to_string(),
+                    format!("{:.3}", s.blocks_per_sec),
+                    opt_field(s.ms_per_block.map(|v| format!("{:.1}", v))),
+                    s.lag_blocks.to_string(),
+                    s.catching_up.to_string(),
+                    opt_field(s.db_size_bytes),
+                ]
+            })
+            .collect();
+        let header = vec![
+            "timestamp",
+            "blocks",
+            "blocks_per_sec",
+            "ms_per_block",
+            "lag_blocks",
+            "catching_up",
+            "db_size_bytes",
+        ];
+        (header, rows)
+    }
+}
+
+/// Sync throughput over a run of samples
+#[derive(Debug, PartialEq)]
+struct SyncSummary {
+    samples: usize,
+    blocks: u64,
+    blocks_per_sec_avg: f64,
+    blocks_per_sec_peak: f64,
+    /// Weighted by blocks per sample (None when nothing was synced)
+    ms_per_block: Option<f64>,
+    lag_avg: f64,
+    lag_max: u64,
+    /// Size at the last sample that has one
+    db_size_bytes: Option<u64>,
+}
+
+impl SyncSummary {
+    /// Summarize a non-empty slice of samples
+    fn of(samples: &[SyncSample]) -> Self {
+        let count = samples.len().max(1) as f64;
+        let (busy_ms, timed_blocks) = samples
+            .iter()
+            .filter_map(|s| s.ms_per_block.map(|ms| (ms * s.blocks as f64, s.blocks)))
+            .fold((0.0, 0), |(ms, blocks), (m, b)| (ms + m, blocks + b));
+        Self {
+            samples: samples.len(),
+            blocks: samples.iter().map(|s| s.blocks).sum(),
+            blocks_per_sec_avg: samples.iter().map(|s| s.blocks_per_sec).sum::<f64>() / count,
+            blocks_per_sec_peak: samples.iter().map(|s| s.blocks_per_sec).fold(0.0, f64::max),
+            ms_per_block: (timed_blocks > 0).then(|| busy_ms / timed_blocks as f64),
+            lag_avg: samples.iter().map(|s| s.lag_blocks as f64).sum::<f64>() / count,
+            lag_max: samples.iter().map(|s| s.lag_blocks).max().unwrap_or(0),
+            db_size_bytes: samples.iter().rev().find_map(|s| s.db_size_bytes),
+        }
+    }
+}
+
+/// Split time-ordered samples into runs by UTC day, keyed by the day's start
+fn daily_buckets(samples: &[SyncSample]) -> Vec<(i64, &[SyncSample])> {
+    samples
+        .chunk_by(|a, b| a.timestamp.div_euclid(86_400) == b.timestamp.div_euclid(86_400))
+        .map(|bucket| (bucket[0].timestamp.div_euclid(86_400) * 86_400, bucket))
+        .collect()
+}
+
+/// Bytes as megabytes
+fn format_mb(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// Bytes/s as a human-readable rate
+fn format_rate(bytes_per_sec: f64) -> String {
+    if bytes_per_sec >= 1_048_576.0 {
+        format!("{:.1} MB/s", bytes_per_sec / 1_048_576.0)
+    } else if bytes_per_sec >= 1024.0 {
+        format!("{:.1} KB/s", bytes_per_sec / 1024.0)
+    } else {
+        format!("{:.0} B/s", bytes_per_sec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(sidechain_key: &str, aura_key: &str, is_ours: bool) -> ValidatorRecord {
+        ValidatorRecord {
+            sidechain_key: sidechain_key.to_string(),
+            aura_key: Some(aura_key.to_string()),
+            grandpa_key: None,
+            label: None,
+            is_ours,
+            registration_status: None,
+            first_seen_epoch: None,
+            total_blocks: 0,
+        }
+    }
+
+    #[test]
+    fn test_resolve_committee() {
+        let committee = vec![
+            "0xAURA1".to_string(),
+            "0xaura2".to_string(),
+            "0xaura3".to_string(),
+            "0xaura1".to_string(),
+        ];
+        // aura2 rotated: the epoch snapshot knows it, the validators table has the new key
+        let epoch_validators = vec![ValidatorEpochRecord {
+            sidechain_epoch: 7,
+            sidechain_key: "0xsc2".to_string(),
+            aura_key: "0xaura2".to_string(),
+            committee_seats: 1,
+            committee_size: 4,
+            is_permissioned: false,
+            stake_lovelace: None,
+            captured_at: 0,
+        }];
+        let validators = vec![
+            validator("0xsc1", "0xaura1", true),
+            validator("0xsc2", "0xaura2new", false),
+        ];
+
+        let seats = resolve_committee(
+            &committee,
+            &epoch_validators,
+            &validators,
+            &KnownValidators::default(),
+        );
+        assert_eq!(seats.len(), 4);
+        assert_eq!(seats[0].sidechain_key.as_deref(), Some("0xsc1"));
+        assert!(seats[0].is_ours);
+        assert_eq!(seats[1].sidechain_key.as_deref(), Some("0xsc2"));
+        assert!(!seats[1].is_ours);
+        assert_eq!(seats[2].sidechain_key, None);
+        assert_eq!(seats[3].position, 3);
+        assert!(seats[3].is_ours);
+    }
+
+    #[test]
+    fn test_diff_committees() {
+        let seat = |aura: &str, is_ours| CommitteeSeat {
+            position: 0,
+            aura_key: aura.to_string(),
+            sidechain_key: None,
+            label: None,
+            is_ours,
+        };
+        let from = vec![
+            seat("0xaura1", true),
+            seat("0xaura2", false),
+            seat("0xAURA1", true),
+            seat("0xaura3", false),
+        ];
+        let to = vec![
+            seat("0xaura1", true),
+            seat("0xaura4", false),
+            seat("0xaura2", false),
+            seat("0xaura4", false),
+        ];
+
+        let changes = diff_committees(&from, &to);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.kind, c.aura_key.as_str(), c.from_seats, c.to_seats))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (SeatChangeKind::Joined, "0xaura4", 0, 2),
+                (SeatChangeKind::Left, "0xaura3", 1, 0),
+                (SeatChangeKind::Changed, "0xaura1", 2, 1),
+            ]
+        );
+        assert!(changes[2].is_ours);
+
+        let report = CommitteeDiffReport {
+            from: 7,
+            to: 9,
+            from_size: 4,
+            to_size: 4,
+            unique_keys: 4,
+            changes,
+        };
+        assert_eq!(
+            report.summary(),
+            "1 joined, 1 left, 1 changed seat count, 1 unchanged; 2 seats reallocated"
+        );
+        let json = report.json();
+        assert_eq!(json["changes"][0]["change"], "joined");
+        assert_eq!(report.csv().1[2][2], "changed");
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let now = 1_760_000_000;
+        assert_eq!(parse_time("-24h", now).unwrap(), now - 86_400);
+        assert_eq!(parse_time("30m", now).unwrap(), now - 1_800);
+        assert_eq!(parse_time("-7d", now).unwrap(), now - 7 * 86_400);
+        assert_eq!(parse_time("now", now).unwrap(), now);
+        assert_eq!(
+            parse_time("2024-01-15T12:00:00Z", now).unwrap(),
+            1_705_320_000
+        );
+        assert_eq!(
+            parse_time("2024-01-15T13:00:00+01:00", now).unwrap(),
+            1_705_320_000
+        );
+        assert!(parse_time("yesterday", now).is_err());
+        assert!(parse_time("-h", now).is_err());
+    }
+
+    #[test]
+    fn test_network_history_summary() {
+        let sample = |timestamp, peers, rate: Option<f64>| NetworkSample {
+            timestamp,
+            peers,
+            peers_inbound: Some(peers / 2),
+            peers_outbound: Some(peers - peers / 2),
+            bytes_in_per_sec: rate,
+            bytes_out_per_sec: rate.map(|r| r / 2.0),
+        };
+        let samples = vec![
+            sample(7_200, 10, None),
+            sample(7_260, 14, Some(2_000.0)),
+            sample(10_799, 12, Some(4_000.0)),
+            sample(10_800, 8, Some(1_000.0)),
+        ];
+
+        let buckets = hourly_buckets(&samples);
+        assert_eq!(buckets.len(), 2);
+        assert_eq!((buckets[0].0, buckets[0].1.len()), (7_200, 3));
+        assert_eq!((buckets[1].0, buckets[1].1.len()), (10_800, 1));
+
+        let summary = NetworkSummary::of(buckets[0].1);
+        assert_eq!((summary.peers_min, summary.peers_max), (10, 14));
+        assert_eq!(summary.peers_avg, 12.0);
+        // The first sample has no rate and is left out of bandwidth figures
+        assert_eq!(summary.bytes_in_avg, Some(3_000.0));
+        assert_eq!(summary.bytes_in_peak, Some(4_000.0));
+        assert_eq!(summary.bytes_out_peak, Some(2_000.0));
+        assert_eq!(format_rate(3_000.0), "2.9 KB/s");
+    }
+
+    #[test]
+    fn test_sync_history_report() {
+        let sample = |timestamp, blocks, ms: Option<f64>, lag| SyncSample {
+            timestamp,
+            blocks,
+            blocks_per_sec: blocks as f64 / 300.0,
+            ms_per_block: ms,
+            lag_blocks: lag,
+            catching_up: false,
+            db_size_bytes: Some(timestamp as u64 * 1024),
+        };
+        // Day 1: 150 blocks at 10 / 20 ms; day 2: slower
+        let samples = vec![
+            sample(86_400, 50, Some(10.0), 0),
+            sample(86_700, 100, Some(20.0), 4),
+            sample(87_000, 0, None, 1),
+            sample(172_800, 60, Some(40.0), 2),
+        ];
+
+        let buckets = daily_buckets(&samples);
+        assert_eq!(buckets.len(), 2);
+        let day1 = SyncSummary::of(buckets[0].1);
+        assert_eq!(day1.blocks, 150);
+        // Weighted by blocks: (50*10 + 100*20) / 150
+        assert!((day1.ms_per_block.unwrap() - 2_500.0 / 150.0).abs() < 1e-9);
+        assert_eq!(day1.lag_max, 4);
+        assert_eq!(day1.db_size_bytes, Some(87_000 * 1024));
+
+        let report = SyncHistoryReport {
+            days: 7,
+            samples,
+            time_zone: DisplayTimeZone::default(),
+        };
+        let render = |format| {
+            OutputWriter::new(format, None, false)
+                .render(&report)
+                .unwrap()
+        };
+        let text = render(OutputFormat::Text);
+        assert!(text.contains("Blocks synced:  210"));
+        assert!(text.contains("(first day 16.7 ms → last day 40.0 ms)"));
+
+        let csv = render(OutputFormat::Csv);
+        assert!(csv.starts_with("timestamp,blocks,blocks_per_sec,ms_per_block"));
+        assert!(csv.contains("\n87000,0,0.000,,1,false,"));
+    }
+
+    #[test]
+    fn test_gaps_report_formats() {
+        // Stored #3..=#15 with #5..=#7 missing; the tip is #21
+        let report = GapsReport::new(vec![(5, 7)], 10, Some((3, 15)), 21);
+        let render = |format| {
+            OutputWriter::new(format, None, false)
+                .render(&report)
+                .unwrap()
+        };
+
+        let text = render(OutputFormat::Text);
+        assert!(text.contains("Found 3 gap(s) in block data:"));
+        assert!(text.contains("Largest gap:          5 blocks"));
+        assert!(text.contains("Completeness:         50.00% (10 of 20 blocks"));
+
+        // The missing head and tail count as gaps, so synced + missing = tip - 1
+        let json: serde_json::Value = serde_json::from_str(&render(OutputFormat::Json)).unwrap();
+        assert_eq!(json["total_missing"], 10);
+        assert_eq!(json["gaps"][2]["missing"], 5);
+
+        assert_eq!(
+            render(OutputFormat::Csv),
+            "from,to,missing\n1,2,2\n5,7,3\n16,20,5\n"
+        );
+
+        // A complete database up to the tip has no gaps
+        let report = GapsReport::new(vec![], 21, Some((1, 21)), 21);
+        assert!(report.gaps.is_empty());
+        assert_eq!(report.completeness, 100.0);
+    }
+
+    #[test]
+    fn test_network_history_report_formats() {
+        let report = NetworkHistoryReport {
+            hours: 24,
+            samples: vec![
+                NetworkSample {
+                    timestamp: 3_600,
+                    peers: 10,
+                    peers_inbound: None,
+                    peers_outbound: None,
+                    bytes_in_per_sec: None,
+                    bytes_out_per_sec: None,
+                },
+                NetworkSample {
+                    timestamp: 3_660,
+                    peers: 12,
+                    peers_inbound: Some(5),
+                    peers_outbound: Some(7),
+                    bytes_in_per_sec: Some(2_048.0),
+                    bytes_out_per_sec: Some(512.25),
+                },
+            ],
+            time_zone: DisplayTimeZone::default(),
+        };
+        let render = |format| {
+            OutputWriter::new(format, None, false)
+                .render(&report)
+                .unwrap()
+        };
+
+        let text = render(OutputFormat::Text);
+        assert!(text.contains("Peers:          min 10 / avg 11.0 / max 12"));
+        assert!(text.contains("Bandwidth in:   avg 2.0 KB/s / peak 2.0 KB/s"));
+
+        let json: serde_json::Value = serde_json::from_str(&render(OutputFormat::Json)).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert!(json[0]["bytes_in_per_sec"].is_null());
+
+        assert_eq!(
+            render(OutputFormat::Csv),
+            "timestamp,peers,peers_inbound,peers_outbound,bytes_in_per_sec,bytes_out_per_sec\n\
+             3600,10,,,,\n\
+             3660,12,5,7,2048.0,512.2\n"
+        );
+    }
+}
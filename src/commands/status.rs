@@ -1,15 +1,65 @@
 //! Status command - display current validator node status
 
+use crate::alerts::{
+    send_digest_webhook, send_grandpa_voter_webhook, send_peer_floor_webhook, GrandpaVoterAlert,
+    GrandpaVoterMonitor, PeerFloorAlert, PeerFloorMonitor, QuietHoursGate,
+};
+use crate::config::{AlertConfig, DisplayTimeZone, ThresholdsConfig};
 use crate::metrics::MetricsClient;
 use crate::midnight::{get_key_status, KeyStatus, RegistrationStatus, ValidatorKeys};
 use crate::rpc::{BlockHeader, RpcClient, SidechainStatus, SyncState, SystemHealth};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Args;
+use signal_hook::consts::signal::SIGHUP;
+use signal_hook_tokio::Signals;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::select;
 use tokio::time;
+use tokio_stream::StreamExt;
 use tracing::{debug, error, info, warn};
 
+/// Longest accepted `--interval` (one check a day)
+const MAX_STATUS_INTERVAL_SECS: u64 = 86_400;
+
+/// Settings the status monitor applies on SIGHUP (dotted config keys or prefixes)
+///
+/// The check interval comes from `--interval` only, so anything else (such as
+/// `sync.poll_interval_secs`) is reported as needing a restart.
+const STATUS_RELOAD_KEYS: &[&str] = &[
+    "rpc.url",
+    "rpc.metrics_url",
+    "rpc.timeout_ms",
+    "thresholds.",
+    "alerts.",
+    "display.timezone",
+];
+
+/// Severity of a status report line (picks the log level or color)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// One line of the status report
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusLine {
+    pub level: LineLevel,
+    pub text: String,
+}
+
+/// Append a formatted line at the given level to a status report
+macro_rules! status_line {
+    ($out:expr, $level:ident, $($arg:tt)*) => {
+        $out.push(StatusLine {
+            level: LineLevel::$level,
+            text: format!($($arg)*),
+        })
+    };
+}
+
 /// Status command arguments
 #[derive(Args, Debug)]
 pub struct StatusArgs {
@@ -29,17 +79,33 @@ pub struct StatusArgs {
     #[arg(short = 'K', long, conflicts_with = "keys_file")]
     pub keystore: Option<PathBuf>,
 
-    /// Monitoring interval in seconds
-    #[arg(short, long)]
+    /// Monitoring interval in seconds (with --watch, defaults to the slot time)
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..=MAX_STATUS_INTERVAL_SECS))]
     pub interval: Option<u64>,
 
     /// Run once and exit (don't loop)
     #[arg(long)]
     pub once: bool,
 
+    /// Redraw the status block in place every --interval seconds (Ctrl+C to exit)
+    #[arg(long, conflicts_with_all = ["once", "check", "oneline"])]
+    pub watch: bool,
+
+    /// Run once and exit non-zero if the node is unhealthy (for cron / monitoring checks)
+    #[arg(long, conflicts_with = "oneline")]
+    pub check: bool,
+
     /// Show explanations for each metric (educational mode)
     #[arg(short = 'E', long)]
     pub explain: bool,
+
+    /// Print a single colored status line and exit (for shell prompts / tmux)
+    #[arg(long, conflicts_with = "explain")]
+    pub oneline: bool,
+
+    /// Maximum visible width of the --oneline output
+    #[arg(long, default_value_t = 80, requires = "oneline")]
+    pub width: usize,
 }
 
 /// Combined validator status for display
@@ -52,6 +118,8 @@ pub struct ValidatorStatus {
     pub sidechain_status: Option<SidechainStatus>,
     pub peer_count: usize,
     pub blocks_produced: u64,
+    /// GRANDPA voter flag from Prometheus (None when metrics are unavailable)
+    pub grandpa_voter: Option<bool>,
     pub key_status: Option<KeyStatus>,
 }
 
@@ -69,8 +137,13 @@ impl ValidatorStatus {
         }
     }
 
-    pub fn is_healthy(&self) -> bool {
-        !self.health.is_syncing && self.health.peers > 0
+    /// Composite health check (sync, peers, finalized lag) against thresholds
+    pub fn health_check(&self, thresholds: &ThresholdsConfig) -> std::result::Result<(), String> {
+        thresholds.assess_node(
+            !self.health.is_syncing,
+            Some(self.peer_count as u64),
+            self.current_block.saturating_sub(self.finalized_block),
+        )
     }
 }
 
@@ -80,6 +153,7 @@ pub struct StatusMonitor {
     metrics: MetricsClient,
     keys: Option<ValidatorKeys>,
     explain: bool,
+    thresholds: ThresholdsConfig,
 }
 
 impl StatusMonitor {
@@ -95,9 +169,16 @@ impl StatusMonitor {
             metrics: MetricsClient::new(metrics_url),
             keys,
             explain,
+            thresholds: ThresholdsConfig::default(),
         }
     }
 
+    /// Set node health thresholds
+    pub fn with_thresholds(mut self, thresholds: ThresholdsConfig) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
     pub async fn get_health(&self) -> Result<SystemHealth> {
         self.rpc.call("system_health", Vec::<()>::new()).await
     }
@@ -148,11 +229,11 @@ impl StatusMonitor {
             }
         };
 
-        let blocks_produced = match self.metrics.fetch_metrics().await {
-            Ok(m) => m.blocks_produced,
+        let (blocks_produced, grandpa_voter) = match self.metrics.fetch_metrics().await {
+            Ok(m) => (m.blocks_produced, Some(m.grandpa_voter)),
             Err(e) => {
                 debug!("Could not fetch metrics: {}", e);
-                0
+                (0, None)
             }
         };
 
@@ -176,180 +257,347 @@ impl StatusMonitor {
             sidechain_status,
             peer_count,
             blocks_produced,
+            grandpa_voter,
             key_status,
         })
     }
 
+    /// Log the status block (one log line per report line, at its level)
     pub fn display_status(&self, status: &ValidatorStatus) {
-        let health_icon = if status.is_healthy() { "✓" } else { "✗" };
+        for line in self.status_lines(status) {
+            match line.level {
+                LineLevel::Info => info!("{}", line.text),
+                LineLevel::Warn => warn!("{}", line.text),
+                LineLevel::Error => error!("{}", line.text),
+            }
+        }
+    }
+
+    /// The status block as lines, shared by the log output and `--watch`
+    pub fn status_lines(&self, status: &ValidatorStatus) -> Vec<StatusLine> {
+        let mut out = Vec::new();
+        let health_icon = if status.health_check(&self.thresholds).is_ok() {
+            "✓"
+        } else {
+            "✗"
+        };
         let sync_icon = if status.health.is_syncing {
             "⟳"
         } else {
             "✓"
         };
 
-        info!("─────────────────────────────────────────");
-        info!(
+        status_line!(out, Info, "─────────────────────────────────────────");
+        status_line!(
+            out,
+            Info,
             "Health: {} | Syncing: {} | Peers: {}",
-            health_icon, sync_icon, status.peer_count
+            health_icon,
+            sync_icon,
+            status.peer_count
         );
         if self.explain {
-            info!("  → Health: Combined indicator - node is connected and not syncing");
-            info!("  → Syncing: ✓ means synced to chain tip, ⟳ means still catching up");
-            info!("  → Peers: Number of connected nodes. Want 10+, minimum 3-5 to function");
+            status_line!(
+                out,
+                Info,
+                "  → Health: Combined indicator - synced, has peers, finality within threshold"
+            );
+            status_line!(
+                out,
+                Info,
+                "  → Syncing: ✓ means synced to chain tip, ⟳ means still catching up"
+            );
+            status_line!(
+                out,
+                Info,
+                "  → Peers: Number of connected nodes. Want 10+, minimum 3-5 to function"
+            );
         }
 
-        info!(
+        status_line!(
+            out,
+            Info,
             "Block: {} | Finalized: {} | Sync: {:.2}%",
             status.current_block,
             status.finalized_block,
             status.sync_percentage()
         );
         if self.explain {
-            info!("  → Block: Current best block (may not be finalized yet)");
-            info!("  → Finalized: Highest block confirmed by GRANDPA consensus");
-            info!("  → Sync: Percentage of known chain downloaded (100% = fully synced)");
+            status_line!(
+                out,
+                Info,
+                "  → Block: Current best block (may not be finalized yet)"
+            );
+            status_line!(
+                out,
+                Info,
+                "  → Finalized: Highest block confirmed by GRANDPA consensus"
+            );
+            status_line!(
+                out,
+                Info,
+                "  → Sync: Percentage of known chain downloaded (100% = fully synced)"
+            );
         }
 
-        info!("Blocks produced: {}", status.blocks_produced);
+        status_line!(out, Info, "Blocks produced: {}", status.blocks_produced);
         if self.explain {
-            info!("  → Blocks produced: Total blocks authored by your node since startup");
-            info!("    This resets when node restarts. For historical data, use 'mvm view'");
+            status_line!(
+                out,
+                Info,
+                "  → Blocks produced: Total blocks authored by your node since startup"
+            );
+            status_line!(
+                out,
+                Info,
+                "    This resets when node restarts. For historical data, use 'mvm view'"
+            );
         }
 
         if let Some(ref sc) = status.sidechain_status {
-            info!(
+            status_line!(
+                out,
+                Info,
                 "Sidechain: epoch {} slot {} | Mainchain: epoch {} slot {}",
-                sc.sidechain.epoch, sc.sidechain.slot, sc.mainchain.epoch, sc.mainchain.slot
+                sc.sidechain.epoch,
+                sc.sidechain.slot,
+                sc.mainchain.epoch,
+                sc.mainchain.slot
             );
             if self.explain {
-                info!("  → Sidechain epoch: 2-hour cycle (preview) that determines committee");
-                info!("  → Mainchain epoch: 24-hour cycle (preview) used for registration");
-                info!("  → Slot: 6-second block production window within each epoch");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Sidechain epoch: 2-hour cycle (preview) that determines committee"
+                );
+                status_line!(
+                    out,
+                    Info,
+                    "  → Mainchain epoch: 24-hour cycle (preview) used for registration"
+                );
+                status_line!(
+                    out,
+                    Info,
+                    "  → Slot: 6-second block production window within each epoch"
+                );
             }
         }
 
         if let Some(ref ks) = status.key_status {
-            self.display_key_status(ks);
+            self.key_status_lines(ks, &mut out);
         }
 
         // Warnings
         if status.health.is_syncing {
-            warn!("Node is still syncing");
+            status_line!(out, Warn, "Node is still syncing");
             if self.explain {
-                info!("  → Your node must finish syncing before it can produce blocks");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Your node must finish syncing before it can produce blocks"
+                );
             }
         }
         if status.peer_count == 0 {
-            error!("No peers connected!");
+            status_line!(out, Error, "No peers connected!");
             if self.explain {
-                info!("  → Check internet connectivity and firewall (port 30333)");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Check internet connectivity and firewall (port 30333)"
+                );
             }
         }
-        if status.current_block.saturating_sub(status.finalized_block) > 100 {
-            warn!(
+        if status.current_block.saturating_sub(status.finalized_block)
+            > self.thresholds.max_finalized_lag
+        {
+            status_line!(
+                out,
+                Warn,
                 "Large finality gap: {} blocks behind",
                 status.current_block - status.finalized_block
             );
             if self.explain {
-                info!("  → Normally finality is within 10-20 blocks. Large gap may indicate");
-                info!("    network issues or your node is on a minority fork");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Normally finality is within 10-20 blocks. Large gap may indicate"
+                );
+                status_line!(
+                    out,
+                    Info,
+                    "    network issues or your node is on a minority fork"
+                );
             }
         }
+        out
     }
 
-    fn display_key_status(&self, ks: &KeyStatus) {
+    fn key_status_lines(&self, ks: &KeyStatus, out: &mut Vec<StatusLine>) {
         let sc_icon = key_status_icon(ks.sidechain_loaded);
         let aura_icon = key_status_icon(ks.aura_loaded);
         let gran_icon = key_status_icon(ks.grandpa_loaded);
 
-        info!(
+        status_line!(
+            out,
+            Info,
             "Keys: sidechain {} | aura {} | grandpa {}",
-            sc_icon, aura_icon, gran_icon
+            sc_icon,
+            aura_icon,
+            gran_icon
         );
         if self.explain {
-            info!("  → Sidechain (crch): Your validator's identity key");
-            info!("  → Aura: Block production authorization key");
-            info!("  → Grandpa: Finality voting key");
-            info!("  → ✓ = loaded in node keystore, ✗ = missing, ? = unable to verify");
+            status_line!(
+                out,
+                Info,
+                "  → Sidechain (crch): Your validator's identity key"
+            );
+            status_line!(out, Info, "  → Aura: Block production authorization key");
+            status_line!(out, Info, "  → Grandpa: Finality voting key");
+            status_line!(
+                out,
+                Info,
+                "  → ✓ = loaded in node keystore, ✗ = missing, ? = unable to verify"
+            );
         }
 
         // Show note if keys can't be verified
         if ks.sidechain_loaded.is_none() && ks.aura_loaded.is_none() && ks.grandpa_loaded.is_none()
         {
-            info!("Note: Key verification requires node started with --rpc-methods=unsafe");
+            status_line!(
+                out,
+                Info,
+                "Note: Key verification requires node started with --rpc-methods=unsafe"
+            );
         }
 
         match &ks.registration {
             Some(RegistrationStatus::Permissioned) => {
-                info!("Registration: ✓ Permissioned candidate");
+                status_line!(out, Info, "Registration: ✓ Permissioned candidate");
                 if self.explain {
-                    info!("  → Permissioned = Midnight Foundation validator, no stake required");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Permissioned = Midnight Foundation validator, no stake required"
+                    );
                 }
             }
             Some(RegistrationStatus::RegisteredValid) => {
-                info!("Registration: ✓ Registered (valid)");
+                status_line!(out, Info, "Registration: ✓ Registered (valid)");
                 if self.explain {
-                    info!("  → Your registration is active and eligible for committee selection");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Your registration is active and eligible for committee selection"
+                    );
                 }
             }
             Some(RegistrationStatus::RegisteredInvalid(reason)) => {
-                warn!("Registration: ⚠ Registered but INVALID: {}", reason);
+                status_line!(
+                    out,
+                    Warn,
+                    "Registration: ⚠ Registered but INVALID: {}",
+                    reason
+                );
                 if self.explain {
-                    info!("  → Registration exists but not valid. May be pending or have issues.");
-                    info!("  → Common causes: insufficient stake, keys mismatch, processing delay");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Registration exists but not valid. May be pending or have issues."
+                    );
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Common causes: insufficient stake, keys mismatch, processing delay"
+                    );
                 }
             }
             Some(RegistrationStatus::NotRegistered) => {
-                error!("Registration: ✗ Not registered");
+                status_line!(out, Error, "Registration: ✗ Not registered");
                 if self.explain {
-                    info!("  → No registration found. Submit registration transaction to participate.");
+                    status_line!(out, Info, "  → No registration found. Submit registration transaction to participate.");
                 }
             }
             None => {
-                info!("Registration: ? Unable to check");
+                status_line!(out, Info, "Registration: ? Unable to check");
             }
         }
 
         // Display committee status
         if let Some(ref committee) = ks.committee_status {
             if committee.in_committee {
-                info!(
+                status_line!(
+                    out,
+                    Info,
                     "Committee: ✓ Elected ({} seats / {} total = {:.2}% selection)",
                     committee.seat_count,
                     committee.committee_size,
                     committee.selection_probability * 100.0
                 );
                 if self.explain {
-                    info!("  → You ARE in this epoch's committee and CAN produce blocks");
-                    info!("  → Seats = how many times you appear in the rotation schedule");
-                    info!("  → More seats = more block production opportunities");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → You ARE in this epoch's committee and CAN produce blocks"
+                    );
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Seats = how many times you appear in the rotation schedule"
+                    );
+                    status_line!(
+                        out,
+                        Info,
+                        "  → More seats = more block production opportunities"
+                    );
                 }
-                info!(
+                status_line!(
+                    out,
+                    Info,
                     "Expected blocks: ~{:.1} per sidechain epoch",
                     committee.expected_blocks_per_epoch
                 );
                 if self.explain {
-                    info!(
+                    status_line!(
+                        out,
+                        Info,
                         "  → Based on your seat count and epoch duration (1200 blocks on preview)"
                     );
                 }
             } else {
-                warn!(
+                status_line!(
+                    out,
+                    Warn,
                     "Committee: ✗ NOT elected (committee size: {})",
                     committee.committee_size
                 );
-                warn!(
+                status_line!(
+                    out,
+                    Warn,
                     "Your validator is registered but was not selected for this epoch's committee."
                 );
-                warn!("Committee selection is stake-weighted random. Keep your node running and staked.");
+                status_line!(out, Warn, "Committee selection is stake-weighted random. Keep your node running and staked.");
                 if self.explain {
-                    info!("  → Being registered does NOT guarantee committee selection");
-                    info!(
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Being registered does NOT guarantee committee selection"
+                    );
+                    status_line!(
+                        out,
+                        Info,
                         "  → Selection is stake-weighted random each sidechain epoch (2h preview)"
                     );
-                    info!("  → Higher stake = higher probability, but not guaranteed");
-                    info!("  → This is NORMAL - wait for next epoch or increase stake");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Higher stake = higher probability, but not guaranteed"
+                    );
+                    status_line!(
+                        out,
+                        Info,
+                        "  → This is NORMAL - wait for next epoch or increase stake"
+                    );
                 }
             }
 
@@ -357,39 +605,139 @@ impl StatusMonitor {
             if let Some(stake) = committee.stake_lovelace {
                 let ada = stake as f64 / 1_000_000.0;
                 if ada >= 1_000_000.0 {
-                    info!("Stake: {:.2}M tADA", ada / 1_000_000.0);
+                    status_line!(out, Info, "Stake: {:.2}M tADA", ada / 1_000_000.0);
                 } else if ada >= 1_000.0 {
-                    info!("Stake: {:.2}K tADA", ada / 1_000.0);
+                    status_line!(out, Info, "Stake: {:.2}K tADA", ada / 1_000.0);
                 } else {
-                    info!("Stake: {:.2} tADA", ada);
+                    status_line!(out, Info, "Stake: {:.2} tADA", ada);
                 }
                 if self.explain {
-                    info!("  → Your delegated stake affects committee selection probability");
+                    status_line!(
+                        out,
+                        Info,
+                        "  → Your delegated stake affects committee selection probability"
+                    );
                 }
             }
         }
 
         if ks.sidechain_loaded == Some(false) {
-            error!("Sidechain key not loaded in keystore!");
+            status_line!(out, Error, "Sidechain key not loaded in keystore!");
             if self.explain {
-                info!("  → Copy your sidechain key file to the node's keystore directory");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Copy your sidechain key file to the node's keystore directory"
+                );
             }
         }
         if ks.aura_loaded == Some(false) {
-            error!("Aura key not loaded in keystore!");
+            status_line!(out, Error, "Aura key not loaded in keystore!");
             if self.explain {
-                info!("  → Without AURA key, your node cannot produce blocks");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Without AURA key, your node cannot produce blocks"
+                );
             }
         }
         if ks.grandpa_loaded == Some(false) {
-            error!("Grandpa key not loaded in keystore!");
+            status_line!(out, Error, "Grandpa key not loaded in keystore!");
             if self.explain {
-                info!("  → Without GRANDPA key, your node cannot vote on finality");
+                status_line!(
+                    out,
+                    Info,
+                    "  → Without GRANDPA key, your node cannot vote on finality"
+                );
             }
         }
     }
 }
 
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// Format status as a single ANSI-colored line, e.g.
+/// `synced #3434818 fin-3 ep245698 4.6% peers16 blocks:2`
+///
+/// Segments are dropped from the end so the visible text fits within `width`.
+/// With `color` off the same text is emitted without escape codes.
+pub fn format_oneline(status: &ValidatorStatus, width: usize, color: bool) -> String {
+    let mut segments: Vec<(String, &str)> = Vec::new();
+
+    if status.health.is_syncing {
+        segments.push((
+            format!("syncing {:.1}%", status.sync_percentage()),
+            ANSI_YELLOW,
+        ));
+    } else if status.peer_count == 0 {
+        segments.push(("isolated".to_string(), ANSI_RED));
+    } else {
+        segments.push(("synced".to_string(), ANSI_GREEN));
+    }
+
+    segments.push((format!("#{}", status.current_block), ""));
+
+    let finality_gap = status.current_block.saturating_sub(status.finalized_block);
+    let fin_color = if finality_gap > 100 {
+        ANSI_YELLOW
+    } else {
+        ANSI_DIM
+    };
+    segments.push((format!("fin-{}", finality_gap), fin_color));
+
+    if let Some(ref sc) = status.sidechain_status {
+        segments.push((format!("ep{}", sc.sidechain.epoch), ""));
+    }
+
+    if let Some(committee) = status
+        .key_status
+        .as_ref()
+        .and_then(|ks| ks.committee_status.as_ref())
+    {
+        if committee.in_committee {
+            segments.push((
+                format!("{:.1}%", committee.selection_probability * 100.0),
+                ANSI_GREEN,
+            ));
+        } else {
+            segments.push(("unelected".to_string(), ANSI_YELLOW));
+        }
+    }
+
+    let peer_color = if status.peer_count == 0 { ANSI_RED } else { "" };
+    segments.push((format!("peers{}", status.peer_count), peer_color));
+    segments.push((format!("blocks:{}", status.blocks_produced), ""));
+
+    let mut line = String::new();
+    let mut visible = 0;
+    for (text, style) in segments {
+        let separator = usize::from(visible > 0);
+        let len = text.chars().count();
+        if visible + separator + len > width {
+            break;
+        }
+        if separator > 0 {
+            line.push(' ');
+        }
+        line.push_str(&paint(&text, style, color));
+        visible += separator + len;
+    }
+    line
+}
+
+/// Wrap text in an ANSI style, or return it unchanged when colors are off
+fn paint(text: &str, style: &str, color: bool) -> String {
+    if !color || style.is_empty() {
+        text.to_string()
+    } else {
+        format!("{}{}{}", style, text, ANSI_RESET)
+    }
+}
+
 fn key_status_icon(status: Option<bool>) -> &'static str {
     match status {
         Some(true) => "✓",
@@ -399,13 +747,16 @@ fn key_status_icon(status: Option<bool>) -> &'static str {
 }
 
 /// Run the status command
-pub async fn run(args: StatusArgs) -> Result<()> {
+pub async fn run(args: StatusArgs, color: bool) -> Result<()> {
     // Load configuration
     let config = crate::config::Config::load()?;
 
     // Use args or fall back to config
-    let rpc_url = args.rpc_url.unwrap_or(config.rpc.url);
-    let metrics_url = args.metrics_url.unwrap_or(config.rpc.metrics_url);
+    let rpc_url = args.rpc_url.clone().unwrap_or(config.rpc.url.clone());
+    let metrics_url = args
+        .metrics_url
+        .clone()
+        .unwrap_or(config.rpc.metrics_url.clone());
     let interval = args.interval.unwrap_or(60);
 
     info!("RPC endpoint: {}", rpc_url);
@@ -427,7 +778,7 @@ pub async fn run(args: StatusArgs) -> Result<()> {
         // Try keystore from args or config
         let keystore_path = args
             .keystore
-            .or_else(|| config.validator.keystore_path.map(PathBuf::from));
+            .or_else(|| config.validator.keystore_path.clone().map(PathBuf::from));
 
         if let Some(ref keystore_path) = keystore_path {
             match ValidatorKeys::from_keystore(keystore_path) {
@@ -453,13 +804,22 @@ pub async fn run(args: StatusArgs) -> Result<()> {
         }
     };
 
-    let monitor = StatusMonitor::new(
+    let mut monitor = StatusMonitor::new(
         &rpc_url,
         &metrics_url,
         keys,
         config.rpc.timeout_ms,
         args.explain,
-    );
+    )
+    .with_thresholds(config.thresholds.clone());
+
+    if args.oneline {
+        match monitor.get_status().await {
+            Ok(status) => println!("{}", format_oneline(&status, args.width, color)),
+            Err(_) => println!("{}", paint("down", ANSI_RED, color)),
+        }
+        return Ok(());
+    }
 
     // Try to get version on startup
     match monitor.get_version().await {
@@ -473,28 +833,389 @@ pub async fn run(args: StatusArgs) -> Result<()> {
         }
     }
 
-    if args.once {
+    if args.check {
+        let status = monitor
+            .get_status()
+            .await
+            .context("Health check failed: could not fetch node status")?;
+        monitor.display_status(&status);
+        if let Err(reason) = status.health_check(&config.thresholds) {
+            anyhow::bail!("Health check failed: {}", reason);
+        }
+        info!("Health check passed");
+    } else if args.once {
         run_check(&monitor).await;
+    } else if args.watch {
+        let interval = args
+            .interval
+            .unwrap_or(config.chain.timing().slot_duration_ms / 1000)
+            .max(1);
+        run_watch(&monitor, interval, config.display.time_zone(), color).await?;
     } else {
         info!("Monitoring interval: {}s", interval);
         let mut interval_timer = time::interval(Duration::from_secs(interval));
 
+        // SIGHUP reloads endpoint settings without restarting the monitor
+        let mut signals = Signals::new([SIGHUP])
+            .context("Failed to register signal handlers")?
+            .fuse();
+        let mut config = config;
+        let mut peer_floor = PeerFloorMonitor::default();
+        let mut grandpa_voter = GrandpaVoterMonitor::default();
+        let mut quiet_gate = QuietHoursGate::new(&config.alerts, config.display.time_zone());
+
         loop {
-            interval_timer.tick().await;
-            run_check(&monitor).await;
+            select! {
+                _ = interval_timer.tick() => {
+                    flush_alert_digest(&mut quiet_gate, &config.alerts).await;
+                    if let Some(status) = run_check(&monitor).await {
+                        let alert = peer_floor.observe(
+                            status.peer_count as u64,
+                            config.thresholds.peers_crit,
+                            Duration::from_secs(config.thresholds.peers_alert_after_secs),
+                            Instant::now(),
+                        );
+                        if let Some(alert) = alert {
+                            notify_peer_floor(&alert, &config.alerts, &mut quiet_gate, chrono::Utc::now())
+                                .await;
+                        }
+                        let voter_alert = status
+                            .grandpa_voter
+                            .filter(|_| config.alerts.grandpa_voter)
+                            .and_then(|voter| grandpa_voter.observe(voter));
+                        if let Some(alert) = voter_alert {
+                            notify_grandpa_voter(alert, &config.alerts, &mut quiet_gate, chrono::Utc::now())
+                                .await;
+                        }
+                    }
+                }
+                Some(_) = signals.next() => {
+                    info!("Received SIGHUP, reloading configuration...");
+                    match config.reload() {
+                        Ok((new_config, changes)) => {
+                            if changes.is_empty() {
+                                info!("Config reload: no changes");
+                                continue;
+                            }
+                            for change in &changes {
+                                if STATUS_RELOAD_KEYS.iter().any(|k| change.key.starts_with(k)) {
+                                    info!("Config reload: {}", change);
+                                } else {
+                                    warn!(
+                                        "Config reload: {} (requires restart to take effect)",
+                                        change
+                                    );
+                                }
+                            }
+                            config = new_config;
+                            quiet_gate.reconfigure(&config.alerts, config.display.time_zone());
+
+                            // CLI flags keep precedence over the config file
+                            let rpc_url = args.rpc_url.clone().unwrap_or(config.rpc.url.clone());
+                            let metrics_url = args
+                                .metrics_url
+                                .clone()
+                                .unwrap_or(config.rpc.metrics_url.clone());
+                            monitor = StatusMonitor::new(
+                                &rpc_url,
+                                &metrics_url,
+                                monitor.keys.take(),
+                                config.rpc.timeout_ms,
+                                args.explain,
+                            )
+                            .with_thresholds(config.thresholds.clone());
+                            info!("RPC endpoint: {}", rpc_url);
+                            info!("Metrics endpoint: {}", metrics_url);
+                        }
+                        Err(e) => {
+                            warn!("Config reload failed, keeping current settings: {:#}", e);
+                        }
+                    }
+                }
+            }
         }
     }
 
     Ok(())
 }
 
-async fn run_check(monitor: &StatusMonitor) {
+async fn run_check(monitor: &StatusMonitor) -> Option<ValidatorStatus> {
     match monitor.get_status().await {
         Ok(status) => {
             monitor.display_status(&status);
+            Some(status)
         }
         Err(e) => {
             error!("Failed to fetch validator status: {}", e);
+            None
+        }
+    }
+}
+
+/// Redraw the status block in place every `interval` seconds until Ctrl+C
+///
+/// Unlike wrapping `mvm status` in watch(1), the process and its RPC
+/// connections stay up between refreshes.
+async fn run_watch(
+    monitor: &StatusMonitor,
+    interval: u64,
+    tz: DisplayTimeZone,
+    color: bool,
+) -> Result<()> {
+    use std::io::Write;
+
+    let mut timer = time::interval(Duration::from_secs(interval));
+    let mut stdout = std::io::stdout();
+    loop {
+        select! {
+            _ = timer.tick() => {
+                let lines = match monitor.get_status().await {
+                    Ok(status) => monitor.status_lines(&status),
+                    Err(e) => vec![StatusLine {
+                        level: LineLevel::Error,
+                        text: format!("Failed to fetch validator status: {}", e),
+                    }],
+                };
+                let header = format!(
+                    "Every {}s · {} · Ctrl+C to exit",
+                    interval,
+                    tz.format(chrono::Utc::now().timestamp())
+                );
+                write!(stdout, "{}", format_watch_screen(&header, &lines, color))?;
+                stdout.flush()?;
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+    Ok(())
+}
+
+/// A full `--watch` screen: clear, header, then the status lines colored by level
+fn format_watch_screen(header: &str, lines: &[StatusLine], color: bool) -> String {
+    let mut screen = String::from("\x1b[H\x1b[2J");
+    screen.push_str(&paint(header, ANSI_DIM, color));
+    screen.push('\n');
+    for line in lines {
+        let text = match line.level {
+            LineLevel::Info => line.text.clone(),
+            LineLevel::Warn => paint(&line.text, ANSI_YELLOW, color),
+            LineLevel::Error => paint(&line.text, ANSI_RED, color),
+        };
+        screen.push_str(&text);
+        screen.push('\n');
+    }
+    screen
+}
+
+/// Log a peer floor alert and forward it to the alert webhook if enabled
+async fn notify_peer_floor(
+    alert: &PeerFloorAlert,
+    alerts: &AlertConfig,
+    quiet_gate: &mut QuietHoursGate,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    match alert {
+        PeerFloorAlert::Below { .. } => error!("{}", alert.message()),
+        PeerFloorAlert::Recovered { .. } => info!("{}", alert.message()),
+    }
+
+    if let (true, Some(url)) = (alerts.enabled, alerts.webhook_url.as_deref()) {
+        if !quiet_gate.admit(alert.severity(), &alert.message(), now) {
+            return;
+        }
+        if let Err(e) = send_peer_floor_webhook(url, alert).await {
+            warn!("Failed to send peer alert webhook: {}", e);
+        }
+    }
+}
+
+/// Log a GRANDPA voter alert and forward it to the alert webhook if enabled
+async fn notify_grandpa_voter(
+    alert: GrandpaVoterAlert,
+    alerts: &AlertConfig,
+    quiet_gate: &mut QuietHoursGate,
+    now: chrono::DateTime<chrono::Utc>,
+) {
+    match alert {
+        GrandpaVoterAlert::Stopped => error!("{}", alert.message()),
+        GrandpaVoterAlert::Resumed => info!("{}", alert.message()),
+    }
+
+    if let (true, Some(url)) = (alerts.enabled, alerts.webhook_url.as_deref()) {
+        if !quiet_gate.admit(alert.severity(), &alert.message(), now) {
+            return;
+        }
+        if let Err(e) = send_grandpa_voter_webhook(url, alert).await {
+            warn!("Failed to send GRANDPA voter alert webhook: {}", e);
+        }
+    }
+}
+
+/// Send warnings held during quiet hours once the window has ended
+async fn flush_alert_digest(quiet_gate: &mut QuietHoursGate, alerts: &AlertConfig) {
+    let Some(digest) = quiet_gate.take_digest(chrono::Utc::now()) else {
+        return;
+    };
+    if let (true, Some(url)) = (alerts.enabled, alerts.webhook_url.as_deref()) {
+        if let Err(e) = send_digest_webhook(url, &digest).await {
+            warn!("Failed to send alert digest webhook: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midnight::keystore::CommitteeStatus;
+    use crate::rpc::ChainStatus;
+    use std::sync::{Arc, Mutex};
+
+    fn status() -> ValidatorStatus {
+        let chain = |epoch| ChainStatus {
+            epoch,
+            slot: 0,
+            next_epoch_timestamp: None,
+        };
+        ValidatorStatus {
+            health: SystemHealth {
+                peers: 16,
+                is_syncing: false,
+                should_have_peers: true,
+            },
+            sync_state: None,
+            current_block: 3434818,
+            finalized_block: 3434815,
+            sidechain_status: Some(SidechainStatus {
+                sidechain: chain(245698),
+                mainchain: chain(1179),
+            }),
+            peer_count: 16,
+            blocks_produced: 2,
+            grandpa_voter: Some(true),
+            key_status: Some(KeyStatus {
+                sidechain_loaded: Some(true),
+                aura_loaded: Some(true),
+                grandpa_loaded: Some(true),
+                registration: Some(RegistrationStatus::RegisteredValid),
+                committee_status: Some(CommitteeStatus {
+                    in_committee: true,
+                    seat_count: 46,
+                    committee_size: 1000,
+                    stake_lovelace: None,
+                    selection_probability: 0.046,
+                    expected_blocks_per_epoch: 55.2,
+                }),
+            }),
         }
     }
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_format_oneline() {
+        let line = format_oneline(&status(), 80, true);
+        assert_eq!(
+            strip_ansi(&line),
+            "synced #3434818 fin-3 ep245698 4.6% peers16 blocks:2"
+        );
+        assert!(line.contains(ANSI_GREEN));
+
+        // Trailing segments are dropped to fit the width
+        let line = strip_ansi(&format_oneline(&status(), 30, true));
+        assert_eq!(line, "synced #3434818 fin-3 ep245698");
+        assert!(line.len() <= 30);
+
+        let mut syncing = status();
+        syncing.health.is_syncing = true;
+        syncing.sync_state = Some(SyncState {
+            current_block: 500,
+            highest_block: 1000,
+            starting_block: 0,
+        });
+        assert!(strip_ansi(&format_oneline(&syncing, 80, true)).starts_with("syncing 50.0% "));
+
+        // No escape codes at all with colors disabled
+        let plain = format_oneline(&status(), 80, false);
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(plain, strip_ansi(&format_oneline(&status(), 80, true)));
+    }
+
+    #[test]
+    fn test_watch_screen() {
+        let monitor = StatusMonitor::new("http://localhost:9944", "", None, 1000, false);
+        let mut status = status();
+        status.peer_count = 0;
+        let lines = monitor.status_lines(&status);
+        assert!(lines[1]
+            .text
+            .starts_with("Health: ✗ | Syncing: ✓ | Peers: 0"));
+        assert!(lines.contains(&StatusLine {
+            level: LineLevel::Error,
+            text: "No peers connected!".to_string(),
+        }));
+
+        // Each redraw starts by clearing the screen; levels pick the color
+        let screen = format_watch_screen("Every 6s", &lines, true);
+        assert!(screen.starts_with("\x1b[H\x1b[2J"));
+        assert!(screen.contains(&format!("{}No peers connected!{}", ANSI_RED, ANSI_RESET)));
+        let plain = format_watch_screen("Every 6s", &lines, false);
+        assert_eq!(plain.matches('\x1b').count(), 2);
+        assert_eq!(plain.lines().count(), lines.len() + 1);
+    }
+
+    #[tokio::test]
+    async fn test_notify_peer_floor_quiet_hours() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let webhook = {
+            let sent = sent.clone();
+            crate::rpc::mock::serve(Arc::new(move |body| {
+                sent.lock().unwrap().push(body);
+                serde_json::json!({})
+            }))
+            .await
+        };
+        let alerts = AlertConfig {
+            enabled: true,
+            webhook_url: Some(webhook),
+            quiet_hours: Some("23:00-07:00".to_string()),
+            ..AlertConfig::default()
+        };
+        let mut gate = QuietHoursGate::new(&alerts, crate::config::DisplayTimeZone::Utc);
+        let at = |h| {
+            chrono::DateTime::parse_from_rfc3339(&format!("2025-06-01T{:02}:00:00Z", h))
+                .unwrap()
+                .with_timezone(&chrono::Utc)
+        };
+
+        // At night the drop pages, but the recovery is held for the digest
+        let below = PeerFloorAlert::Below {
+            peers: 0,
+            floor: 3,
+            duration_secs: 300,
+        };
+        notify_peer_floor(&below, &alerts, &mut gate, at(3)).await;
+        let recovered = PeerFloorAlert::Recovered { peers: 8, floor: 3 };
+        notify_peer_floor(&recovered, &alerts, &mut gate, at(4)).await;
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0]["alert_type"], "peer_count_low");
+        assert_eq!(sent[0]["severity"], "critical");
+        assert_eq!(gate.take_digest(at(8)), Some(vec![recovered.message()]));
+    }
 }
@@ -1,15 +1,28 @@
 //! Sync command - synchronize blocks to local database
 
-use crate::db::{BlockRecord, Database, ValidatorEpochRecord, ValidatorRecord};
-use crate::midnight::{extract_slot_from_digest, ChainTiming, ValidatorSet};
-use crate::rpc::{RpcClient, SidechainStatus, SignedBlock};
+use crate::config::{slow_poll_warning, MAX_POLL_INTERVAL_SECS};
+use crate::db::{
+    check_chain_identity, BlockRecord, ChainCheck, ChainIdentity, Database, EpochAnomaly,
+    NetworkSample, SyncSample, ValidatorEpochRecord, ValidatorRecord,
+};
+use crate::metrics::MetricsClient;
+use crate::midnight::validators::Validator;
+use crate::midnight::{
+    extract_slot_from_digest, Calibration, ChainTiming, StatePruning, ValidatorSet,
+};
+use crate::rpc::{
+    decode_lenient, BlockHeader, HeadSubscription, RpcClient, SidechainStatus, SignedBlock,
+    SystemHealth,
+};
+use crate::server::{ExporterState, HealthState, SharedExporter, SharedHealth};
 use anyhow::{Context, Result};
 use clap::Args;
 use signal_hook::consts::signal::*;
 use signal_hook_tokio::Signals;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::select;
 use tokio::time;
 use tokio_stream::StreamExt;
@@ -39,7 +52,7 @@ pub struct SyncArgs {
     pub finalized_only: Option<bool>,
 
     /// Seconds between new block checks
-    #[arg(long)]
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..=MAX_POLL_INTERVAL_SECS))]
     pub poll_interval: Option<u64>,
 
     /// Run in daemon mode (continuous sync)
@@ -49,12 +62,58 @@ pub struct SyncArgs {
     /// PID file path for daemon mode
     #[arg(long)]
     pub pid_file: Option<PathBuf>,
+
+    /// Serve /healthz, /readyz and /metrics on this address (e.g. 127.0.0.1:9101)
+    #[arg(long)]
+    pub listen: Option<String>,
+
+    /// Serve only /metrics (database lag) on this address (e.g. 127.0.0.1:9900)
+    #[arg(long)]
+    pub exporter_bind: Option<String>,
+
+    /// Print the sync plan (range, pruning, gaps) without fetching or writing blocks
+    #[arg(long, conflicts_with = "daemon")]
+    pub dry_run: bool,
+
+    /// Query epochs for every block during initial sync instead of reusing them within an epoch
+    #[arg(long)]
+    pub no_catch_up: bool,
+
+    /// Sync even if the node is on a different chain than the one this database was built from
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Settings `mvm sync` picks up on SIGHUP without a restart (dotted config keys or prefixes)
+const HOT_RELOAD_KEYS: &[&str] = &[
+    "sync.poll_interval_secs",
+    "sync.finalized_only",
+    "sync.network_",
+    "sync.sync_sample_secs",
+    "sync.sync_history_days",
+    "sync.calibration_interval_secs",
+    "validator.label",
+];
+
+fn is_hot_reloadable(key: &str) -> bool {
+    HOT_RELOAD_KEYS.iter().any(|k| key.starts_with(k))
+}
+
+/// Warn when the poll interval is long enough to trail block production
+fn warn_slow_poll(config: &crate::config::Config, poll_interval: u64) {
+    if let Some(warning) = slow_poll_warning(
+        "Poll interval",
+        poll_interval * 1000,
+        config.chain.timing().slot_duration_ms,
+    ) {
+        warn!("{}", warning);
+    }
 }
 
 /// Run the sync command
 pub async fn run(args: SyncArgs) -> Result<()> {
     // Load configuration
-    let config = crate::config::Config::load()?;
+    let mut config = crate::config::Config::load()?;
 
     // Use args or fall back to config
     let rpc_url = args.rpc_url.unwrap_or_else(|| config.rpc.url.clone());
@@ -62,24 +121,25 @@ pub async fn run(args: SyncArgs) -> Result<()> {
         .db_path
         .unwrap_or_else(|| std::path::PathBuf::from(&config.database.path));
     let batch_size = args.batch_size.unwrap_or(config.sync.batch_size);
-    let poll_interval = args.poll_interval.unwrap_or(config.sync.poll_interval_secs);
-    let finalized_only = args.finalized_only.unwrap_or(config.sync.finalized_only);
+    let mut poll_interval = args.poll_interval.unwrap_or(config.sync.poll_interval_secs);
+    let mut finalized_only = args.finalized_only.unwrap_or(config.sync.finalized_only);
     let start_block = args.start_block.unwrap_or(config.sync.start_block);
 
     info!("Starting block synchronization");
     info!("RPC endpoint: {}", rpc_url);
     info!("Database: {}", db_path.display());
+    warn_slow_poll(&config, poll_interval);
 
     // Create PID file if specified
-    let _pid_file = if let Some(ref pid_path) = args.pid_file {
+    let _pid_file = if let Some(pid_path) = args.pid_file.as_ref().filter(|_| !args.dry_run) {
         Some(crate::daemon::PidFile::create(pid_path)?)
     } else {
         None
     };
 
-    // Set up signal handling for graceful shutdown
-    let signals =
-        Signals::new([SIGTERM, SIGINT, SIGQUIT]).context("Failed to register signal handlers")?;
+    // Set up signal handling for graceful shutdown (SIGHUP reloads config)
+    let signals = Signals::new([SIGTERM, SIGINT, SIGQUIT, SIGHUP])
+        .context("Failed to register signal handlers")?;
     let mut signals = signals.fuse();
 
     if args.daemon {
@@ -90,8 +150,45 @@ pub async fn run(args: SyncArgs) -> Result<()> {
     let db = Database::open(&db_path)?;
     info!("Database opened successfully");
 
-    // Connect to RPC with configured timeout and retry settings
-    let rpc = RpcClient::with_config(&rpc_url, config.rpc.timeout_ms, config.rpc.retry_config());
+    // Start health and metrics endpoints if requested (/readyz stays 503 until
+    // initial sync completes; /metrics reports the database lag)
+    let listen_addr = args
+        .listen
+        .clone()
+        .or(config.server.listen_addr.clone())
+        .filter(|_| !args.dry_run);
+    let exporter_addr = args
+        .exporter_bind
+        .clone()
+        .or(config.server.exporter_addr.clone())
+        .filter(|_| !args.dry_run);
+    let exporter: Option<SharedExporter> = (listen_addr.is_some() || exporter_addr.is_some())
+        .then(|| Arc::new(Mutex::new(ExporterState::default())));
+    let health = match (listen_addr, &exporter) {
+        (Some(addr), Some(exporter)) => {
+            let health: SharedHealth = Arc::new(Mutex::new(HealthState::default()));
+            crate::server::start(
+                &addr,
+                health.clone(),
+                exporter.clone(),
+                Duration::from_secs(config.server.ready_max_age_secs),
+            )
+            .await?;
+            Some(health)
+        }
+        _ => None,
+    };
+    if let (Some(addr), Some(exporter)) = (exporter_addr, &exporter) {
+        crate::server::start_exporter(&addr, exporter.clone()).await?;
+    }
+
+    // Connect to RPC with configured timeout, retry, and connection pool settings
+    let rpc = RpcClient::with_options(
+        &rpc_url,
+        config.rpc.timeout_ms,
+        config.rpc.retry_config(),
+        config.rpc.pool_config(),
+    );
 
     // Get current chain state
     // In daemon mode, retry with backoff until node is available
@@ -135,6 +232,8 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
             rpc_url, rpc_url
         ))?
     };
+    verify_chain_identity(&rpc, &db, &db_path, args.force, args.dry_run).await?;
+
     let finalized = get_finalized_block(&rpc).await?;
     let sidechain_status = get_sidechain_status(&rpc).await.ok();
     let mainchain_epoch = sidechain_status
@@ -149,24 +248,49 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
     // Get chain timing from config
     let mut chain_timing = config.chain.timing();
 
-    // If genesis timestamp not configured, calculate from current slot and time
-    if chain_timing.genesis_timestamp_ms.is_none() {
-        if let Some(ref status) = sidechain_status {
-            let current_slot = status.sidechain.slot;
-            let now_ms = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_millis() as u64)
-                .unwrap_or(0);
-
-            // genesis_time = now - (current_slot * slot_duration)
-            let calculated_genesis =
-                now_ms.saturating_sub(current_slot * chain_timing.slot_duration_ms);
-            chain_timing.genesis_timestamp_ms = Some(calculated_genesis);
+    // If genesis timestamp not configured, calibrate it from block timestamps
+    // (falling back to the stored calibration, then to the current slot and time)
+    let calibrating = chain_timing.genesis_timestamp_ms.is_none();
+    if calibrating {
+        let calibration = match calibrate_timing(&rpc, chain_tip).await {
+            Ok(calibration) => {
+                if !args.dry_run {
+                    if let Err(e) = db.set_timing_calibration(&calibration) {
+                        warn!("Failed to store timing calibration: {}", e);
+                    }
+                }
+                Some(calibration)
+            }
+            Err(e) => {
+                warn!("Timing calibration failed: {:#}", e);
+                let stored = db.get_timing_calibration().ok().flatten();
+                if stored.is_some() {
+                    info!("Using the stored timing calibration");
+                }
+                stored
+            }
+        };
+        match calibration {
+            Some(calibration) => apply_timing_calibration(&mut chain_timing, &calibration),
+            None => {
+                if let Some(ref status) = sidechain_status {
+                    let current_slot = status.sidechain.slot;
+                    let now_ms = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_millis() as u64)
+                        .unwrap_or(0);
+
+                    // genesis_time = now - (current_slot * slot_duration)
+                    let calculated_genesis =
+                        now_ms.saturating_sub(current_slot * chain_timing.slot_duration_ms);
+                    chain_timing.genesis_timestamp_ms = Some(calculated_genesis);
 
-            debug!(
-                "Calculated genesis timestamp: {} (from slot {} at {})",
-                calculated_genesis, current_slot, now_ms
-            );
+                    debug!(
+                        "Calculated genesis timestamp: {} (from slot {} at {})",
+                        calculated_genesis, current_slot, now_ms
+                    );
+                }
+            }
         }
     }
 
@@ -199,7 +323,8 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
     );
 
     // Detect if historical state is available for author attribution
-    if let Some(safe_start) = detect_safe_start_block(&rpc, chain_tip).await {
+    let safe_start = detect_safe_start_block(&rpc, chain_tip).await;
+    if let Some(safe_start) = safe_start {
         if start_from < safe_start {
             warn!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
             warn!("Historical state is pruned before block {}", safe_start);
@@ -217,6 +342,20 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
         }
     }
 
+    // Cache committees per SIDECHAIN epoch (committees rotate every ~2h on preview, ~10h on mainnet)
+    // We store (validator_set, block_hash_used) so we can fetch the committee
+    // at the correct historical point for each epoch.
+    // NOTE: Previously this was keyed by mainchain_epoch (24h/5d) which caused incorrect
+    // attribution when multiple sidechain epochs fell within the same mainchain epoch.
+    let mut state = SyncState::default();
+
+    // Catch-up mode: during the initial bulk sync, reuse epochs between boundaries
+    if !args.no_catch_up {
+        state.epoch_cache = Some(EpochCache::default());
+    }
+    let sync_started = Instant::now();
+    let mut sync_meter = SyncMeter::new(&db_path);
+
     // Initial sync: catch up to chain tip
     let mut current_block = start_from;
     let target = if finalized_only { finalized } else { chain_tip };
@@ -227,10 +366,33 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
         0
     };
 
+    if args.dry_run {
+        print_sync_plan(&db, start_from, target, total_blocks_to_sync, safe_start)?;
+        return Ok(());
+    }
+
     while current_block <= target {
         let batch_end = std::cmp::min(current_block + batch_size as u64 - 1, target);
 
-        let synced = sync_block_range(&rpc, &db, current_block, batch_end, &chain_timing).await?;
+        let batch_started = Instant::now();
+        let synced = sync_block_range(
+            &rpc,
+            &db,
+            current_block,
+            batch_end,
+            &chain_timing,
+            &mut state,
+        )
+        .await?;
+        sync_meter.record(synced, batch_started.elapsed());
+        if sync_meter.is_due(config.sync.sync_sample_secs) {
+            sync_meter.flush(
+                &db,
+                chain_tip.saturating_sub(batch_end),
+                true,
+                config.sync.sync_history_days,
+            );
+        }
 
         if synced > 0 {
             let blocks_synced_so_far = batch_end - start_from + 1;
@@ -259,6 +421,9 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
 
     // Mark syncing complete
     db.update_sync_status(target, finalized, chain_tip, mainchain_epoch, false)?;
+    if config.sync.sync_sample_secs > 0 && sync_meter.blocks > 0 {
+        sync_meter.flush(&db, 0, true, config.sync.sync_history_days);
+    }
 
     let total_blocks = db.count_blocks()?;
     info!(
@@ -269,39 +434,78 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
         "{} blocks in database (block range: {}-{})",
         total_blocks, start_from, target
     );
-
-    // Continuous sync: poll for new blocks
-    info!(
-        "Sync at 100.0% - Watching for new blocks (poll interval: {}s)",
-        poll_interval
-    );
+    let elapsed = sync_started.elapsed().as_secs_f64();
+    if total_blocks_to_sync > 0 && elapsed > 0.0 {
+        info!(
+            "Initial sync took {:.1}s ({:.1} blocks/s)",
+            elapsed,
+            total_blocks_to_sync as f64 / elapsed
+        );
+    }
+    if let Some(cache) = state.epoch_cache.take() {
+        info!(
+            "Catch-up mode: {} epoch queries, {} blocks reused cached epochs",
+            cache.queries, cache.hits
+        );
+    }
+    log_slot_failures(state.slot_failures);
+
+    update_health(health.as_ref(), |h| {
+        h.is_loading = false;
+        h.last_success = Some(Instant::now());
+        h.node_issue = None;
+        h.last_error = None;
+    });
+
+    // Continuous sync: ws(s) endpoints push new heads, others are polled
+    let mut heads = HeadWatch::start(&rpc, finalized_only).await;
+    if heads.is_subscribed() {
+        info!(
+            "Sync at 100.0% - Watching for new blocks (subscribed, poll fallback: {}s)",
+            poll_interval
+        );
+    } else {
+        info!(
+            "Sync at 100.0% - Watching for new blocks (poll interval: {}s)",
+            poll_interval
+        );
+    }
     let mut interval = time::interval(Duration::from_secs(poll_interval));
     let mut last_synced = target;
 
+    // Network history sampling runs on its own cadence alongside block polling
+    let mut network_sampler = NetworkSampler::new(&config.rpc.metrics_url);
+    let mut network_sample_secs = config.sync.network_sample_secs;
+    let mut network_interval = time::interval(Duration::from_secs(network_sample_secs.max(1)));
+
+    // Sync throughput samples cover a full interval, so the first one is one interval out
+    let mut sync_sample_secs = config.sync.sync_sample_secs;
+    let mut sync_sample_interval = sample_interval(sync_sample_secs);
+    let mut last_tip = chain_tip;
+
+    // Slot timing is refitted on its own cadence to follow drift
+    let mut calibration_secs = config.sync.calibration_interval_secs;
+    let mut calibration_interval = sample_interval(calibration_secs);
+
     loop {
         select! {
-            _ = interval.tick() => {
-                // Get current state
-                let new_tip = match get_chain_tip(&rpc).await {
-                    Ok(tip) => tip,
-                    Err(e) => {
-                        warn!("Failed to get chain tip: {}", e);
-                        continue;
-                    }
-                };
+            _ = heads.wait(&mut interval) => {
+                heads.resubscribe_if_due(&rpc).await;
 
-                let new_finalized = match get_finalized_block(&rpc).await {
-                    Ok(fin) => fin,
+                // Get current state in a single batched round trip
+                let (new_tip, new_finalized, sidechain_status) = match get_chain_heads(&rpc).await {
+                    Ok(heads) => heads,
                     Err(e) => {
-                        warn!("Failed to get finalized block: {}", e);
+                        warn!("Failed to get chain state: {}", e);
+                        update_health(health.as_ref(), |h| h.last_error = Some(format!("RPC error: {}", e)));
                         continue;
                     }
                 };
 
+                last_tip = new_tip;
+
                 // Get current epoch (may have changed since start)
-                let current_mainchain_epoch = get_sidechain_status(&rpc)
-                    .await
-                    .ok()
+                let current_mainchain_epoch = sidechain_status
                     .map(|s| s.mainchain.epoch)
                     .unwrap_or(mainchain_epoch);
 
@@ -328,8 +532,10 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
                     };
 
                     if target > last_synced {
-                        match sync_block_range(&rpc, &db, last_synced + 1, target, &chain_timing).await {
+                        let batch_started = Instant::now();
+                        match sync_block_range(&rpc, &db, last_synced + 1, target, &chain_timing, &mut state).await {
                             Ok(synced) => {
+                                sync_meter.record(synced, batch_started.elapsed());
                                 if synced > 0 {
                                     // Calculate how far behind we are
                                     let blocks_behind = new_tip.saturating_sub(target);
@@ -364,6 +570,50 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
                         debug!("Sync at 100.0% - No new blocks");
                     }
                 }
+
+                // Ready while the database keeps up with the node (1 block tolerance)
+                // and finality stays within the configured threshold
+                let sync_target = if finalized_only { new_finalized } else { new_tip };
+                if let Some(exporter) = &exporter {
+                    if let Ok(mut state) = exporter.lock() {
+                        state.sync_lag_blocks = Some(sync_target.saturating_sub(last_synced));
+                    }
+                }
+                let node_issue = config
+                    .thresholds
+                    .assess_node(
+                        sync_target.saturating_sub(last_synced) <= 1,
+                        None,
+                        new_tip.saturating_sub(new_finalized),
+                    )
+                    .err();
+                update_health(health.as_ref(), |h| {
+                    h.last_success = Some(Instant::now());
+                    h.node_issue = node_issue;
+                    h.last_error = None;
+                });
+            }
+            _ = network_interval.tick(), if network_sample_secs > 0 => {
+                if let Err(e) = network_sampler
+                    .sample(&rpc, &db, config.sync.network_history_days)
+                    .await
+                {
+                    warn!("Failed to record network sample: {}", e);
+                }
+            }
+            _ = sync_sample_interval.tick(), if sync_sample_secs > 0 => {
+                sync_meter.flush(&db, last_tip.saturating_sub(last_synced), false, config.sync.sync_history_days);
+            }
+            _ = calibration_interval.tick(), if calibrating && calibration_secs > 0 => {
+                match calibrate_timing(&rpc, last_tip).await {
+                    Ok(calibration) => {
+                        apply_timing_calibration(&mut chain_timing, &calibration);
+                        if let Err(e) = db.set_timing_calibration(&calibration) {
+                            warn!("Failed to store timing calibration: {}", e);
+                        }
+                    }
+                    Err(e) => warn!("Timing recalibration failed, keeping current timing: {:#}", e),
+                }
             }
             Some(signal) = signals.next() => {
                 match signal {
@@ -371,6 +621,39 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
                         info!("Received signal {}, initiating graceful shutdown...", signal);
                         break;
                     }
+                    SIGHUP => {
+                        info!("Received SIGHUP, reloading configuration...");
+                        if let Some(new_config) = reload_config(&config) {
+                            config = new_config;
+
+                            // CLI flags keep precedence over the config file
+                            let new_poll_interval =
+                                args.poll_interval.unwrap_or(config.sync.poll_interval_secs);
+                            if new_poll_interval != poll_interval {
+                                poll_interval = new_poll_interval;
+                                interval = time::interval(Duration::from_secs(poll_interval));
+                                info!("Poll interval now {}s", poll_interval);
+                                warn_slow_poll(&config, poll_interval);
+                            }
+                            finalized_only = args.finalized_only.unwrap_or(config.sync.finalized_only);
+                            heads.set_finalized(finalized_only);
+                            if config.sync.network_sample_secs != network_sample_secs {
+                                network_sample_secs = config.sync.network_sample_secs;
+                                network_interval = time::interval(Duration::from_secs(network_sample_secs.max(1)));
+                                info!("Network sample interval now {}s", network_sample_secs);
+                            }
+                            if config.sync.sync_sample_secs != sync_sample_secs {
+                                sync_sample_secs = config.sync.sync_sample_secs;
+                                sync_sample_interval = sample_interval(sync_sample_secs);
+                                info!("Sync sample interval now {}s", sync_sample_secs);
+                            }
+                            if config.sync.calibration_interval_secs != calibration_secs {
+                                calibration_secs = config.sync.calibration_interval_secs;
+                                calibration_interval = sample_interval(calibration_secs);
+                                info!("Calibration interval now {}s", calibration_secs);
+                            }
+                        }
+                    }
                     _ => {
                         debug!("Received unexpected signal {}", signal);
                     }
@@ -381,9 +664,51 @@ Tip: Make sure your Midnight node is running and RPC is enabled.
 
     info!("Shutting down gracefully...");
     info!("Final sync status: {} blocks synced", last_synced);
+    log_slot_failures(state.slot_failures);
     Ok(())
 }
 
+/// Update the health server state (no-op when the server is disabled)
+fn update_health(health: Option<&SharedHealth>, f: impl FnOnce(&mut HealthState)) {
+    if let Some(health) = health {
+        if let Ok(mut state) = health.lock() {
+            f(&mut state);
+        }
+    }
+}
+
+/// Reload configuration on SIGHUP and log what changed
+///
+/// Returns the new configuration, or None if nothing changed or the reload
+/// failed (in which case the current settings stay in effect).
+fn reload_config(current: &crate::config::Config) -> Option<crate::config::Config> {
+    let (new_config, changes) = match current.reload() {
+        Ok(reloaded) => reloaded,
+        Err(e) => {
+            warn!("Config reload failed, keeping current settings: {:#}", e);
+            return None;
+        }
+    };
+
+    if changes.is_empty() {
+        info!("Config reload: no changes");
+        return None;
+    }
+
+    for change in &changes {
+        if is_hot_reloadable(&change.key) {
+            info!("Config reload: {}", change);
+        } else {
+            warn!(
+                "Config reload: {} (requires restart to take effect)",
+                change
+            );
+        }
+    }
+
+    Some(new_config)
+}
+
 async fn get_chain_tip(rpc: &RpcClient) -> Result<u64> {
     let header: crate::rpc::BlockHeader = rpc
         .call_with_retry("chain_getHeader", Vec::<()>::new())
@@ -400,6 +725,44 @@ async fn get_finalized_block(rpc: &RpcClient) -> Result<u64> {
     Ok(header.block_number())
 }
 
+/// Fetch chain tip, finalized block and sidechain status with one batch request
+///
+/// The sidechain status is optional; tip and finalized failures are errors.
+async fn get_chain_heads(rpc: &RpcClient) -> Result<(u64, u64, Option<SidechainStatus>)> {
+    let no_params = serde_json::json!([]);
+    let mut results = rpc
+        .call_batch(&[
+            ("chain_getHeader", no_params.clone()),
+            ("chain_getFinalizedHead", no_params.clone()),
+            ("sidechain_getStatus", no_params),
+        ])
+        .await?
+        .into_iter();
+
+    let mut next = || {
+        results
+            .next()
+            .unwrap_or_else(|| Err(anyhow::anyhow!("Missing RPC batch result")))
+    };
+    let header: crate::rpc::BlockHeader =
+        serde_json::from_value(next()?).context("Failed to parse block header")?;
+    let finalized_hash: String =
+        serde_json::from_value(next()?).context("Failed to parse finalized head")?;
+    let status = next()
+        .ok()
+        .and_then(|v| decode_lenient::<SidechainStatus>("sidechain_getStatus", v));
+
+    let finalized_header: crate::rpc::BlockHeader = rpc
+        .call_with_retry("chain_getHeader", vec![&finalized_hash])
+        .await?;
+
+    Ok((
+        header.block_number(),
+        finalized_header.block_number(),
+        status,
+    ))
+}
+
 async fn get_sidechain_status(rpc: &RpcClient) -> Result<SidechainStatus> {
     rpc.call_with_retry("sidechain_getStatus", Vec::<()>::new())
         .await
@@ -415,6 +778,112 @@ async fn get_sidechain_status_at_block(
         .await
 }
 
+/// Refuse to sync blocks from a different chain into this database
+///
+/// Records the node's chain name and genesis hash on first sync. Afterwards a
+/// different genesis hash is an error unless `force` is set, in which case the
+/// new chain is recorded. Dry runs check but never write.
+async fn verify_chain_identity(
+    rpc: &RpcClient,
+    db: &Database,
+    db_path: &Path,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let live = ChainIdentity {
+        name: rpc
+            .call_with_retry("system_chain", Vec::<()>::new())
+            .await
+            .context("Failed to fetch chain name")?,
+        genesis_hash: get_block_hash(rpc, 0)
+            .await
+            .context("Failed to fetch genesis hash")?,
+    };
+
+    match check_chain_identity(db.get_chain_identity()?, &live) {
+        ChainCheck::Same => {
+            debug!("Chain: {} (genesis {})", live.name, live.genesis_hash);
+            return Ok(());
+        }
+        ChainCheck::Unrecorded => {
+            // A database from before chain tracking may already hold another chain's blocks
+            if let Some(number) = db.get_min_block_number()? {
+                let stored = db.get_block(number)?.map(|b| b.block_hash);
+                let on_node = get_block_hash(rpc, number).await.ok();
+                if stored.is_none() || stored != on_node {
+                    let stored = stored.unwrap_or_default();
+                    let on_node = on_node.unwrap_or_else(|| "(unavailable)".to_string());
+                    if !force {
+                        anyhow::bail!(
+                            "Cannot confirm this database is from the node's chain.
+
+  Database: {} has block {} as {}
+  Node:     {} (genesis {}) has block {} as {}
+
+Mixing chains corrupts block, epoch and validator history.
+Tip: Point --db-path at a separate database for this network,
+     or pass --force to sync into this one anyway.",
+                            db_path.display(),
+                            number,
+                            stored,
+                            live.name,
+                            live.genesis_hash,
+                            number,
+                            on_node
+                        );
+                    }
+                    warn!(
+                        "Block {} is {} in {} but {} on the node; continuing because of --force",
+                        number,
+                        stored,
+                        db_path.display(),
+                        on_node
+                    );
+                }
+            }
+            info!("Chain: {} (genesis {})", live.name, live.genesis_hash);
+        }
+        ChainCheck::Renamed { previous } => {
+            info!(
+                "Chain renamed from {} to {} (same genesis {})",
+                previous, live.name, live.genesis_hash
+            );
+        }
+        ChainCheck::Different { stored } if force => {
+            warn!(
+                "Node is on {} (genesis {}) but {} was built from {} (genesis {}); continuing because of --force",
+                live.name,
+                live.genesis_hash,
+                db_path.display(),
+                stored.name,
+                stored.genesis_hash
+            );
+        }
+        ChainCheck::Different { stored } => {
+            anyhow::bail!(
+                "Node is on a different chain than this database.
+
+  Database: {} was built from {} (genesis {})
+  Node:     {} (genesis {})
+
+Mixing chains corrupts block, epoch and validator history.
+Tip: Point --db-path at a separate database for this network,
+     or pass --force to sync into this one anyway.",
+                db_path.display(),
+                stored.name,
+                stored.genesis_hash,
+                live.name,
+                live.genesis_hash
+            );
+        }
+    }
+
+    if !dry_run {
+        db.set_chain_identity(&live)?;
+    }
+    Ok(())
+}
+
 async fn get_block_hash(rpc: &RpcClient, block_number: u64) -> Result<String> {
     rpc.call_with_retry("chain_getBlockHash", vec![block_number])
         .await
@@ -424,79 +893,144 @@ async fn get_block(rpc: &RpcClient, hash: &str) -> Result<SignedBlock> {
     rpc.call_with_retry("chain_getBlock", vec![hash]).await
 }
 
-/// Detect the oldest block that has state available for committee queries.
-///
-/// Non-archive nodes prune historical state. This function uses binary search
-/// to find the approximate boundary between pruned and available state.
-///
-/// Returns the oldest block number where state is available, or None if all state
-/// is available (archive node) or detection failed.
-async fn detect_safe_start_block(rpc: &RpcClient, chain_tip: u64) -> Option<u64> {
-    // Most pruned nodes keep ~256 blocks of state
-    // We'll check a few sample points to detect if pruning is in effect
+/// Storage key of `Timestamp::Now`: twox128("Timestamp") ++ twox128("Now")
+const TIMESTAMP_NOW_KEY: &str =
+    "0xf0c365c3cf59d671eb72da0e7a4113c49f1f0515f462cdcf84e0f1d6045dfcbb";
+
+/// Blocks sampled per timing calibration
+const CALIBRATION_SAMPLES: u64 = 8;
+
+/// Blocks below the tip the samples spread over; within the 256 blocks of
+/// state a pruned node keeps
+const CALIBRATION_SPAN: u64 = 240;
+
+/// Slot and on-chain timestamp (`Timestamp::Now`, ms) of a block
+async fn timestamp_sample(rpc: &RpcClient, block_number: u64) -> Result<(u64, u64)> {
+    let hash = get_block_hash(rpc, block_number).await?;
+    let header: BlockHeader = rpc
+        .call_with_retry("chain_getHeader", vec![hash.as_str()])
+        .await?;
+    let slot = header
+        .digest
+        .as_ref()
+        .and_then(|d| extract_slot_from_digest(&d.logs))
+        .context("Block has no slot in its digest")?;
+    let value: Option<String> = rpc
+        .call_with_retry("state_getStorage", vec![TIMESTAMP_NOW_KEY, hash.as_str()])
+        .await?;
+    let bytes = hex::decode(
+        value
+            .context("Timestamp::Now not in state")?
+            .trim_start_matches("0x"),
+    )?;
+    let bytes: [u8; 8] = bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Unexpected Timestamp::Now encoding"))?;
+    Ok((slot, u64::from_le_bytes(bytes)))
+}
 
-    // First, check if we're on an archive node by testing an old block
-    let test_old_block = chain_tip.saturating_sub(1000);
-    if test_old_block == 0 {
-        return None; // Chain is too short to need pruning detection
+/// Fit slot timing to the timestamps of blocks spread below `tip`
+async fn calibrate_timing(rpc: &RpcClient, tip: u64) -> Result<Calibration> {
+    let first = tip.saturating_sub(CALIBRATION_SPAN).max(1);
+    let step = (tip.saturating_sub(first) / (CALIBRATION_SAMPLES - 1)).max(1);
+    let mut samples = Vec::new();
+    for block_number in (first..=tip)
+        .step_by(step as usize)
+        .take(CALIBRATION_SAMPLES as usize)
+    {
+        match timestamp_sample(rpc, block_number).await {
+            Ok(sample) => samples.push(sample),
+            Err(e) => debug!("No timing sample from block {}: {:#}", block_number, e),
+        }
     }
+    Calibration::fit(&samples).with_context(|| {
+        format!(
+            "Only {} of {} blocks gave a timestamp sample",
+            samples.len(),
+            CALIBRATION_SAMPLES
+        )
+    })
+}
 
-    // Get hash for the test block
-    let hash = match get_block_hash(rpc, test_old_block).await {
-        Ok(h) => h,
-        Err(_) => return None,
-    };
+/// Use a calibration for block timestamps and log the result
+fn apply_timing_calibration(timing: &mut ChainTiming, calibration: &Calibration) {
+    let previous = timing.genesis_timestamp_ms;
+    if !timing.apply_calibration(calibration) {
+        warn!(
+            "Calibrated slot duration {:.1}ms is far from the configured chain profile's {}ms; keeping it",
+            calibration.slot_duration_ms, timing.slot_duration_ms
+        );
+    }
+    let genesis = timing.genesis_timestamp_ms.unwrap_or(0);
+    match previous {
+        Some(previous) if previous == genesis => debug!("Timing calibration unchanged"),
+        Some(previous) => info!(
+            "Recalibrated genesis timestamp: {} ({:+}ms, {} blocks)",
+            genesis,
+            genesis as i64 - previous as i64,
+            calibration.samples
+        ),
+        None => info!(
+            "Calibrated genesis timestamp {} and slot duration {:.3}ms from {} blocks",
+            genesis, calibration.slot_duration_ms, calibration.samples
+        ),
+    }
+}
 
-    // Try to query committee at this old block
-    match ValidatorSet::fetch_committee_at_block(rpc, Some(&hash)).await {
-        Ok(_) => {
-            // State is available for old blocks - likely an archive node
-            debug!(
-                "Historical state available at block {} - archive node detected",
-                test_old_block
+/// Summarize what a sync would do without touching any blocks (`--dry-run`)
+fn print_sync_plan(
+    db: &Database,
+    start_from: u64,
+    target: u64,
+    total_blocks: u64,
+    safe_start: Option<u64>,
+) -> Result<()> {
+    info!("Dry run - no blocks will be fetched or written");
+    info!("  Start block:     {}", start_from);
+    info!("  Target block:    {}", target);
+    info!("  Blocks to sync:  {}", total_blocks);
+
+    match safe_start {
+        Some(safe) if start_from < safe && total_blocks > 0 => {
+            let unattributed = safe.min(target + 1) - start_from;
+            info!(
+                "  Attribution:     {} blocks before {} will have no author (pruned state)",
+                unattributed, safe
             );
-            None
         }
-        Err(e) => {
-            let err_str = format!("{:?}", e);
-            if err_str.contains("State already discarded") || err_str.contains("UnknownBlock") {
-                // State is pruned - do binary search to find the boundary
-                debug!(
-                    "State pruned at block {}, searching for safe start...",
-                    test_old_block
-                );
-
-                // Binary search between test_old_block and chain_tip
-                let mut low = test_old_block;
-                let mut high = chain_tip;
+        _ => info!("  Attribution:     full (historical state available)"),
+    }
 
-                while high - low > 10 {
-                    let mid = (low + high) / 2;
-                    let mid_hash = match get_block_hash(rpc, mid).await {
-                        Ok(h) => h,
-                        Err(_) => break,
-                    };
+    let gaps = db.find_gaps()?;
+    if gaps.is_empty() {
+        info!("  Gaps:            none");
+    } else {
+        let missing: u64 = gaps.iter().map(|(start, end)| end - start + 1).sum();
+        info!(
+            "  Gaps:            {} ranges, {} blocks missing (run with --start-block to backfill)",
+            gaps.len(),
+            missing
+        );
+        for (start, end) in gaps.iter().take(10) {
+            info!("    {} - {}", start, end);
+        }
+        if gaps.len() > 10 {
+            info!("    ... and {} more", gaps.len() - 10);
+        }
+    }
 
-                    match ValidatorSet::fetch_committee_at_block(rpc, Some(&mid_hash)).await {
-                        Ok(_) => {
-                            // State available at mid, search lower
-                            high = mid;
-                        }
-                        Err(_) => {
-                            // State pruned at mid, search higher
-                            low = mid;
-                        }
-                    }
-                }
+    Ok(())
+}
 
-                // Return slightly above high to ensure we're in safe territory
-                Some(high + 1)
-            } else {
-                // Different error - can't determine pruning status
-                debug!("Could not determine pruning status: {}", e);
-                None
-            }
-        }
+/// Detect the oldest block that has state available for committee queries.
+///
+/// Non-archive nodes prune historical state; see `StatePruning::detect`.
+/// Returns a block just inside the retained state, or None if all state is
+/// available (archive node) or detection failed.
+async fn detect_safe_start_block(rpc: &RpcClient, chain_tip: u64) -> Option<u64> {
+    match StatePruning::detect(rpc, chain_tip).await {
+        StatePruning::Pruned { oldest_block, .. } => Some(oldest_block + 1),
+        StatePruning::Archive | StatePruning::Unknown => None,
     }
 }
 
@@ -513,10 +1047,155 @@ struct CommitteeCache {
     used_fallback: bool,
 }
 
+/// Committees fetched during sync, keyed by SIDECHAIN epoch
+///
+/// Owned by the sync loop so it survives across batches and poll ticks; without
+/// this every batch re-fetched the committee for an epoch it had just seen.
+/// Only the most recent epochs are kept since blocks arrive in epoch order.
+#[derive(Default)]
+struct CommitteeCacheMap {
+    entries: HashMap<u64, CommitteeCache>,
+}
+
+impl CommitteeCacheMap {
+    /// Number of sidechain epochs kept in memory
+    const MAX_EPOCHS: usize = 8;
+
+    fn get(&self, sidechain_epoch: u64) -> Option<&CommitteeCache> {
+        self.entries.get(&sidechain_epoch)
+    }
+
+    /// Insert a committee, evicting the oldest epochs beyond the limit
+    fn insert(&mut self, sidechain_epoch: u64, entry: CommitteeCache) {
+        self.entries.insert(sidechain_epoch, entry);
+        while self.entries.len() > Self::MAX_EPOCHS {
+            if let Some(oldest) = self.entries.keys().min().copied() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Epochs reused between sidechain epoch boundaries during catch-up
+///
+/// Epochs only change at sidechain epoch boundaries, which fall on multiples of
+/// the slots per epoch. Once a block's epochs have been queried, later blocks
+/// whose slot lies in the same epoch window reuse them. Blocks within
+/// `BOUNDARY_MARGIN_SLOTS` of either edge of the window are always queried.
+#[derive(Default)]
+struct EpochCache {
+    /// Slot-derived epoch window and the (mainchain, sidechain) epochs queried in it
+    last: Option<(u64, (u64, u64))>,
+    /// Offset between the queried sidechain epoch and the slot-derived window
+    offset: Option<i128>,
+    /// Set when queried epochs stop matching slot-derived windows
+    disabled: bool,
+    queries: u64,
+    hits: u64,
+}
+
+impl EpochCache {
+    /// Slots either side of an epoch boundary that always get a query
+    const BOUNDARY_MARGIN_SLOTS: u64 = 5;
+
+    /// Cached epochs for a slot, if it is safely inside the last queried window
+    fn lookup(&mut self, slot: u64, slots_per_epoch: u64) -> Option<(u64, u64)> {
+        if self.disabled || slot == 0 || slots_per_epoch <= 2 * Self::BOUNDARY_MARGIN_SLOTS {
+            return None;
+        }
+        let position = slot % slots_per_epoch;
+        if position < Self::BOUNDARY_MARGIN_SLOTS
+            || position >= slots_per_epoch - Self::BOUNDARY_MARGIN_SLOTS
+        {
+            return None;
+        }
+        let (window, epochs) = self.last?;
+        (window == slot / slots_per_epoch).then(|| {
+            self.hits += 1;
+            epochs
+        })
+    }
+
+    /// Remember the epochs queried for a slot
+    fn record(&mut self, slot: u64, slots_per_epoch: u64, epochs: (u64, u64)) {
+        self.queries += 1;
+        if self.disabled || slot == 0 || slots_per_epoch == 0 || epochs.1 == 0 {
+            return;
+        }
+        let window = slot / slots_per_epoch;
+
+        // Chain timing must agree with the node, or reuse would mislabel blocks
+        let offset = epochs.1 as i128 - window as i128;
+        if self.offset.is_some_and(|known| known != offset) {
+            warn!(
+                "Sidechain epoch {} doesn't match slot {} for the configured chain timing; \
+                 querying epochs for every block",
+                epochs.1, slot
+            );
+            self.disabled = true;
+            self.last = None;
+            return;
+        }
+        self.offset = Some(offset);
+        self.last = Some((window, epochs));
+    }
+}
+
+/// State carried across batches and poll ticks of a sync run
+#[derive(Default)]
+struct SyncState {
+    committee_cache: CommitteeCacheMap,
+    /// Set during catch-up so epochs are reused within an epoch window
+    epoch_cache: Option<EpochCache>,
+    /// Blocks whose digest had no decodable AURA slot (author can't be attributed)
+    slot_failures: u64,
+}
+
+/// Upsert a block's author into the validators table and count the block
+///
+/// Failures are logged rather than returned: the block itself is still stored
+/// (or re-attributed), and `mvm db recompute-totals` repairs any drift.
+pub(crate) fn record_block_author(db: &Database, validator: &Validator, mainchain_epoch: u64) {
+    let registration_status = if validator.is_placeholder() {
+        Some("unknown".to_string())
+    } else if validator.is_permissioned {
+        Some("permissioned".to_string())
+    } else {
+        Some("registered".to_string())
+    };
+
+    let validator_record = ValidatorRecord {
+        sidechain_key: validator.sidechain_key.clone(),
+        aura_key: Some(validator.aura_key.clone()),
+        grandpa_key: (!validator.grandpa_key.is_empty()).then(|| validator.grandpa_key.clone()),
+        label: None,
+        is_ours: false, // Will be set by keys command
+        registration_status,
+        first_seen_epoch: Some(mainchain_epoch),
+        total_blocks: 0, // Will be incremented by database
+    };
+
+    if let Err(e) = db.upsert_validator(&validator_record) {
+        warn!(
+            "Failed to upsert validator {}: {}",
+            validator.sidechain_key, e
+        );
+    }
+
+    if let Err(e) = db.increment_block_count(&validator.sidechain_key) {
+        warn!(
+            "Failed to increment block count for validator {}: {}",
+            validator.sidechain_key, e
+        );
+    }
+}
+
 /// Capture validator epoch snapshot data
 ///
 /// Stores validator state (committee seats, registration status) for a sidechain epoch.
 /// This is called when we first encounter a new sidechain epoch during sync.
+/// Committee members missing from the candidate list get placeholder rows so
+/// the epoch's seats still add up to the committee size.
 fn capture_validator_epoch_snapshot(
     db: &Database,
     sidechain_epoch: u64,
@@ -527,7 +1206,19 @@ fn capture_validator_epoch_snapshot(
 
     let mut captured_count = 0;
 
-    for candidate in &validator_set.candidates {
+    let unmatched = validator_set.unmatched_committee_members();
+    for (aura_key, seats) in &unmatched {
+        warn!(
+            "Sidechain epoch {}: committee member {} ({} seats) is not in the candidate list, recording as a placeholder",
+            sidechain_epoch, aura_key, seats
+        );
+    }
+    let placeholders: Vec<Validator> = unmatched
+        .iter()
+        .map(|(aura_key, _)| Validator::placeholder(aura_key))
+        .collect();
+
+    for candidate in validator_set.candidates.iter().chain(&placeholders) {
         let seats = validator_set.count_seats(&candidate.aura_key) as u32;
 
         let record = ValidatorEpochRecord {
@@ -559,22 +1250,79 @@ fn capture_validator_epoch_snapshot(
     Ok(())
 }
 
+/// Replace a sidechain epoch's seats snapshot and committee with `validator_set`
+pub(crate) fn replace_validator_epoch_snapshot(
+    db: &Database,
+    sidechain_epoch: u64,
+    validator_set: &ValidatorSet,
+) -> Result<()> {
+    db.delete_validator_epoch_snapshot(sidechain_epoch)?;
+    capture_validator_epoch_snapshot(db, sidechain_epoch, validator_set)?;
+    db.store_committee_snapshot(sidechain_epoch, &validator_set.committee)?;
+    Ok(())
+}
+
+/// Re-fetch the validator set at `block_hash` and overwrite the epoch's snapshot
+///
+/// Returns the new set for attribution, or None if the re-fetch failed (the
+/// first snapshot is kept). A mismatch that persists is stored as fetched.
+async fn refetch_validator_set(
+    rpc: &RpcClient,
+    db: &Database,
+    sidechain_epoch: u64,
+    mainchain_epoch: u64,
+    block_hash: &str,
+) -> Option<ValidatorSet> {
+    let vset = match ValidatorSet::fetch_with_committee(rpc, mainchain_epoch, Some(block_hash))
+        .await
+    {
+        Ok(vset) => vset,
+        Err(e) => {
+            warn!(
+                    "Re-fetch of validator set for sidechain epoch {} failed, keeping first snapshot: {}",
+                    sidechain_epoch, e
+                );
+            return None;
+        }
+    };
+
+    if vset.assigned_seats() != vset.committee_size() {
+        warn!(
+            "Sidechain epoch {}: still {} of {} seats matched after re-fetch (committee member not registered?)",
+            sidechain_epoch,
+            vset.assigned_seats(),
+            vset.committee_size()
+        );
+    }
+    if let Err(e) = replace_validator_epoch_snapshot(db, sidechain_epoch, &vset) {
+        warn!(
+            "Failed to overwrite validator epoch snapshot for epoch {}: {}",
+            sidechain_epoch, e
+        );
+    }
+    Some(vset)
+}
+
+/// Report blocks synced without a decodable AURA slot
+fn log_slot_failures(slot_failures: u64) {
+    if slot_failures > 0 {
+        warn!(
+            "Slot extraction failed for {} block(s); their authors were not attributed (see debug log for digests)",
+            slot_failures
+        );
+    }
+}
+
 async fn sync_block_range(
     rpc: &RpcClient,
     db: &Database,
     from: u64,
     to: u64,
     chain_timing: &ChainTiming,
+    state: &mut SyncState,
 ) -> Result<u64> {
     let mut synced = 0;
 
-    // Cache committees per SIDECHAIN epoch (committees rotate every ~2h on preview, ~10h on mainnet)
-    // We store (validator_set, block_hash_used) so we can fetch the committee
-    // at the correct historical point for each epoch.
-    // NOTE: Previously this was keyed by mainchain_epoch (24h/5d) which caused incorrect
-    // attribution when multiple sidechain epochs fell within the same mainchain epoch.
-    let mut committee_cache: HashMap<u64, CommitteeCache> = HashMap::new();
-
     // Track sidechain epochs where we've captured validator epoch snapshots
     // Initialize with epochs already in database to avoid duplicates
     let mut captured_sidechain_epochs: HashSet<u64> = HashSet::new();
@@ -584,7 +1332,7 @@ async fn sync_block_range(
             rpc,
             db,
             block_num,
-            &mut committee_cache,
+            state,
             &mut captured_sidechain_epochs,
             chain_timing,
         )
@@ -608,7 +1356,7 @@ async fn sync_single_block(
     rpc: &RpcClient,
     db: &Database,
     block_number: u64,
-    committee_cache: &mut HashMap<u64, CommitteeCache>,
+    state: &mut SyncState,
     captured_sidechain_epochs: &mut HashSet<u64>,
     chain_timing: &ChainTiming,
 ) -> Result<bool> {
@@ -628,23 +1376,62 @@ async fn sync_single_block(
 
     let header = &signed_block.block.header;
 
-    // Determine the actual epochs for this block by querying at the block hash
-    let (mainchain_epoch, sidechain_epoch) = match get_sidechain_status_at_block(rpc, &hash).await {
-        Ok(status) => {
-            debug!(
-                "Block {} is from mainchain epoch {}, sidechain epoch {}",
-                block_number, status.mainchain.epoch, status.sidechain.epoch
-            );
-            (status.mainchain.epoch, status.sidechain.epoch)
-        }
-        Err(e) => {
-            warn!(
-                "Failed to get epoch for block {} (hash {}): {}. Using epoch 0.",
-                block_number, hash, e
-            );
-            (0, 0)
-        }
+    // Extract slot from digest
+    let slot = header
+        .digest
+        .as_ref()
+        .and_then(|d| extract_slot_from_digest(&d.logs))
+        .unwrap_or(0);
+    // Genesis has no pre-runtime digest
+    if slot == 0 && block_number > 0 {
+        state.slot_failures += 1;
+        debug!(
+            "Block {} has no AURA slot in its digest: {:?}",
+            block_number,
+            header.digest.as_ref().map(|d| &d.logs)
+        );
+    }
+
+    // Determine the actual epochs for this block by querying at the block hash,
+    // unless catch-up mode already knows them for this slot
+    let slots_per_epoch = chain_timing.blocks_per_sidechain_epoch();
+    let cached_epochs = state
+        .epoch_cache
+        .as_mut()
+        .and_then(|cache| cache.lookup(slot, slots_per_epoch));
+    let (mainchain_epoch, sidechain_epoch) = match cached_epochs {
+        Some(epochs) => epochs,
+        None => match get_sidechain_status_at_block(rpc, &hash).await {
+            Ok(status) => {
+                debug!(
+                    "Block {} is from mainchain epoch {}, sidechain epoch {}",
+                    block_number, status.mainchain.epoch, status.sidechain.epoch
+                );
+                let epochs = (status.mainchain.epoch, status.sidechain.epoch);
+                if let Some(cache) = state.epoch_cache.as_mut() {
+                    cache.record(slot, slots_per_epoch, epochs);
+                }
+                epochs
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to get epoch for block {} (hash {}): {}. Using epoch 0.",
+                    block_number, hash, e
+                );
+                (0, 0)
+            }
+        },
     };
+    let (mainchain_epoch, sidechain_epoch) = check_epoch_order(
+        rpc,
+        db,
+        block_number,
+        &hash,
+        slot,
+        (mainchain_epoch, sidechain_epoch),
+    )
+    .await?;
+    let committee_cache = &mut state.committee_cache;
 
     // Fetch or retrieve cached committee for this SIDECHAIN epoch
     // IMPORTANT: Committees rotate every sidechain epoch (~2h preview, ~10h mainnet),
@@ -653,7 +1440,7 @@ async fn sync_single_block(
     //
     // If historical state is pruned, we skip attribution entirely rather than
     // attribute incorrectly using the current committee. See docs/BLOCK_ATTRIBUTION.md
-    let validator_set = if let Some(cached) = committee_cache.get(&sidechain_epoch) {
+    let validator_set = if let Some(cached) = committee_cache.get(sidechain_epoch) {
         // Already cached - check if this was a fallback (pruned state)
         if cached.used_fallback {
             // State was pruned for this epoch - skip attribution
@@ -714,7 +1501,7 @@ async fn sync_single_block(
                     None
                 } else {
                     committee_cache
-                        .get(&sidechain_epoch)
+                        .get(sidechain_epoch)
                         .map(|c| &c.validator_set)
                 }
             }
@@ -734,7 +1521,8 @@ async fn sync_single_block(
     // 1. We have valid validator data (not pruned state)
     // 2. We haven't already captured this sidechain epoch
     // 3. The epoch is valid (> 0)
-    if let Some(vset) = &validator_set {
+    let mut refetched = None;
+    if let Some(vset) = validator_set {
         if sidechain_epoch > 0 && !captured_sidechain_epochs.contains(&sidechain_epoch) {
             // Check if already in database
             let already_captured = db
@@ -748,65 +1536,64 @@ async fn sync_single_block(
                         sidechain_epoch, e
                     );
                 }
+
+                // Seats that don't add up to the committee size point to a partial
+                // or raced fetch: re-fetch once and overwrite the snapshot
+                if vset.assigned_seats() != vset.committee_size() {
+                    warn!(
+                        "Sidechain epoch {}: only {} of {} committee seats matched a candidate, re-fetching",
+                        sidechain_epoch,
+                        vset.assigned_seats(),
+                        vset.committee_size()
+                    );
+                    refetched =
+                        refetch_validator_set(rpc, db, sidechain_epoch, mainchain_epoch, &hash)
+                            .await;
+                }
             }
             captured_sidechain_epochs.insert(sidechain_epoch);
         }
     }
 
-    // Extract slot from digest
-    let slot = header
-        .digest
-        .as_ref()
-        .and_then(|d| extract_slot_from_digest(&d.logs))
-        .unwrap_or(0);
+    // Attribute with the re-fetched set, which also replaces the cached one
+    let validator_set = match refetched {
+        Some(vs) => {
+            committee_cache.insert(
+                sidechain_epoch,
+                CommitteeCache {
+                    validator_set: vs,
+                    fetched_at_block: hash.clone(),
+                    used_fallback: false,
+                },
+            );
+            committee_cache
+                .get(sidechain_epoch)
+                .map(|c| &c.validator_set)
+        }
+        None => validator_set,
+    };
 
     // Calculate block author from slot and validator set
     let author_key = if let Some(vset) = validator_set {
         if slot > 0 {
-            if let Some(validator) = vset.get_author(slot) {
-                // Determine registration status
-                let registration_status = if validator.is_permissioned {
-                    Some("permissioned".to_string())
-                } else {
-                    Some("registered".to_string())
-                };
+            if let Some(validator) = vset.get_author_or_placeholder(slot) {
+                record_block_author(db, &validator, mainchain_epoch);
 
-                // Upsert validator record and increment block count
-                let validator_record = ValidatorRecord {
-                    sidechain_key: validator.sidechain_key.clone(),
-                    aura_key: Some(validator.aura_key.clone()),
-                    grandpa_key: Some(validator.grandpa_key.clone()),
-                    label: None,
-                    is_ours: false, // Will be set by keys command
-                    registration_status,
-                    first_seen_epoch: Some(mainchain_epoch),
-                    total_blocks: 0, // Will be incremented by database
-                };
-
-                // Upsert validator (insert or update)
-                if let Err(e) = db.upsert_validator(&validator_record) {
-                    warn!(
-                        "Failed to upsert validator {}: {}",
-                        validator.sidechain_key, e
+                if validator.is_placeholder() {
+                    debug!(
+                        "Block {} authored by committee member {} missing from the candidate list",
+                        block_number, validator.aura_key
                     );
-                }
-
-                // Increment block count
-                if let Err(e) = db.increment_block_count(&validator.sidechain_key) {
-                    warn!(
-                        "Failed to increment block count for validator {}: {}",
-                        validator.sidechain_key, e
+                } else {
+                    debug!(
+                        "Block {} authored by validator {} (slot {} % {} committee seats)",
+                        block_number,
+                        validator.sidechain_key,
+                        slot,
+                        vset.committee_size()
                     );
                 }
 
-                debug!(
-                    "Block {} authored by validator {} (slot {} % {} committee seats)",
-                    block_number,
-                    validator.sidechain_key,
-                    slot,
-                    vset.committee_size()
-                );
-
                 Some(validator.sidechain_key.clone())
             } else {
                 warn!(
@@ -853,6 +1640,709 @@ async fn sync_single_block(
         extrinsics_count: signed_block.block.extrinsics.len() as u32,
     };
 
+    // A second block for an already-stored slot means the author equivocated
+    for collision in db.find_slot_collisions(&record)? {
+        if db.record_slot_collision(&collision)? {
+            warn!(
+                "Slot {} claimed by two blocks: #{} {} and #{} {} (possible equivocation)",
+                collision.slot_number,
+                collision.other_block_number,
+                collision.other_block_hash,
+                collision.block_number,
+                collision.block_hash
+            );
+        }
+    }
+
     db.insert_block(&record)?;
     Ok(true)
 }
+
+/// Guard against sidechain epochs going backwards with block number
+///
+/// A regression against the stored neighbours is re-queried at the block hash
+/// once (it may come from the catch-up cache or a lagging state query). If the
+/// node still reports it, the anomaly is recorded for `mvm db verify` and the
+/// block is stored with unknown epochs (0, 0) rather than a wrong one, which
+/// also skips author attribution against the wrong committee.
+async fn check_epoch_order(
+    rpc: &RpcClient,
+    db: &Database,
+    block_number: u64,
+    hash: &str,
+    slot: u64,
+    epochs: (u64, u64),
+) -> Result<(u64, u64)> {
+    let Some((neighbor_block_number, neighbor_epoch)) =
+        db.find_epoch_regression(block_number, epochs.1)?
+    else {
+        return Ok(epochs);
+    };
+
+    if let Ok(status) = get_sidechain_status_at_block(rpc, hash).await {
+        let requeried = (status.mainchain.epoch, status.sidechain.epoch);
+        if db
+            .find_epoch_regression(block_number, requeried.1)?
+            .is_none()
+        {
+            debug!(
+                "Block {}: sidechain epoch {} corrected to {} on re-query",
+                block_number, epochs.1, requeried.1
+            );
+            return Ok(requeried);
+        }
+    }
+
+    let anomaly = EpochAnomaly {
+        block_number,
+        block_hash: hash.to_string(),
+        slot_number: slot,
+        reported_epoch: epochs.1,
+        neighbor_block_number,
+        neighbor_epoch,
+        detected_at: chrono::Utc::now().timestamp(),
+    };
+    if db.record_epoch_anomaly(&anomaly)? {
+        warn!(
+            "Block {} reported sidechain epoch {}, out of order with block {} in epoch {}; storing it with unknown epoch (see mvm db verify)",
+            block_number, epochs.1, neighbor_block_number, neighbor_epoch
+        );
+    }
+    Ok((0, 0))
+}
+
+/// Records peer counts and bandwidth rates into `network_samples`
+struct NetworkSampler {
+    metrics: MetricsClient,
+    /// Previous (timestamp, bytes in, bytes out) for rate calculation
+    last_bandwidth: Option<(i64, u64, u64)>,
+}
+
+impl NetworkSampler {
+    fn new(metrics_url: &str) -> Self {
+        Self {
+            metrics: MetricsClient::new(metrics_url),
+            last_bandwidth: None,
+        }
+    }
+
+    /// Take one sample, store it and prune samples beyond the retention window
+    async fn sample(&mut self, rpc: &RpcClient, db: &Database, history_days: u64) -> Result<()> {
+        let health: SystemHealth = rpc
+            .call("system_health", Vec::<()>::new())
+            .await
+            .context("Failed to get peer count")?;
+        let timestamp = chrono::Utc::now().timestamp();
+
+        // Prometheus metrics are optional: without them only the peer count is kept
+        let metrics = match self.metrics.fetch_metrics().await {
+            Ok(m) => Some(m),
+            Err(e) => {
+                debug!("Network sample without metrics: {}", e);
+                None
+            }
+        };
+
+        let mut sample = NetworkSample {
+            timestamp,
+            peers: health.peers as u64,
+            peers_inbound: None,
+            peers_outbound: None,
+            bytes_in_per_sec: None,
+            bytes_out_per_sec: None,
+        };
+        if let Some(m) = metrics {
+            sample.peers_inbound = Some(
+                m.connections_in_opened
+                    .saturating_sub(m.connections_in_closed),
+            );
+            sample.peers_outbound = Some(
+                m.connections_out_opened
+                    .saturating_sub(m.connections_out_closed),
+            );
+            let current = (timestamp, m.bandwidth_in, m.bandwidth_out);
+            if let Some((rate_in, rate_out)) = self
+                .last_bandwidth
+                .and_then(|prev| bandwidth_rates(prev, current))
+            {
+                sample.bytes_in_per_sec = Some(rate_in);
+                sample.bytes_out_per_sec = Some(rate_out);
+            }
+            self.last_bandwidth = Some(current);
+        }
+
+        db.insert_network_sample(&sample)?;
+        let pruned = db.prune_network_samples(timestamp - (history_days * 86_400) as i64)?;
+        if pruned > 0 {
+            debug!("Pruned {} old network samples", pruned);
+        }
+        Ok(())
+    }
+}
+
+/// Interval whose first tick is one period from now (0 = off, never polled)
+fn sample_interval(secs: u64) -> time::Interval {
+    let period = Duration::from_secs(secs.max(1));
+    time::interval_at(time::Instant::now() + period, period)
+}
+
+/// Wait before subscribing again after a head subscription fails or drops
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(30);
+
+/// New-head subscription for ws(s) endpoints, with the poll interval as fallback
+///
+/// Each pushed header wakes the sync loop at once and pushes the next poll
+/// back, so an idle subscribed daemon makes no RPC calls between blocks.
+/// If the subscription drops, polling carries on and it is retried every
+/// `RESUBSCRIBE_DELAY`.
+struct HeadWatch {
+    subscription: Option<HeadSubscription>,
+    /// Follow finalized heads rather than new ones
+    finalized: bool,
+    /// When to try subscribing again (None = subscribed, or not a ws endpoint)
+    retry_at: Option<Instant>,
+}
+
+impl HeadWatch {
+    async fn start(rpc: &RpcClient, finalized: bool) -> Self {
+        let mut watch = Self {
+            subscription: None,
+            finalized,
+            retry_at: rpc.is_websocket().then(Instant::now),
+        };
+        watch.resubscribe_if_due(rpc).await;
+        watch
+    }
+
+    fn is_subscribed(&self) -> bool {
+        self.subscription.is_some()
+    }
+
+    /// Subscribe if a (re)try is due
+    async fn resubscribe_if_due(&mut self, rpc: &RpcClient) {
+        if self.retry_at.is_none_or(|at| Instant::now() < at) {
+            return;
+        }
+        match rpc.subscribe_heads(self.finalized).await {
+            Ok(subscription) => {
+                info!(
+                    "Subscribed to {} heads",
+                    if self.finalized { "finalized" } else { "new" }
+                );
+                self.subscription = Some(subscription);
+                self.retry_at = None;
+            }
+            Err(e) => {
+                warn!(
+                    "Head subscription failed, polling instead (retry in {}s): {:#}",
+                    RESUBSCRIBE_DELAY.as_secs(),
+                    e
+                );
+                self.retry_at = Some(Instant::now() + RESUBSCRIBE_DELAY);
+            }
+        }
+    }
+
+    /// Switch between new and finalized heads, resubscribing on the next wake
+    fn set_finalized(&mut self, finalized: bool) {
+        if finalized != self.finalized {
+            self.finalized = finalized;
+            if self.subscription.take().is_some() {
+                self.retry_at = Some(Instant::now());
+            }
+        }
+    }
+
+    /// Wait until a header is pushed or the poll interval elapses
+    async fn wait(&mut self, interval: &mut time::Interval) {
+        let Some(subscription) = self.subscription.as_mut() else {
+            interval.tick().await;
+            return;
+        };
+        let pushed = select! {
+            _ = interval.tick() => return,
+            header = subscription.next() => header,
+        };
+        match pushed {
+            Ok(header) => {
+                debug!("Node pushed block #{}", header.block_number());
+                interval.reset();
+            }
+            Err(e) => {
+                warn!(
+                    "Head subscription ended, polling every {}s: {:#}",
+                    interval.period().as_secs(),
+                    e
+                );
+                self.subscription = None;
+                self.retry_at = Some(Instant::now() + RESUBSCRIBE_DELAY);
+            }
+        }
+    }
+}
+
+/// Accumulates sync throughput between `sync_samples` rows
+struct SyncMeter {
+    db_path: PathBuf,
+    /// Start of the current sample window
+    window_start: Instant,
+    /// Blocks stored in the window
+    blocks: u64,
+    /// Time spent in `sync_block_range` in the window
+    busy: Duration,
+}
+
+impl SyncMeter {
+    fn new(db_path: &Path) -> Self {
+        Self {
+            db_path: db_path.to_path_buf(),
+            window_start: Instant::now(),
+            blocks: 0,
+            busy: Duration::ZERO,
+        }
+    }
+
+    /// Count a synced range and the time it took
+    fn record(&mut self, blocks: u64, busy: Duration) {
+        self.blocks += blocks;
+        self.busy += busy;
+    }
+
+    /// Whether a sample interval has passed (never when sampling is off)
+    fn is_due(&self, sample_secs: u64) -> bool {
+        sample_secs > 0 && self.window_start.elapsed() >= Duration::from_secs(sample_secs)
+    }
+
+    /// Close the window into a sample and start the next one
+    fn take_sample(
+        &mut self,
+        now: Instant,
+        timestamp: i64,
+        lag_blocks: u64,
+        catching_up: bool,
+    ) -> SyncSample {
+        let elapsed = now.duration_since(self.window_start).as_secs_f64();
+        let sample = SyncSample {
+            timestamp,
+            blocks: self.blocks,
+            blocks_per_sec: if elapsed > 0.0 {
+                self.blocks as f64 / elapsed
+            } else {
+                0.0
+            },
+            ms_per_block: (self.blocks > 0)
+                .then(|| self.busy.as_secs_f64() * 1000.0 / self.blocks as f64),
+            lag_blocks,
+            catching_up,
+            db_size_bytes: db_file_size(&self.db_path),
+        };
+        self.window_start = now;
+        self.blocks = 0;
+        self.busy = Duration::ZERO;
+        sample
+    }
+
+    /// Store a sample for the window and prune samples beyond the retention window
+    ///
+    /// Failures are logged: throughput history must never stop the sync.
+    fn flush(&mut self, db: &Database, lag_blocks: u64, catching_up: bool, history_days: u64) {
+        let timestamp = chrono::Utc::now().timestamp();
+        let sample = self.take_sample(Instant::now(), timestamp, lag_blocks, catching_up);
+        let result = db
+            .insert_sync_sample(&sample)
+            .and_then(|_| db.prune_sync_samples(timestamp - (history_days * 86_400) as i64));
+        match result {
+            Ok(pruned) if pruned > 0 => debug!("Pruned {} old sync samples", pruned),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to record sync sample: {}", e),
+        }
+    }
+}
+
+/// Database file size including its WAL (None if the file can't be read)
+fn db_file_size(db_path: &Path) -> Option<u64> {
+    let size = std::fs::metadata(db_path).ok()?.len();
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
+    Some(size + std::fs::metadata(&wal_path).map(|m| m.len()).unwrap_or(0))
+}
+
+/// Bytes/s in and out between two cumulative (timestamp, in, out) readings
+///
+/// None when no time has passed or a counter went backwards (node restart).
+fn bandwidth_rates(prev: (i64, u64, u64), current: (i64, u64, u64)) -> Option<(f64, f64)> {
+    let elapsed = current.0 - prev.0;
+    if elapsed <= 0 || current.1 < prev.1 || current.2 < prev.2 {
+        return None;
+    }
+    Some((
+        (current.1 - prev.1) as f64 / elapsed as f64,
+        (current.2 - prev.2) as f64 / elapsed as f64,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midnight::validators::Validator;
+    use crate::rpc::mock::{serve, MockNode};
+    use serde_json::json;
+    use serde_json::Value;
+
+    fn committee_entry(aura_keys: &[&str]) -> CommitteeCache {
+        let candidates = aura_keys
+            .iter()
+            .map(|aura| Validator {
+                sidechain_key: format!("0xsc{}", &aura[2..]),
+                aura_key: aura.to_string(),
+                grandpa_key: aura.to_string(),
+                is_permissioned: false,
+                stake_lovelace: None,
+            })
+            .collect();
+        CommitteeCache {
+            validator_set: ValidatorSet {
+                epoch: 1000,
+                candidates,
+                committee: aura_keys.iter().map(|k| k.to_string()).collect(),
+            },
+            fetched_at_block: "0xhash".to_string(),
+            used_fallback: false,
+        }
+    }
+
+    #[test]
+    fn test_committee_cache_keyed_by_sidechain_epoch() {
+        let mut cache = CommitteeCacheMap::default();
+
+        // Two sidechain epochs inside the same mainchain epoch (1000) with rotated committees
+        cache.insert(245_000, committee_entry(&["0x111", "0x222"]));
+        cache.insert(245_001, committee_entry(&["0x333", "0x444"]));
+
+        let author = |epoch: u64, slot: u64| {
+            cache
+                .get(epoch)
+                .and_then(|c| c.validator_set.get_author(slot))
+                .map(|v| v.aura_key.clone())
+        };
+        assert_eq!(author(245_000, 0).as_deref(), Some("0x111"));
+        assert_eq!(author(245_001, 0).as_deref(), Some("0x333"));
+        assert_eq!(author(245_002, 0), None);
+    }
+
+    #[test]
+    fn test_epoch_cache() {
+        let mut cache = EpochCache::default();
+        // 1200 slots per epoch; slot 1_200_100 is in window 1000
+        assert_eq!(cache.lookup(1_200_100, 1200), None);
+        cache.record(1_200_100, 1200, (900, 245_000));
+
+        assert_eq!(cache.lookup(1_200_101, 1200), Some((900, 245_000)));
+        assert_eq!(cache.lookup(1_201_194, 1200), Some((900, 245_000)));
+        // Near the boundaries, or in the next window: query again
+        assert_eq!(cache.lookup(1_201_195, 1200), None);
+        assert_eq!(cache.lookup(1_201_202, 1200), None);
+        assert_eq!(cache.lookup(1_201_210, 1200), None);
+        assert_eq!((cache.queries, cache.hits), (1, 2));
+
+        cache.record(1_201_210, 1200, (900, 245_001));
+        assert_eq!(cache.lookup(1_201_300, 1200), Some((900, 245_001)));
+
+        // Queried epoch disagrees with the slot-derived window: stop reusing
+        cache.record(1_202_500, 1200, (900, 245_001));
+        assert!(cache.disabled);
+        assert_eq!(cache.lookup(1_202_501, 1200), None);
+    }
+
+    #[test]
+    fn test_bandwidth_rates() {
+        assert_eq!(
+            bandwidth_rates((1_000, 6_000, 3_000), (1_060, 66_000, 9_000)),
+            Some((1_000.0, 100.0))
+        );
+        // Counter reset after a node restart
+        assert_eq!(
+            bandwidth_rates((1_000, 6_000, 3_000), (1_060, 500, 9_000)),
+            None
+        );
+        assert_eq!(
+            bandwidth_rates((1_000, 6_000, 3_000), (1_000, 6_000, 3_000)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sync_meter() {
+        let mut meter = SyncMeter::new(Path::new("/nonexistent/mvm.db"));
+        let start = meter.window_start;
+        meter.record(100, Duration::from_millis(1_500));
+        meter.record(50, Duration::from_millis(1_500));
+
+        let sample = meter.take_sample(start + Duration::from_secs(60), 1_000, 3, true);
+        assert_eq!(sample.blocks, 150);
+        assert_eq!(sample.blocks_per_sec, 2.5);
+        assert_eq!(sample.ms_per_block, Some(20.0));
+        assert_eq!((sample.lag_blocks, sample.catching_up), (3, true));
+        assert_eq!(sample.db_size_bytes, None);
+
+        // The next window starts empty
+        let idle = meter.take_sample(start + Duration::from_secs(120), 1_060, 0, false);
+        assert_eq!((idle.blocks, idle.blocks_per_sec), (0, 0.0));
+        assert_eq!(idle.ms_per_block, None);
+    }
+
+    #[test]
+    fn test_committee_cache_evicts_oldest() {
+        let mut cache = CommitteeCacheMap::default();
+        for epoch in 0..(CommitteeCacheMap::MAX_EPOCHS as u64 + 2) {
+            cache.insert(epoch, committee_entry(&["0x111"]));
+        }
+        assert_eq!(cache.entries.len(), CommitteeCacheMap::MAX_EPOCHS);
+        assert!(cache.get(0).is_none());
+        assert!(cache.get(1).is_none());
+        assert!(cache
+            .get(CommitteeCacheMap::MAX_EPOCHS as u64 + 1)
+            .is_some());
+    }
+
+    const AURA_A: &str = "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const AURA_B: &str = "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    fn block_hash(number: u64) -> String {
+        format!("0x{:064x}", number)
+    }
+
+    /// Node with blocks 1..=3 at slots 1000..=1002, all in sidechain epoch 500
+    /// (mainchain 100), and a two-seat committee [A, B] behind AuraApi_authorities
+    fn mock_chain() -> MockNode {
+        let mut node = MockNode::new()
+            .result(
+                "sidechain_getStatus",
+                json!({"sidechain": {"epoch": 500, "slot": 1000}, "mainchain": {"epoch": 100, "slot": 4000}}),
+            )
+            .result_for(
+                "sidechain_getAriadneParameters",
+                json!([100]),
+                json!({
+                    "permissionedCandidates": [
+                        {"sidechainPublicKey": "0x0a", "auraPublicKey": AURA_A, "grandpaPublicKey": "0x1a", "isValid": true},
+                        {"sidechainPublicKey": "0x0b", "auraPublicKey": AURA_B, "grandpaPublicKey": "0x1b", "isValid": true},
+                    ],
+                    "candidateRegistrations": {},
+                }),
+            )
+            // SCALE Vec<[u8; 32]>: compact length 2 (0x08), then the keys
+            .result(
+                "state_call",
+                json!(format!("0x08{}{}", &AURA_A[2..], &AURA_B[2..])),
+            );
+
+        for number in 1..=3u64 {
+            let slot = 999 + number;
+            let aura_digest = format!("0x066175726120{}", hex::encode(slot.to_le_bytes()));
+            node = node
+                .result_for(
+                    "chain_getBlockHash",
+                    json!([number]),
+                    json!(block_hash(number)),
+                )
+                .result_for(
+                    "chain_getBlock",
+                    json!([block_hash(number)]),
+                    json!({"block": {
+                        "header": {
+                            "parentHash": block_hash(number - 1),
+                            "number": format!("0x{:x}", number),
+                            "stateRoot": "0x00",
+                            "extrinsicsRoot": "0x00",
+                            "digest": {"logs": [aura_digest]},
+                        },
+                        "extrinsics": ["0x0401", "0x0402"],
+                    }}),
+                );
+        }
+        node
+    }
+
+    #[tokio::test]
+    async fn test_calibrate_timing_from_block_timestamps() {
+        // Blocks 1..=3 at slots 1000..=1002 with Timestamp::Now = 5s + slot × 6s
+        let mut node = mock_chain();
+        for number in 1..=3u64 {
+            let slot = 999 + number;
+            node = node
+                .result_for(
+                    "chain_getHeader",
+                    json!([block_hash(number)]),
+                    json!({
+                        "number": format!("0x{:x}", number),
+                        "digest": {"logs": [format!("0x066175726120{}", hex::encode(slot.to_le_bytes()))]},
+                    }),
+                )
+                .result_for(
+                    "state_getStorage",
+                    json!([TIMESTAMP_NOW_KEY, block_hash(number)]),
+                    json!(format!("0x{}", hex::encode((5_000 + slot * 6_000).to_le_bytes()))),
+                );
+        }
+        let rpc = RpcClient::new(&node.start().await);
+
+        let calibration = calibrate_timing(&rpc, 3).await.unwrap();
+        assert_eq!(calibration.samples, 3);
+        let mut timing = ChainTiming::default();
+        apply_timing_calibration(&mut timing, &calibration);
+        assert_eq!(timing.genesis_timestamp_ms, Some(5_000));
+        assert_eq!(timing.slot_duration_ms, 6_000);
+
+        // Without state (pruned, or an unknown block) there is nothing to fit
+        assert!(calibrate_timing(&rpc, 300).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_block_range_refetches_partial_validator_set() {
+        // The first candidate fetch misses validator B, as if it raced an update
+        let chain = mock_chain().into_handler();
+        let candidate_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let endpoint = serve(Arc::new(move |request: Value| {
+            let is_candidates = request["method"] == "sidechain_getAriadneParameters";
+            if is_candidates && candidate_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                return json!({"jsonrpc": "2.0", "id": request["id"], "result": {
+                    "permissionedCandidates": [
+                        {"sidechainPublicKey": "0x0a", "auraPublicKey": AURA_A, "grandpaPublicKey": "0x1a", "isValid": true},
+                    ],
+                    "candidateRegistrations": {},
+                }});
+            }
+            chain(request)
+        }))
+        .await;
+        let rpc = RpcClient::new(&endpoint);
+        let db = Database::open_in_memory().unwrap();
+        let mut state = SyncState::default();
+
+        sync_block_range(&rpc, &db, 1, 3, &ChainTiming::default(), &mut state)
+            .await
+            .unwrap();
+
+        // The snapshot was overwritten and attribution used the complete set
+        let seats: Vec<_> = db
+            .get_validators_for_epoch(500)
+            .unwrap()
+            .iter()
+            .map(|v| (v.sidechain_key.clone(), v.committee_seats))
+            .collect();
+        assert_eq!(seats.len(), 2);
+        assert!(seats.contains(&("0x0b".to_string(), 1)));
+        assert_eq!(
+            db.get_block(2).unwrap().unwrap().author_key.as_deref(),
+            Some("0x0b")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_block_range_attributes_authors() {
+        let rpc = RpcClient::new(&mock_chain().start().await);
+        let db = Database::open_in_memory().unwrap();
+        let mut state = SyncState::default();
+
+        let synced = sync_block_range(&rpc, &db, 1, 3, &ChainTiming::default(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(synced, 3);
+
+        // author = committee[slot % 2]: 1000 -> A, 1001 -> B, 1002 -> A
+        let block = db.get_block(1).unwrap().unwrap();
+        assert_eq!(block.block_hash, block_hash(1));
+        assert_eq!(block.slot_number, 1000);
+        assert_eq!((block.epoch, block.sidechain_epoch), (100, 500));
+        assert_eq!(block.extrinsics_count, 2);
+        assert_eq!(block.author_key.as_deref(), Some("0x0a"));
+        assert_eq!(
+            db.get_block(2).unwrap().unwrap().author_key.as_deref(),
+            Some("0x0b")
+        );
+
+        assert_eq!(db.get_validator("0x0a").unwrap().unwrap().total_blocks, 2);
+        assert_eq!(db.get_validator("0x0b").unwrap().unwrap().total_blocks, 1);
+        assert_eq!(
+            db.get_committee_snapshot(500).unwrap(),
+            Some(vec![AURA_A.to_string(), AURA_B.to_string()])
+        );
+        assert!(db.has_validator_epoch_snapshot(500).unwrap());
+
+        // Already stored blocks are skipped
+        let synced = sync_block_range(&rpc, &db, 1, 3, &ChainTiming::default(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(synced, 0);
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_identity_of_unrecorded_database() {
+        let db = Database::open_in_memory().unwrap();
+        let path = Path::new("mvm.db");
+        let node = |first_block: String| {
+            MockNode::new()
+                .result("system_chain", json!("Midnight Preview"))
+                .result_for("chain_getBlockHash", json!([0]), json!(block_hash(0)))
+                .result_for("chain_getBlockHash", json!([1]), json!(first_block))
+        };
+
+        // A database with blocks but no recorded chain, from a different chain
+        let rpc = RpcClient::new(&mock_chain().start().await);
+        sync_block_range(
+            &rpc,
+            &db,
+            1,
+            3,
+            &ChainTiming::default(),
+            &mut SyncState::default(),
+        )
+        .await
+        .unwrap();
+        let other = RpcClient::new(&node(block_hash(99)).start().await);
+        let err = verify_chain_identity(&other, &db, path, false, false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Cannot confirm"));
+        assert_eq!(db.get_chain_identity().unwrap(), None);
+        verify_chain_identity(&other, &db, path, true, true)
+            .await
+            .unwrap();
+
+        // Same chain: adopted and recorded
+        let same = RpcClient::new(&node(block_hash(1)).start().await);
+        verify_chain_identity(&same, &db, path, false, false)
+            .await
+            .unwrap();
+        assert_eq!(
+            db.get_chain_identity().unwrap().unwrap().name,
+            "Midnight Preview"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_block_range_skips_attribution_on_pruned_state() {
+        // Historical committee query fails as on a pruned node; the current one works
+        let node = mock_chain().error_for(
+            "state_call",
+            json!(["AuraApi_authorities", "0x", block_hash(1)]),
+            "State already discarded for BlockId::Hash(0x01)",
+        );
+        let rpc = RpcClient::new(&node.start().await);
+        let db = Database::open_in_memory().unwrap();
+        let mut state = SyncState::default();
+
+        let synced = sync_block_range(&rpc, &db, 1, 3, &ChainTiming::default(), &mut state)
+            .await
+            .unwrap();
+        assert_eq!(synced, 3);
+
+        // Stored without authors rather than attributed with the wrong committee
+        for number in 1..=3 {
+            assert_eq!(db.get_block(number).unwrap().unwrap().author_key, None);
+        }
+        assert!(db.get_validator("0x0a").unwrap().is_none());
+        assert_eq!(db.get_committee_snapshot(500).unwrap(), None);
+    }
+}
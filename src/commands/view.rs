@@ -1,9 +1,13 @@
 //! View command - interactive TUI for real-time monitoring
 
+use crate::config::{slow_poll_warning, MAX_REFRESH_INTERVAL_MS, MIN_REFRESH_INTERVAL_MS};
 use crate::db::Database;
 use crate::metrics::{MetricsClient, NodeExporterClient};
-use crate::rpc::RpcClient;
-use crate::tui::{App, Event, EventHandler};
+use crate::midnight::ValidatorKeys;
+use crate::rpc::{RetryConfig, RpcClient};
+use crate::server::{ExporterState, HealthState, SharedExporter, SharedHealth};
+use crate::tui::snapshot::{load_state, write_snapshot};
+use crate::tui::{App, AppState, Event, EventHandler, EventSeverity, Theme, ViewMode};
 use anyhow::{Context, Result};
 use clap::Args;
 use crossterm::{
@@ -12,58 +16,351 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tracing::error;
+use tokio::sync::mpsc;
+use tokio::{select, time};
+use tracing::{error, info, warn};
 
 /// View command arguments
 #[derive(Args, Debug)]
 pub struct ViewArgs {
-    /// Validator node RPC endpoint URL
+    /// Validator node RPC endpoint URL (repeat to monitor several nodes; `n` switches)
     #[arg(short, long)]
-    pub rpc_url: Option<String>,
+    pub rpc_url: Vec<String>,
 
     /// SQLite database path
     #[arg(short, long)]
     pub db_path: Option<PathBuf>,
 
     /// Refresh interval in milliseconds
-    #[arg(long)]
+    #[arg(long, value_parser = clap::value_parser!(u64).range(
+        MIN_REFRESH_INTERVAL_MS..=MAX_REFRESH_INTERVAL_MS
+    ))]
     pub refresh_interval: Option<u64>,
+
+    /// Serve /healthz, /readyz and /metrics on this address (e.g. 127.0.0.1:9101)
+    #[arg(long)]
+    pub listen: Option<String>,
+
+    /// Serve MVM's stats as Prometheus metrics on this address (e.g. 127.0.0.1:9900)
+    #[arg(long)]
+    pub exporter_bind: Option<String>,
+
+    /// Run headless, periodically writing dashboard.txt and state.json here
+    #[arg(long)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Seconds between snapshots in headless mode
+    #[arg(long, requires = "output_dir")]
+    pub snapshot_interval: Option<u64>,
+
+    /// Recent epochs loaded in the validator detail popup (default from config: 50)
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub tail: Option<u64>,
+
+    /// Re-render a state dump written with `w` instead of connecting to a node
+    #[arg(long, conflicts_with_all = ["output_dir", "listen", "exporter_bind", "rpc_url"])]
+    pub replay: Option<PathBuf>,
+
+    /// Start in focus mode (full-screen key metrics; `0` in the TUI)
+    #[arg(long)]
+    pub focus: bool,
+
+    /// Decode extrinsic call names in the block detail popup (fetches runtime metadata)
+    #[arg(long)]
+    pub decode_extrinsics: bool,
+}
+
+/// Endpoints and storage for one monitored node
+#[derive(Debug, Clone, PartialEq)]
+struct NodeTarget {
+    /// Title bar name (None keeps the App default: validator.name or hostname)
+    name: Option<String>,
+    rpc_url: String,
+    metrics_url: String,
+    node_exporter_url: Option<String>,
+    db_path: PathBuf,
+    keystore_path: Option<String>,
+}
+
+/// One monitored node: its own clients, database, and fetched state
+struct NodeSession {
+    app: App,
+    rpc: RpcClient,
+    metrics: MetricsClient,
+    node_exporter: Option<NodeExporterClient>,
+    db: Database,
+}
+
+impl NodeSession {
+    async fn update(&mut self) {
+        // Learn the node's RPC method names before the first fetch (retried
+        // on later refreshes if the node wasn't reachable yet)
+        if !self.rpc.is_probed() {
+            self.rpc.probe().await;
+        }
+        if let Err(e) = self
+            .app
+            .update(
+                &self.rpc,
+                &self.metrics,
+                self.node_exporter.as_ref(),
+                &self.db,
+            )
+            .await
+        {
+            error!("Update failed for {}: {}", self.app.state.node_name, e);
+        }
+    }
+}
+
+/// Requests from the UI to a node's refresher
+enum Command {
+    /// Refresh now (`r`); the interval restarts from this update
+    Refresh,
+    /// Decode the call breakdown of the block with this hash
+    BlockCalls(String),
+}
+
+/// Results from a node's refresher
+enum Refreshed {
+    State(Box<AppState>),
+    BlockCalls { hash: String, breakdown: String },
+}
+
+/// The UI's side of one node: what is drawn, a database connection for the
+/// drill-down popups, and the channels to the node's refresher
+struct NodeView {
+    app: App,
+    db: Database,
+    commands: mpsc::UnboundedSender<Command>,
+    results: mpsc::UnboundedReceiver<Refreshed>,
+}
+
+impl NodeView {
+    /// Apply whatever the refresher has finished; true when new state arrived
+    fn receive(&mut self) -> bool {
+        let mut refreshed = false;
+        while let Ok(result) = self.results.try_recv() {
+            match result {
+                Refreshed::State(state) => {
+                    self.app.apply_refresh(*state);
+                    refreshed = true;
+                }
+                Refreshed::BlockCalls { hash, breakdown } => {
+                    self.app.set_block_calls(&hash, breakdown)
+                }
+            }
+        }
+        refreshed
+    }
+
+    fn send(&self, command: Command) {
+        // A closed channel means the refresh thread is gone; the view just goes stale
+        let _ = self.commands.send(command);
+    }
+}
+
+/// Resolve the nodes to monitor
+///
+/// The first node comes from `[rpc]` (or the first `--rpc-url`). Any
+/// `--rpc-url` flags replace `[[nodes]]`; extra nodes without their own
+/// settings get metrics on port 9615 of their RPC host and the shared database.
+fn node_targets(
+    config: &crate::config::Config,
+    cli_urls: &[String],
+    db_path: &Path,
+) -> Vec<NodeTarget> {
+    let extra = |rpc_url: &str| NodeTarget {
+        name: rpc_host(rpc_url),
+        rpc_url: rpc_url.to_string(),
+        metrics_url: default_metrics_url(rpc_url).unwrap_or_else(|| config.rpc.metrics_url.clone()),
+        node_exporter_url: None,
+        db_path: db_path.to_path_buf(),
+        keystore_path: None,
+    };
+
+    let mut targets = vec![NodeTarget {
+        name: None,
+        rpc_url: cli_urls.first().unwrap_or(&config.rpc.url).clone(),
+        metrics_url: config.rpc.metrics_url.clone(),
+        node_exporter_url: config.rpc.node_exporter_url.clone(),
+        db_path: db_path.to_path_buf(),
+        keystore_path: config.validator.keystore_path.clone(),
+    }];
+
+    if cli_urls.len() > 1 {
+        targets.extend(cli_urls[1..].iter().map(|url| extra(url)));
+    } else if cli_urls.is_empty() {
+        targets.extend(config.nodes.iter().map(|node| {
+            let defaults = extra(&node.rpc_url);
+            NodeTarget {
+                name: node.name.clone().or(defaults.name),
+                metrics_url: node.metrics_url.clone().unwrap_or(defaults.metrics_url),
+                node_exporter_url: node.node_exporter_url.clone(),
+                db_path: node
+                    .db_path
+                    .as_ref()
+                    .map(PathBuf::from)
+                    .unwrap_or(defaults.db_path),
+                keystore_path: node.keystore_path.clone(),
+                rpc_url: defaults.rpc_url,
+            }
+        }));
+    }
+
+    targets
+}
+
+/// Host part of an http(s) RPC URL
+fn rpc_host(rpc_url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(rpc_url).ok()?;
+    url.host_str().map(String::from)
+}
+
+/// Substrate's default Prometheus endpoint on the RPC host
+fn default_metrics_url(rpc_url: &str) -> Option<String> {
+    rpc_host(rpc_url).map(|host| format!("http://{}:9615/metrics", host))
 }
 
 /// Run the view command
-pub async fn run(args: ViewArgs) -> Result<()> {
+pub async fn run(args: ViewArgs, color: bool) -> Result<()> {
     // Load configuration
-    let config = crate::config::Config::load()?;
+    let mut config = crate::config::Config::load()?;
+    if let Some(tail) = args.tail {
+        config.view.history_epochs = tail as usize;
+    }
+
+    if let Some(ref path) = args.replay {
+        return run_replay(&config, path, args.focus, color);
+    }
 
     // Use args or fall back to config
-    let rpc_url = args.rpc_url.unwrap_or(config.rpc.url);
     let db_path = args
         .db_path
         .unwrap_or_else(|| std::path::PathBuf::from(&config.database.path));
     let refresh_interval = args
         .refresh_interval
         .unwrap_or(config.view.refresh_interval_ms);
+    // Logged before the terminal takes over, and kept in the Events view
+    let slow_refresh = slow_poll_warning(
+        "Refresh interval",
+        refresh_interval,
+        config.chain.timing().slot_duration_ms,
+    );
+    if let Some(ref warning) = slow_refresh {
+        warn!("{}", warning);
+    }
 
     // Connect to RPC, metrics, and database BEFORE initializing terminal
-    let rpc = RpcClient::with_timeout(&rpc_url, config.rpc.timeout_ms);
-    let metrics = MetricsClient::new(&config.rpc.metrics_url);
-    let node_exporter = config
-        .rpc
-        .node_exporter_url
-        .as_ref()
-        .map(|url| NodeExporterClient::new(url));
-    let db = Database::open(&db_path).context(format!(
-        "Failed to open database at {}.
+    let targets = node_targets(&config, &args.rpc_url, &db_path);
+    let node_count = targets.len();
+    let mut sessions = Vec::with_capacity(node_count);
+    for (index, target) in targets.iter().enumerate() {
+        let db = open_database(&target.db_path)?;
+        let mut app = build_app(&config, target, color);
+        if let Some(ref warning) = slow_refresh {
+            app.record_event(EventSeverity::Warning, "config", warning.clone());
+        }
+        if args.focus {
+            app.set_view(ViewMode::Focus);
+        }
+        app.decode_extrinsics = args.decode_extrinsics || config.view.decode_extrinsics;
+        if node_count > 1 {
+            app.node_position = Some((index + 1, node_count));
+        }
+        sessions.push(NodeSession {
+            app,
+            rpc: RpcClient::with_options(
+                &target.rpc_url,
+                config.rpc.timeout_ms,
+                RetryConfig::default(),
+                config.rpc.pool_config(),
+            )
+            .with_slow_call_threshold(config.rpc.slow_call_ms),
+            metrics: MetricsClient::new(&target.metrics_url),
+            node_exporter: target
+                .node_exporter_url
+                .as_ref()
+                .map(|url| NodeExporterClient::new(url)),
+            db,
+        });
+    }
 
-Tip: If you installed MVM, the database should be at /opt/midnight/mvm/data/mvm.db
-     Try: mvm view --db-path /opt/midnight/mvm/data/mvm.db
-     Or set MVM_DB_PATH=/opt/midnight/mvm/data/mvm.db in your environment
+    // Start health and metrics endpoints if requested (before the terminal
+    // takes over, so bind errors are visible)
+    let listen_addr = args.listen.or(config.server.listen_addr.clone());
+    let exporter_addr = args.exporter_bind.or(config.server.exporter_addr.clone());
+    let published = if listen_addr.is_some() || exporter_addr.is_some() {
+        let exporter: SharedExporter = Arc::new(Mutex::new(ExporterState::default()));
+        let health = match listen_addr {
+            Some(addr) => {
+                let health: SharedHealth = Arc::new(Mutex::new(HealthState::default()));
+                crate::server::start(
+                    &addr,
+                    health.clone(),
+                    exporter.clone(),
+                    Duration::from_secs(config.server.ready_max_age_secs),
+                )
+                .await?;
+                Some(health)
+            }
+            None => None,
+        };
+        if let Some(addr) = exporter_addr {
+            crate::server::start_exporter(&addr, exporter.clone()).await?;
+        }
+        Some(Published { health, exporter })
+    } else {
+        None
+    };
 
-     If running locally without install, use: mvm view --db-path ./mvm.db",
-        db_path.display()
-    ))?;
+    // Headless snapshot mode - no terminal at all (first node only)
+    if let Some(output_dir) = args.output_dir {
+        let snapshot_interval = args
+            .snapshot_interval
+            .unwrap_or(config.view.snapshot_interval_secs);
+        if node_count > 1 {
+            warn!("Headless mode snapshots the first node only");
+        }
+        let mut session = sessions.swap_remove(0);
+        session.update().await;
+        publish(published.as_ref(), &session.app);
+        return run_headless(
+            &mut session,
+            &output_dir,
+            refresh_interval,
+            snapshot_interval,
+            published.as_ref(),
+        )
+        .await;
+    }
+
+    // The UI draws its own copy of each node's app; the refresher fetches
+    // into the session's app, which alone needs the keystore
+    let mut views = Vec::with_capacity(node_count);
+    let mut refreshers = Vec::with_capacity(node_count);
+    for (mut session, target) in sessions.into_iter().zip(&targets) {
+        let fetch_target = NodeTarget {
+            keystore_path: None,
+            ..target.clone()
+        };
+        let mut fetch_app = build_app(&config, &fetch_target, color);
+        fetch_app.local_keys = session.app.local_keys.take();
+        let app = std::mem::replace(&mut session.app, fetch_app);
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let (result_tx, results) = mpsc::unbounded_channel();
+        views.push(NodeView {
+            app,
+            db: open_database(&target.db_path)?,
+            commands,
+            results,
+        });
+        refreshers.push((session, command_rx, result_tx));
+    }
+    spawn_refreshers(refreshers, Duration::from_millis(refresh_interval))?;
 
     // Initialize terminal
     enable_raw_mode().context("Failed to enable raw mode")?;
@@ -72,42 +369,16 @@ Tip: If you installed MVM, the database should be at /opt/midnight/mvm/data/mvm.
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).context("Failed to create terminal")?;
 
-    // Initialize app with network-specific timing
-    let mut app = App::new().with_chain_timing(config.chain.timing());
-
-    // Set node name from config if specified
-    if let Some(ref name) = config.validator.name {
-        app.state.node_name = name.clone();
-    }
-
-    // Set expected IP for external address filtering
-    if let Some(ref ip) = config.view.expected_ip {
-        app.expected_ip = Some(ip.clone());
-    }
-
-    // Do initial update
-    if let Err(e) = app
-        .update(&rpc, &metrics, node_exporter.as_ref(), &db)
-        .await
-    {
-        error!("Initial update failed: {}", e);
-    }
-
     // Create event handler with 1-second tick for UI updates
     let event_handler = EventHandler::new(Duration::from_millis(1000));
 
-    // Run the TUI loop (data refresh at configured interval)
+    // Run the TUI loop (data arrives from the refreshers at the configured interval)
     let res = run_tui(
         &mut terminal,
-        &mut app,
-        &rpc,
-        &metrics,
-        node_exporter.as_ref(),
-        &db,
+        &mut views,
         &event_handler,
-        refresh_interval,
-    )
-    .await;
+        published.as_ref(),
+    );
 
     // Restore terminal (always, even on error)
     let _ = disable_raw_mode();
@@ -123,46 +394,337 @@ Tip: If you installed MVM, the database should be at /opt/midnight/mvm/data/mvm.
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn run_tui(
+/// Open a node's database, with install hints on failure
+fn open_database(path: &Path) -> Result<Database> {
+    Database::open(path).context(format!(
+        "Failed to open database at {}.
+
+Tip: If you installed MVM, the database should be at /opt/midnight/mvm/data/mvm.db
+     Try: mvm view --db-path /opt/midnight/mvm/data/mvm.db
+     Or set MVM_DB_PATH=/opt/midnight/mvm/data/mvm.db in your environment
+
+     If running locally without install, use: mvm view --db-path ./mvm.db",
+        path.display()
+    ))
+}
+
+/// Show a recorded state dump with no node, metrics or database connection
+fn run_replay(config: &crate::config::Config, path: &Path, focus: bool, color: bool) -> Result<()> {
+    // Display settings come from config; the keystore is not needed
+    let mut target = node_targets(config, &[], Path::new(&config.database.path)).remove(0);
+    target.keystore_path = None;
+    let mut app = build_app(config, &target, color);
+    app.state = load_state(path)?;
+    if focus {
+        app.set_view(ViewMode::Focus);
+    }
+    app.replay_source = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned());
+
+    enable_raw_mode().context("Failed to enable raw mode")?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen).context("Failed to enter alternate screen")?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend).context("Failed to create terminal")?;
+    let event_handler = EventHandler::new(Duration::from_millis(1000));
+
+    let res = (|| -> Result<()> {
+        loop {
+            terminal.draw(|f| crate::tui::render(f, &app))?;
+            if let Event::Key(key) = event_handler.next()? {
+                if !crate::tui::event::handle_key_event(key, &mut app, None) {
+                    break;
+                }
+                // Nothing to refresh from
+                app.refresh_requested = false;
+            }
+            if app.should_quit {
+                break;
+            }
+        }
+        Ok(())
+    })();
+
+    let _ = disable_raw_mode();
+    let _ = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let _ = terminal.show_cursor();
+    res
+}
+
+/// Create the TUI state for one node from config
+fn build_app(config: &crate::config::Config, target: &NodeTarget, color: bool) -> App {
+    // Initialize app with network-specific timing
+    let mut app = App::new()
+        .with_chain_timing(config.chain.timing())
+        .with_committee_ref(config.rpc.committee_ref());
+
+    // Set node name from the node entry, else from config if specified
+    if let Some(name) = target.name.as_ref().or(config.validator.name.as_ref()) {
+        app.state.node_name = name.clone();
+    }
+
+    // External address selection: filter (validated at load), pinned address, P2P port
+    app.expected_ip = config
+        .view
+        .expected_ip
+        .as_deref()
+        .and_then(crate::config::IpFilter::from_str);
+    if let Some(ref ip) = config.network.public_ip {
+        app.public_ip = Some(ip.clone());
+        app.state.external_ips = vec![ip.clone()];
+    }
+    app.p2p_port = config.network.p2p_port;
+    if !color {
+        app.theme = Theme::Monochrome;
+    }
+    app.charset = config.view.charset();
+    app.finality_history_len = config.view.finality_history_len;
+    app.history_epochs = config.view.history_epochs;
+    app.time_zone = config.display.time_zone();
+    app.rewards = config.rewards.clone();
+    if let Some(ref path) = target.keystore_path {
+        match ValidatorKeys::from_keystore(Path::new(path)) {
+            Ok(keys) => app.local_keys = Some(keys),
+            Err(e) => warn!("Keystore not loaded, skipping on-chain key check: {}", e),
+        }
+    }
+    app.thresholds = config.thresholds.clone();
+    app.grandpa_voter_alert = config.alerts.grandpa_voter;
+    app
+}
+
+fn run_tui(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-    app: &mut App,
-    rpc: &RpcClient,
-    metrics: &MetricsClient,
-    node_exporter: Option<&NodeExporterClient>,
-    db: &Database,
+    nodes: &mut [NodeView],
     event_handler: &EventHandler,
-    data_refresh_interval_ms: u64,
+    published: Option<&Published>,
 ) -> Result<()> {
-    let data_refresh_interval = Duration::from_millis(data_refresh_interval_ms);
+    let mut active = 0;
 
     loop {
-        // Render UI (every tick - 1 second)
-        terminal.draw(|f| crate::tui::render(f, app))?;
+        // Take in what the refreshers finished since the last pass; every
+        // node keeps refreshing so switching shows current data immediately
+        for (index, node) in nodes.iter_mut().enumerate() {
+            if node.receive() && index == 0 {
+                publish(published, &node.app);
+            }
+        }
+
+        // Render UI (every tick - 1 second - to update the "Updated Xs ago" counter)
+        terminal.draw(|f| crate::tui::render(f, &nodes[active].app))?;
 
         // Handle events
         match event_handler.next()? {
             Event::Key(key) => {
-                if !crate::tui::event::handle_key_event(key, app, Some(db)) {
+                if let Some(delta) = crate::tui::event::node_switch_delta(key, &nodes[active].app) {
+                    let next = (active as isize + delta).rem_euclid(nodes.len() as isize) as usize;
+                    let (left, right) = nodes.split_at_mut(active.max(next));
+                    let (from, to) = if active < next {
+                        (&left[active].app, &mut right[0].app)
+                    } else {
+                        (&right[0].app, &mut left[next].app)
+                    };
+                    to.follow_view(from);
+                    active = next;
+                    continue;
+                }
+                let node = &mut nodes[active];
+                let refreshing = node.app.refresh_requested;
+                if !crate::tui::event::handle_key_event(key, &mut node.app, Some(&node.db)) {
                     break;
                 }
-            }
-            Event::Tick | Event::Resize => {
-                // Only fetch new data at the configured refresh interval
-                if app.last_update.elapsed() >= data_refresh_interval {
-                    if let Err(e) = app.update(rpc, metrics, node_exporter, db).await {
-                        error!("Update failed: {}", e);
-                    }
+                // Manual refresh: "Refreshing…" shows until the refresher reports back
+                if node.app.refresh_requested && !refreshing {
+                    node.send(Command::Refresh);
+                }
+                // Block popup call breakdown, filled in when it has been decoded
+                if let Some(hash) = node.app.take_block_calls_request() {
+                    node.send(Command::BlockCalls(hash));
                 }
-                // UI still redraws every tick to update the "Updated Xs ago" counter
             }
+            // The next draw picks up the new size and the "Updated Xs ago" counter
+            Event::Resize | Event::Tick => {}
         }
 
         // Check if should quit
-        if app.should_quit {
+        if nodes[active].app.should_quit {
             break;
         }
     }
 
     Ok(())
 }
+
+/// Refresh each node in the background, off the UI thread
+///
+/// The sessions run on one thread of their own (the database connection
+/// can't be shared across threads, so they can't be `tokio::spawn`ed), each
+/// as a separate task: a slow or unreachable node delays neither input and
+/// redraws nor the other nodes.
+fn spawn_refreshers(
+    refreshers: Vec<(
+        NodeSession,
+        mpsc::UnboundedReceiver<Command>,
+        mpsc::UnboundedSender<Refreshed>,
+    )>,
+    interval: Duration,
+) -> Result<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to start the refresh runtime")?;
+    std::thread::Builder::new()
+        .name("mvm-refresh".to_string())
+        .spawn(move || {
+            let tasks = tokio::task::LocalSet::new();
+            for (session, commands, results) in refreshers {
+                tasks.spawn_local(refresh_node(session, interval, commands, results));
+            }
+            runtime.block_on(tasks);
+        })
+        .context("Failed to start the refresh thread")?;
+    Ok(())
+}
+
+/// Refresh one node at the interval (and on request) until the UI goes away
+async fn refresh_node(
+    mut session: NodeSession,
+    interval: Duration,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    results: mpsc::UnboundedSender<Refreshed>,
+) {
+    let mut refresh = time::interval(interval);
+    refresh.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+
+    loop {
+        select! {
+            _ = refresh.tick() => {}
+            command = commands.recv() => match command {
+                Some(Command::Refresh) => refresh.reset(),
+                Some(Command::BlockCalls(hash)) => {
+                    let breakdown = session.app.block_calls(&session.rpc, &hash).await;
+                    if results.send(Refreshed::BlockCalls { hash, breakdown }).is_err() {
+                        break;
+                    }
+                    continue;
+                }
+                None => break,
+            },
+        }
+        session.update().await;
+        let state = session.app.take_refreshed_state();
+        if results.send(Refreshed::State(Box::new(state))).is_err() {
+            break;
+        }
+    }
+}
+
+/// Refresh data and write snapshots to disk until interrupted
+async fn run_headless(
+    session: &mut NodeSession,
+    output_dir: &Path,
+    data_refresh_interval_ms: u64,
+    snapshot_interval_secs: u64,
+    published: Option<&Published>,
+) -> Result<()> {
+    info!(
+        "Writing dashboard snapshots to {} every {}s",
+        output_dir.display(),
+        snapshot_interval_secs
+    );
+
+    let mut refresh = time::interval(Duration::from_millis(data_refresh_interval_ms));
+    let mut snapshot = time::interval(Duration::from_secs(snapshot_interval_secs.max(1)));
+    // The initial update already ran; skip the immediate first refresh tick
+    refresh.tick().await;
+
+    loop {
+        select! {
+            _ = refresh.tick() => {
+                session.update().await;
+                publish(published, &session.app);
+            }
+            _ = snapshot.tick() => {
+                if let Err(e) = write_snapshot(output_dir, &session.app) {
+                    warn!("Failed to write snapshot: {:#}", e);
+                }
+            }
+            _ = tokio::signal::ctrl_c() => {
+                info!("Interrupted, stopping snapshots");
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// State read by the optional health server and Prometheus exporter
+struct Published {
+    /// None when only the exporter runs
+    health: Option<SharedHealth>,
+    exporter: SharedExporter,
+}
+
+/// Publish the latest readiness inputs and stats (no-op when no server runs)
+fn publish(published: Option<&Published>, app: &App) {
+    let Some(published) = published else {
+        return;
+    };
+    if let Some(health) = &published.health {
+        if let Ok(mut state) = health.lock() {
+            *state = app.health_state();
+        }
+    }
+    if let Ok(mut state) = published.exporter.lock() {
+        *state = app.exporter_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, NodeConfig};
+
+    #[test]
+    fn test_node_targets() {
+        let db = Path::new("/tmp/mvm.db");
+        let mut config = Config::default();
+        config.validator.keystore_path = Some("/keystore".to_string());
+        config.nodes.push(NodeConfig {
+            name: None,
+            rpc_url: "http://10.0.0.2:9944".to_string(),
+            metrics_url: None,
+            node_exporter_url: None,
+            db_path: Some("/tmp/backup.db".to_string()),
+            keystore_path: None,
+        });
+
+        // Config: [rpc] first, then [[nodes]] with derived defaults
+        let targets = node_targets(&config, &[], db);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].rpc_url, config.rpc.url);
+        assert_eq!(targets[0].name, None);
+        assert_eq!(targets[0].keystore_path.as_deref(), Some("/keystore"));
+        assert_eq!(targets[1].name.as_deref(), Some("10.0.0.2"));
+        assert_eq!(targets[1].metrics_url, "http://10.0.0.2:9615/metrics");
+        assert_eq!(targets[1].db_path, PathBuf::from("/tmp/backup.db"));
+
+        // --rpc-url flags replace [[nodes]]
+        let urls = vec![
+            "http://a:9944".to_string(),
+            "https://b.example:443".to_string(),
+        ];
+        let targets = node_targets(&config, &urls, db);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].rpc_url, "http://a:9944");
+        assert_eq!(targets[0].metrics_url, config.rpc.metrics_url);
+        assert_eq!(targets[1].name.as_deref(), Some("b.example"));
+        assert_eq!(targets[1].db_path, db);
+        assert_eq!(targets[1].keystore_path, None);
+
+        // A single --rpc-url keeps the single-node view
+        assert_eq!(node_targets(&config, &urls[..1], db).len(), 1);
+    }
+}
@@ -3,8 +3,19 @@
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
-use std::path::PathBuf;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Chain profile from the global `--profile` flag, which outranks the config
+static CLI_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Set the chain profile given on the command line (first call wins)
+pub fn set_cli_profile(profile: &str) {
+    let _ = CLI_PROFILE.set(profile.to_string());
+}
 
 /// Configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -32,6 +43,52 @@ pub struct Config {
 
     #[serde(default)]
     pub alerts: AlertConfig,
+
+    #[serde(default)]
+    pub server: ServerConfig,
+
+    #[serde(default)]
+    pub thresholds: ThresholdsConfig,
+
+    #[serde(default)]
+    pub display: DisplayConfig,
+
+    #[serde(default)]
+    pub rewards: RewardsConfig,
+
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    /// Additional nodes for the TUI node switcher
+    #[serde(default)]
+    pub nodes: Vec<NodeConfig>,
+}
+
+/// An additional node shown in `mvm view` (`[[nodes]]`)
+///
+/// The top-level `[rpc]` node is always the first node; unset fields here
+/// fall back to its `[rpc]`, `[database]`, and `[validator]` values.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NodeConfig {
+    /// Display name in the title bar (defaults to the RPC host)
+    #[serde(default)]
+    pub name: Option<String>,
+
+    pub rpc_url: String,
+
+    /// Prometheus metrics endpoint (defaults to port 9615 on the RPC host)
+    #[serde(default)]
+    pub metrics_url: Option<String>,
+
+    #[serde(default)]
+    pub node_exporter_url: Option<String>,
+
+    /// Database for this node (defaults to database.path)
+    #[serde(default)]
+    pub db_path: Option<String>,
+
+    #[serde(default)]
+    pub keystore_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +119,30 @@ pub struct RpcConfig {
     /// Maximum delay between retries in milliseconds
     #[serde(default = "default_retry_max_delay")]
     pub retry_max_delay_ms: u64,
+
+    /// Maximum idle HTTP connections kept open to the node
+    #[serde(default = "default_pool_size")]
+    pub pool_size: usize,
+
+    /// Idle connection lifetime and TCP keep-alive interval in seconds
+    /// (0 = open a new connection per request)
+    #[serde(default = "default_keepalive_secs")]
+    pub keepalive_secs: u64,
+
+    /// Connect timeout in milliseconds (timeout_ms bounds the whole request)
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout_ms: u64,
+
+    /// RPC calls taking at least this many ms show as "slow RPC" in the TUI
+    /// status bar (0 = off); every call's timing is logged with --verbose
+    #[serde(default = "default_slow_call_ms")]
+    pub slow_call_ms: u64,
+
+    /// Block to query the committee at: "finalized", "tip", or "offset:N"
+    /// (N blocks behind tip). Querying behind tip avoids election flicker
+    /// at epoch boundaries.
+    #[serde(default = "default_committee_ref")]
+    pub committee_ref: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -96,6 +177,47 @@ pub struct SyncConfig {
 
     #[serde(default)]
     pub start_block: u64,
+
+    /// Seconds between peer/bandwidth samples while syncing continuously (0 = off)
+    #[serde(default = "default_network_sample_secs")]
+    pub network_sample_secs: u64,
+
+    /// Days of network samples kept for `mvm query network-history`
+    #[serde(default = "default_network_history_days")]
+    pub network_history_days: u64,
+
+    /// Seconds between sync throughput samples (0 = off)
+    #[serde(default = "default_sync_sample_secs")]
+    pub sync_sample_secs: u64,
+
+    /// Days of sync throughput samples kept for `mvm query sync-history`
+    #[serde(default = "default_sync_history_days")]
+    pub sync_history_days: u64,
+
+    /// Seconds between slot timing recalibrations from block timestamps
+    /// (0 = calibrate at startup only; unused when chain.genesis_timestamp_ms is set)
+    #[serde(default = "default_calibration_interval_secs")]
+    pub calibration_interval_secs: u64,
+}
+
+fn default_network_sample_secs() -> u64 {
+    60
+}
+
+fn default_network_history_days() -> u64 {
+    7
+}
+
+fn default_sync_sample_secs() -> u64 {
+    300
+}
+
+fn default_sync_history_days() -> u64 {
+    30
+}
+
+fn default_calibration_interval_secs() -> u64 {
+    3600
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,10 +225,87 @@ pub struct ViewConfig {
     #[serde(default = "default_refresh_interval")]
     pub refresh_interval_ms: u64,
 
-    /// Expected external IP address for filtering peer-reported addresses
-    /// Only addresses matching this IP will be displayed
+    /// Expected external IP (exact address or CIDR such as "203.0.113.0/24")
+    /// Only peer-reported addresses matching it are displayed
     #[serde(default)]
     pub expected_ip: Option<String>,
+
+    /// Number of finalization lag samples kept for the dashboard sparkline
+    #[serde(default = "default_finality_history_len")]
+    pub finality_history_len: usize,
+
+    /// Epochs loaded when the validator detail popup opens (scrolling past them loads more)
+    #[serde(default = "default_history_epochs")]
+    pub history_epochs: usize,
+
+    /// Seconds between headless snapshots written by `mvm view --output-dir`
+    #[serde(default = "default_snapshot_interval")]
+    pub snapshot_interval_secs: u64,
+
+    /// Sparkline and progress-bar characters: "unicode" or "ascii"
+    /// (unset: unicode on a UTF-8 locale, ascii otherwise)
+    #[serde(default)]
+    pub charset: Option<String>,
+
+    /// Decode extrinsic call names in the block detail popup (fetches runtime metadata)
+    #[serde(default)]
+    pub decode_extrinsics: bool,
+}
+
+impl ViewConfig {
+    /// The configured charset, else the one the locale supports
+    pub fn charset(&self) -> Charset {
+        self.charset
+            .as_deref()
+            .and_then(Charset::from_str)
+            .unwrap_or_else(Charset::from_env)
+    }
+}
+
+/// Characters used for TUI sparklines and progress bars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Charset {
+    /// Block characters (▁▂▃ ━░)
+    #[default]
+    Unicode,
+    /// Plain ASCII for terminals and fonts without block characters
+    Ascii,
+}
+
+impl Charset {
+    /// Parse "unicode" or "ascii"
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "unicode" => Some(Charset::Unicode),
+            "ascii" => Some(Charset::Ascii),
+            _ => None,
+        }
+    }
+
+    /// Unicode if the locale's character set is UTF-8
+    ///
+    /// `locale` is the first non-empty of LC_ALL, LC_CTYPE and LANG; an unset
+    /// locale is the POSIX "C" locale, which is ASCII.
+    pub fn from_locale(locale: Option<&str>) -> Self {
+        let utf8 = locale.is_some_and(|l| {
+            let l = l.to_lowercase();
+            l.contains("utf-8") || l.contains("utf8")
+        });
+        if utf8 {
+            Charset::Unicode
+        } else {
+            Charset::Ascii
+        }
+    }
+
+    /// Charset for the current process locale
+    pub fn from_env() -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|v| !v.is_empty());
+        Self::from_locale(locale.as_deref())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -128,10 +327,29 @@ pub struct ChainConfig {
     #[serde(default = "default_network")]
     pub network: String,
 
+    /// Built-in timing profile: "preview", "preprod" or "mainnet"
+    /// Takes precedence over `network` (and `--profile` over both)
+    #[serde(default)]
+    pub profile: Option<String>,
+
     /// Optional: Override genesis timestamp (milliseconds since Unix epoch)
     /// If not set, uses the network preset default (when available)
     #[serde(default)]
     pub genesis_timestamp_ms: Option<u64>,
+
+    /// Optional overrides of the profile's timing
+    #[serde(default)]
+    pub slot_duration_ms: Option<u64>,
+
+    #[serde(default)]
+    pub sidechain_epoch_ms: Option<u64>,
+
+    #[serde(default)]
+    pub mainchain_epoch_ms: Option<u64>,
+
+    /// Alternative to `sidechain_epoch_ms`: epoch length in slots
+    #[serde(default)]
+    pub blocks_per_sidechain_epoch: Option<u64>,
 }
 
 /// Alert configuration for block production monitoring
@@ -157,6 +375,297 @@ pub struct AlertConfig {
     /// Cooldown between alerts in seconds (to avoid spam)
     #[serde(default = "default_alert_cooldown")]
     pub cooldown_secs: u64,
+
+    /// Daily window such as "23:00-07:00" (in `display.timezone`) during which
+    /// only critical alerts are sent; warnings are held for a digest afterwards
+    #[serde(default)]
+    pub quiet_hours: Option<String>,
+
+    /// Alert when the node stops being a GRANDPA voter (TUI warning, webhook)
+    #[serde(default = "default_grandpa_voter_alert")]
+    pub grandpa_voter: bool,
+}
+
+/// Built-in HTTP servers: health probes and MVM's own metrics (/healthz,
+/// /readyz, /metrics), plus an optional metrics-only exporter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Address to listen on, e.g. "127.0.0.1:9101" (disabled when unset)
+    #[serde(default)]
+    pub listen_addr: Option<String>,
+
+    /// /readyz reports not ready if the last successful RPC fetch is older than this
+    #[serde(default = "default_ready_max_age")]
+    pub ready_max_age_secs: u64,
+
+    /// Address to serve only /metrics on, e.g. "127.0.0.1:9900" (disabled when unset)
+    #[serde(default)]
+    pub exporter_addr: Option<String>,
+}
+
+fn default_ready_max_age() -> u64 {
+    120
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            listen_addr: None,
+            ready_max_age_secs: default_ready_max_age(),
+            exporter_addr: None,
+        }
+    }
+}
+
+/// Node health thresholds shared by the TUI status bar, `status --check` and /readyz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdsConfig {
+    /// Node is unhealthy when finalization trails the best block by more than this
+    #[serde(default = "default_max_finalized_lag")]
+    pub max_finalized_lag: u64,
+
+    /// Peer count below this is flagged as low (caution)
+    #[serde(default = "default_peers_warn")]
+    pub peers_warn: u64,
+
+    /// Peer count below this is critical (isolation risk) and alerts once it persists
+    #[serde(default = "default_peers_crit")]
+    pub peers_crit: u64,
+
+    /// Seconds the peer count must stay below `peers_crit` before alerting
+    #[serde(default = "default_peers_alert_after")]
+    pub peers_alert_after_secs: u64,
+
+    /// Host memory usage (percent) above which the dashboard shows a warning
+    #[serde(default = "default_memory_warn_percent")]
+    pub memory_warn_percent: f64,
+
+    /// Host clock offset from chain time (seconds) above which the dashboard warns
+    #[serde(default = "default_max_clock_skew")]
+    pub max_clock_skew_secs: u64,
+
+    /// Blocks MVM's database may trail the chain tip before the dashboard flags
+    /// a stalled `mvm sync` (only while the gap keeps growing)
+    #[serde(default = "default_max_db_lag_blocks")]
+    pub max_db_lag_blocks: u64,
+
+    /// Share of the epoch (percent) that may pass with our validator elected but
+    /// no blocks produced before the dashboard shows a block-production gap
+    #[serde(default = "default_alert_missed_block_epoch_percent")]
+    pub alert_missed_block_epoch_percent: f64,
+}
+
+/// How timestamps are shown in the TUI and CLI output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    /// "utc", "local", or an IANA zone name such as "Europe/London"
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+}
+
+fn default_timezone() -> String {
+    "utc".to_string()
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            timezone: default_timezone(),
+        }
+    }
+}
+
+impl DisplayConfig {
+    /// Get the configured time zone (UTC if unrecognized)
+    pub fn time_zone(&self) -> DisplayTimeZone {
+        DisplayTimeZone::from_str(&self.timezone).unwrap_or_default()
+    }
+}
+
+/// Node networking details used to pick the displayed external address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Address shown in the dashboard Identity row, overriding detection
+    #[serde(default)]
+    pub public_ip: Option<String>,
+
+    /// P2P port the node listens on; addresses on it are preferred for display
+    #[serde(default = "default_p2p_port")]
+    pub p2p_port: u16,
+}
+
+fn default_p2p_port() -> u16 {
+    30333
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            public_ip: None,
+            p2p_port: default_p2p_port(),
+        }
+    }
+}
+
+/// Reward estimate settings for the validator identity popup
+///
+/// Rewards are modelled as proportional to blocks produced; see
+/// `midnight::rewards` for the formula. Adjust here if reward mechanics change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsConfig {
+    /// Completed sidechain epochs averaged for the estimate
+    #[serde(default = "default_rewards_window_epochs")]
+    pub window_epochs: u32,
+
+    /// Reward paid per block produced (omit to show block figures only)
+    #[serde(default)]
+    pub reward_per_block: Option<f64>,
+
+    /// Unit label for `reward_per_block`
+    #[serde(default = "default_rewards_unit")]
+    pub unit: String,
+}
+
+fn default_rewards_window_epochs() -> u32 {
+    12
+}
+
+fn default_rewards_unit() -> String {
+    "NIGHT".to_string()
+}
+
+impl Default for RewardsConfig {
+    fn default() -> Self {
+        Self {
+            window_epochs: default_rewards_window_epochs(),
+            reward_per_block: None,
+            unit: default_rewards_unit(),
+        }
+    }
+}
+
+/// Time zone used for displayed timestamps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayTimeZone {
+    #[default]
+    Utc,
+    /// The host's local zone (shown as a UTC offset)
+    Local,
+    /// An IANA zone (shown with its abbreviation)
+    Named(chrono_tz::Tz),
+}
+
+impl DisplayTimeZone {
+    /// Parse "utc", "local", or an IANA zone name
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "utc" | "" => Some(DisplayTimeZone::Utc),
+            "local" => Some(DisplayTimeZone::Local),
+            _ => s.trim().parse().ok().map(DisplayTimeZone::Named),
+        }
+    }
+
+    /// Format a Unix timestamp (seconds), always including the zone
+    pub fn format(&self, timestamp: i64) -> String {
+        use chrono::{Local, TimeZone, Utc};
+        const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+        let formatted = match self {
+            DisplayTimeZone::Utc => Utc
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(|dt| format!("{} UTC", dt.format(FORMAT))),
+            DisplayTimeZone::Local => Local
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S %:z").to_string()),
+            DisplayTimeZone::Named(tz) => tz
+                .timestamp_opt(timestamp, 0)
+                .single()
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S %Z").to_string()),
+        };
+        formatted.unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// Wall-clock time of day in this zone
+    pub fn time_of_day(&self, now: chrono::DateTime<chrono::Utc>) -> chrono::NaiveTime {
+        match self {
+            DisplayTimeZone::Utc => now.time(),
+            DisplayTimeZone::Local => now.with_timezone(&chrono::Local).time(),
+            DisplayTimeZone::Named(tz) => now.with_timezone(tz).time(),
+        }
+    }
+}
+
+fn default_max_finalized_lag() -> u64 {
+    100 // ~10 minutes at 6s blocks; GRANDPA normally keeps within 10-20
+}
+
+fn default_peers_warn() -> u64 {
+    8
+}
+
+fn default_peers_crit() -> u64 {
+    3
+}
+
+fn default_peers_alert_after() -> u64 {
+    120 // Ride out brief peer churn during restarts
+}
+
+fn default_memory_warn_percent() -> f64 {
+    85.0
+}
+
+fn default_max_clock_skew() -> u64 {
+    5
+}
+
+fn default_max_db_lag_blocks() -> u64 {
+    20 // ~2 minutes at 6s blocks; normal polling trails by 1-2
+}
+
+fn default_alert_missed_block_epoch_percent() -> f64 {
+    50.0 // Early in the epoch a seat or two can legitimately not have come up yet
+}
+
+impl Default for ThresholdsConfig {
+    fn default() -> Self {
+        Self {
+            max_finalized_lag: default_max_finalized_lag(),
+            peers_warn: default_peers_warn(),
+            peers_crit: default_peers_crit(),
+            peers_alert_after_secs: default_peers_alert_after(),
+            memory_warn_percent: default_memory_warn_percent(),
+            max_clock_skew_secs: default_max_clock_skew(),
+            max_db_lag_blocks: default_max_db_lag_blocks(),
+            alert_missed_block_epoch_percent: default_alert_missed_block_epoch_percent(),
+        }
+    }
+}
+
+impl ThresholdsConfig {
+    /// Composite node health: synced to tip, has peers (when known), and
+    /// finality keeping up. Returns the first failing reason.
+    pub fn assess_node(
+        &self,
+        is_synced: bool,
+        peers: Option<u64>,
+        finalized_lag: u64,
+    ) -> std::result::Result<(), String> {
+        if !is_synced {
+            return Err("node is not synced".to_string());
+        }
+        if peers == Some(0) {
+            return Err("node has no peers".to_string());
+        }
+        if finalized_lag > self.max_finalized_lag {
+            return Err(format!(
+                "finalized block is {} behind best (limit {})",
+                finalized_lag, self.max_finalized_lag
+            ));
+        }
+        Ok(())
+    }
 }
 
 fn default_alert_threshold() -> u8 {
@@ -167,6 +676,10 @@ fn default_min_expected_blocks() -> u32 {
     5
 }
 
+fn default_grandpa_voter_alert() -> bool {
+    true
+}
+
 fn default_alert_cooldown() -> u64 {
     300 // 5 minutes
 }
@@ -179,6 +692,8 @@ impl Default for AlertConfig {
             min_expected_blocks: default_min_expected_blocks(),
             webhook_url: None,
             cooldown_secs: default_alert_cooldown(),
+            quiet_hours: None,
+            grandpa_voter: true,
         }
     }
 }
@@ -187,18 +702,42 @@ impl Default for ChainConfig {
     fn default() -> Self {
         Self {
             network: default_network(),
+            profile: None,
             genesis_timestamp_ms: None,
+            slot_duration_ms: None,
+            sidechain_epoch_ms: None,
+            mainchain_epoch_ms: None,
+            blocks_per_sidechain_epoch: None,
         }
     }
 }
 
 impl ChainConfig {
+    /// Name of the timing profile in effect (`profile`, else `network`)
+    pub fn profile_name(&self) -> &str {
+        self.profile.as_deref().unwrap_or(&self.network)
+    }
+
     /// Get the ChainTiming for this configuration
+    ///
+    /// Starts from the profile's built-in values; explicit fields override them.
     pub fn timing(&self) -> crate::midnight::ChainTiming {
-        let network = crate::midnight::Network::from_str(&self.network)
+        let network = crate::midnight::Network::from_str(self.profile_name())
             .unwrap_or(crate::midnight::Network::Preview);
         let mut timing = crate::midnight::ChainTiming::for_network(network);
 
+        if let Some(slot_duration_ms) = self.slot_duration_ms {
+            timing.slot_duration_ms = slot_duration_ms;
+        }
+        if let Some(sidechain_epoch_ms) = self.sidechain_epoch_ms {
+            timing.sidechain_epoch_ms = sidechain_epoch_ms;
+        } else if let Some(blocks) = self.blocks_per_sidechain_epoch {
+            timing.sidechain_epoch_ms = blocks * timing.slot_duration_ms;
+        }
+        if let Some(mainchain_epoch_ms) = self.mainchain_epoch_ms {
+            timing.mainchain_epoch_ms = mainchain_epoch_ms;
+        }
+
         // Override genesis if specified
         if let Some(genesis) = self.genesis_timestamp_ms {
             timing.genesis_timestamp_ms = Some(genesis);
@@ -206,12 +745,57 @@ impl ChainConfig {
 
         timing
     }
+
+    /// Reject unknown profiles and timing that can't describe a chain
+    fn validate(&self) -> Result<()> {
+        for (key, value) in [
+            ("chain.network", Some(&self.network)),
+            ("chain.profile", self.profile.as_ref()),
+        ] {
+            if let Some(value) = value {
+                if crate::midnight::Network::from_str(value).is_none() {
+                    anyhow::bail!(
+                        "Invalid {}: {} (expected preview, preprod or mainnet)",
+                        key,
+                        value
+                    );
+                }
+            }
+        }
+        if self.sidechain_epoch_ms.is_some() && self.blocks_per_sidechain_epoch.is_some() {
+            anyhow::bail!(
+                "Set chain.sidechain_epoch_ms or chain.blocks_per_sidechain_epoch, not both"
+            );
+        }
+        let timing = self.timing();
+        if timing.slot_duration_ms == 0 {
+            anyhow::bail!("chain.slot_duration_ms must be greater than 0");
+        }
+        if timing.sidechain_epoch_ms < timing.slot_duration_ms {
+            anyhow::bail!("The sidechain epoch must be at least one slot long");
+        }
+        if timing.mainchain_epoch_ms < timing.sidechain_epoch_ms {
+            anyhow::bail!("The mainchain epoch must be at least one sidechain epoch long");
+        }
+        Ok(())
+    }
+}
+
+fn default_committee_ref() -> String {
+    "finalized".to_string()
 }
 
 fn default_network() -> String {
     "preview".to_string()
 }
 
+/// Whether an RPC endpoint uses a supported scheme (http, https, ws, wss, or unix on Unix)
+fn is_valid_rpc_url(url: &str) -> bool {
+    let is_unix = cfg!(unix) && url.starts_with(crate::rpc::UNIX_SCHEME);
+    let is_ws = url.starts_with("ws://") || url.starts_with("wss://");
+    url.starts_with("http://") || url.starts_with("https://") || is_ws || is_unix
+}
+
 // Default values
 fn default_rpc_url() -> String {
     "http://localhost:9944".to_string()
@@ -225,13 +809,58 @@ fn default_timeout() -> u64 {
     30000
 }
 
+fn default_pool_size() -> usize {
+    8
+}
+
+fn default_keepalive_secs() -> u64 {
+    60
+}
+
+fn default_connect_timeout() -> u64 {
+    5000
+}
+
+fn default_slow_call_ms() -> u64 {
+    2000
+}
+
+/// Directory under the XDG data home holding the default database
+const DATA_DIR_NAME: &str = "midnight-validator-monitor";
+
 fn default_db_path() -> String {
-    // Use /opt/midnight/mvm/data/mvm.db if it exists, otherwise local
-    let opt_path = "/opt/midnight/mvm/data/mvm.db";
-    if std::path::Path::new("/opt/midnight/mvm/data").exists() {
-        opt_path.to_string()
-    } else {
-        "./mvm.db".to_string()
+    // Installed layout (`mvm install`) keeps its data under /opt
+    if Path::new("/opt/midnight/mvm/data").exists() {
+        return "/opt/midnight/mvm/data/mvm.db".to_string();
+    }
+    // A database in the working directory (the old default) stays in use
+    if Path::new("./mvm.db").exists() {
+        return "./mvm.db".to_string();
+    }
+    // $XDG_DATA_HOME (or ~/.local/share)/midnight-validator-monitor/mvm.db
+    directories::BaseDirs::new()
+        .map(|dirs| {
+            dirs.data_dir()
+                .join(DATA_DIR_NAME)
+                .join("mvm.db")
+                .display()
+                .to_string()
+        })
+        .unwrap_or_else(|| "./mvm.db".to_string())
+}
+
+/// Expand a leading `~` to the user's home directory (other paths unchanged)
+pub fn expand_tilde(path: &str) -> String {
+    let home = directories::BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+    expand_home(path, home.as_deref())
+}
+
+fn expand_home(path: &str, home: Option<&Path>) -> String {
+    match (path.strip_prefix('~'), home) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            format!("{}{}", home.display(), rest)
+        }
+        _ => path.to_string(),
     }
 }
 
@@ -243,10 +872,48 @@ fn default_poll_interval() -> u64 {
     6
 }
 
+/// Longest accepted sync poll interval (`sync.poll_interval_secs`, `--poll-interval`)
+pub const MAX_POLL_INTERVAL_SECS: u64 = 3_600;
+
+/// Accepted TUI refresh interval range (`view.refresh_interval_ms`, `--refresh-interval`)
+pub const MIN_REFRESH_INTERVAL_MS: u64 = 100;
+pub const MAX_REFRESH_INTERVAL_MS: u64 = 3_600_000;
+
+/// Polling slower than this many slots visibly lags behind block production
+const SLOW_POLL_SLOTS: u64 = 5;
+
+/// Warning for a poll interval much longer than the slot time, if it is
+///
+/// New blocks arrive every slot, so polling every `interval_ms` shows them up
+/// to that late; past a few slots the monitor noticeably trails the chain.
+pub fn slow_poll_warning(setting: &str, interval_ms: u64, slot_ms: u64) -> Option<String> {
+    (slot_ms > 0 && interval_ms > SLOW_POLL_SLOTS * slot_ms).then(|| {
+        format!(
+            "{} of {:.1}s spans {} slots ({:.0}s each): new blocks will show up late",
+            setting,
+            interval_ms as f64 / 1000.0,
+            interval_ms / slot_ms,
+            slot_ms as f64 / 1000.0
+        )
+    })
+}
+
 fn default_refresh_interval() -> u64 {
     6000 // Match Midnight block interval of 6 seconds
 }
 
+fn default_snapshot_interval() -> u64 {
+    60
+}
+
+fn default_finality_history_len() -> usize {
+    30 // ~3 minutes at the default refresh interval
+}
+
+fn default_history_epochs() -> usize {
+    50
+}
+
 fn default_max_retries() -> u32 {
     3
 }
@@ -269,6 +936,11 @@ impl Default for RpcConfig {
             max_retries: default_max_retries(),
             retry_initial_delay_ms: default_retry_initial_delay(),
             retry_max_delay_ms: default_retry_max_delay(),
+            pool_size: default_pool_size(),
+            keepalive_secs: default_keepalive_secs(),
+            connect_timeout_ms: default_connect_timeout(),
+            slow_call_ms: default_slow_call_ms(),
+            committee_ref: default_committee_ref(),
         }
     }
 }
@@ -283,6 +955,93 @@ impl RpcConfig {
             backoff_multiplier: 2.0,
         }
     }
+
+    /// Convert to PoolConfig for use with RpcClient
+    pub fn pool_config(&self) -> crate::rpc::PoolConfig {
+        crate::rpc::PoolConfig {
+            max_idle_per_host: self.pool_size,
+            keepalive_secs: self.keepalive_secs,
+            connect_timeout_ms: self.connect_timeout_ms,
+        }
+    }
+
+    /// Get the block reference used for committee queries
+    pub fn committee_ref(&self) -> CommitteeRef {
+        CommitteeRef::from_str(&self.committee_ref).unwrap_or_default()
+    }
+}
+
+/// Block reference for committee (AuraApi_authorities) queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitteeRef {
+    /// Latest finalized block
+    #[default]
+    Finalized,
+    /// Current best block
+    Tip,
+    /// Fixed number of blocks behind the best block
+    Offset(u64),
+}
+
+impl CommitteeRef {
+    /// Parse "finalized", "tip", or "offset:N"
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "finalized" => Some(CommitteeRef::Finalized),
+            "tip" | "best" => Some(CommitteeRef::Tip),
+            other => other
+                .strip_prefix("offset:")
+                .and_then(|n| n.trim().parse().ok())
+                .map(CommitteeRef::Offset),
+        }
+    }
+}
+
+/// IP address match: a single address or a CIDR block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpFilter {
+    network: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpFilter {
+    /// Parse "203.0.113.10", "203.0.113.0/24" or an IPv6 equivalent
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, len)) => (addr, Some(len.trim().parse::<u8>().ok()?)),
+            None => (s, None),
+        };
+        let network: IpAddr = addr.trim().trim_matches(['[', ']']).parse().ok()?;
+        let max_len = if network.is_ipv4() { 32 } else { 128 };
+        let prefix_len = prefix_len.unwrap_or(max_len);
+        (prefix_len <= max_len).then_some(Self {
+            network,
+            prefix_len,
+        })
+    }
+
+    /// Whether `ip` falls within this address or block
+    pub fn matches(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => prefix_eq(
+                u32::from(net).into(),
+                u32::from(ip).into(),
+                self.prefix_len,
+                32,
+            ),
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                prefix_eq(u128::from(net), u128::from(ip), self.prefix_len, 128)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Compare the top `prefix_len` bits of two `bits`-wide addresses
+fn prefix_eq(a: u128, b: u128, prefix_len: u8, bits: u32) -> bool {
+    let host_bits = bits - prefix_len as u32;
+    host_bits >= bits || (a >> host_bits) == (b >> host_bits)
 }
 
 impl Default for DatabaseConfig {
@@ -300,6 +1059,11 @@ impl Default for SyncConfig {
             poll_interval_secs: default_poll_interval(),
             finalized_only: false,
             start_block: 0,
+            network_sample_secs: default_network_sample_secs(),
+            network_history_days: default_network_history_days(),
+            sync_sample_secs: default_sync_sample_secs(),
+            sync_history_days: default_sync_history_days(),
+            calibration_interval_secs: default_calibration_interval_secs(),
         }
     }
 }
@@ -309,6 +1073,11 @@ impl Default for ViewConfig {
         Self {
             refresh_interval_ms: default_refresh_interval(),
             expected_ip: None,
+            finality_history_len: default_finality_history_len(),
+            history_epochs: default_history_epochs(),
+            snapshot_interval_secs: default_snapshot_interval(),
+            charset: None,
+            decode_extrinsics: false,
         }
     }
 }
@@ -327,31 +1096,90 @@ impl Config {
             tracing::info!("Using default configuration (no config file found)");
         }
 
-        // Override with environment variables
+        // Override with environment variables, then the --profile flag
         config.apply_env_overrides();
+        if let Some(profile) = CLI_PROFILE.get() {
+            config.chain.profile = Some(profile.clone());
+        }
+        config.expand_paths();
+
+        // A zero interval would busy-loop, so reject it before any command starts
+        config.validate_intervals()?;
+        // Wrong timing skews every epoch percentage, so the same goes for the chain profile
+        config.chain.validate()?;
+        tracing::info!("Chain timing: {}", config.chain.timing().summary());
 
         Ok(config)
     }
 
+    /// Reject poll and refresh intervals that are zero or absurdly large
+    fn validate_intervals(&self) -> Result<()> {
+        let poll = self.sync.poll_interval_secs;
+        if !(1..=MAX_POLL_INTERVAL_SECS).contains(&poll) {
+            anyhow::bail!(
+                "Invalid sync.poll_interval_secs: {} (expected 1 to {} seconds; blocks arrive every {}s)",
+                poll,
+                MAX_POLL_INTERVAL_SECS,
+                self.chain.timing().slot_duration_ms / 1000
+            );
+        }
+        let refresh = self.view.refresh_interval_ms;
+        if !(MIN_REFRESH_INTERVAL_MS..=MAX_REFRESH_INTERVAL_MS).contains(&refresh) {
+            anyhow::bail!(
+                "Invalid view.refresh_interval_ms: {} (expected {} to {} milliseconds)",
+                refresh,
+                MIN_REFRESH_INTERVAL_MS,
+                MAX_REFRESH_INTERVAL_MS
+            );
+        }
+        Ok(())
+    }
+
+    /// Resolve `~` in configured file paths
+    fn expand_paths(&mut self) {
+        self.database.path = expand_tilde(&self.database.path);
+        for path in [
+            &mut self.validator.keystore_path,
+            &mut self.daemon.pid_file,
+            &mut self.daemon.log_file,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *path = expand_tilde(path);
+        }
+        for node in &mut self.nodes {
+            for path in [&mut node.db_path, &mut node.keystore_path]
+                .into_iter()
+                .flatten()
+            {
+                *path = expand_tilde(path);
+            }
+        }
+    }
+
     /// Load configuration from file (searches multiple locations)
     fn load_from_file() -> Result<Option<(Self, PathBuf)>> {
         let paths = Self::config_file_paths();
 
         for path in &paths {
             if path.exists() {
-                let contents = fs::read_to_string(path)
-                    .with_context(|| format!("Failed to read config file: {}", path.display()))?;
-
-                let config: Config = toml::from_str(&contents)
-                    .with_context(|| format!("Failed to parse config file: {}", path.display()))?;
-
-                return Ok(Some((config, path.clone())));
+                return Ok(Some((Self::load_file(path)?, path.clone())));
             }
         }
 
         Ok(None)
     }
 
+    /// Parse a single config file (no environment overrides)
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file: {}", path.display()))
+    }
+
     /// Get list of config file paths to search (in order of priority)
     pub fn config_file_paths() -> Vec<PathBuf> {
         let mut paths = Vec::new();
@@ -418,18 +1246,154 @@ impl Config {
             self.view.expected_ip = Some(expected_ip);
         }
 
+        if let Ok(public_ip) = std::env::var("MVM_PUBLIC_IP") {
+            self.network.public_ip = Some(public_ip);
+        }
+
         // Chain
         if let Ok(network) = std::env::var("MVM_NETWORK") {
             self.chain.network = network;
         }
+
+        // Server
+        if let Ok(listen_addr) = std::env::var("MVM_LISTEN_ADDR") {
+            self.server.listen_addr = Some(listen_addr);
+        }
+
+        // Display
+        if let Ok(timezone) = std::env::var("MVM_TIMEZONE") {
+            self.display.timezone = timezone;
+        }
     }
 
     /// Validate configuration
     pub fn validate(&self) -> Result<()> {
-        // Validate RPC URL
-        if !self.rpc.url.starts_with("http://") && !self.rpc.url.starts_with("https://") {
+        // Validate RPC URLs
+        if !is_valid_rpc_url(&self.rpc.url) {
             anyhow::bail!("Invalid RPC URL: {}", self.rpc.url);
         }
+        for node in &self.nodes {
+            if !is_valid_rpc_url(&node.rpc_url) {
+                anyhow::bail!("Invalid nodes.rpc_url: {}", node.rpc_url);
+            }
+        }
+
+        if self.rpc.connect_timeout_ms == 0 {
+            anyhow::bail!("rpc.connect_timeout_ms must be greater than 0");
+        }
+
+        // Validate committee reference
+        if CommitteeRef::from_str(&self.rpc.committee_ref).is_none() {
+            anyhow::bail!(
+                "Invalid rpc.committee_ref: {} (expected finalized, tip, or offset:N)",
+                self.rpc.committee_ref
+            );
+        }
+
+        // Validate display time zone
+        if DisplayTimeZone::from_str(&self.display.timezone).is_none() {
+            anyhow::bail!(
+                "Invalid display.timezone: {} (expected utc, local, or an IANA name like Europe/London)",
+                self.display.timezone
+            );
+        }
+
+        self.chain.validate()?;
+
+        // Validate TUI charset
+        if let Some(charset) = &self.view.charset {
+            if Charset::from_str(charset).is_none() {
+                anyhow::bail!(
+                    "Invalid view.charset: {} (expected unicode or ascii)",
+                    charset
+                );
+            }
+        }
+
+        // Validate external IP settings
+        if let Some(expected_ip) = &self.view.expected_ip {
+            if IpFilter::from_str(expected_ip).is_none() {
+                anyhow::bail!(
+                    "Invalid view.expected_ip: {} (expected an IP address or CIDR, e.g. 203.0.113.0/24)",
+                    expected_ip
+                );
+            }
+        }
+        if let Some(public_ip) = &self.network.public_ip {
+            if public_ip
+                .trim()
+                .trim_matches(['[', ']'])
+                .parse::<IpAddr>()
+                .is_err()
+            {
+                anyhow::bail!(
+                    "Invalid network.public_ip: {} (expected an IP address)",
+                    public_ip
+                );
+            }
+        }
+
+        // Validate alert quiet hours
+        if let Some(quiet_hours) = &self.alerts.quiet_hours {
+            if crate::alerts::QuietHours::from_str(quiet_hours).is_none() {
+                anyhow::bail!(
+                    "Invalid alerts.quiet_hours: {} (expected HH:MM-HH:MM, e.g. 23:00-07:00)",
+                    quiet_hours
+                );
+            }
+        }
+
+        // Validate network history retention
+        if self.sync.network_sample_secs > 0 && self.sync.network_history_days == 0 {
+            anyhow::bail!("sync.network_history_days must be greater than 0 (or set sync.network_sample_secs = 0 to stop sampling)");
+        }
+        if self.sync.sync_sample_secs > 0 && self.sync.sync_history_days == 0 {
+            anyhow::bail!("sync.sync_history_days must be greater than 0 (or set sync.sync_sample_secs = 0 to stop sampling)");
+        }
+
+        // Validate reward estimate settings
+        if self.rewards.window_epochs == 0 {
+            anyhow::bail!("rewards.window_epochs must be greater than 0");
+        }
+        if let Some(reward) = self.rewards.reward_per_block {
+            if !reward.is_finite() || reward < 0.0 {
+                anyhow::bail!(
+                    "Invalid rewards.reward_per_block: {} (expected a non-negative number)",
+                    reward
+                );
+            }
+        }
+
+        // Validate peer thresholds
+        if self.thresholds.peers_crit > self.thresholds.peers_warn {
+            anyhow::bail!(
+                "thresholds.peers_crit ({}) must not exceed thresholds.peers_warn ({})",
+                self.thresholds.peers_crit,
+                self.thresholds.peers_warn
+            );
+        }
+
+        let memory_warn = self.thresholds.memory_warn_percent;
+        if !(memory_warn > 0.0 && memory_warn <= 100.0) {
+            anyhow::bail!(
+                "Invalid thresholds.memory_warn_percent: {} (must be between 0 and 100)",
+                memory_warn
+            );
+        }
+
+        let missed_block = self.thresholds.alert_missed_block_epoch_percent;
+        if !(0.0..=100.0).contains(&missed_block) {
+            anyhow::bail!(
+                "Invalid thresholds.alert_missed_block_epoch_percent: {} (must be between 0 and 100)",
+                missed_block
+            );
+        }
+
+        if self.view.history_epochs == 0 {
+            anyhow::bail!("view.history_epochs must be greater than 0");
+        }
+
+        self.validate_intervals()?;
 
         // Validate batch size
         if self.sync.batch_size == 0 {
@@ -439,6 +1403,55 @@ impl Config {
         Ok(())
     }
 
+    /// Reload configuration from disk (used on SIGHUP)
+    ///
+    /// The new configuration is loaded and validated in full before being
+    /// returned, so a broken file never leaves the caller half-updated.
+    /// Returns the new configuration along with every setting that changed.
+    pub fn reload(&self) -> Result<(Config, Vec<ConfigChange>)> {
+        let new_config = Self::load()?;
+        new_config
+            .validate()
+            .context("Reloaded configuration is invalid")?;
+        let changes = self.diff(&new_config);
+        Ok((new_config, changes))
+    }
+
+    /// List settings that differ between this configuration and another
+    pub fn diff(&self, other: &Config) -> Vec<ConfigChange> {
+        let mut old = BTreeMap::new();
+        let mut new = BTreeMap::new();
+        if let Ok(value) = toml::Value::try_from(self) {
+            flatten_toml("", &value, &mut old);
+        }
+        if let Ok(value) = toml::Value::try_from(other) {
+            flatten_toml("", &value, &mut new);
+        }
+
+        let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let old_value = old.get(key);
+                let new_value = new.get(key);
+                if old_value == new_value {
+                    return None;
+                }
+                // Changes are logged, so secrets only show whether they're set
+                let secret = SECRET_KEYS.contains(&key.as_str());
+                let shown = |value: Option<&String>| match value {
+                    None => "(unset)".to_string(),
+                    Some(_) if secret => "(redacted)".to_string(),
+                    Some(value) => value.clone(),
+                };
+                Some(ConfigChange {
+                    key: key.clone(),
+                    old: shown(old_value),
+                    new: shown(new_value),
+                })
+            })
+            .collect()
+    }
+
     /// Get example configuration as TOML string (for programmatic access)
     #[allow(dead_code)]
     pub fn example_toml() -> String {
@@ -459,6 +1472,43 @@ impl Config {
     }
 }
 
+/// Settings whose values `Config::diff` hides (credentials)
+const SECRET_KEYS: &[&str] = &["alerts.webhook_url"];
+
+/// A single setting that changed between two configurations
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted key, e.g. `sync.poll_interval_secs`
+    pub key: String,
+    pub old: String,
+    pub new: String,
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.key, self.old, self.new)
+    }
+}
+
+/// Flatten a TOML value into dotted keys
+fn flatten_toml(prefix: &str, value: &toml::Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_toml(&path, value, out);
+            }
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -468,11 +1518,274 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.rpc.url, "http://localhost:9944");
         assert_eq!(config.sync.batch_size, 100);
+
+        let pool = config.rpc.pool_config();
+        assert_eq!(pool.max_idle_per_host, 8);
+        assert_eq!(pool.keepalive_secs, 60);
+        assert_eq!(pool.connect_timeout_ms, 5000);
     }
 
     #[test]
     fn test_config_validation() {
         let config = Config::default();
         assert!(config.validate().is_ok());
+
+        let mut config = Config::default();
+        config.rpc.connect_timeout_ms = 0;
+        assert!(config.validate().is_err());
+
+        let mut config: Config = toml::from_str(
+            r#"
+            [[nodes]]
+            name = "backup"
+            rpc_url = "http://10.0.0.2:9944"
+
+            [[nodes]]
+            rpc_url = "ftp://10.0.0.3:9944"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.nodes.len(), 2);
+        assert_eq!(config.nodes[0].name.as_deref(), Some("backup"));
+        assert!(config.validate().is_err());
+        config.nodes.pop();
+        assert!(config.validate().is_ok());
+        config.rpc.url = "wss://10.0.0.3:9944".to_string();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_chain_profile() {
+        let chain: ChainConfig = toml::from_str(
+            r#"
+            network = "preview"
+            profile = "mainnet"
+            slot_duration_ms = 5000
+            "#,
+        )
+        .unwrap();
+        assert!(chain.validate().is_ok());
+        let timing = chain.timing();
+        // The profile wins over network; explicit fields win over the profile
+        assert_eq!(timing.network, crate::midnight::Network::Mainnet);
+        assert_eq!(timing.slot_duration_ms, 5_000);
+        assert_eq!(timing.sidechain_epoch_ms, 10 * 3_600_000);
+
+        let chain = ChainConfig {
+            blocks_per_sidechain_epoch: Some(600),
+            ..ChainConfig::default()
+        };
+        assert_eq!(chain.timing().sidechain_epoch_ms, 600 * 6_000);
+        assert_eq!(chain.timing().blocks_per_sidechain_epoch(), 600);
+
+        let both = ChainConfig {
+            sidechain_epoch_ms: Some(3_600_000),
+            ..chain.clone()
+        };
+        assert!(both.validate().is_err());
+        let unknown = ChainConfig {
+            profile: Some("devnet".to_string()),
+            ..ChainConfig::default()
+        };
+        assert!(unknown.validate().is_err());
+        let inverted = ChainConfig {
+            mainchain_epoch_ms: Some(1_000),
+            ..ChainConfig::default()
+        };
+        assert!(inverted.validate().is_err());
+    }
+
+    #[test]
+    fn test_interval_validation() {
+        let mut config = Config::default();
+        config.sync.poll_interval_secs = 0;
+        assert!(config.validate_intervals().is_err());
+        config.sync.poll_interval_secs = MAX_POLL_INTERVAL_SECS + 1;
+        assert!(config.validate().is_err());
+        config.sync.poll_interval_secs = 30;
+        assert!(config.validate().is_ok());
+
+        config.view.refresh_interval_ms = 0;
+        assert!(config.validate().is_err());
+        config.view.refresh_interval_ms = MIN_REFRESH_INTERVAL_MS;
+        assert!(config.validate().is_ok());
+
+        // Up to 5 slots is fine; beyond that the monitor trails the chain
+        assert_eq!(slow_poll_warning("Poll interval", 30_000, 6_000), None);
+        assert_eq!(
+            slow_poll_warning("Poll interval", 60_000, 6_000).unwrap(),
+            "Poll interval of 60.0s spans 10 slots (6s each): new blocks will show up late"
+        );
+        assert_eq!(slow_poll_warning("Poll interval", 60_000, 0), None);
+    }
+
+    #[test]
+    fn test_config_diff() {
+        let old = Config::default();
+        assert!(old.diff(&old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.sync.poll_interval_secs = 30;
+        new.alerts.webhook_url = Some("https://example.com/hook".to_string());
+
+        let changes = old.diff(&new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].key, "alerts.webhook_url");
+        assert_eq!(changes[0].old, "(unset)");
+        assert_eq!(changes[0].new, "(redacted)");
+        assert_eq!(changes[1].key, "sync.poll_interval_secs");
+        assert_eq!(changes[1].new, "30");
+    }
+
+    #[test]
+    fn test_committee_ref_parse() {
+        assert_eq!(
+            CommitteeRef::from_str("finalized"),
+            Some(CommitteeRef::Finalized)
+        );
+        assert_eq!(CommitteeRef::from_str("TIP"), Some(CommitteeRef::Tip));
+        assert_eq!(
+            CommitteeRef::from_str("offset:5"),
+            Some(CommitteeRef::Offset(5))
+        );
+        assert_eq!(CommitteeRef::from_str("offset:"), None);
+        assert_eq!(CommitteeRef::from_str("latest"), None);
+
+        let mut config = Config::default();
+        assert_eq!(config.rpc.committee_ref(), CommitteeRef::Finalized);
+        config.rpc.committee_ref = "offset:abc".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_ip_filter() {
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+
+        // Exact match no longer accepts prefixes of other addresses
+        let exact = IpFilter::from_str("203.0.113.1").unwrap();
+        assert!(exact.matches(ip("203.0.113.1")));
+        assert!(!exact.matches(ip("203.0.113.10")));
+
+        let block = IpFilter::from_str("203.0.113.0/24").unwrap();
+        assert!(block.matches(ip("203.0.113.200")));
+        assert!(!block.matches(ip("203.0.114.1")));
+        assert!(!block.matches(ip("2001:db8::1")));
+
+        let v6 = IpFilter::from_str("[2001:db8::]/32").unwrap();
+        assert!(v6.matches(ip("2001:db8:1::5")));
+        assert!(IpFilter::from_str("0.0.0.0/0")
+            .unwrap()
+            .matches(ip("198.51.100.7")));
+
+        assert_eq!(IpFilter::from_str("203.0.113"), None);
+        assert_eq!(IpFilter::from_str("203.0.113.0/33"), None);
+
+        let mut config = Config::default();
+        config.view.expected_ip = Some("203.0.113".to_string());
+        assert!(config.validate().is_err());
+        config.view.expected_ip = None;
+        config.network.public_ip = Some("node.example.com".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_display_time_zone() {
+        assert_eq!(DisplayTimeZone::from_str("UTC"), Some(DisplayTimeZone::Utc));
+        assert_eq!(
+            DisplayTimeZone::from_str("local"),
+            Some(DisplayTimeZone::Local)
+        );
+        assert_eq!(DisplayTimeZone::from_str("Mars/Olympus"), None);
+
+        // 2024-01-15 12:00:00 UTC
+        let ts = 1_705_320_000;
+        assert_eq!(DisplayTimeZone::Utc.format(ts), "2024-01-15 12:00:00 UTC");
+        let tokyo = DisplayTimeZone::from_str("Asia/Tokyo").unwrap();
+        assert_eq!(tokyo.format(ts), "2024-01-15 21:00:00 JST");
+
+        let mut config = Config::default();
+        assert_eq!(config.display.time_zone(), DisplayTimeZone::Utc);
+        config.display.timezone = "Mars/Olympus".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_charset() {
+        assert_eq!(Charset::from_str("ASCII"), Some(Charset::Ascii));
+        assert_eq!(Charset::from_str("unicode"), Some(Charset::Unicode));
+        assert_eq!(Charset::from_str("latin1"), None);
+
+        assert_eq!(Charset::from_locale(Some("en_GB.UTF-8")), Charset::Unicode);
+        assert_eq!(Charset::from_locale(Some("C.utf8")), Charset::Unicode);
+        assert_eq!(Charset::from_locale(Some("POSIX")), Charset::Ascii);
+        assert_eq!(Charset::from_locale(None), Charset::Ascii);
+
+        let mut config = Config::default();
+        config.view.charset = Some("ascii".to_string());
+        assert_eq!(config.view.charset(), Charset::Ascii);
+        config.view.charset = Some("latin1".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_expand_home() {
+        let home = Some(Path::new("/home/midnight"));
+        assert_eq!(expand_home("~/mvm.db", home), "/home/midnight/mvm.db");
+        assert_eq!(expand_home("~", home), "/home/midnight");
+        // Other users' homes and non-leading tildes are left alone
+        assert_eq!(expand_home("~alice/mvm.db", home), "~alice/mvm.db");
+        assert_eq!(expand_home("/data/~/mvm.db", home), "/data/~/mvm.db");
+        assert_eq!(expand_home("~/mvm.db", None), "~/mvm.db");
+    }
+
+    #[test]
+    fn test_load_file_reports_line() {
+        let path =
+            std::env::temp_dir().join(format!("mvm-config-test-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "[rpc]\nurl = \"http://localhost:9944\"\n\n[sync]\nbatch_size = \"big\"\n",
+        )
+        .unwrap();
+
+        let err = Config::load_file(&path).unwrap_err();
+        let cause = err.root_cause().to_string();
+        assert!(cause.contains("line 5"), "{}", cause);
+        assert!(cause.contains("batch_size"), "{}", cause);
+
+        fs::write(&path, "[sync]\nbatch_size = 50\n").unwrap();
+        let config = Config::load_file(&path).unwrap();
+        assert_eq!(config.sync.batch_size, 50);
+        assert_eq!(config.rpc.url, default_rpc_url());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_assess_node() {
+        let thresholds = ThresholdsConfig::default();
+        assert!(thresholds.assess_node(true, Some(8), 3).is_ok());
+        assert!(thresholds.assess_node(true, None, 100).is_ok());
+        assert_eq!(
+            thresholds.assess_node(false, Some(8), 3).unwrap_err(),
+            "node is not synced"
+        );
+        assert!(thresholds.assess_node(true, Some(0), 3).is_err());
+        assert!(thresholds
+            .assess_node(true, Some(8), 101)
+            .unwrap_err()
+            .contains("101 behind"));
+
+        let mut config = Config::default();
+        config.thresholds.peers_crit = 10;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.thresholds.memory_warn_percent = 120.0;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.thresholds.alert_missed_block_epoch_percent = -5.0;
+        assert!(config.validate().is_err());
     }
 }
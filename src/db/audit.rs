@@ -0,0 +1,343 @@
+//! Seats-vs-blocks consistency audit
+//!
+//! Cross-checks the per-epoch data MVM derives block expectations from:
+//! - committee seats summed across `validator_epochs` equal the committee size
+//! - our recorded seats match our AURA keys counted in `committee_snapshots`
+//! - attributed blocks never exceed the slots in the epoch
+//!
+//! Loading is separate from checking so the checks stay pure and testable.
+
+use crate::midnight::validators::PLACEHOLDER_KEY_PREFIX;
+use anyhow::Result;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+/// Per-epoch facts the audit cross-checks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EpochAuditInput {
+    pub epoch: u64,
+    /// Sum of `committee_seats` across the epoch's `validator_epochs` rows
+    pub seat_sum: u64,
+    /// Committee size recorded on those rows (0 when none were captured)
+    pub recorded_committee_size: u64,
+    /// Committee entries in `committee_snapshots` (0 when no snapshot)
+    pub snapshot_size: u64,
+    /// Seats recorded in `validator_epochs` for our validators
+    pub our_recorded_seats: u64,
+    /// Our AURA keys counted in the committee snapshot
+    pub our_snapshot_seats: u64,
+    /// Blocks with an attributed author in the epoch
+    pub attributed_blocks: u64,
+    /// Distinct committee members in the snapshot with no real candidate row
+    /// (0 when seats weren't captured)
+    pub unmatched_members: u64,
+    /// Committee seats held by those members
+    pub unmatched_seats: u64,
+    /// Slots in a sidechain epoch (upper bound on blocks)
+    pub slots_per_epoch: u64,
+}
+
+/// A specific inconsistency found by the audit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AuditIssue {
+    /// Seats across validators don't add up to the committee size
+    SeatSumMismatch { seat_sum: u64, committee_size: u64 },
+    /// Our recorded seats disagree with the committee snapshot
+    OurSeatsMismatch { recorded: u64, snapshot: u64 },
+    /// More blocks attributed than there are slots
+    BlocksExceedSlots { blocks: u64, slots: u64 },
+}
+
+impl std::fmt::Display for AuditIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditIssue::SeatSumMismatch {
+                seat_sum,
+                committee_size,
+            } => write!(
+                f,
+                "seats sum to {} but committee size is {}",
+                seat_sum, committee_size
+            ),
+            AuditIssue::OurSeatsMismatch { recorded, snapshot } => write!(
+                f,
+                "our recorded seats ({}) differ from committee snapshot ({})",
+                recorded, snapshot
+            ),
+            AuditIssue::BlocksExceedSlots { blocks, slots } => write!(
+                f,
+                "{} attributed blocks exceed {} slots in the epoch",
+                blocks, slots
+            ),
+        }
+    }
+}
+
+/// Check one epoch's data for internal consistency
+///
+/// Checks whose inputs were never captured (no seat rows, no snapshot) are skipped.
+pub fn audit_epoch(input: &EpochAuditInput) -> Vec<AuditIssue> {
+    let mut issues = Vec::new();
+
+    if input.recorded_committee_size > 0 && input.seat_sum != input.recorded_committee_size {
+        issues.push(AuditIssue::SeatSumMismatch {
+            seat_sum: input.seat_sum,
+            committee_size: input.recorded_committee_size,
+        });
+    }
+
+    if input.snapshot_size > 0
+        && input.recorded_committee_size > 0
+        && input.our_recorded_seats != input.our_snapshot_seats
+    {
+        issues.push(AuditIssue::OurSeatsMismatch {
+            recorded: input.our_recorded_seats,
+            snapshot: input.our_snapshot_seats,
+        });
+    }
+
+    if input.slots_per_epoch > 0 && input.attributed_blocks > input.slots_per_epoch {
+        issues.push(AuditIssue::BlocksExceedSlots {
+            blocks: input.attributed_blocks,
+            slots: input.slots_per_epoch,
+        });
+    }
+
+    issues
+}
+
+/// Gather the audit inputs for one sidechain epoch
+pub fn get_epoch_audit_input(
+    conn: &Connection,
+    epoch: u64,
+    slots_per_epoch: u64,
+) -> Result<EpochAuditInput> {
+    let (seat_sum, recorded_committee_size): (i64, i64) = conn.query_row(
+        "SELECT COALESCE(SUM(committee_seats), 0), COALESCE(MAX(committee_size), 0)
+         FROM validator_epochs WHERE sidechain_epoch = ?1",
+        params![epoch as i64],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+
+    let snapshot_size: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM committee_snapshots WHERE epoch = ?1",
+        params![epoch as i64],
+        |row| row.get(0),
+    )?;
+
+    let our_recorded_seats: i64 = conn.query_row(
+        "SELECT COALESCE(SUM(ve.committee_seats), 0)
+         FROM validator_epochs ve
+         JOIN validators v ON v.sidechain_key = ve.sidechain_key
+         WHERE ve.sidechain_epoch = ?1 AND v.is_ours = 1",
+        params![epoch as i64],
+        |row| row.get(0),
+    )?;
+
+    let our_snapshot_seats: i64 = conn.query_row(
+        "SELECT COUNT(*)
+         FROM committee_snapshots cs
+         JOIN validators v ON lower(v.aura_key) = lower(cs.aura_key)
+         WHERE cs.epoch = ?1 AND v.is_ours = 1",
+        params![epoch as i64],
+        |row| row.get(0),
+    )?;
+
+    let attributed_blocks: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM blocks WHERE sidechain_epoch = ?1 AND author_key IS NOT NULL",
+        params![epoch as i64],
+        |row| row.get(0),
+    )?;
+
+    // Placeholder rows (unknown:<aura key>) don't count as a match
+    let (unmatched_members, unmatched_seats): (i64, i64) = if recorded_committee_size > 0 {
+        conn.query_row(
+            "SELECT COUNT(DISTINCT lower(cs.aura_key)), COUNT(*)
+             FROM committee_snapshots cs
+             WHERE cs.epoch = ?1 AND NOT EXISTS (
+                 SELECT 1 FROM validator_epochs ve
+                 WHERE ve.sidechain_epoch = ?1
+                   AND lower(ve.aura_key) = lower(cs.aura_key)
+                   AND ve.sidechain_key NOT LIKE ?2 || '%'
+             )",
+            params![epoch as i64, PLACEHOLDER_KEY_PREFIX],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?
+    } else {
+        (0, 0)
+    };
+
+    Ok(EpochAuditInput {
+        epoch,
+        seat_sum: seat_sum as u64,
+        recorded_committee_size: recorded_committee_size as u64,
+        snapshot_size: snapshot_size as u64,
+        our_recorded_seats: our_recorded_seats as u64,
+        our_snapshot_seats: our_snapshot_seats as u64,
+        attributed_blocks: attributed_blocks as u64,
+        unmatched_members: unmatched_members as u64,
+        unmatched_seats: unmatched_seats as u64,
+        slots_per_epoch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::blocks::{
+        store_committee_snapshot, store_validator_epoch, ValidatorEpochRecord,
+    };
+    use crate::db::schema::init_schema;
+    use crate::db::validators::{upsert_validator, ValidatorRecord};
+
+    fn consistent() -> EpochAuditInput {
+        EpochAuditInput {
+            epoch: 100,
+            seat_sum: 1200,
+            recorded_committee_size: 1200,
+            snapshot_size: 1200,
+            our_recorded_seats: 3,
+            our_snapshot_seats: 3,
+            attributed_blocks: 1150,
+            unmatched_members: 0,
+            unmatched_seats: 0,
+            slots_per_epoch: 1200,
+        }
+    }
+
+    #[test]
+    fn test_audit_epoch() {
+        assert!(audit_epoch(&consistent()).is_empty());
+
+        let input = EpochAuditInput {
+            seat_sum: 1180,
+            our_recorded_seats: 2,
+            attributed_blocks: 1201,
+            ..consistent()
+        };
+        assert_eq!(
+            audit_epoch(&input),
+            vec![
+                AuditIssue::SeatSumMismatch {
+                    seat_sum: 1180,
+                    committee_size: 1200
+                },
+                AuditIssue::OurSeatsMismatch {
+                    recorded: 2,
+                    snapshot: 3
+                },
+                AuditIssue::BlocksExceedSlots {
+                    blocks: 1201,
+                    slots: 1200
+                },
+            ]
+        );
+        assert_eq!(
+            audit_epoch(&input)[0].to_string(),
+            "seats sum to 1180 but committee size is 1200"
+        );
+
+        // Nothing captured for the epoch: seat checks are skipped
+        let input = EpochAuditInput {
+            seat_sum: 0,
+            recorded_committee_size: 0,
+            snapshot_size: 0,
+            our_recorded_seats: 0,
+            ..consistent()
+        };
+        assert!(audit_epoch(&input).is_empty());
+    }
+
+    #[test]
+    fn test_get_epoch_audit_input() {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        upsert_validator(
+            &conn,
+            &ValidatorRecord {
+                sidechain_key: "0xours".to_string(),
+                aura_key: Some("0xAURA1".to_string()),
+                grandpa_key: None,
+                label: None,
+                is_ours: true,
+                registration_status: None,
+                first_seen_epoch: None,
+                total_blocks: 0,
+            },
+        )
+        .unwrap();
+
+        // Snapshot gives us 2 of 3 seats, but only 1 was recorded for us
+        store_committee_snapshot(
+            &conn,
+            7,
+            &[
+                "0xaura1".to_string(),
+                "0xaura2".to_string(),
+                "0xaura1".to_string(),
+            ],
+        )
+        .unwrap();
+        for (key, aura, seats) in [("0xours", "0xaura1", 1), ("0xother", "0xaura2", 1)] {
+            store_validator_epoch(
+                &conn,
+                &ValidatorEpochRecord {
+                    sidechain_epoch: 7,
+                    sidechain_key: key.to_string(),
+                    aura_key: aura.to_string(),
+                    committee_seats: seats,
+                    committee_size: 3,
+                    is_permissioned: false,
+                    stake_lovelace: None,
+                    captured_at: 0,
+                },
+            )
+            .unwrap();
+        }
+
+        let input = get_epoch_audit_input(&conn, 7, 1200).unwrap();
+        assert_eq!(input.seat_sum, 2);
+        assert_eq!(input.recorded_committee_size, 3);
+        assert_eq!(input.snapshot_size, 3);
+        assert_eq!(input.our_recorded_seats, 1);
+        assert_eq!(input.our_snapshot_seats, 2);
+        assert_eq!(input.unmatched_members, 0);
+        assert_eq!(audit_epoch(&input).len(), 2);
+
+        // Epoch 8: 0xaura3 isn't a candidate; its placeholder row keeps the seats
+        // adding up but it still counts as unmatched
+        store_committee_snapshot(
+            &conn,
+            8,
+            &[
+                "0xaura1".to_string(),
+                "0xaura3".to_string(),
+                "0xaura3".to_string(),
+            ],
+        )
+        .unwrap();
+        for (key, aura, seats) in [("0xours", "0xaura1", 1), ("unknown:0xaura3", "0xaura3", 2)] {
+            store_validator_epoch(
+                &conn,
+                &ValidatorEpochRecord {
+                    sidechain_epoch: 8,
+                    sidechain_key: key.to_string(),
+                    aura_key: aura.to_string(),
+                    committee_seats: seats,
+                    committee_size: 3,
+                    is_permissioned: false,
+                    stake_lovelace: None,
+                    captured_at: 0,
+                },
+            )
+            .unwrap();
+        }
+
+        let input = get_epoch_audit_input(&conn, 8, 1200).unwrap();
+        assert_eq!(input.seat_sum, 3);
+        assert_eq!(input.unmatched_members, 1);
+        assert_eq!(input.unmatched_seats, 2);
+        assert!(audit_epoch(&input).is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 use anyhow::Result;
 use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Block record for database storage
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockRecord {
     pub block_number: u64,
     pub block_hash: String,
@@ -34,7 +36,7 @@ pub struct SyncStatusRecord {
 
 /// Validator epoch snapshot record
 /// Captures validator state (seats, registration status) for each sidechain epoch
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorEpochRecord {
     pub sidechain_epoch: u64,
     pub sidechain_key: String,
@@ -47,12 +49,17 @@ pub struct ValidatorEpochRecord {
 }
 
 /// Insert a block into the database
+///
+/// Blocks at or below the finalization watermark are stored as finalized,
+/// since `mark_finalized` never revisits that range.
 pub fn insert_block(conn: &Connection, block: &BlockRecord) -> Result<()> {
     conn.execute(
         "INSERT OR REPLACE INTO blocks
          (block_number, block_hash, parent_hash, state_root, extrinsics_root,
           slot_number, epoch, sidechain_epoch, timestamp, is_finalized, author_key, extrinsics_count, created_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9,
+                 ?10 OR ?1 <= COALESCE((SELECT last_marked_finalized FROM sync_status WHERE id = 1), 0),
+                 ?11, ?12, ?13)",
         params![
             block.block_number as i64,
             &block.block_hash,
@@ -111,12 +118,39 @@ pub fn get_max_block_number(conn: &Connection) -> Result<Option<u64>> {
     Ok(result.map(|n| n as u64))
 }
 
+/// Get the lowest block number in the database
+pub fn get_min_block_number(conn: &Connection) -> Result<Option<u64>> {
+    let result: Option<i64> =
+        conn.query_row("SELECT MIN(block_number) FROM blocks", [], |row| row.get(0))?;
+    Ok(result.map(|n| n as u64))
+}
+
 /// Mark blocks as finalized up to a given block number
+///
+/// Only the range above the `last_marked_finalized` watermark is swept, so
+/// the per-poll cost stays constant as the database grows. Returns the
+/// number of blocks newly marked.
 pub fn mark_finalized(conn: &Connection, up_to_block: u64) -> Result<usize> {
-    let updated = conn.execute(
-        "UPDATE blocks SET is_finalized = 1 WHERE block_number <= ?1 AND is_finalized = 0",
+    let last_marked: i64 = conn.query_row(
+        "SELECT last_marked_finalized FROM sync_status WHERE id = 1",
+        [],
+        |row| row.get(0),
+    )?;
+    if up_to_block as i64 <= last_marked {
+        return Ok(0);
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    let updated = tx.execute(
+        "UPDATE blocks SET is_finalized = 1
+         WHERE block_number > ?1 AND block_number <= ?2 AND is_finalized = 0",
+        params![last_marked, up_to_block as i64],
+    )?;
+    tx.execute(
+        "UPDATE sync_status SET last_marked_finalized = ?1 WHERE id = 1",
         params![up_to_block as i64],
     )?;
+    tx.commit()?;
     Ok(updated)
 }
 
@@ -187,6 +221,16 @@ pub fn count_finalized_blocks(conn: &Connection) -> Result<u64> {
     Ok(count as u64)
 }
 
+/// Count blocks with a known author
+pub fn count_attributed_blocks(conn: &Connection) -> Result<u64> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM blocks WHERE author_key IS NOT NULL",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count as u64)
+}
+
 /// Get blocks in a range
 pub fn get_blocks_in_range(
     conn: &Connection,
@@ -204,28 +248,99 @@ pub fn get_blocks_in_range(
     );
 
     let mut stmt = conn.prepare(&sql)?;
-    let rows = stmt.query_map(params![from as i64, to as i64], |row| {
-        Ok(BlockRecord {
-            block_number: row.get::<_, i64>(0)? as u64,
-            block_hash: row.get(1)?,
-            parent_hash: row.get(2)?,
-            state_root: row.get(3)?,
-            extrinsics_root: row.get(4)?,
-            slot_number: row.get::<_, i64>(5)? as u64,
-            epoch: row.get::<_, i64>(6)? as u64,
-            sidechain_epoch: row.get::<_, i64>(7)? as u64,
-            timestamp: row.get(8)?,
-            is_finalized: row.get::<_, i32>(9)? != 0,
-            author_key: row.get(10)?,
-            extrinsics_count: row.get::<_, i32>(11)? as u32,
-        })
-    })?;
+    let rows = stmt.query_map(params![from as i64, to as i64], block_from_row)?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
+/// Get the lowest stored block in a sidechain epoch
+pub fn get_first_block_in_sidechain_epoch(
+    conn: &Connection,
+    sidechain_epoch: u64,
+) -> Result<Option<BlockRecord>> {
+    let mut stmt = conn.prepare(
+        "SELECT block_number, block_hash, parent_hash, state_root, extrinsics_root,
+                slot_number, epoch, sidechain_epoch, timestamp, is_finalized, author_key, extrinsics_count
+         FROM blocks WHERE sidechain_epoch = ?1
+         ORDER BY block_number ASC LIMIT 1",
+    )?;
+
+    match stmt.query_row(params![sidechain_epoch as i64], block_from_row) {
+        Ok(block) => Ok(Some(block)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Get blocks with `from_ts <= timestamp <= to_ts` (Unix seconds), oldest first
+pub fn get_blocks_in_time_range(
+    conn: &Connection,
+    from_ts: i64,
+    to_ts: i64,
+    limit: Option<u32>,
+) -> Result<Vec<BlockRecord>> {
+    let limit_clause = limit.map_or(String::new(), |l| format!(" LIMIT {}", l));
+    let sql = format!(
+        "SELECT block_number, block_hash, parent_hash, state_root, extrinsics_root,
+                slot_number, epoch, sidechain_epoch, timestamp, is_finalized, author_key, extrinsics_count
+         FROM blocks WHERE timestamp >= ?1 AND timestamp <= ?2
+         ORDER BY block_number ASC{}",
+        limit_clause
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(params![from_ts, to_ts], block_from_row)?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
+/// Blocks in a range stored without an author (e.g. synced while state was pruned)
+pub fn get_unattributed_blocks(conn: &Connection, from: u64, to: u64) -> Result<Vec<BlockRecord>> {
+    let mut stmt = conn.prepare(
+        "SELECT block_number, block_hash, parent_hash, state_root, extrinsics_root,
+                slot_number, epoch, sidechain_epoch, timestamp, is_finalized, author_key, extrinsics_count
+         FROM blocks
+         WHERE block_number >= ?1 AND block_number <= ?2 AND author_key IS NULL
+         ORDER BY block_number ASC",
+    )?;
+    let rows = stmt.query_map(params![from as i64, to as i64], block_from_row)?;
 
     rows.collect::<std::result::Result<Vec<_>, _>>()
         .map_err(Into::into)
 }
 
+/// Set the author of a block that has none; returns false if it already had one
+pub fn set_block_author(conn: &Connection, block_number: u64, author_key: &str) -> Result<bool> {
+    let updated = conn.execute(
+        "UPDATE blocks SET author_key = ?2 WHERE block_number = ?1 AND author_key IS NULL",
+        params![block_number as i64, author_key],
+    )?;
+    Ok(updated > 0)
+}
+
+/// Map a row selected in `BlockRecord` column order
+fn block_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<BlockRecord> {
+    Ok(BlockRecord {
+        block_number: row.get::<_, i64>(0)? as u64,
+        block_hash: row.get(1)?,
+        parent_hash: row.get(2)?,
+        state_root: row.get(3)?,
+        extrinsics_root: row.get(4)?,
+        slot_number: row.get::<_, i64>(5)? as u64,
+        epoch: row.get::<_, i64>(6)? as u64,
+        sidechain_epoch: row.get::<_, i64>(7)? as u64,
+        timestamp: row.get(8)?,
+        is_finalized: row.get::<_, i32>(9)? != 0,
+        author_key: row.get(10)?,
+        extrinsics_count: row.get::<_, i32>(11)? as u32,
+    })
+}
+
 /// Find gaps in block sequence
+///
+/// Returns inclusive (first_missing, last_missing) ranges.
 pub fn find_gaps(conn: &Connection) -> Result<Vec<(u64, u64)>> {
     let mut stmt = conn.prepare(
         "SELECT b1.block_number + 1 AS gap_start,
@@ -248,6 +363,204 @@ pub fn find_gaps(conn: &Connection) -> Result<Vec<(u64, u64)>> {
         .map_err(Into::into)
 }
 
+/// Two different blocks claiming the same slot (possible equivocation)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotCollision {
+    pub slot_number: u64,
+    /// The block that arrived later
+    pub block_number: u64,
+    pub block_hash: String,
+    /// The block already stored for the slot
+    pub other_block_number: u64,
+    pub other_block_hash: String,
+    pub author_key: Option<String>,
+    pub detected_at: i64,
+}
+
+/// Find stored blocks that claim the same slot as `block` under a different hash
+///
+/// Called before inserting a synced block; each result is a candidate
+/// equivocation to record with `record_slot_collision`. Blocks without a
+/// known slot (slot 0) are never compared.
+pub fn find_slot_collisions(conn: &Connection, block: &BlockRecord) -> Result<Vec<SlotCollision>> {
+    if block.slot_number == 0 {
+        return Ok(Vec::new());
+    }
+    let mut stmt = conn.prepare(
+        "SELECT block_number, block_hash FROM blocks
+         WHERE slot_number = ?1 AND block_hash != ?2
+         ORDER BY block_number",
+    )?;
+    let detected_at = chrono::Utc::now().timestamp();
+    let rows = stmt.query_map(
+        params![block.slot_number as i64, &block.block_hash],
+        |row| {
+            Ok(SlotCollision {
+                slot_number: block.slot_number,
+                block_number: block.block_number,
+                block_hash: block.block_hash.clone(),
+                other_block_number: row.get::<_, i64>(0)? as u64,
+                other_block_hash: row.get(1)?,
+                author_key: block.author_key.clone(),
+                detected_at,
+            })
+        },
+    )?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
+/// Record a slot collision; returns false if this pair was already recorded
+pub fn record_slot_collision(conn: &Connection, collision: &SlotCollision) -> Result<bool> {
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO slot_collisions
+         (slot_number, block_number, block_hash, other_block_number, other_block_hash,
+          author_key, detected_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            collision.slot_number as i64,
+            collision.block_number as i64,
+            &collision.block_hash,
+            collision.other_block_number as i64,
+            &collision.other_block_hash,
+            &collision.author_key,
+            collision.detected_at
+        ],
+    )?;
+    Ok(inserted > 0)
+}
+
+/// Count recorded slot collisions
+pub fn count_slot_collisions(conn: &Connection) -> Result<u64> {
+    let count: i64 =
+        conn.query_row("SELECT COUNT(*) FROM slot_collisions", [], |row| row.get(0))?;
+    Ok(count as u64)
+}
+
+/// Get the most recently recorded slot collisions, newest first
+pub fn get_recent_slot_collisions(conn: &Connection, limit: u32) -> Result<Vec<SlotCollision>> {
+    let mut stmt = conn.prepare(
+        "SELECT slot_number, block_number, block_hash, other_block_number, other_block_hash,
+                author_key, detected_at
+         FROM slot_collisions ORDER BY id DESC LIMIT ?1",
+    )?;
+    let rows = stmt.query_map(params![limit], |row| {
+        Ok(SlotCollision {
+            slot_number: row.get::<_, i64>(0)? as u64,
+            block_number: row.get::<_, i64>(1)? as u64,
+            block_hash: row.get(2)?,
+            other_block_number: row.get::<_, i64>(3)? as u64,
+            other_block_hash: row.get(4)?,
+            author_key: row.get(5)?,
+            detected_at: row.get(6)?,
+        })
+    })?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
+/// A block whose reported sidechain epoch is out of order with a stored neighbour
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpochAnomaly {
+    pub block_number: u64,
+    pub block_hash: String,
+    pub slot_number: u64,
+    /// Sidechain epoch the node reported for the block
+    pub reported_epoch: u64,
+    /// Nearest stored block the reported epoch contradicts
+    pub neighbor_block_number: u64,
+    pub neighbor_epoch: u64,
+    pub detected_at: i64,
+}
+
+/// Find a stored neighbour that `epoch` would put out of order at `block_number`
+///
+/// Sidechain epochs never decrease with block number, so the nearest stored
+/// block below must not have a higher epoch and the nearest above not a lower
+/// one. Epoch 0 means unknown and is never compared. Returns the offending
+/// neighbour's (block number, sidechain epoch).
+pub fn find_epoch_regression(
+    conn: &Connection,
+    block_number: u64,
+    epoch: u64,
+) -> Result<Option<(u64, u64)>> {
+    if epoch == 0 {
+        return Ok(None);
+    }
+    let neighbor = |sql: &str| -> Result<Option<(u64, u64)>> {
+        match conn.query_row(sql, params![block_number as i64], |row| {
+            Ok((row.get::<_, i64>(0)? as u64, row.get::<_, i64>(1)? as u64))
+        }) {
+            Ok(found) => Ok(Some(found)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    };
+
+    let below = neighbor(
+        "SELECT block_number, sidechain_epoch FROM blocks
+         WHERE block_number < ?1 AND sidechain_epoch > 0
+         ORDER BY block_number DESC LIMIT 1",
+    )?;
+    if let Some((number, neighbor_epoch)) = below {
+        if neighbor_epoch > epoch {
+            return Ok(Some((number, neighbor_epoch)));
+        }
+    }
+
+    let above = neighbor(
+        "SELECT block_number, sidechain_epoch FROM blocks
+         WHERE block_number > ?1 AND sidechain_epoch > 0
+         ORDER BY block_number ASC LIMIT 1",
+    )?;
+    Ok(above.filter(|&(_, neighbor_epoch)| neighbor_epoch < epoch))
+}
+
+/// Record an epoch anomaly; returns false if the block was already recorded
+pub fn record_epoch_anomaly(conn: &Connection, anomaly: &EpochAnomaly) -> Result<bool> {
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO epoch_anomalies
+         (block_number, block_hash, slot_number, reported_epoch, neighbor_block_number,
+          neighbor_epoch, detected_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            anomaly.block_number as i64,
+            &anomaly.block_hash,
+            anomaly.slot_number as i64,
+            anomaly.reported_epoch as i64,
+            anomaly.neighbor_block_number as i64,
+            anomaly.neighbor_epoch as i64,
+            anomaly.detected_at
+        ],
+    )?;
+    Ok(inserted > 0)
+}
+
+/// Get recorded epoch anomalies, lowest block first
+pub fn get_epoch_anomalies(conn: &Connection) -> Result<Vec<EpochAnomaly>> {
+    let mut stmt = conn.prepare(
+        "SELECT block_number, block_hash, slot_number, reported_epoch, neighbor_block_number,
+                neighbor_epoch, detected_at
+         FROM epoch_anomalies ORDER BY block_number",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(EpochAnomaly {
+            block_number: row.get::<_, i64>(0)? as u64,
+            block_hash: row.get(1)?,
+            slot_number: row.get::<_, i64>(2)? as u64,
+            reported_epoch: row.get::<_, i64>(3)? as u64,
+            neighbor_block_number: row.get::<_, i64>(4)? as u64,
+            neighbor_epoch: row.get::<_, i64>(5)? as u64,
+            detected_at: row.get(6)?,
+        })
+    })?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
 /// Count blocks by author in a specific epoch
 #[allow(dead_code)]
 pub fn count_blocks_by_author_in_epoch(
@@ -286,6 +599,8 @@ pub fn count_blocks_by_author_since(
 /// * `author_keys` - List of author keys to count (combined for "our" validators)
 /// * `bucket_duration_secs` - Duration of each bucket in seconds
 /// * `num_buckets` - Number of buckets to return
+/// * `now` - End of the newest bucket (Unix seconds); pass chain time, since a
+///   skewed host clock would shift every bucket against block timestamps
 ///
 /// # Returns
 /// Vector of counts from oldest bucket to newest (left to right for sparkline)
@@ -295,12 +610,12 @@ pub fn get_block_counts_bucketed(
     author_keys: &[String],
     bucket_duration_secs: i64,
     num_buckets: usize,
+    now: i64,
 ) -> Result<Vec<u64>> {
     if author_keys.is_empty() || num_buckets == 0 {
         return Ok(vec![0; num_buckets]);
     }
 
-    let now = chrono::Utc::now().timestamp();
     let total_duration = bucket_duration_secs * num_buckets as i64;
     let start_time = now - total_duration;
 
@@ -428,6 +743,44 @@ pub fn get_block_counts_by_epoch(
     Ok(buckets)
 }
 
+/// Get total block counts (all authors) per sidechain epoch for the network sparkline
+///
+/// Same window as `get_block_counts_by_epoch`: `num_epochs` completed epochs
+/// before `current_epoch`, oldest first.
+pub fn get_total_block_counts_by_epoch(
+    conn: &Connection,
+    current_epoch: u64,
+    num_epochs: usize,
+) -> Result<Vec<u64>> {
+    if num_epochs == 0 {
+        return Ok(Vec::new());
+    }
+
+    let end_epoch = current_epoch.saturating_sub(1);
+    let start_epoch = end_epoch.saturating_sub(num_epochs as u64 - 1);
+
+    let mut stmt = conn.prepare(
+        "SELECT sidechain_epoch, COUNT(*)
+         FROM blocks
+         WHERE sidechain_epoch >= ?1 AND sidechain_epoch <= ?2
+         GROUP BY sidechain_epoch",
+    )?;
+    let rows = stmt.query_map(params![start_epoch as i64, end_epoch as i64], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?))
+    })?;
+
+    let mut buckets = vec![0u64; num_epochs];
+    for row in rows {
+        let (epoch, count) = row?;
+        let idx = (epoch as u64).saturating_sub(start_epoch) as usize;
+        if idx < num_epochs {
+            buckets[idx] = count as u64;
+        }
+    }
+
+    Ok(buckets)
+}
+
 /// Get seat counts per epoch for specified validators (for sparkline)
 /// Returns a vector of seat counts, one per epoch, oldest first
 pub fn get_seats_by_epoch(
@@ -547,6 +900,75 @@ pub fn get_committee_snapshot(conn: &Connection, epoch: u64) -> Result<Option<Ve
     }
 }
 
+/// Committee membership change between an epoch and the one before it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommitteeChurn {
+    pub epoch: u64,
+    /// AURA keys in this epoch's committee that held no seat in the previous one
+    pub joined: usize,
+    /// AURA keys from the previous committee with no seat in this one
+    pub left: usize,
+    /// Seats that changed hands (sum of per-key seat gains)
+    pub seats_reallocated: usize,
+    pub committee_size: usize,
+}
+
+impl CommitteeChurn {
+    /// Summary line, e.g. "3 joined, 2 left, 40 seats reallocated"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} joined, {} left, {} seats reallocated",
+            self.joined, self.left, self.seats_reallocated
+        )
+    }
+}
+
+/// Compare the committee snapshot for `epoch` against `epoch - 1`
+///
+/// Returns None unless both snapshots are stored.
+pub fn get_committee_churn(conn: &Connection, epoch: u64) -> Result<Option<CommitteeChurn>> {
+    if epoch == 0 {
+        return Ok(None);
+    }
+    let (Some(current), Some(previous)) = (
+        get_committee_snapshot(conn, epoch)?,
+        get_committee_snapshot(conn, epoch - 1)?,
+    ) else {
+        return Ok(None);
+    };
+
+    let seat_counts = |committee: &[String]| {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for key in committee {
+            *counts.entry(key.to_lowercase()).or_default() += 1;
+        }
+        counts
+    };
+    let current_seats = seat_counts(&current);
+    let previous_seats = seat_counts(&previous);
+
+    let joined = current_seats
+        .keys()
+        .filter(|k| !previous_seats.contains_key(*k))
+        .count();
+    let left = previous_seats
+        .keys()
+        .filter(|k| !current_seats.contains_key(*k))
+        .count();
+    let seats_reallocated = current_seats
+        .iter()
+        .map(|(k, &seats)| seats.saturating_sub(previous_seats.get(k).copied().unwrap_or(0)))
+        .sum();
+
+    Ok(Some(CommitteeChurn {
+        epoch,
+        joined,
+        left,
+        seats_reallocated,
+        committee_size: current.len(),
+    }))
+}
+
 /// Get committee size for an epoch
 #[allow(dead_code)]
 pub fn get_committee_size(conn: &Connection, epoch: u64) -> Result<Option<usize>> {
@@ -662,6 +1084,127 @@ pub fn get_validators_for_epoch(
         .map_err(Into::into)
 }
 
+/// A validator's standing by blocks produced in one sidechain epoch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpochRank {
+    pub epoch: u64,
+    /// 1 = most blocks; ties share a rank
+    pub rank: u32,
+    /// Authors with at least one block in the epoch
+    pub authors: u32,
+    pub blocks: u64,
+}
+
+/// Rank `author_key` among the epoch's block authors, ordered by block count
+///
+/// Computed from the blocks table rather than stored, so re-synced gaps are
+/// reflected. Returns None when the validator produced no blocks that epoch.
+pub fn get_rank_in_epoch(
+    conn: &Connection,
+    author_key: &str,
+    sidechain_epoch: u64,
+) -> Result<Option<EpochRank>> {
+    let row: (Option<i64>, i64, i64) = conn.query_row(
+        "WITH counts AS (
+             SELECT author_key, COUNT(*) AS n FROM blocks
+             WHERE sidechain_epoch = ?1 AND author_key IS NOT NULL
+             GROUP BY author_key
+         ),
+         ours AS (SELECT n FROM counts WHERE author_key = ?2)
+         SELECT (SELECT n FROM ours),
+                (SELECT COUNT(*) FROM counts),
+                (SELECT COUNT(*) FROM counts WHERE n > (SELECT n FROM ours))",
+        params![sidechain_epoch as i64, author_key],
+        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+
+    Ok(row.0.map(|blocks| EpochRank {
+        epoch: sidechain_epoch,
+        rank: row.2 as u32 + 1,
+        authors: row.1 as u32,
+        blocks: blocks as u64,
+    }))
+}
+
+/// Ranks for the `limit` completed epochs before `current_epoch`, oldest first
+///
+/// Epochs where the validator produced nothing are skipped.
+pub fn get_rank_history(
+    conn: &Connection,
+    author_key: &str,
+    current_epoch: u64,
+    limit: usize,
+) -> Result<Vec<EpochRank>> {
+    let mut ranks = Vec::new();
+    for epoch in current_epoch.saturating_sub(limit as u64)..current_epoch {
+        if let Some(rank) = get_rank_in_epoch(conn, author_key, epoch)? {
+            ranks.push(rank);
+        }
+    }
+    Ok(ranks)
+}
+
+/// One author's share of a sidechain epoch's blocks
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochAuthorShare {
+    pub sidechain_key: String,
+    pub blocks: u64,
+    pub seats: u32,
+    pub committee_size: u32,
+}
+
+/// Block production per author for one sidechain epoch, most blocks first
+///
+/// Committee members from `get_validators_for_epoch` are included even when
+/// they produced nothing, so under-producers show up alongside the leaders.
+pub fn get_epoch_author_distribution(
+    conn: &Connection,
+    sidechain_epoch: u64,
+) -> Result<Vec<EpochAuthorShare>> {
+    let mut stmt = conn.prepare(
+        "SELECT author_key, COUNT(*) FROM blocks
+         WHERE sidechain_epoch = ?1 AND author_key IS NOT NULL
+         GROUP BY author_key",
+    )?;
+    let mut counts: HashMap<String, u64> = stmt
+        .query_map(params![sidechain_epoch as i64], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64))
+        })?
+        .collect::<std::result::Result<_, _>>()?;
+
+    let mut shares: Vec<EpochAuthorShare> = get_validators_for_epoch(conn, sidechain_epoch)?
+        .into_iter()
+        .filter_map(|v| {
+            let blocks = counts.remove(&v.sidechain_key);
+            (v.committee_seats > 0 || blocks.is_some()).then(|| EpochAuthorShare {
+                blocks: blocks.unwrap_or(0),
+                sidechain_key: v.sidechain_key,
+                seats: v.committee_seats,
+                committee_size: v.committee_size,
+            })
+        })
+        .collect();
+
+    // Authors without a validator snapshot for the epoch
+    shares.extend(
+        counts
+            .into_iter()
+            .map(|(sidechain_key, blocks)| EpochAuthorShare {
+                sidechain_key,
+                blocks,
+                seats: 0,
+                committee_size: 0,
+            }),
+    );
+
+    shares.sort_by(|a, b| {
+        b.blocks
+            .cmp(&a.blocks)
+            .then_with(|| a.sidechain_key.cmp(&b.sidechain_key))
+    });
+    Ok(shares)
+}
+
 /// Get the latest validator epoch snapshot for each validator
 #[allow(dead_code)]
 pub fn get_latest_validator_epochs(conn: &Connection) -> Result<Vec<ValidatorEpochRecord>> {
@@ -705,6 +1248,24 @@ pub fn has_validator_epoch_snapshot(conn: &Connection, sidechain_epoch: u64) ->
     Ok(count > 0)
 }
 
+/// Delete a sidechain epoch's validator snapshot so it can be captured again
+pub fn delete_validator_epoch_snapshot(conn: &Connection, sidechain_epoch: u64) -> Result<usize> {
+    Ok(conn.execute(
+        "DELETE FROM validator_epochs WHERE sidechain_epoch = ?1",
+        params![sidechain_epoch as i64],
+    )?)
+}
+
+/// Count sidechain epochs with a validator epoch snapshot
+pub fn count_snapshot_epochs(conn: &Connection) -> Result<u64> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(DISTINCT sidechain_epoch) FROM validator_epochs",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count as u64)
+}
+
 /// Validator epoch history record for drill-down view
 #[derive(Debug, Clone)]
 pub struct ValidatorEpochHistoryRecord {
@@ -756,6 +1317,147 @@ pub fn get_validator_epoch_history(
         .map_err(Into::into)
 }
 
+/// Weight of each completed epoch relative to the next newer one in the liveness score
+const LIVENESS_DECAY: f64 = 0.85;
+
+/// Block-production liveness over recent completed epochs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LivenessScore {
+    /// 0-100: recency-weighted share of expected blocks produced
+    pub score: f64,
+    /// Completed epochs in the window in which the validator held seats
+    pub epochs: u32,
+    /// Blocks produced across those epochs
+    pub blocks: u64,
+    /// Blocks expected across those epochs from the seats held
+    pub expected: f64,
+}
+
+/// Liveness score for a validator over the `window` completed epochs before `current_epoch`
+///
+/// Each epoch with seats scores blocks / expected, capped at 1, where expected
+/// is seats / committee_size × `blocks_per_epoch`. Epoch scores are averaged
+/// with weight `LIVENESS_DECAY`^age (age 0 = the last completed epoch), so a
+/// recent outage moves the score more than an old one. Epochs without seats
+/// expected nothing and are left out; None if no epoch in the window had seats.
+pub fn get_liveness_score(
+    conn: &Connection,
+    sidechain_key: &str,
+    current_epoch: u64,
+    window: usize,
+    blocks_per_epoch: u64,
+) -> Result<Option<LivenessScore>> {
+    let from = current_epoch.saturating_sub(window as u64);
+    let mut stmt = conn.prepare(
+        "SELECT ve.sidechain_epoch, ve.committee_seats, ve.committee_size,
+                (SELECT COUNT(*) FROM blocks b
+                 WHERE b.author_key = ?1 AND b.sidechain_epoch = ve.sidechain_epoch)
+         FROM validator_epochs ve
+         WHERE ve.sidechain_key = ?1
+           AND ve.sidechain_epoch >= ?2 AND ve.sidechain_epoch < ?3
+           AND ve.committee_seats > 0 AND ve.committee_size > 0",
+    )?;
+    let rows = stmt.query_map(
+        params![sidechain_key, from as i64, current_epoch as i64],
+        |row| {
+            Ok((
+                row.get::<_, i64>(0)? as u64,
+                row.get::<_, i64>(1)? as f64,
+                row.get::<_, i64>(2)? as f64,
+                row.get::<_, i64>(3)? as u64,
+            ))
+        },
+    )?;
+
+    let mut liveness = LivenessScore {
+        score: 0.0,
+        epochs: 0,
+        blocks: 0,
+        expected: 0.0,
+    };
+    let (mut weighted, mut total_weight) = (0.0, 0.0);
+    for row in rows {
+        let (epoch, seats, committee_size, blocks) = row?;
+        let expected = seats / committee_size * blocks_per_epoch as f64;
+        if expected <= 0.0 {
+            continue;
+        }
+        let weight = LIVENESS_DECAY.powi((current_epoch - 1 - epoch) as i32);
+        weighted += weight * (blocks as f64 / expected).min(1.0);
+        total_weight += weight;
+        liveness.epochs += 1;
+        liveness.blocks += blocks;
+        liveness.expected += expected;
+    }
+
+    if liveness.epochs == 0 {
+        return Ok(None);
+    }
+    liveness.score = weighted / total_weight * 100.0;
+    Ok(Some(liveness))
+}
+
+/// Stake snapshot for a single epoch
+#[derive(Debug, Clone, PartialEq)]
+pub struct StakeHistoryRecord {
+    pub epoch: u64,
+    pub stake_lovelace: Option<u64>,
+}
+
+/// Change in stake between the two most recent epochs with a known stake
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StakeChange {
+    /// Epoch of the earlier stake snapshot
+    pub previous_epoch: u64,
+    /// Epoch of the latest stake snapshot
+    pub latest_epoch: u64,
+    /// Latest stake minus previous stake, in lovelace
+    pub delta_lovelace: i64,
+}
+
+impl StakeChange {
+    /// Compare the latest two known stakes in a history ordered most recent first
+    pub fn from_history(history: &[StakeHistoryRecord]) -> Option<Self> {
+        let mut known = history
+            .iter()
+            .filter_map(|r| r.stake_lovelace.map(|stake| (r.epoch, stake)));
+        let (latest_epoch, latest) = known.next()?;
+        let (previous_epoch, previous) = known.next()?;
+
+        Some(Self {
+            previous_epoch,
+            latest_epoch,
+            delta_lovelace: latest as i64 - previous as i64,
+        })
+    }
+}
+
+/// Get a validator's per-epoch stake snapshots
+///
+/// Returns up to `limit` epochs ordered by epoch descending (most recent first).
+/// Epochs captured before stake data was available have `stake_lovelace = None`.
+pub fn get_stake_history(
+    conn: &Connection,
+    sidechain_key: &str,
+    limit: usize,
+) -> Result<Vec<StakeHistoryRecord>> {
+    let mut stmt = conn.prepare(
+        "SELECT sidechain_epoch, stake_lovelace FROM validator_epochs
+         WHERE sidechain_key = ?1
+         ORDER BY sidechain_epoch DESC
+         LIMIT ?2",
+    )?;
+    let rows = stmt.query_map(params![sidechain_key, limit as i64], |row| {
+        Ok(StakeHistoryRecord {
+            epoch: row.get::<_, i64>(0)? as u64,
+            stake_lovelace: row.get::<_, Option<i64>>(1)?.map(|s| s as u64),
+        })
+    })?;
+
+    rows.collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
 /// Get total committee seats for specified validators over the last N epochs
 ///
 /// Returns the sum of committee_seats for all specified sidechain_keys
@@ -802,6 +1504,24 @@ pub fn get_total_seats_for_epochs(
     Ok(total as u64)
 }
 
+/// Blocks and committee seats for validators over the last `num_epochs`
+/// completed sidechain epochs, as (blocks, seats)
+///
+/// A rolling window (e.g. a week of epochs) gives a medium-term production
+/// figure that doesn't depend on how long a single epoch is.
+pub fn get_blocks_by_author_in_window(
+    conn: &Connection,
+    sidechain_keys: &[String],
+    current_epoch: u64,
+    num_epochs: usize,
+) -> Result<(u64, u64)> {
+    let blocks = get_block_counts_by_epoch(conn, sidechain_keys, current_epoch, num_epochs)?
+        .iter()
+        .sum();
+    let seats = get_total_seats_for_epochs(conn, sidechain_keys, current_epoch, num_epochs)?;
+    Ok((blocks, seats))
+}
+
 /// Committee selection statistics for a validator
 #[derive(Debug, Clone, Default)]
 pub struct CommitteeSelectionStats {
@@ -825,6 +1545,10 @@ pub struct CommitteeSelectionStats {
     pub stake_share_percent: Option<f64>,
     /// Percentage of committee seats held by permissioned validators
     pub permissioned_seats_percent: f64,
+    /// Epochs spanned by this validator's history (first to last tracked, inclusive)
+    pub epoch_span: u64,
+    /// Epochs within that span with no validator snapshot at all (sync missed them)
+    pub missing_snapshot_epochs: u64,
 }
 
 impl CommitteeSelectionStats {
@@ -856,6 +1580,36 @@ impl CommitteeSelectionStats {
             Some(self.total_seats as f64 / self.times_selected as f64)
         }
     }
+
+    /// Note on data coverage when snapshots are missing (None if history is complete)
+    ///
+    /// Selection rates are computed over tracked epochs only, so missing
+    /// snapshots would otherwise silently skew them.
+    pub fn coverage_note(&self) -> Option<String> {
+        if self.missing_snapshot_epochs == 0 {
+            return None;
+        }
+        Some(format!(
+            "based on {} of {} epochs; snapshots missing for {}",
+            self.epoch_span.saturating_sub(self.missing_snapshot_epochs),
+            self.epoch_span,
+            self.missing_snapshot_epochs
+        ))
+    }
+}
+
+/// Count sidechain epochs in [from, to] that have no validator snapshot
+pub fn count_missing_snapshot_epochs(conn: &Connection, from: u64, to: u64) -> Result<u64> {
+    if to < from {
+        return Ok(0);
+    }
+    let present: i64 = conn.query_row(
+        "SELECT COUNT(DISTINCT sidechain_epoch) FROM validator_epochs
+         WHERE sidechain_epoch BETWEEN ?1 AND ?2",
+        params![from as i64, to as i64],
+        |row| row.get(0),
+    )?;
+    Ok((to - from + 1).saturating_sub(present as u64))
 }
 
 /// Get committee selection statistics for a validator
@@ -870,15 +1624,43 @@ pub fn get_committee_selection_stats(
             COUNT(*) as epochs_tracked,
             SUM(CASE WHEN committee_seats > 0 THEN 1 ELSE 0 END) as times_selected,
             COALESCE(SUM(committee_seats), 0) as total_seats,
-            MAX(CASE WHEN committee_seats > 0 THEN sidechain_epoch ELSE NULL END) as last_selected
+            MAX(CASE WHEN committee_seats > 0 THEN sidechain_epoch ELSE NULL END) as last_selected,
+            MIN(sidechain_epoch) as first_tracked,
+            MAX(sidechain_epoch) as last_tracked
          FROM validator_epochs
          WHERE sidechain_key = ?1",
     )?;
 
-    let (epochs_tracked, times_selected, total_seats, last_selected): (i64, i64, i64, Option<i64>) =
-        stmt.query_row(params![sidechain_key], |row| {
-            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-        })?;
+    #[allow(clippy::type_complexity)]
+    let (epochs_tracked, times_selected, total_seats, last_selected, first_tracked, last_tracked): (
+        i64,
+        Option<i64>,
+        i64,
+        Option<i64>,
+        Option<i64>,
+        Option<i64>,
+    ) = stmt.query_row(params![sidechain_key], |row| {
+        Ok((
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+        ))
+    })?;
+
+    // Detect epochs where sync missed the snapshot entirely
+    let (epoch_span, missing_snapshot_epochs) = match (first_tracked, last_tracked) {
+        (Some(first), Some(last)) => {
+            let (first, last) = (first as u64, last as u64);
+            (
+                last - first + 1,
+                count_missing_snapshot_epochs(conn, first, last)?,
+            )
+        }
+        _ => (0, 0),
+    };
 
     // Check if currently in committee
     let currently_in_committee: bool = conn
@@ -899,7 +1681,7 @@ pub fn get_committee_selection_stats(
 
     Ok(CommitteeSelectionStats {
         epochs_tracked: epochs_tracked as u64,
-        times_selected: times_selected as u64,
+        times_selected: times_selected.unwrap_or(0) as u64,
         total_seats: total_seats as u64,
         last_selected_epoch: last_selected.map(|e| e as u64),
         current_epoch,
@@ -908,6 +1690,8 @@ pub fn get_committee_selection_stats(
         total_dynamic_validators: total_dynamic,
         stake_share_percent: stake_share,
         permissioned_seats_percent: permissioned_percent,
+        epoch_span,
+        missing_snapshot_epochs,
     })
 }
 
@@ -963,6 +1747,84 @@ fn get_stake_rank_info(
     }
 }
 
+/// One dynamic (non-permissioned) validator's standing in an epoch, by stake
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DynamicValidatorRank {
+    pub sidechain_key: String,
+    /// 1-based rank by stake among dynamic validators (ties share a rank)
+    pub stake_rank: u32,
+    pub stake_lovelace: u64,
+    /// Share of all dynamic stake in the epoch
+    pub stake_share_percent: f64,
+    pub committee_seats: u32,
+    /// Epochs with a snapshot of this validator, and how many of them had seats
+    pub epochs_tracked: u64,
+    pub times_selected: u64,
+}
+
+impl DynamicValidatorRank {
+    /// Percentage of tracked epochs in which the validator held seats
+    pub fn selection_rate(&self) -> Option<f64> {
+        (self.epochs_tracked > 0)
+            .then(|| self.times_selected as f64 / self.epochs_tracked as f64 * 100.0)
+    }
+}
+
+/// Dynamic validators with known stake in an epoch, highest stake first
+///
+/// Ranks and shares match [`get_stake_rank_info`]: the rank is one more than
+/// the number of validators with strictly higher stake.
+pub fn get_dynamic_leaderboard(
+    conn: &Connection,
+    sidechain_epoch: u64,
+) -> Result<Vec<DynamicValidatorRank>> {
+    let mut stmt = conn.prepare(
+        "SELECT cur.sidechain_key, cur.stake_lovelace, cur.committee_seats,
+                COUNT(hist.sidechain_epoch),
+                COALESCE(SUM(CASE WHEN hist.committee_seats > 0 THEN 1 ELSE 0 END), 0)
+         FROM validator_epochs cur
+         JOIN validator_epochs hist ON hist.sidechain_key = cur.sidechain_key
+         WHERE cur.sidechain_epoch = ?1 AND cur.is_permissioned = 0
+           AND cur.stake_lovelace IS NOT NULL
+         GROUP BY cur.sidechain_key
+         ORDER BY cur.stake_lovelace DESC, cur.sidechain_key",
+    )?;
+    let rows = stmt
+        .query_map(params![sidechain_epoch as i64], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)? as u64,
+                row.get::<_, u32>(2)?,
+                row.get::<_, i64>(3)? as u64,
+                row.get::<_, i64>(4)? as u64,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total_stake: u64 = rows.iter().map(|r| r.1).sum();
+    let mut leaderboard: Vec<DynamicValidatorRank> = Vec::with_capacity(rows.len());
+    for (position, (key, stake, seats, tracked, selected)) in rows.into_iter().enumerate() {
+        let stake_rank = match leaderboard.last() {
+            Some(prev) if prev.stake_lovelace == stake => prev.stake_rank,
+            _ => position as u32 + 1,
+        };
+        leaderboard.push(DynamicValidatorRank {
+            sidechain_key: key,
+            stake_rank,
+            stake_lovelace: stake,
+            stake_share_percent: if total_stake > 0 {
+                stake as f64 / total_stake as f64 * 100.0
+            } else {
+                0.0
+            },
+            committee_seats: seats,
+            epochs_tracked: tracked,
+            times_selected: selected,
+        });
+    }
+    Ok(leaderboard)
+}
+
 /// Get percentage of committee seats held by permissioned validators
 fn get_permissioned_seats_percent(conn: &Connection, current_epoch: u64) -> Result<f64> {
     let result: (i64, i64) = conn
@@ -1022,6 +1884,35 @@ mod tests {
         assert_eq!(retrieved.slot_number, 100000);
         assert_eq!(retrieved.epoch, 100);
         assert_eq!(retrieved.sidechain_epoch, 1200);
+
+        insert_block(
+            &conn,
+            &BlockRecord {
+                block_number: 1001,
+                block_hash: "0x124".to_string(),
+                author_key: None,
+                ..block
+            },
+        )
+        .unwrap();
+        assert_eq!(count_blocks(&conn).unwrap(), 2);
+        assert_eq!(count_attributed_blocks(&conn).unwrap(), 1);
+
+        // Re-attribution only fills in missing authors
+        let missing = get_unattributed_blocks(&conn, 0, 2000).unwrap();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].block_number, 1001);
+        assert!(set_block_author(&conn, 1001, "0xother").unwrap());
+        assert!(!set_block_author(&conn, 1000, "0xother").unwrap());
+        assert!(get_unattributed_blocks(&conn, 0, 2000).unwrap().is_empty());
+        assert_eq!(
+            get_block(&conn, 1000)
+                .unwrap()
+                .unwrap()
+                .author_key
+                .as_deref(),
+            Some("0xvalidator")
+        );
     }
 
     #[test]
@@ -1069,6 +1960,399 @@ mod tests {
         assert_eq!(count_finalized_blocks(&conn).unwrap(), 5);
     }
 
+    #[test]
+    fn test_mark_finalized_incremental() {
+        let conn = setup_db();
+
+        let block = |i: u64| BlockRecord {
+            block_number: i,
+            block_hash: format!("0x{}", i),
+            parent_hash: format!("0x{}", i - 1),
+            state_root: "0x".to_string(),
+            extrinsics_root: "0x".to_string(),
+            slot_number: i * 100,
+            epoch: 1,
+            sidechain_epoch: 12,
+            timestamp: 1234567890 + i as i64,
+            is_finalized: false,
+            author_key: None,
+            extrinsics_count: 0,
+        };
+        for i in 1..=10 {
+            insert_block(&conn, &block(i)).unwrap();
+        }
+
+        // Only the delta above the watermark is counted
+        assert_eq!(mark_finalized(&conn, 4).unwrap(), 4);
+        assert_eq!(mark_finalized(&conn, 7).unwrap(), 3);
+        assert_eq!(mark_finalized(&conn, 7).unwrap(), 0);
+        assert_eq!(mark_finalized(&conn, 6).unwrap(), 0);
+        assert_eq!(count_finalized_blocks(&conn).unwrap(), 7);
+
+        // Backfilled blocks below the watermark are stored finalized
+        conn.execute("DELETE FROM blocks WHERE block_number = 3", [])
+            .unwrap();
+        insert_block(&conn, &block(3)).unwrap();
+        assert!(get_block(&conn, 3).unwrap().unwrap().is_finalized);
+        insert_block(&conn, &block(11)).unwrap();
+        assert!(!get_block(&conn, 11).unwrap().unwrap().is_finalized);
+
+        assert_eq!(mark_finalized(&conn, 11).unwrap(), 4);
+        assert_eq!(count_finalized_blocks(&conn).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_epoch_regression() {
+        let conn = setup_db();
+        let block = |number: u64, sidechain_epoch: u64| BlockRecord {
+            block_number: number,
+            block_hash: format!("0x{:02x}", number),
+            parent_hash: "0x".to_string(),
+            state_root: "0x".to_string(),
+            extrinsics_root: "0x".to_string(),
+            slot_number: 1000 + number,
+            epoch: 1,
+            sidechain_epoch,
+            timestamp: 1234567890,
+            is_finalized: false,
+            author_key: None,
+            extrinsics_count: 0,
+        };
+        insert_block(&conn, &block(10, 5)).unwrap();
+        insert_block(&conn, &block(11, 0)).unwrap(); // unknown epoch is skipped
+        insert_block(&conn, &block(20, 7)).unwrap();
+
+        assert_eq!(find_epoch_regression(&conn, 15, 5).unwrap(), None);
+        assert_eq!(find_epoch_regression(&conn, 15, 7).unwrap(), None);
+        assert_eq!(find_epoch_regression(&conn, 21, 8).unwrap(), None);
+        assert_eq!(find_epoch_regression(&conn, 15, 0).unwrap(), None);
+        // Lower than the block before, higher than the block after
+        assert_eq!(find_epoch_regression(&conn, 15, 4).unwrap(), Some((10, 5)));
+        assert_eq!(find_epoch_regression(&conn, 15, 8).unwrap(), Some((20, 7)));
+        assert_eq!(find_epoch_regression(&conn, 21, 6).unwrap(), Some((20, 7)));
+
+        let anomaly = EpochAnomaly {
+            block_number: 15,
+            block_hash: "0x0f".to_string(),
+            slot_number: 1015,
+            reported_epoch: 4,
+            neighbor_block_number: 10,
+            neighbor_epoch: 5,
+            detected_at: 1234567890,
+        };
+        assert!(record_epoch_anomaly(&conn, &anomaly).unwrap());
+        assert!(!record_epoch_anomaly(&conn, &anomaly).unwrap());
+        assert_eq!(get_epoch_anomalies(&conn).unwrap(), vec![anomaly]);
+    }
+
+    #[test]
+    fn test_slot_collisions() {
+        let conn = setup_db();
+
+        let block = |number: u64, hash: &str, slot: u64| BlockRecord {
+            block_number: number,
+            block_hash: hash.to_string(),
+            parent_hash: "0x".to_string(),
+            state_root: "0x".to_string(),
+            extrinsics_root: "0x".to_string(),
+            slot_number: slot,
+            epoch: 1,
+            sidechain_epoch: 12,
+            timestamp: 1234567890,
+            is_finalized: false,
+            author_key: Some("0xauthor".to_string()),
+            extrinsics_count: 0,
+        };
+        insert_block(&conn, &block(1, "0xaa", 500)).unwrap();
+
+        // Same block re-synced is not a collision
+        assert!(find_slot_collisions(&conn, &block(1, "0xaa", 500))
+            .unwrap()
+            .is_empty());
+        assert!(find_slot_collisions(&conn, &block(2, "0xbb", 501))
+            .unwrap()
+            .is_empty());
+
+        // A second block for slot 500 collides with the stored one
+        let competing = block(2, "0xcc", 500);
+        let collisions = find_slot_collisions(&conn, &competing).unwrap();
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(collisions[0].other_block_number, 1);
+        assert_eq!(collisions[0].other_block_hash, "0xaa");
+        assert_eq!(collisions[0].block_hash, "0xcc");
+
+        assert!(record_slot_collision(&conn, &collisions[0]).unwrap());
+        assert!(!record_slot_collision(&conn, &collisions[0]).unwrap());
+        assert_eq!(count_slot_collisions(&conn).unwrap(), 1);
+
+        let recent = get_recent_slot_collisions(&conn, 5).unwrap();
+        assert_eq!(recent, collisions);
+    }
+
+    #[test]
+    fn test_find_gaps() {
+        let conn = setup_db();
+
+        // Blocks 1-3, 6, 10 stored: gaps are 4-5 and 7-9
+        for i in [1u64, 2, 3, 6, 10] {
+            let block = BlockRecord {
+                block_number: i,
+                block_hash: format!("0x{}", i),
+                parent_hash: format!("0x{}", i - 1),
+                state_root: "0x".to_string(),
+                extrinsics_root: "0x".to_string(),
+                slot_number: i * 100,
+                epoch: 1,
+                sidechain_epoch: 12,
+                timestamp: 1234567890 + i as i64,
+                is_finalized: false,
+                author_key: None,
+                extrinsics_count: 0,
+            };
+            insert_block(&conn, &block).unwrap();
+        }
+
+        assert_eq!(find_gaps(&conn).unwrap(), vec![(4, 5), (7, 9)]);
+        assert_eq!(
+            get_first_block_in_sidechain_epoch(&conn, 12)
+                .unwrap()
+                .map(|b| b.block_number),
+            Some(1)
+        );
+        assert!(get_first_block_in_sidechain_epoch(&conn, 13)
+            .unwrap()
+            .is_none());
+        assert_eq!(get_min_block_number(&conn).unwrap(), Some(1));
+        assert_eq!(get_max_block_number(&conn).unwrap(), Some(10));
+
+        // Time window bounds are inclusive
+        let numbers = |blocks: Vec<BlockRecord>| -> Vec<u64> {
+            blocks.iter().map(|b| b.block_number).collect()
+        };
+        let in_window = get_blocks_in_time_range(&conn, 1234567892, 1234567896, None).unwrap();
+        assert_eq!(numbers(in_window), vec![2, 3, 6]);
+        let limited = get_blocks_in_time_range(&conn, 0, i64::MAX, Some(2)).unwrap();
+        assert_eq!(numbers(limited), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_total_block_counts_by_epoch() {
+        let conn = setup_db();
+
+        // Epoch 10: 3 blocks, epoch 11: none, epoch 12: 2 blocks, epoch 13 (current): 1
+        for (i, epoch) in [10u64, 10, 10, 12, 12, 13].into_iter().enumerate() {
+            let block = BlockRecord {
+                block_number: i as u64 + 1,
+                block_hash: format!("0x{}", i),
+                parent_hash: "0x".to_string(),
+                state_root: "0x".to_string(),
+                extrinsics_root: "0x".to_string(),
+                slot_number: i as u64,
+                epoch: 1,
+                sidechain_epoch: epoch,
+                timestamp: 1234567890,
+                is_finalized: false,
+                author_key: Some(format!("0xauthor{}", i % 2)),
+                extrinsics_count: 0,
+            };
+            insert_block(&conn, &block).unwrap();
+        }
+
+        // Current epoch 13 is excluded; window is epochs 9-12
+        assert_eq!(
+            get_total_block_counts_by_epoch(&conn, 13, 4).unwrap(),
+            vec![0, 3, 0, 2]
+        );
+        assert!(get_total_block_counts_by_epoch(&conn, 13, 0)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_epoch_author_distribution() {
+        let conn = setup_db();
+
+        // 0xaaa: 3 blocks, 0xbbb: 1 block, 0xccc: seated but idle, 0xddd: no snapshot
+        for (i, author) in ["0xaaa", "0xbbb", "0xaaa", "0xaaa", "0xddd"]
+            .into_iter()
+            .enumerate()
+        {
+            let block = BlockRecord {
+                block_number: i as u64 + 1,
+                block_hash: format!("0x{}", i),
+                parent_hash: "0x".to_string(),
+                state_root: "0x".to_string(),
+                extrinsics_root: "0x".to_string(),
+                slot_number: i as u64,
+                epoch: 1,
+                sidechain_epoch: 20,
+                timestamp: 1234567890,
+                is_finalized: false,
+                author_key: Some(author.to_string()),
+                extrinsics_count: 0,
+            };
+            insert_block(&conn, &block).unwrap();
+        }
+        for (key, seats) in [("0xaaa", 2), ("0xbbb", 2), ("0xccc", 1), ("0xeee", 0)] {
+            store_validator_epoch(
+                &conn,
+                &ValidatorEpochRecord {
+                    sidechain_epoch: 20,
+                    sidechain_key: key.to_string(),
+                    aura_key: format!("{}aura", key),
+                    committee_seats: seats,
+                    committee_size: 5,
+                    is_permissioned: false,
+                    stake_lovelace: None,
+                    captured_at: 0,
+                },
+            )
+            .unwrap();
+        }
+
+        let shares = get_epoch_author_distribution(&conn, 20).unwrap();
+        let summary: Vec<(&str, u64, u32)> = shares
+            .iter()
+            .map(|s| (s.sidechain_key.as_str(), s.blocks, s.seats))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("0xaaa", 3, 2),
+                ("0xbbb", 1, 2),
+                ("0xddd", 1, 0),
+                ("0xccc", 0, 1)
+            ]
+        );
+        assert!(get_epoch_author_distribution(&conn, 21).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rank_in_epoch() {
+        let conn = setup_db();
+        let mut number = 0;
+        // Epoch 20: a=3, b=1, c=3; epoch 21: b=2, a=1
+        for (epoch, author, count) in [
+            (20u64, "0xa", 3),
+            (20, "0xb", 1),
+            (20, "0xc", 3),
+            (21, "0xb", 2),
+            (21, "0xa", 1),
+        ] {
+            for _ in 0..count {
+                number += 1;
+                insert_block(
+                    &conn,
+                    &BlockRecord {
+                        block_number: number,
+                        block_hash: format!("0x{}", number),
+                        parent_hash: "0x0".to_string(),
+                        state_root: "0x0".to_string(),
+                        extrinsics_root: "0x0".to_string(),
+                        slot_number: number,
+                        epoch: 1,
+                        sidechain_epoch: epoch,
+                        timestamp: 0,
+                        is_finalized: true,
+                        author_key: Some(author.to_string()),
+                        extrinsics_count: 0,
+                    },
+                )
+                .unwrap();
+            }
+        }
+
+        // Ties share the higher rank
+        let rank = |key, epoch| get_rank_in_epoch(&conn, key, epoch).unwrap();
+        assert_eq!(
+            rank("0xa", 20),
+            Some(EpochRank {
+                epoch: 20,
+                rank: 1,
+                authors: 3,
+                blocks: 3
+            })
+        );
+        assert_eq!(rank("0xc", 20).unwrap().rank, 1);
+        assert_eq!(rank("0xb", 20).unwrap().rank, 3);
+        assert_eq!(rank("0xa", 21).unwrap().rank, 2);
+        assert_eq!(rank("0xc", 21), None);
+
+        // Completed epochs only, oldest first, skipping epochs without blocks
+        let history = get_rank_history(&conn, "0xa", 22, 5).unwrap();
+        assert_eq!(
+            history
+                .iter()
+                .map(|r| (r.epoch, r.rank))
+                .collect::<Vec<_>>(),
+            vec![(20, 1), (21, 2)]
+        );
+        assert_eq!(get_rank_history(&conn, "0xc", 21, 5).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_blocks_by_author_in_window() {
+        let conn = setup_db();
+
+        // Ours produced in epochs 9, 10 and the current epoch 12; someone else in 10
+        for (i, (epoch, author)) in [
+            (9u64, "0xours"),
+            (10, "0xours"),
+            (10, "0xother"),
+            (12, "0xours"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let block = BlockRecord {
+                block_number: i as u64 + 1,
+                block_hash: format!("0x{}", i),
+                parent_hash: "0x".to_string(),
+                state_root: "0x".to_string(),
+                extrinsics_root: "0x".to_string(),
+                slot_number: i as u64,
+                epoch: 1,
+                sidechain_epoch: epoch,
+                timestamp: 1234567890,
+                is_finalized: false,
+                author_key: Some(author.to_string()),
+                extrinsics_count: 0,
+            };
+            insert_block(&conn, &block).unwrap();
+        }
+        for (epoch, seats) in [(9u64, 2u32), (10, 1), (12, 3)] {
+            store_validator_epoch(
+                &conn,
+                &ValidatorEpochRecord {
+                    sidechain_epoch: epoch,
+                    sidechain_key: "0xours".to_string(),
+                    aura_key: "0xaura".to_string(),
+                    committee_seats: seats,
+                    committee_size: 1200,
+                    is_permissioned: false,
+                    stake_lovelace: None,
+                    captured_at: 0,
+                },
+            )
+            .unwrap();
+        }
+
+        let ours = vec!["0xours".to_string()];
+        // Current epoch 12 is excluded; window is epochs 10-11
+        assert_eq!(
+            get_blocks_by_author_in_window(&conn, &ours, 12, 2).unwrap(),
+            (1, 1)
+        );
+        assert_eq!(
+            get_blocks_by_author_in_window(&conn, &ours, 12, 7).unwrap(),
+            (2, 3)
+        );
+        assert_eq!(
+            get_blocks_by_author_in_window(&conn, &[], 12, 7).unwrap(),
+            (0, 0)
+        );
+    }
+
     #[test]
     fn test_validator_epoch_snapshot() {
         let conn = setup_db();
@@ -1117,6 +2401,216 @@ mod tests {
         // Test has_validator_epoch_snapshot
         assert!(has_validator_epoch_snapshot(&conn, 100).unwrap());
         assert!(!has_validator_epoch_snapshot(&conn, 101).unwrap());
+        assert_eq!(count_snapshot_epochs(&conn).unwrap(), 1);
+
+        // Re-capture starts from an empty epoch
+        assert_eq!(delete_validator_epoch_snapshot(&conn, 100).unwrap(), 2);
+        assert!(!has_validator_epoch_snapshot(&conn, 100).unwrap());
+    }
+
+    #[test]
+    fn test_selection_stats_snapshot_gaps() {
+        let conn = setup_db();
+
+        // Snapshots for epochs 100, 101, 104 - epochs 102-103 were missed by sync
+        for (epoch, seats) in [(100u64, 2u32), (101, 0), (104, 3)] {
+            let record = ValidatorEpochRecord {
+                sidechain_epoch: epoch,
+                sidechain_key: "0xsidechain1".to_string(),
+                aura_key: "0xaura1".to_string(),
+                committee_seats: seats,
+                committee_size: 1200,
+                is_permissioned: false,
+                stake_lovelace: Some(1_000_000_000),
+                captured_at: 0,
+            };
+            store_validator_epoch(&conn, &record).unwrap();
+        }
+
+        assert_eq!(count_missing_snapshot_epochs(&conn, 100, 104).unwrap(), 2);
+
+        let stats = get_committee_selection_stats(&conn, "0xsidechain1", 104).unwrap();
+        assert_eq!(stats.epochs_tracked, 3);
+        assert_eq!(stats.times_selected, 2);
+        assert_eq!(stats.epoch_span, 5);
+        assert_eq!(stats.missing_snapshot_epochs, 2);
+        assert_eq!(
+            stats.coverage_note().unwrap(),
+            "based on 3 of 5 epochs; snapshots missing for 2"
+        );
+    }
+
+    #[test]
+    fn test_dynamic_leaderboard() {
+        let conn = setup_db();
+        let record = |epoch: u64, key: &str, seats: u32, permissioned: bool, stake: Option<u64>| {
+            ValidatorEpochRecord {
+                sidechain_epoch: epoch,
+                sidechain_key: key.to_string(),
+                aura_key: format!("aura-{}", key),
+                committee_seats: seats,
+                committee_size: 10,
+                is_permissioned: permissioned,
+                stake_lovelace: stake,
+                captured_at: 0,
+            }
+        };
+        for r in [
+            record(100, "0xa", 0, false, Some(1_000)),
+            record(101, "0xa", 2, false, Some(3_000)),
+            record(101, "0xb", 1, false, Some(3_000)),
+            record(101, "0xc", 0, false, Some(4_000)),
+            record(101, "0xd", 0, false, None),
+            record(101, "0xperm", 7, true, Some(9_000)),
+        ] {
+            store_validator_epoch(&conn, &r).unwrap();
+        }
+
+        let board = get_dynamic_leaderboard(&conn, 101).unwrap();
+        let keys: Vec<_> = board.iter().map(|r| r.sidechain_key.as_str()).collect();
+        // Permissioned and unknown-stake validators are left out
+        assert_eq!(keys, ["0xc", "0xa", "0xb"]);
+        assert_eq!(board[0].stake_rank, 1);
+        // Equal stake shares a rank
+        assert_eq!((board[1].stake_rank, board[2].stake_rank), (2, 2));
+        assert!((board[0].stake_share_percent - 40.0).abs() < 1e-9);
+        assert_eq!((board[1].epochs_tracked, board[1].times_selected), (2, 1));
+        assert_eq!(board[1].selection_rate(), Some(50.0));
+
+        // Consistent with the per-validator stake rank in the identity card
+        for row in &board {
+            let (rank, total, share) = get_stake_rank_info(&conn, &row.sidechain_key, 101).unwrap();
+            assert_eq!(rank, Some(row.stake_rank));
+            assert_eq!(total, 3);
+            assert!((share.unwrap() - row.stake_share_percent).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_committee_churn() {
+        let conn = setup_db();
+        let keys = |list: &[&str]| list.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+
+        store_committee_snapshot(&conn, 100, &keys(&["0xa", "0xa", "0xb", "0xc"])).unwrap();
+        // 0xc left, 0xd joined with 2 seats, 0xb gained a seat from 0xa
+        store_committee_snapshot(&conn, 101, &keys(&["0xa", "0xb", "0xb", "0xd", "0xd"])).unwrap();
+
+        let churn = get_committee_churn(&conn, 101).unwrap().unwrap();
+        assert_eq!(churn.joined, 1);
+        assert_eq!(churn.left, 1);
+        assert_eq!(churn.seats_reallocated, 3);
+        assert_eq!(churn.committee_size, 5);
+        assert_eq!(churn.summary(), "1 joined, 1 left, 3 seats reallocated");
+
+        // No previous snapshot to compare against
+        assert!(get_committee_churn(&conn, 100).unwrap().is_none());
+        assert!(get_committee_churn(&conn, 102).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stake_history() {
+        let conn = setup_db();
+
+        for (epoch, stake) in [
+            (100u64, Some(5_000_000_000u64)),
+            (101, None),
+            (102, Some(4_250_000_000)),
+            (103, Some(4_750_000_000)),
+        ] {
+            let record = ValidatorEpochRecord {
+                sidechain_epoch: epoch,
+                sidechain_key: "0xsidechain1".to_string(),
+                aura_key: "0xaura1".to_string(),
+                committee_seats: 1,
+                committee_size: 1200,
+                is_permissioned: false,
+                stake_lovelace: stake,
+                captured_at: 0,
+            };
+            store_validator_epoch(&conn, &record).unwrap();
+        }
+
+        let history = get_stake_history(&conn, "0xsidechain1", 10).unwrap();
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[0].epoch, 103);
+        assert_eq!(history[2].stake_lovelace, None);
+
+        let change = StakeChange::from_history(&history).unwrap();
+        assert_eq!(change.latest_epoch, 103);
+        assert_eq!(change.previous_epoch, 102);
+        assert_eq!(change.delta_lovelace, 500_000_000);
+
+        // Skips epochs without stake data
+        let change = StakeChange::from_history(&history[1..]).unwrap();
+        assert_eq!(change.previous_epoch, 100);
+        assert_eq!(change.delta_lovelace, -750_000_000);
+
+        // A single known stake has nothing to compare against
+        assert!(StakeChange::from_history(&history[..1]).is_none());
+        assert!(get_stake_history(&conn, "0xunknown", 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_liveness_score() {
+        let conn = setup_db();
+
+        // 1 of 10 seats, 100 blocks per epoch: 10 expected per seated epoch
+        for (epoch, seats) in [(100u64, 1u32), (101, 0), (102, 1), (103, 1), (104, 1)] {
+            let record = ValidatorEpochRecord {
+                sidechain_epoch: epoch,
+                sidechain_key: "0xsidechain1".to_string(),
+                aura_key: "0xaura1".to_string(),
+                committee_seats: seats,
+                committee_size: 10,
+                is_permissioned: false,
+                stake_lovelace: None,
+                captured_at: 0,
+            };
+            store_validator_epoch(&conn, &record).unwrap();
+        }
+        // Epoch 100: 20 (capped at expected), 102: 5, 103: 10, 104 (current): 0
+        let epochs = std::iter::repeat_n(100u64, 20)
+            .chain(std::iter::repeat_n(102, 5))
+            .chain(std::iter::repeat_n(103, 10));
+        for (i, epoch) in epochs.enumerate() {
+            let block = BlockRecord {
+                block_number: i as u64 + 1,
+                block_hash: format!("0x{}", i),
+                parent_hash: "0x".to_string(),
+                state_root: "0x".to_string(),
+                extrinsics_root: "0x".to_string(),
+                slot_number: i as u64,
+                epoch: 1,
+                sidechain_epoch: epoch,
+                timestamp: 1234567890,
+                is_finalized: false,
+                author_key: Some("0xsidechain1".to_string()),
+                extrinsics_count: 0,
+            };
+            insert_block(&conn, &block).unwrap();
+        }
+
+        let liveness = get_liveness_score(&conn, "0xsidechain1", 104, 10, 100)
+            .unwrap()
+            .unwrap();
+        // Epoch 101 had no seats and the current epoch 104 isn't complete
+        assert_eq!(liveness.epochs, 3);
+        assert_eq!(liveness.blocks, 35);
+        assert!((liveness.expected - 30.0).abs() < 1e-9);
+        let d = LIVENESS_DECAY;
+        let expected = (1.0 + 0.5 * d + d.powi(3)) / (1.0 + d + d.powi(3)) * 100.0;
+        assert!((liveness.score - expected).abs() < 1e-9);
+
+        // A window covering only the last completed epoch
+        let recent = get_liveness_score(&conn, "0xsidechain1", 104, 1, 100)
+            .unwrap()
+            .unwrap();
+        assert_eq!(recent.score, 100.0);
+        assert!(get_liveness_score(&conn, "0xunknown", 104, 10, 100)
+            .unwrap()
+            .is_none());
     }
 
     #[test]
@@ -0,0 +1,171 @@
+//! Chain identity of the database
+//!
+//! Block numbers and epochs only mean something on the chain they came from,
+//! so `mvm sync` records the node's chain name and genesis hash in
+//! `schema_meta` on first sync and refuses to continue when a later node
+//! reports a different genesis (e.g. a preview database pointed at mainnet).
+//! The slot timing calibrated from block timestamps is kept alongside it.
+
+use super::schema::{get_meta, set_meta};
+use crate::midnight::Calibration;
+use anyhow::Result;
+use rusqlite::Connection;
+
+/// Chain a database was built from (`system_chain` and block 0's hash)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainIdentity {
+    pub name: String,
+    pub genesis_hash: String,
+}
+
+/// How a live node's chain compares to the one stored in the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainCheck {
+    /// Nothing stored yet (new database, or one from before chain tracking)
+    Unrecorded,
+    /// Same genesis and name
+    Same,
+    /// Same genesis under a different name (e.g. renamed chain spec)
+    Renamed { previous: String },
+    /// Different genesis: a different network
+    Different { stored: ChainIdentity },
+}
+
+/// Compare the live node's chain against the stored identity
+pub fn check_chain_identity(stored: Option<ChainIdentity>, live: &ChainIdentity) -> ChainCheck {
+    match stored {
+        None => ChainCheck::Unrecorded,
+        Some(stored) if stored.genesis_hash != live.genesis_hash => {
+            ChainCheck::Different { stored }
+        }
+        Some(stored) if stored.name != live.name => ChainCheck::Renamed {
+            previous: stored.name,
+        },
+        Some(_) => ChainCheck::Same,
+    }
+}
+
+/// The chain identity stored in the database, if any
+pub fn get_chain_identity(conn: &Connection) -> Result<Option<ChainIdentity>> {
+    let name = get_meta(conn, "chain_name")?;
+    let genesis_hash = get_meta(conn, "genesis_hash")?;
+    Ok(match (name, genesis_hash) {
+        (Some(name), Some(genesis_hash)) => Some(ChainIdentity { name, genesis_hash }),
+        _ => None,
+    })
+}
+
+/// Record (or replace) the chain identity
+pub fn set_chain_identity(conn: &Connection, identity: &ChainIdentity) -> Result<()> {
+    set_meta(conn, "chain_name", &identity.name)?;
+    set_meta(conn, "genesis_hash", &identity.genesis_hash)?;
+    Ok(())
+}
+
+/// The last slot timing calibration, if one was stored
+pub fn get_timing_calibration(conn: &Connection) -> Result<Option<Calibration>> {
+    let number = |key: &str| -> Result<Option<f64>> {
+        Ok(get_meta(conn, key)?.and_then(|v| v.parse().ok()))
+    };
+    Ok(
+        match (
+            number("calibration_slot_ms")?,
+            number("calibration_anchor_slot")?,
+            number("calibration_anchor_ms")?,
+            number("calibration_samples")?,
+        ) {
+            (Some(slot_duration_ms), Some(anchor_slot), Some(anchor_ms), Some(samples)) => {
+                Some(Calibration {
+                    slot_duration_ms,
+                    anchor_slot: anchor_slot as u64,
+                    anchor_ms: anchor_ms as u64,
+                    samples: samples as usize,
+                })
+            }
+            _ => None,
+        },
+    )
+}
+
+/// Record (or replace) the slot timing calibration
+pub fn set_timing_calibration(conn: &Connection, calibration: &Calibration) -> Result<()> {
+    set_meta(
+        conn,
+        "calibration_slot_ms",
+        &calibration.slot_duration_ms.to_string(),
+    )?;
+    set_meta(
+        conn,
+        "calibration_anchor_slot",
+        &calibration.anchor_slot.to_string(),
+    )?;
+    set_meta(
+        conn,
+        "calibration_anchor_ms",
+        &calibration.anchor_ms.to_string(),
+    )?;
+    set_meta(
+        conn,
+        "calibration_samples",
+        &calibration.samples.to_string(),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::init_schema;
+
+    fn identity(name: &str, genesis_hash: &str) -> ChainIdentity {
+        ChainIdentity {
+            name: name.to_string(),
+            genesis_hash: genesis_hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_chain_identity() {
+        let conn = &Connection::open_in_memory().unwrap();
+        init_schema(conn).unwrap();
+
+        let preview = identity("Midnight Preview", "0xaaa");
+        assert_eq!(get_chain_identity(conn).unwrap(), None);
+        assert_eq!(check_chain_identity(None, &preview), ChainCheck::Unrecorded);
+
+        set_chain_identity(conn, &preview).unwrap();
+        let stored = get_chain_identity(conn).unwrap();
+        assert_eq!(stored.as_ref(), Some(&preview));
+        assert_eq!(
+            check_chain_identity(stored.clone(), &preview),
+            ChainCheck::Same
+        );
+        assert_eq!(
+            check_chain_identity(stored.clone(), &identity("Midnight Testnet", "0xaaa")),
+            ChainCheck::Renamed {
+                previous: "Midnight Preview".to_string()
+            }
+        );
+        // The genesis hash decides, whatever the name
+        assert_eq!(
+            check_chain_identity(stored, &identity("Midnight Preview", "0xbbb")),
+            ChainCheck::Different { stored: preview }
+        );
+    }
+
+    #[test]
+    fn test_timing_calibration_round_trip() {
+        let conn = &Connection::open_in_memory().unwrap();
+        init_schema(conn).unwrap();
+
+        assert_eq!(get_timing_calibration(conn).unwrap(), None);
+        let calibration = Calibration {
+            slot_duration_ms: 6_000.25,
+            anchor_slot: 294_000_123,
+            anchor_ms: 1_764_000_738_000,
+            samples: 8,
+        };
+        set_timing_calibration(conn, &calibration).unwrap();
+        assert_eq!(get_timing_calibration(conn).unwrap(), Some(calibration));
+    }
+}
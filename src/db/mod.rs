@@ -1,17 +1,63 @@
+mod audit;
 mod blocks;
+mod chain;
+mod network;
 mod schema;
+mod sync_samples;
 mod validators;
 
+pub use audit::{audit_epoch, AuditIssue, EpochAuditInput};
 pub use blocks::{
-    BlockRecord, CommitteeSelectionStats, SyncStatusRecord, ValidatorEpochHistoryRecord,
-    ValidatorEpochRecord,
+    BlockRecord, CommitteeChurn, CommitteeSelectionStats, DynamicValidatorRank, EpochAnomaly,
+    EpochAuthorShare, EpochRank, LivenessScore, SlotCollision, StakeChange, SyncStatusRecord,
+    ValidatorEpochHistoryRecord, ValidatorEpochRecord,
 };
+pub use chain::{check_chain_identity, ChainCheck, ChainIdentity};
+pub use network::NetworkSample;
 pub use schema::{init_schema, CURRENT_SCHEMA_VERSION};
+pub use sync_samples::SyncSample;
 pub use validators::*;
 
+use crate::midnight::Calibration;
 use anyhow::{Context, Result};
-use rusqlite::Connection;
-use std::path::Path;
+use rusqlite::{Connection, ErrorCode};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How long a connection waits for another process's lock before giving up
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether an error was caused by another connection holding the SQLite lock
+pub fn is_busy_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<rusqlite::Error>(),
+            Some(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(e.code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+        )
+    })
+}
+
+/// Explain lock errors in plain terms; other errors pass through unchanged
+pub fn explain_busy(err: anyhow::Error) -> anyhow::Error {
+    if is_busy_error(&err) {
+        err.context(
+            "database is busy — is a sync daemon running? Retry shortly, or stop `mvm sync` for long operations",
+        )
+    } else {
+        err
+    }
+}
+
+/// Backup file path for `db_path` stamped with the current UTC time: `<db>.bak-<timestamp>`
+pub fn timestamped_backup_path(db_path: &Path) -> PathBuf {
+    let mut path = db_path.as_os_str().to_owned();
+    path.push(format!(
+        ".bak-{}",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    PathBuf::from(path)
+}
 
 /// Database wrapper for MVM
 pub struct Database {
@@ -20,7 +66,14 @@ pub struct Database {
 
 impl Database {
     /// Open or create database at the specified path
+    ///
+    /// Waits up to `BUSY_TIMEOUT` for locks held by another process (e.g. the
+    /// sync daemon) and reports a lock that outlasts it as a friendly error.
     pub fn open(path: &Path) -> Result<Self> {
+        Self::open_inner(path).map_err(explain_busy)
+    }
+
+    fn open_inner(path: &Path) -> Result<Self> {
         // Create parent directory if it doesn't exist
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)
@@ -30,6 +83,13 @@ impl Database {
         let conn = Connection::open(path)
             .with_context(|| format!("Failed to open database at {}", path.display()))?;
 
+        // Wait for a concurrent writer instead of failing immediately with SQLITE_BUSY
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+
+        // Refuse non-SQLite files and databases that belong to something else
+        schema::check_database_format(&conn)
+            .with_context(|| format!("Cannot use database at {}", path.display()))?;
+
         // Initialize schema (creates tables if they don't exist)
         init_schema(&conn)?;
 
@@ -65,6 +125,41 @@ impl Database {
         schema::get_meta(&self.conn, key)
     }
 
+    /// Chain name and genesis hash recorded by the first sync
+    pub fn get_chain_identity(&self) -> Result<Option<ChainIdentity>> {
+        chain::get_chain_identity(&self.conn)
+    }
+
+    pub fn set_chain_identity(&self, identity: &ChainIdentity) -> Result<()> {
+        chain::set_chain_identity(&self.conn, identity)
+    }
+
+    /// Slot timing last calibrated by `mvm sync`
+    pub fn get_timing_calibration(&self) -> Result<Option<Calibration>> {
+        chain::get_timing_calibration(&self.conn)
+    }
+
+    pub fn set_timing_calibration(&self, calibration: &Calibration) -> Result<()> {
+        chain::set_timing_calibration(&self.conn, calibration)
+    }
+
+    /// Copy the database to `path` with SQLite's online backup API
+    ///
+    /// Safe while another process (e.g. the sync daemon) is writing: the copy
+    /// includes WAL contents and is a consistent snapshot. Refuses to
+    /// overwrite an existing file.
+    pub fn backup_to(&self, path: &Path) -> Result<()> {
+        if path.exists() {
+            anyhow::bail!("Backup target {} already exists", path.display());
+        }
+        let mut dst = Connection::open(path)
+            .with_context(|| format!("Failed to create backup at {}", path.display()))?;
+        rusqlite::backup::Backup::new(&self.conn, &mut dst)?
+            .run_to_completion(256, Duration::from_millis(50), None)
+            .with_context(|| format!("Failed to back up database to {}", path.display()))?;
+        Ok(())
+    }
+
     /// Get a reference to the underlying connection
     #[allow(dead_code)]
     pub fn conn(&self) -> &Connection {
@@ -84,6 +179,10 @@ impl Database {
         blocks::get_max_block_number(&self.conn)
     }
 
+    pub fn get_min_block_number(&self) -> Result<Option<u64>> {
+        blocks::get_min_block_number(&self.conn)
+    }
+
     pub fn mark_finalized(&self, up_to_block: u64) -> Result<usize> {
         blocks::mark_finalized(&self.conn, up_to_block)
     }
@@ -96,6 +195,10 @@ impl Database {
         blocks::count_finalized_blocks(&self.conn)
     }
 
+    pub fn count_attributed_blocks(&self) -> Result<u64> {
+        blocks::count_attributed_blocks(&self.conn)
+    }
+
     pub fn get_blocks_in_range(
         &self,
         from: u64,
@@ -105,10 +208,91 @@ impl Database {
         blocks::get_blocks_in_range(&self.conn, from, to, limit)
     }
 
+    pub fn get_first_block_in_sidechain_epoch(
+        &self,
+        sidechain_epoch: u64,
+    ) -> Result<Option<BlockRecord>> {
+        blocks::get_first_block_in_sidechain_epoch(&self.conn, sidechain_epoch)
+    }
+
+    pub fn get_blocks_in_time_range(
+        &self,
+        from_ts: i64,
+        to_ts: i64,
+        limit: Option<u32>,
+    ) -> Result<Vec<BlockRecord>> {
+        blocks::get_blocks_in_time_range(&self.conn, from_ts, to_ts, limit)
+    }
+
+    pub fn get_unattributed_blocks(&self, from: u64, to: u64) -> Result<Vec<BlockRecord>> {
+        blocks::get_unattributed_blocks(&self.conn, from, to)
+    }
+
+    pub fn set_block_author(&self, block_number: u64, author_key: &str) -> Result<bool> {
+        blocks::set_block_author(&self.conn, block_number, author_key)
+    }
+
     pub fn find_gaps(&self) -> Result<Vec<(u64, u64)>> {
         blocks::find_gaps(&self.conn)
     }
 
+    pub fn find_slot_collisions(&self, block: &BlockRecord) -> Result<Vec<SlotCollision>> {
+        blocks::find_slot_collisions(&self.conn, block)
+    }
+
+    pub fn record_slot_collision(&self, collision: &SlotCollision) -> Result<bool> {
+        blocks::record_slot_collision(&self.conn, collision)
+    }
+
+    pub fn count_slot_collisions(&self) -> Result<u64> {
+        blocks::count_slot_collisions(&self.conn)
+    }
+
+    pub fn find_epoch_regression(
+        &self,
+        block_number: u64,
+        epoch: u64,
+    ) -> Result<Option<(u64, u64)>> {
+        blocks::find_epoch_regression(&self.conn, block_number, epoch)
+    }
+
+    pub fn record_epoch_anomaly(&self, anomaly: &EpochAnomaly) -> Result<bool> {
+        blocks::record_epoch_anomaly(&self.conn, anomaly)
+    }
+
+    pub fn get_epoch_anomalies(&self) -> Result<Vec<EpochAnomaly>> {
+        blocks::get_epoch_anomalies(&self.conn)
+    }
+
+    pub fn insert_network_sample(&self, sample: &NetworkSample) -> Result<()> {
+        network::insert_network_sample(&self.conn, sample)
+    }
+
+    pub fn prune_network_samples(&self, before: i64) -> Result<usize> {
+        network::prune_network_samples(&self.conn, before)
+    }
+
+    pub fn get_network_samples(&self, since: i64) -> Result<Vec<NetworkSample>> {
+        network::get_network_samples(&self.conn, since)
+    }
+
+    // Sync throughput history
+    pub fn insert_sync_sample(&self, sample: &SyncSample) -> Result<()> {
+        sync_samples::insert_sync_sample(&self.conn, sample)
+    }
+
+    pub fn prune_sync_samples(&self, before: i64) -> Result<usize> {
+        sync_samples::prune_sync_samples(&self.conn, before)
+    }
+
+    pub fn get_sync_samples(&self, since: i64) -> Result<Vec<SyncSample>> {
+        sync_samples::get_sync_samples(&self.conn, since)
+    }
+
+    pub fn get_recent_slot_collisions(&self, limit: u32) -> Result<Vec<SlotCollision>> {
+        blocks::get_recent_slot_collisions(&self.conn, limit)
+    }
+
     #[allow(dead_code)]
     pub fn count_blocks_by_author_in_epoch(&self, author_key: &str, epoch: u64) -> Result<u64> {
         blocks::count_blocks_by_author_in_epoch(&self.conn, author_key, epoch)
@@ -128,12 +312,14 @@ impl Database {
         author_keys: &[String],
         bucket_duration_secs: i64,
         num_buckets: usize,
+        now: i64,
     ) -> Result<Vec<u64>> {
         blocks::get_block_counts_bucketed(
             &self.conn,
             author_keys,
             bucket_duration_secs,
             num_buckets,
+            now,
         )
     }
 
@@ -147,6 +333,10 @@ impl Database {
         blocks::get_committee_snapshot(&self.conn, epoch)
     }
 
+    pub fn get_committee_churn(&self, epoch: u64) -> Result<Option<CommitteeChurn>> {
+        blocks::get_committee_churn(&self.conn, epoch)
+    }
+
     #[allow(dead_code)]
     pub fn get_committee_size(&self, epoch: u64) -> Result<Option<usize>> {
         blocks::get_committee_size(&self.conn, epoch)
@@ -178,7 +368,30 @@ impl Database {
         blocks::get_validators_for_epoch(&self.conn, sidechain_epoch)
     }
 
-    #[allow(dead_code)]
+    pub fn get_epoch_author_distribution(
+        &self,
+        sidechain_epoch: u64,
+    ) -> Result<Vec<EpochAuthorShare>> {
+        blocks::get_epoch_author_distribution(&self.conn, sidechain_epoch)
+    }
+
+    pub fn get_rank_in_epoch(
+        &self,
+        author_key: &str,
+        sidechain_epoch: u64,
+    ) -> Result<Option<EpochRank>> {
+        blocks::get_rank_in_epoch(&self.conn, author_key, sidechain_epoch)
+    }
+
+    pub fn get_rank_history(
+        &self,
+        author_key: &str,
+        current_epoch: u64,
+        limit: usize,
+    ) -> Result<Vec<EpochRank>> {
+        blocks::get_rank_history(&self.conn, author_key, current_epoch, limit)
+    }
+
     pub fn get_latest_validator_epochs(&self) -> Result<Vec<ValidatorEpochRecord>> {
         blocks::get_latest_validator_epochs(&self.conn)
     }
@@ -187,6 +400,14 @@ impl Database {
         blocks::has_validator_epoch_snapshot(&self.conn, sidechain_epoch)
     }
 
+    pub fn delete_validator_epoch_snapshot(&self, sidechain_epoch: u64) -> Result<usize> {
+        blocks::delete_validator_epoch_snapshot(&self.conn, sidechain_epoch)
+    }
+
+    pub fn count_snapshot_epochs(&self) -> Result<u64> {
+        blocks::count_snapshot_epochs(&self.conn)
+    }
+
     pub fn get_total_seats_for_epochs(
         &self,
         sidechain_keys: &[String],
@@ -196,6 +417,20 @@ impl Database {
         blocks::get_total_seats_for_epochs(&self.conn, sidechain_keys, current_epoch, num_epochs)
     }
 
+    pub fn get_blocks_by_author_in_window(
+        &self,
+        sidechain_keys: &[String],
+        current_epoch: u64,
+        num_epochs: usize,
+    ) -> Result<(u64, u64)> {
+        blocks::get_blocks_by_author_in_window(
+            &self.conn,
+            sidechain_keys,
+            current_epoch,
+            num_epochs,
+        )
+    }
+
     pub fn get_block_counts_by_epoch(
         &self,
         author_keys: &[String],
@@ -205,6 +440,22 @@ impl Database {
         blocks::get_block_counts_by_epoch(&self.conn, author_keys, current_epoch, num_epochs)
     }
 
+    pub fn get_epoch_audit_input(
+        &self,
+        epoch: u64,
+        slots_per_epoch: u64,
+    ) -> Result<EpochAuditInput> {
+        audit::get_epoch_audit_input(&self.conn, epoch, slots_per_epoch)
+    }
+
+    pub fn get_total_block_counts_by_epoch(
+        &self,
+        current_epoch: u64,
+        num_epochs: usize,
+    ) -> Result<Vec<u64>> {
+        blocks::get_total_block_counts_by_epoch(&self.conn, current_epoch, num_epochs)
+    }
+
     pub fn get_seats_by_epoch(
         &self,
         sidechain_keys: &[String],
@@ -222,6 +473,30 @@ impl Database {
         blocks::get_validator_epoch_history(&self.conn, sidechain_key, limit)
     }
 
+    pub fn get_liveness_score(
+        &self,
+        sidechain_key: &str,
+        current_epoch: u64,
+        window: usize,
+        blocks_per_epoch: u64,
+    ) -> Result<Option<LivenessScore>> {
+        blocks::get_liveness_score(
+            &self.conn,
+            sidechain_key,
+            current_epoch,
+            window,
+            blocks_per_epoch,
+        )
+    }
+
+    pub fn get_stake_history(
+        &self,
+        sidechain_key: &str,
+        limit: usize,
+    ) -> Result<Vec<blocks::StakeHistoryRecord>> {
+        blocks::get_stake_history(&self.conn, sidechain_key, limit)
+    }
+
     pub fn get_committee_selection_stats(
         &self,
         sidechain_key: &str,
@@ -230,6 +505,13 @@ impl Database {
         blocks::get_committee_selection_stats(&self.conn, sidechain_key, current_epoch)
     }
 
+    pub fn get_dynamic_leaderboard(
+        &self,
+        sidechain_epoch: u64,
+    ) -> Result<Vec<DynamicValidatorRank>> {
+        blocks::get_dynamic_leaderboard(&self.conn, sidechain_epoch)
+    }
+
     // Sync status operations
     pub fn get_sync_status(&self) -> Result<SyncStatusRecord> {
         blocks::get_sync_status(&self.conn)
@@ -267,6 +549,10 @@ impl Database {
         validators::increment_block_count(&self.conn, sidechain_key)
     }
 
+    pub fn recompute_block_totals(&self) -> Result<Vec<BlockCountCorrection>> {
+        validators::recompute_block_totals(&self.conn)
+    }
+
     pub fn count_validators(&self) -> Result<u64> {
         validators::count_validators(&self.conn)
     }
@@ -309,4 +595,68 @@ mod tests {
         let status = db.get_sync_status().unwrap();
         assert_eq!(status.last_synced_block, 100);
     }
+
+    #[test]
+    fn test_backup_to() {
+        let dir = std::env::temp_dir().join(format!("mvm-backup-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("mvm.db");
+
+        let db = Database::open(&db_path).unwrap();
+        db.update_sync_status(42, 40, 43, 1, true).unwrap();
+
+        let backup_path = timestamped_backup_path(&db_path);
+        assert!(backup_path
+            .to_string_lossy()
+            .starts_with(&*db_path.to_string_lossy()));
+        assert!(backup_path.to_string_lossy().contains(".bak-"));
+
+        db.backup_to(&backup_path).unwrap();
+        let restored = Database::open(&backup_path).unwrap();
+        assert_eq!(restored.get_sync_status().unwrap().last_synced_block, 42);
+
+        // Never overwrite an earlier backup
+        assert!(db.backup_to(&backup_path).is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_concurrent_connections() {
+        let path = std::env::temp_dir().join(format!("mvm-lock-test-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let writer = Database::open(&path).unwrap();
+        let reader = Database::open(&path).unwrap();
+        reader.conn.busy_timeout(Duration::from_millis(50)).unwrap();
+
+        // Writer holds the write lock, as the sync daemon does mid-batch
+        writer.conn.execute_batch("BEGIN IMMEDIATE").unwrap();
+        writer.update_sync_status(10, 9, 11, 1, true).unwrap();
+
+        // WAL lets the second connection keep reading
+        assert_eq!(reader.count_blocks().unwrap(), 0);
+
+        // A competing write times out with a friendly message
+        let err = reader
+            .update_sync_status(20, 19, 21, 1, true)
+            .map_err(explain_busy)
+            .unwrap_err();
+        assert!(is_busy_error(&err));
+        assert!(err.to_string().contains("is a sync daemon running?"));
+
+        // Once the writer commits, the second connection can write
+        writer.conn.execute_batch("COMMIT").unwrap();
+        reader.update_sync_status(20, 19, 21, 1, true).unwrap();
+        assert_eq!(writer.get_sync_status().unwrap().last_synced_block, 20);
+
+        // Unrelated errors are left alone
+        assert!(!is_busy_error(&anyhow::anyhow!("something else")));
+
+        drop(writer);
+        drop(reader);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+        }
+    }
 }
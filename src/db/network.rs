@@ -0,0 +1,108 @@
+//! Network history samples for capacity planning
+//!
+//! `mvm sync` records peer counts and bandwidth rates at a fixed cadence
+//! (`sync.network_sample_secs`) and prunes samples older than
+//! `sync.network_history_days`, so operators can see peaks and typical
+//! values over days with `mvm query network-history`.
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+/// One network sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkSample {
+    /// Unix seconds
+    pub timestamp: i64,
+    /// Connected peers (system_health)
+    pub peers: u64,
+    /// Open inbound / outbound connections (None without Prometheus metrics)
+    pub peers_inbound: Option<u64>,
+    pub peers_outbound: Option<u64>,
+    /// Bandwidth rates in bytes/s since the previous sample (None for the first)
+    pub bytes_in_per_sec: Option<f64>,
+    pub bytes_out_per_sec: Option<f64>,
+}
+
+/// Store a sample (a second sample in the same second replaces the first)
+pub fn insert_network_sample(conn: &Connection, sample: &NetworkSample) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO network_samples
+         (timestamp, peers, peers_inbound, peers_outbound, bytes_in_per_sec, bytes_out_per_sec)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            sample.timestamp,
+            sample.peers,
+            sample.peers_inbound,
+            sample.peers_outbound,
+            sample.bytes_in_per_sec,
+            sample.bytes_out_per_sec,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Delete samples older than `before` (Unix seconds), returning how many were removed
+pub fn prune_network_samples(conn: &Connection, before: i64) -> Result<usize> {
+    Ok(conn.execute(
+        "DELETE FROM network_samples WHERE timestamp < ?1",
+        params![before],
+    )?)
+}
+
+/// Samples at or after `since` (Unix seconds), oldest first
+pub fn get_network_samples(conn: &Connection, since: i64) -> Result<Vec<NetworkSample>> {
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, peers, peers_inbound, peers_outbound, bytes_in_per_sec, bytes_out_per_sec
+         FROM network_samples WHERE timestamp >= ?1 ORDER BY timestamp",
+    )?;
+    let samples = stmt
+        .query_map(params![since], |row| {
+            Ok(NetworkSample {
+                timestamp: row.get(0)?,
+                peers: row.get(1)?,
+                peers_inbound: row.get(2)?,
+                peers_outbound: row.get(3)?,
+                bytes_in_per_sec: row.get(4)?,
+                bytes_out_per_sec: row.get(5)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::init_schema;
+
+    fn sample(timestamp: i64, peers: u64) -> NetworkSample {
+        NetworkSample {
+            timestamp,
+            peers,
+            peers_inbound: Some(peers / 2),
+            peers_outbound: Some(peers - peers / 2),
+            bytes_in_per_sec: Some(1500.0),
+            bytes_out_per_sec: None,
+        }
+    }
+
+    #[test]
+    fn test_network_samples() {
+        let conn = &Connection::open_in_memory().unwrap();
+        init_schema(conn).unwrap();
+
+        for (ts, peers) in [(1_000, 10), (1_060, 12), (1_120, 9)] {
+            insert_network_sample(conn, &sample(ts, peers)).unwrap();
+        }
+        // Same second: replaced, not duplicated
+        insert_network_sample(conn, &sample(1_120, 11)).unwrap();
+
+        let samples = get_network_samples(conn, 1_060).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0], sample(1_060, 12));
+        assert_eq!(samples[1].peers, 11);
+
+        assert_eq!(prune_network_samples(conn, 1_100).unwrap(), 2);
+        assert_eq!(get_network_samples(conn, 0).unwrap().len(), 1);
+    }
+}
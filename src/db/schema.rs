@@ -1,9 +1,9 @@
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use rusqlite::Connection;
 use tracing::info;
 
 /// Current schema version - increment when making schema changes
-pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+pub const CURRENT_SCHEMA_VERSION: u32 = 6;
 
 /// SQL schema for MVM database
 pub const SCHEMA: &str = r#"
@@ -96,7 +96,9 @@ CREATE TABLE IF NOT EXISTS sync_status (
     chain_tip_block INTEGER NOT NULL DEFAULT 0,
     current_epoch INTEGER NOT NULL DEFAULT 0,
     is_syncing INTEGER DEFAULT 1,
-    last_updated INTEGER NOT NULL
+    last_updated INTEGER NOT NULL,
+    -- Highest block number already swept by mark_finalized
+    last_marked_finalized INTEGER NOT NULL DEFAULT 0
 );
 
 -- Initialize singleton
@@ -104,12 +106,148 @@ INSERT OR IGNORE INTO sync_status (id, last_synced_block, last_finalized_block,
 VALUES (1, 0, 0, 0, 0, 0);
 "#;
 
+/// Slot collision events (schema v3), kept separate so the migration can create it too
+const SLOT_COLLISIONS_SCHEMA: &str = r#"
+-- Two blocks seen for the same slot (possible equivocation)
+CREATE TABLE IF NOT EXISTS slot_collisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slot_number INTEGER NOT NULL,
+    block_number INTEGER NOT NULL,
+    block_hash TEXT NOT NULL,
+    other_block_number INTEGER NOT NULL,
+    other_block_hash TEXT NOT NULL,
+    author_key TEXT,
+    detected_at INTEGER NOT NULL,
+    UNIQUE(slot_number, block_hash, other_block_hash)
+);
+
+CREATE INDEX IF NOT EXISTS idx_slot_collisions_slot ON slot_collisions(slot_number);
+"#;
+
+const NETWORK_SAMPLES_SCHEMA: &str = r#"
+-- Peer count and bandwidth samples recorded by mvm sync (bounded retention)
+CREATE TABLE IF NOT EXISTS network_samples (
+    timestamp INTEGER PRIMARY KEY,
+    peers INTEGER NOT NULL,
+    peers_inbound INTEGER,
+    peers_outbound INTEGER,
+    bytes_in_per_sec REAL,
+    bytes_out_per_sec REAL
+);
+"#;
+
+const SYNC_SAMPLES_SCHEMA: &str = r#"
+-- Sync throughput samples recorded by mvm sync (bounded retention)
+CREATE TABLE IF NOT EXISTS sync_samples (
+    timestamp INTEGER PRIMARY KEY,
+    blocks INTEGER NOT NULL,
+    blocks_per_sec REAL NOT NULL,
+    ms_per_block REAL,
+    lag_blocks INTEGER NOT NULL,
+    catching_up INTEGER NOT NULL DEFAULT 0,
+    db_size_bytes INTEGER
+);
+"#;
+
+const EPOCH_ANOMALIES_SCHEMA: &str = r#"
+-- Blocks whose reported sidechain epoch went backwards relative to a neighbour
+CREATE TABLE IF NOT EXISTS epoch_anomalies (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    block_number INTEGER NOT NULL,
+    block_hash TEXT NOT NULL UNIQUE,
+    slot_number INTEGER NOT NULL,
+    reported_epoch INTEGER NOT NULL,
+    neighbor_block_number INTEGER NOT NULL,
+    neighbor_epoch INTEGER NOT NULL,
+    detected_at INTEGER NOT NULL
+);
+"#;
+
 /// Initialize database schema
 pub fn init_schema(conn: &Connection) -> Result<()> {
     conn.execute_batch(SCHEMA)?;
+    conn.execute_batch(SLOT_COLLISIONS_SCHEMA)?;
+    conn.execute_batch(NETWORK_SAMPLES_SCHEMA)?;
+    conn.execute_batch(SYNC_SAMPLES_SCHEMA)?;
+    conn.execute_batch(EPOCH_ANOMALIES_SCHEMA)?;
+    Ok(())
+}
+
+/// Verify the file is a SQLite database this application can own
+///
+/// Rejects files that are not SQLite at all, and SQLite databases that
+/// belong to something else (user tables present, but none of ours), so
+/// a misconfigured path never gets mvm tables grafted onto it.
+pub fn check_database_format(conn: &Connection) -> Result<()> {
+    // Forces the header to be read; fails with "file is not a database" otherwise
+    conn.query_row("PRAGMA schema_version", [], |row| row.get::<_, i64>(0))
+        .context("File is not a valid SQLite database")?;
+
+    let tables = list_tables(conn)?;
+    if tables.is_empty() {
+        return Ok(());
+    }
+
+    let ours = ["schema_meta", "blocks", "validators", "sync_status"];
+    if !tables.iter().any(|t| ours.contains(&t.as_str())) {
+        bail!(
+            "Database does not look like an mvm database (found tables: {}). \
+             Check database.path in your configuration.",
+            tables.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// List user tables in the database
+fn list_tables(conn: &Connection) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    let tables = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(tables)
+}
+
+/// Check whether a table has a given column
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(columns.iter().any(|c| c == column))
+}
+
+/// Add a column unless it already exists (keeps migrations idempotent)
+pub fn add_column_if_missing(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<()> {
+    if !has_column(conn, table, column)? {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )
+        .with_context(|| format!("Failed to add column {}.{}", table, column))?;
+        info!("Added column {}.{}", table, column);
+    }
     Ok(())
 }
 
+/// Check whether the database holds any synced data
+fn has_existing_data(conn: &Connection) -> Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT (SELECT COUNT(*) FROM blocks) + (SELECT COUNT(*) FROM validators)",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
 /// Check if schema_meta table exists (for detecting pre-v1.0 databases)
 fn has_schema_meta(conn: &Connection) -> Result<bool> {
     let count: i64 = conn.query_row(
@@ -189,14 +327,13 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
             [],
         )?;
 
-        // Initialize as version 1 (the current schema without migrations)
+        // Initialize as version 1 (the base schema), then migrate forward below
         init_schema_meta(conn, 1, app_version)?;
         info!("Initialized schema metadata for existing database (version 1)");
-        return Ok(());
     }
 
     // Get current database version
-    let db_version = get_schema_version(conn)?;
+    let mut db_version = get_schema_version(conn)?;
 
     // Check if database is from the future (newer than this app)
     if db_version > CURRENT_SCHEMA_VERSION {
@@ -208,15 +345,22 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         );
     }
 
-    // Check if database needs migration
     if db_version == 0 {
-        // New database - initialize metadata
-        init_schema_meta(conn, CURRENT_SCHEMA_VERSION, app_version)?;
-        info!(
-            "Initialized new database with schema version {}",
-            CURRENT_SCHEMA_VERSION
-        );
-        return Ok(());
+        // schema_meta exists (init_schema creates it) but was never stamped.
+        // With data present this is a pre-v1.0 database that must be migrated
+        // from the base schema; otherwise it is brand new.
+        if has_existing_data(conn)? {
+            init_schema_meta(conn, 1, app_version)?;
+            info!("Detected unversioned database with existing data, treating as version 1");
+            db_version = 1;
+        } else {
+            init_schema_meta(conn, CURRENT_SCHEMA_VERSION, app_version)?;
+            info!(
+                "Initialized new database with schema version {}",
+                CURRENT_SCHEMA_VERSION
+            );
+            return Ok(());
+        }
     }
 
     if db_version == CURRENT_SCHEMA_VERSION {
@@ -230,9 +374,14 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         db_version, CURRENT_SCHEMA_VERSION
     );
 
+    // Each migration and its version bump commit together, so a failure
+    // leaves the database at the last fully-applied version
     for version in (db_version + 1)..=CURRENT_SCHEMA_VERSION {
-        run_migration(conn, version)?;
-        update_migration_meta(conn, version, app_version)?;
+        let tx = conn.unchecked_transaction()?;
+        run_migration(&tx, version)
+            .with_context(|| format!("Migration to schema version {} failed", version))?;
+        update_migration_meta(&tx, version, app_version)?;
+        tx.commit()?;
         info!("Completed migration to version {}", version);
     }
 
@@ -241,16 +390,41 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
 
 /// Run a specific migration
 /// Add new migrations here as match arms when schema changes
-fn run_migration(_conn: &Connection, to_version: u32) -> Result<()> {
+fn run_migration(conn: &Connection, to_version: u32) -> Result<()> {
     match to_version {
         // Version 1 is the base schema - no migration needed
         1 => Ok(()),
 
+        // Finalization watermark so mark_finalized only sweeps new blocks
+        2 => add_column_if_missing(
+            conn,
+            "sync_status",
+            "last_marked_finalized",
+            "INTEGER NOT NULL DEFAULT 0",
+        ),
+
+        // Slot collision (equivocation) events
+        3 => conn
+            .execute_batch(SLOT_COLLISIONS_SCHEMA)
+            .context("Failed to create slot_collisions table"),
+
+        // Network history samples
+        4 => conn
+            .execute_batch(NETWORK_SAMPLES_SCHEMA)
+            .context("Failed to create network_samples table"),
+
+        // Sync throughput samples
+        5 => conn
+            .execute_batch(SYNC_SAMPLES_SCHEMA)
+            .context("Failed to create sync_samples table"),
+
+        // Epoch regressions caught during sync
+        6 => conn
+            .execute_batch(EPOCH_ANOMALIES_SCHEMA)
+            .context("Failed to create epoch_anomalies table"),
+
         // Future migrations go here:
-        // 2 => {
-        //     _conn.execute("ALTER TABLE blocks ADD COLUMN new_field TEXT", [])?;
-        //     Ok(())
-        // }
+        // 7 => add_column_if_missing(conn, "blocks", "new_field", "TEXT"),
         _ => bail!("Unknown migration version: {}", to_version),
     }
 }
@@ -279,6 +453,9 @@ mod tests {
         assert!(tables.contains(&"validator_epochs".to_string()));
         assert!(tables.contains(&"sync_status".to_string()));
         assert!(tables.contains(&"schema_meta".to_string()));
+        assert!(tables.contains(&"slot_collisions".to_string()));
+        assert!(tables.contains(&"sync_samples".to_string()));
+        assert!(tables.contains(&"epoch_anomalies".to_string()));
     }
 
     #[test]
@@ -314,8 +491,55 @@ mod tests {
         // Run migrations - should detect missing schema_meta and initialize it
         run_migrations(&conn).unwrap();
 
-        // Verify schema_meta was created and version set to 1
+        // Verify schema_meta was created and the database migrated forward from 1
         assert!(has_schema_meta(&conn).unwrap());
-        assert_eq!(get_schema_version(&conn).unwrap(), 1);
+        assert_eq!(get_schema_version(&conn).unwrap(), CURRENT_SCHEMA_VERSION);
+        assert!(has_column(&conn, "sync_status", "last_marked_finalized").unwrap());
+        assert!(list_tables(&conn)
+            .unwrap()
+            .contains(&"slot_collisions".to_string()));
+    }
+
+    #[test]
+    fn test_unstamped_database_with_data() {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO validators (sidechain_key, created_at, updated_at) VALUES ('0xabc', 0, 0)",
+            [],
+        )
+        .unwrap();
+
+        run_migrations(&conn).unwrap();
+        assert_eq!(get_schema_version(&conn).unwrap(), CURRENT_SCHEMA_VERSION);
+        assert!(get_meta(&conn, "last_migration").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_database_format_check() {
+        // Empty and mvm databases are accepted
+        let conn = Connection::open_in_memory().unwrap();
+        check_database_format(&conn).unwrap();
+        init_schema(&conn).unwrap();
+        check_database_format(&conn).unwrap();
+
+        // Someone else's database is rejected
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE invoices (id INTEGER PRIMARY KEY)", [])
+            .unwrap();
+        let err = check_database_format(&conn).unwrap_err().to_string();
+        assert!(err.contains("invoices"));
+    }
+
+    #[test]
+    fn test_add_column_if_missing() {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        add_column_if_missing(&conn, "blocks", "extra", "TEXT").unwrap();
+        assert!(has_column(&conn, "blocks", "extra").unwrap());
+
+        // Second run is a no-op rather than a "duplicate column" error
+        add_column_if_missing(&conn, "blocks", "extra", "TEXT").unwrap();
     }
 }
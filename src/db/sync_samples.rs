@@ -0,0 +1,116 @@
+//! Sync throughput samples for performance trends
+//!
+//! `mvm sync` records how fast it stores blocks and how far it trails the
+//! chain at a fixed cadence (`sync.sync_sample_secs`), pruning samples older
+//! than `sync.sync_history_days`, so operators can see whether sync slows
+//! down over time with `mvm query sync-history`.
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+/// One sync throughput sample
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncSample {
+    /// Unix seconds
+    pub timestamp: i64,
+    /// Blocks stored since the previous sample
+    pub blocks: u64,
+    /// Blocks stored per second of wall-clock time since the previous sample
+    pub blocks_per_sec: f64,
+    /// Average time spent syncing each of those blocks (None when none were synced)
+    pub ms_per_block: Option<f64>,
+    /// Blocks between the chain tip and the last stored block
+    pub lag_blocks: u64,
+    /// Taken during the initial catch-up rather than while following the tip
+    pub catching_up: bool,
+    /// Database file size including the WAL (None if unreadable)
+    pub db_size_bytes: Option<u64>,
+}
+
+/// Store a sample (a second sample in the same second replaces the first)
+pub fn insert_sync_sample(conn: &Connection, sample: &SyncSample) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_samples
+         (timestamp, blocks, blocks_per_sec, ms_per_block, lag_blocks, catching_up, db_size_bytes)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            sample.timestamp,
+            sample.blocks,
+            sample.blocks_per_sec,
+            sample.ms_per_block,
+            sample.lag_blocks,
+            sample.catching_up,
+            sample.db_size_bytes,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Delete samples older than `before` (Unix seconds), returning how many were removed
+pub fn prune_sync_samples(conn: &Connection, before: i64) -> Result<usize> {
+    Ok(conn.execute(
+        "DELETE FROM sync_samples WHERE timestamp < ?1",
+        params![before],
+    )?)
+}
+
+/// Samples at or after `since` (Unix seconds), oldest first
+pub fn get_sync_samples(conn: &Connection, since: i64) -> Result<Vec<SyncSample>> {
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, blocks, blocks_per_sec, ms_per_block, lag_blocks, catching_up, db_size_bytes
+         FROM sync_samples WHERE timestamp >= ?1 ORDER BY timestamp",
+    )?;
+    let samples = stmt
+        .query_map(params![since], |row| {
+            Ok(SyncSample {
+                timestamp: row.get(0)?,
+                blocks: row.get(1)?,
+                blocks_per_sec: row.get(2)?,
+                ms_per_block: row.get(3)?,
+                lag_blocks: row.get(4)?,
+                catching_up: row.get(5)?,
+                db_size_bytes: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::schema::init_schema;
+
+    fn sample(timestamp: i64, blocks: u64) -> SyncSample {
+        SyncSample {
+            timestamp,
+            blocks,
+            blocks_per_sec: blocks as f64 / 300.0,
+            ms_per_block: (blocks > 0).then_some(12.5),
+            lag_blocks: 1,
+            catching_up: false,
+            db_size_bytes: Some(4_096),
+        }
+    }
+
+    #[test]
+    fn test_sync_samples() {
+        let conn = &Connection::open_in_memory().unwrap();
+        init_schema(conn).unwrap();
+
+        for (ts, blocks) in [(1_000, 50), (1_300, 0), (1_600, 48)] {
+            insert_sync_sample(conn, &sample(ts, blocks)).unwrap();
+        }
+        // Same second: replaced, not duplicated
+        insert_sync_sample(conn, &sample(1_600, 52)).unwrap();
+
+        let samples = get_sync_samples(conn, 1_300).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0], sample(1_300, 0));
+        assert_eq!(samples[0].ms_per_block, None);
+        assert_eq!(samples[1].blocks, 52);
+
+        assert_eq!(prune_sync_samples(conn, 1_500).unwrap(), 2);
+        assert_eq!(get_sync_samples(conn, 0).unwrap().len(), 1);
+    }
+}
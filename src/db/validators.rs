@@ -1,8 +1,9 @@
 use anyhow::Result;
 use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
 
 /// Validator record for database storage
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorRecord {
     pub sidechain_key: String,
     pub aura_key: Option<String>,
@@ -144,6 +145,55 @@ pub fn increment_block_count(conn: &Connection, sidechain_key: &str) -> Result<(
     Ok(())
 }
 
+/// A validator whose stored `total_blocks` disagreed with the blocks table
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockCountCorrection {
+    pub sidechain_key: String,
+    /// Count previously stored on the validator row
+    pub stored: u64,
+    /// Authored blocks actually present in the blocks table
+    pub actual: u64,
+}
+
+/// Recount authored blocks per validator from the blocks table
+///
+/// `total_blocks` is incremented during sync and can drift after an interrupted
+/// sync or re-attribution. Every row that disagrees with the recount is rewritten
+/// in a single transaction; the corrected rows are returned.
+pub fn recompute_block_totals(conn: &Connection) -> Result<Vec<BlockCountCorrection>> {
+    let tx = conn.unchecked_transaction()?;
+
+    let corrections = {
+        let mut stmt = tx.prepare(
+            "SELECT v.sidechain_key, v.total_blocks, COUNT(b.block_number)
+             FROM validators v
+             LEFT JOIN blocks b ON b.author_key = v.sidechain_key
+             GROUP BY v.sidechain_key
+             HAVING v.total_blocks != COUNT(b.block_number)
+             ORDER BY v.sidechain_key",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(BlockCountCorrection {
+                sidechain_key: row.get(0)?,
+                stored: row.get::<_, i64>(1)? as u64,
+                actual: row.get::<_, i64>(2)? as u64,
+            })
+        })?;
+        rows.collect::<std::result::Result<Vec<_>, _>>()?
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    for c in &corrections {
+        tx.execute(
+            "UPDATE validators SET total_blocks = ?2, updated_at = ?3 WHERE sidechain_key = ?1",
+            params![c.sidechain_key, c.actual as i64, now],
+        )?;
+    }
+    tx.commit()?;
+
+    Ok(corrections)
+}
+
 /// Count total validators
 pub fn count_validators(conn: &Connection) -> Result<u64> {
     let count: i64 = conn.query_row("SELECT COUNT(*) FROM validators", [], |row| row.get(0))?;
@@ -159,3 +209,88 @@ pub fn count_our_validators(conn: &Connection) -> Result<u64> {
     )?;
     Ok(count as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::blocks::{insert_block, BlockRecord};
+    use crate::db::schema::init_schema;
+
+    fn validator(key: &str, total_blocks: u64) -> ValidatorRecord {
+        ValidatorRecord {
+            sidechain_key: key.to_string(),
+            aura_key: None,
+            grandpa_key: None,
+            label: None,
+            is_ours: false,
+            registration_status: None,
+            first_seen_epoch: None,
+            total_blocks,
+        }
+    }
+
+    fn block(number: u64, author: Option<&str>) -> BlockRecord {
+        BlockRecord {
+            block_number: number,
+            block_hash: format!("0x{:x}", number),
+            parent_hash: "0xparent".to_string(),
+            state_root: "0xstate".to_string(),
+            extrinsics_root: "0xext".to_string(),
+            slot_number: number,
+            epoch: 1,
+            sidechain_epoch: 1,
+            timestamp: 0,
+            is_finalized: true,
+            author_key: author.map(|a| a.to_string()),
+            extrinsics_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_recompute_block_totals() {
+        let conn = Connection::open_in_memory().unwrap();
+        init_schema(&conn).unwrap();
+
+        // 0xaaa drifted high, 0xbbb is correct, 0xccc missed increments
+        upsert_validator(&conn, &validator("0xaaa", 5)).unwrap();
+        upsert_validator(&conn, &validator("0xbbb", 1)).unwrap();
+        upsert_validator(&conn, &validator("0xccc", 0)).unwrap();
+        for (n, author) in [
+            (1, Some("0xaaa")),
+            (2, Some("0xbbb")),
+            (3, Some("0xccc")),
+            (4, Some("0xccc")),
+            (5, None),
+        ] {
+            insert_block(&conn, &block(n, author)).unwrap();
+        }
+
+        let corrections = recompute_block_totals(&conn).unwrap();
+        assert_eq!(
+            corrections,
+            vec![
+                BlockCountCorrection {
+                    sidechain_key: "0xaaa".to_string(),
+                    stored: 5,
+                    actual: 1,
+                },
+                BlockCountCorrection {
+                    sidechain_key: "0xccc".to_string(),
+                    stored: 0,
+                    actual: 2,
+                },
+            ]
+        );
+        assert_eq!(
+            get_validator(&conn, "0xaaa").unwrap().unwrap().total_blocks,
+            1
+        );
+        assert_eq!(
+            get_validator(&conn, "0xccc").unwrap().unwrap().total_blocks,
+            2
+        );
+
+        // Second run has nothing left to fix
+        assert!(recompute_block_totals(&conn).unwrap().is_empty());
+    }
+}
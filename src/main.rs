@@ -6,6 +6,7 @@ mod db;
 mod metrics;
 mod midnight;
 mod rpc;
+mod server;
 mod tui;
 
 use anyhow::Result;
@@ -24,6 +25,14 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Disable colored output (also honored via the NO_COLOR env var)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Chain timing profile (overrides [chain] profile/network; explicit timing fields still apply)
+    #[arg(long, global = true, value_parser = ["preview", "preprod", "mainnet"])]
+    profile: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -39,6 +48,15 @@ enum Commands {
     /// Query stored block data
     Query(commands::QueryArgs),
 
+    /// Database maintenance (recompute totals, verify, reattribute authors, recapture epochs)
+    Db(commands::DbArgs),
+
+    /// Attribute authors for blocks synced without one, using an archive node
+    BackfillAuthors(commands::BackfillAuthorsArgs),
+
+    /// Export stored blocks as CSV or newline-delimited JSON
+    Export(commands::ExportArgs),
+
     /// Verify and manage session keys
     Keys(commands::KeysArgs),
 
@@ -54,6 +72,9 @@ enum Commands {
     /// Troubleshooting guides and documentation
     Guide(commands::GuideArgs),
 
+    /// Generate config artifacts for external tools (Prometheus alert rules)
+    Generate(commands::GenerateArgs),
+
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for
@@ -65,12 +86,24 @@ enum Commands {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    let color = color_enabled(cli.no_color);
+    if let Some(ref profile) = cli.profile {
+        config::set_cli_profile(profile);
+    }
 
-    // Skip console logging for TUI and completions (completions must output clean shell script)
-    let skip_logging = matches!(
-        cli.command,
-        Some(Commands::View(_)) | Some(Commands::Completions { .. })
-    );
+    // Skip console logging for TUI and completions (completions must output clean shell script).
+    // JSON query output and one-line status are skipped too so they can be consumed by other tools,
+    // and `status --watch` so log lines don't scroll its redrawn screen.
+    let skip_logging = match &cli.command {
+        Some(Commands::View(args)) => args.output_dir.is_none(),
+        Some(Commands::Completions { .. }) => true,
+        Some(Commands::Query(args)) => args.is_machine_output(),
+        Some(Commands::Config(args)) => args.is_machine_output(),
+        Some(Commands::Generate(args)) => args.is_machine_output(),
+        Some(Commands::Export(args)) => args.is_machine_output(),
+        Some(Commands::Status(args)) => args.oneline || args.watch,
+        _ => false,
+    };
 
     // Initialize logging (skip for TUI and completions)
     if !skip_logging {
@@ -79,7 +112,10 @@ async fn main() -> Result<()> {
         } else {
             Level::INFO
         };
-        let subscriber = FmtSubscriber::builder().with_max_level(log_level).finish();
+        let subscriber = FmtSubscriber::builder()
+            .with_max_level(log_level)
+            .with_ansi(color)
+            .finish();
         tracing::subscriber::set_global_default(subscriber)?;
 
         info!(
@@ -92,19 +128,28 @@ async fn main() -> Result<()> {
     // Handle commands - default to status if no command given
     match cli.command {
         Some(Commands::Status(args)) => {
-            commands::status::run(args).await?;
+            commands::status::run(args, color).await?;
         }
         Some(Commands::Sync(args)) => {
             commands::sync::run(args).await?;
         }
         Some(Commands::Query(args)) => {
-            commands::query::run(args).await?;
+            commands::query::run(args, color).await?;
+        }
+        Some(Commands::Db(args)) => {
+            commands::db::run(args).await?;
+        }
+        Some(Commands::BackfillAuthors(args)) => {
+            commands::db::run_backfill_authors(args).await?;
+        }
+        Some(Commands::Export(args)) => {
+            commands::export::run(args).await?;
         }
         Some(Commands::Keys(args)) => {
             commands::keys::run(args).await?;
         }
         Some(Commands::View(args)) => {
-            commands::view::run(args).await?;
+            commands::view::run(args, color).await?;
         }
         Some(Commands::Config(args)) => {
             commands::config::run(args).await?;
@@ -115,6 +160,9 @@ async fn main() -> Result<()> {
         Some(Commands::Guide(args)) => {
             commands::guide::run(args).await?;
         }
+        Some(Commands::Generate(args)) => {
+            commands::generate::run(args).await?;
+        }
         Some(Commands::Completions { shell }) => {
             let mut cmd = Cli::command();
             generate(shell, &mut cmd, "mvm", &mut std::io::stdout());
@@ -130,11 +178,20 @@ async fn main() -> Result<()> {
                 keystore: None,
                 interval: None,
                 once: false,
+                watch: false,
+                check: false,
                 explain: false,
+                oneline: false,
+                width: 80,
             };
-            commands::status::run(args).await?;
+            commands::status::run(args, color).await?;
         }
     }
 
     Ok(())
 }
+
+/// Whether output may be styled: off with `--no-color` or a non-empty NO_COLOR (https://no-color.org)
+fn color_enabled(no_color_flag: bool) -> bool {
+    !no_color_flag && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
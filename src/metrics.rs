@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use reqwest::Client;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Type alias for parsed Prometheus metrics: metric_name -> Vec<(labels, value)>
 type ParsedMetrics = HashMap<String, Vec<(HashMap<String, String>, f64)>>;
@@ -271,6 +272,71 @@ fn parse_metrics(body: &str) -> BlockProductionMetrics {
     metrics
 }
 
+/// Consecutive failures before a scrape endpoint is backed off
+pub const BREAKER_MAX_FAILURES: u32 = 3;
+
+/// How often a backed-off scrape endpoint is retried
+pub const BREAKER_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Circuit breaker for an optional scrape endpoint (metrics, node_exporter)
+///
+/// After `BREAKER_MAX_FAILURES` failures in a row the endpoint is tried only
+/// once per `BREAKER_RETRY_INTERVAL` instead of on every refresh, so a dead
+/// endpoint doesn't add its timeout to each update. One success closes it.
+#[derive(Debug, Default, Clone)]
+pub struct ScrapeBreaker {
+    failures: u32,
+    /// Last attempt while open (None when closed)
+    last_open_attempt: Option<Instant>,
+}
+
+/// Breaker state change worth reporting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerTransition {
+    /// Backed off after repeated failures
+    Opened { failures: u32 },
+    /// Endpoint answered again
+    Closed,
+}
+
+impl ScrapeBreaker {
+    /// Whether to scrape now (always when closed, once per retry interval when open)
+    pub fn should_attempt(&self, now: Instant) -> bool {
+        self.last_open_attempt
+            .is_none_or(|last| now.duration_since(last) >= BREAKER_RETRY_INTERVAL)
+    }
+
+    /// Whether the endpoint is currently backed off
+    pub fn is_open(&self) -> bool {
+        self.last_open_attempt.is_some()
+    }
+
+    /// Record a scrape result, returning the transition if the state changed
+    pub fn record(&mut self, ok: bool, now: Instant) -> Option<BreakerTransition> {
+        if ok {
+            self.failures = 0;
+            return self
+                .last_open_attempt
+                .take()
+                .map(|_| BreakerTransition::Closed);
+        }
+
+        self.failures += 1;
+        if self.last_open_attempt.is_some() {
+            // Still down: wait another interval before the next attempt
+            self.last_open_attempt = Some(now);
+            None
+        } else if self.failures >= BREAKER_MAX_FAILURES {
+            self.last_open_attempt = Some(now);
+            Some(BreakerTransition::Opened {
+                failures: self.failures,
+            })
+        } else {
+            None
+        }
+    }
+}
+
 /// Simple Prometheus text format parser
 /// Returns a map of metric_name -> Vec<(labels, value)>
 fn parse_prometheus_text(body: &str) -> ParsedMetrics {
@@ -404,4 +470,36 @@ substrate_sub_libp2p_pending_connections{chain="testnet-02"} 5
         assert_eq!(metrics.peers_discovered, 109);
         assert_eq!(metrics.pending_connections, 5);
     }
+
+    #[test]
+    fn test_scrape_breaker() {
+        let mut breaker = ScrapeBreaker::default();
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        // Scraped every refresh until the failure limit
+        assert_eq!(breaker.record(false, at(0)), None);
+        assert!(breaker.should_attempt(at(2)));
+        assert_eq!(breaker.record(false, at(2)), None);
+        assert_eq!(
+            breaker.record(false, at(4)),
+            Some(BreakerTransition::Opened { failures: 3 })
+        );
+        assert!(breaker.is_open());
+
+        // Open: retried once per interval, further failures stay quiet
+        assert!(!breaker.should_attempt(at(30)));
+        assert!(breaker.should_attempt(at(64)));
+        assert_eq!(breaker.record(false, at(64)), None);
+        assert!(!breaker.should_attempt(at(100)));
+
+        // One success closes it and resets the count
+        assert_eq!(
+            breaker.record(true, at(124)),
+            Some(BreakerTransition::Closed)
+        );
+        assert!(!breaker.is_open() && breaker.should_attempt(at(125)));
+        assert_eq!(breaker.record(false, at(126)), None);
+        assert!(!breaker.is_open());
+    }
 }
@@ -1,22 +1,95 @@
+//! Block header digest decoding
+//!
+//! Digest logs are SCALE-encoded `DigestItem`s, hex strings as returned by
+//! `chain_getBlock`. Layout: one variant byte, then for the engine variants a
+//! 4-byte engine ID and compact-length-prefixed data:
+//!
+//! | Byte | Variant | Payload |
+//! |------|---------|---------|
+//! | 0x00 | Other | data |
+//! | 0x04 | Consensus | engine + data (e.g. AURA authority changes) |
+//! | 0x05 | Seal | engine + data (block signature, always last) |
+//! | 0x06 | PreRuntime | engine + data (AURA slot, Midnight's `mcsh`) |
+//! | 0x08 | RuntimeEnvironmentUpdated | none |
+//!
+//! Only the AURA PreRuntime item carries the slot; the others are decoded so
+//! unexpected shapes can be told apart from a missing slot.
+
+use super::scale::decode_compact;
+
+/// AURA consensus engine ID
+pub const AURA_ENGINE_ID: [u8; 4] = *b"aura";
+
+/// A decoded header digest item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DigestItem {
+    PreRuntime { engine: [u8; 4], data: Vec<u8> },
+    Consensus { engine: [u8; 4], data: Vec<u8> },
+    Seal { engine: [u8; 4], data: Vec<u8> },
+    Other(Vec<u8>),
+    RuntimeEnvironmentUpdated,
+}
+
+impl DigestItem {
+    /// Decode one hex digest log (with or without 0x prefix, any case)
+    ///
+    /// Returns None for malformed hex, unknown variants, or truncated data.
+    pub fn decode(log: &str) -> Option<Self> {
+        let bytes = hex::decode(log.trim().trim_start_matches("0x")).ok()?;
+        let (&variant, rest) = bytes.split_first()?;
+
+        // Engine variants: 4-byte engine ID followed by length-prefixed data
+        let engine_item = |rest: &[u8]| -> Option<([u8; 4], Vec<u8>)> {
+            let engine: [u8; 4] = rest.get(..4)?.try_into().ok()?;
+            let data = decode_vec(&rest[4..])?;
+            Some((engine, data))
+        };
+
+        match variant {
+            0 => decode_vec(rest).map(DigestItem::Other),
+            4 => engine_item(rest).map(|(engine, data)| DigestItem::Consensus { engine, data }),
+            5 => engine_item(rest).map(|(engine, data)| DigestItem::Seal { engine, data }),
+            6 => engine_item(rest).map(|(engine, data)| DigestItem::PreRuntime { engine, data }),
+            8 => Some(DigestItem::RuntimeEnvironmentUpdated),
+            _ => None,
+        }
+    }
+
+    /// The AURA slot, if this is an AURA pre-runtime item
+    pub fn aura_slot(&self) -> Option<u64> {
+        match self {
+            DigestItem::PreRuntime { engine, data } if *engine == AURA_ENGINE_ID => {
+                let arr: [u8; 8] = data.as_slice().try_into().ok()?;
+                Some(u64::from_le_bytes(arr))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decode a compact-length-prefixed byte vector, requiring the exact length
+fn decode_vec(bytes: &[u8]) -> Option<Vec<u8>> {
+    let (len, offset) = decode_compact(bytes).ok()?;
+    let data = bytes.get(offset..)?;
+    (data.len() == len).then(|| data.to_vec())
+}
+
 /// Extract AURA slot number from block digest logs
 ///
 /// AURA pre-runtime digest format:
 /// - First byte: 0x06 (PreRuntime)
 /// - Bytes 1-4: "aura" in hex (61757261)
-/// - Bytes 5-12: slot number as little-endian u64
+/// - Byte 5: compact length (0x20 = 8 bytes)
+/// - Bytes 6-13: slot number as little-endian u64
 ///
 /// Example: "0x066175726120778c911100000000"
+///
+/// Other items (Midnight's `mcsh` pre-runtime, consensus changes, the seal)
+/// are skipped. Returns None when no log is a well-formed AURA pre-runtime item.
 pub fn extract_slot_from_digest(logs: &[String]) -> Option<u64> {
-    for log in logs {
-        // PreRuntime AURA format: 0x06 + "aura"(61757261) + slot_le_bytes
-        if log.starts_with("0x0661757261") && log.len() >= 30 {
-            let slot_hex = &log[14..30]; // 8 bytes = 16 hex chars
-            let bytes = hex::decode(slot_hex).ok()?;
-            let arr: [u8; 8] = bytes.try_into().ok()?;
-            return Some(u64::from_le_bytes(arr));
-        }
-    }
-    None
+    logs.iter()
+        .filter_map(|log| DigestItem::decode(log))
+        .find_map(|item| item.aura_slot())
 }
 
 /// Extract timestamp from block extrinsics
@@ -70,5 +143,58 @@ mod tests {
         // Too short to contain full slot
         let logs = vec!["0x0661757261".to_string()];
         assert_eq!(extract_slot_from_digest(&logs), None);
+
+        // Length prefix says 8 bytes but only 7 follow
+        let logs = vec!["0x066175726120778c9111000000".to_string()];
+        assert_eq!(extract_slot_from_digest(&logs), None);
+    }
+
+    /// Fixtures for each digest variant a Midnight header can carry
+    #[test]
+    fn test_digest_item_variants() {
+        // PreRuntime aura: slot 294751351
+        let item = DigestItem::decode("0x066175726120778c911100000000").unwrap();
+        assert_eq!(item.aura_slot(), Some(294751351));
+
+        // PreRuntime mcsh (mainchain block hash, 32 bytes): no slot
+        let mcsh = format!("0x066d63736880{}", "ab".repeat(32));
+        let item = DigestItem::decode(&mcsh).unwrap();
+        assert!(matches!(item, DigestItem::PreRuntime { engine, .. } if &engine == b"mcsh"));
+        assert_eq!(item.aura_slot(), None);
+
+        // Consensus aura (authority change): no slot even with 8 bytes of data
+        let item = DigestItem::decode("0x046175726120778c911100000000").unwrap();
+        assert!(matches!(item, DigestItem::Consensus { .. }));
+        assert_eq!(item.aura_slot(), None);
+
+        // Seal aura: 64-byte signature behind a two-byte compact length (0x0101)
+        let seal = format!("0x05617572610101{}", "cd".repeat(64));
+        let item = DigestItem::decode(&seal).unwrap();
+        assert!(matches!(item, DigestItem::Seal { ref data, .. } if data.len() == 64));
+
+        // Other and RuntimeEnvironmentUpdated
+        assert_eq!(
+            DigestItem::decode("0x000c010203"),
+            Some(DigestItem::Other(vec![1, 2, 3]))
+        );
+        assert_eq!(
+            DigestItem::decode("0x08"),
+            Some(DigestItem::RuntimeEnvironmentUpdated)
+        );
+
+        // Unknown variant and bad hex
+        assert_eq!(DigestItem::decode("0x07"), None);
+        assert_eq!(DigestItem::decode("0xzz"), None);
+    }
+
+    #[test]
+    fn test_extract_slot_full_header() {
+        // Slot is found regardless of item order, prefix, or hex case
+        let logs = vec![
+            format!("0x066d63736880{}", "ab".repeat(32)),
+            "066175726120778C911100000000".to_string(),
+            format!("0x05617572610101{}", "cd".repeat(64)),
+        ];
+        assert_eq!(extract_slot_from_digest(&logs), Some(294751351));
     }
 }
@@ -0,0 +1,592 @@
+//! Extrinsic call names from runtime metadata
+//!
+//! Blocks only carry extrinsics as opaque SCALE bytes. To show what a block
+//! contained, the runtime metadata (`state_getMetadata`, V14 or V15) is parsed
+//! just far enough to map pallet and call indices to names, and to skip the
+//! address, signature and signed extensions in front of the call of a signed
+//! extrinsic. Only type shapes are kept; docs and storage layouts are read past.
+
+use super::scale::decode_compact;
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+
+/// Shape of a registry type, enough to skip over an encoded value
+#[derive(Debug, Clone)]
+enum TypeDef {
+    Composite(Vec<u32>),
+    Variant(Vec<Variant>),
+    Sequence(u32),
+    Array(u32, u32),
+    Tuple(Vec<u32>),
+    Primitive(u8),
+    Compact,
+    BitSequence(u32),
+}
+
+#[derive(Debug, Clone)]
+struct Variant {
+    name: String,
+    index: u8,
+    fields: Vec<u32>,
+}
+
+/// Registry type: its shape and named type parameters
+#[derive(Debug, Clone)]
+struct Type {
+    params: Vec<(String, Option<u32>)>,
+    def: TypeDef,
+}
+
+/// Pallet and call names of one runtime, for labelling extrinsics
+#[derive(Debug, Clone)]
+pub struct RuntimeCalls {
+    types: HashMap<u32, Type>,
+    /// Pallet index to (name, call type id)
+    pallets: HashMap<u8, (String, Option<u32>)>,
+    /// Types between the version byte and the call of a signed extrinsic
+    /// (address, signature, then each signed extension)
+    signed_prefix: Vec<u32>,
+}
+
+/// Cursor over SCALE bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .context("Unexpected end of SCALE data")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn compact(&mut self) -> Result<usize> {
+        let (value, used) = decode_compact(&self.bytes[self.pos.min(self.bytes.len())..])?;
+        self.pos += used;
+        Ok(value)
+    }
+
+    fn type_id(&mut self) -> Result<u32> {
+        Ok(self.compact()? as u32)
+    }
+
+    /// Skip a compact integer of any size (big-integer mode included)
+    fn skip_compact(&mut self) -> Result<()> {
+        let first = self.u8()?;
+        let extra = match first & 0b11 {
+            0b00 => 0,
+            0b01 => 1,
+            0b10 => 3,
+            _ => (first >> 2) as usize + 4,
+        };
+        self.take(extra)?;
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.compact()?;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn skip_bytes(&mut self) -> Result<()> {
+        let len = self.compact()?;
+        self.take(len)?;
+        Ok(())
+    }
+
+    fn skip_strings(&mut self) -> Result<()> {
+        for _ in 0..self.compact()? {
+            self.skip_bytes()?;
+        }
+        Ok(())
+    }
+
+    fn option<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<Option<T>> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => read(self).map(Some),
+            other => bail!("Invalid Option tag {}", other),
+        }
+    }
+}
+
+/// Encoded size of each scale-info primitive (None: str, length-prefixed)
+fn primitive_size(primitive: u8) -> Option<usize> {
+    match primitive {
+        0 | 3 | 9 => Some(1),
+        4 | 10 => Some(2),
+        1 | 5 | 11 => Some(4),
+        6 | 12 => Some(8),
+        7 | 13 => Some(16),
+        8 | 14 => Some(32),
+        _ => None,
+    }
+}
+
+fn read_fields(r: &mut Reader) -> Result<Vec<u32>> {
+    let mut fields = Vec::new();
+    for _ in 0..r.compact()? {
+        r.option(Reader::skip_bytes)?;
+        fields.push(r.type_id()?);
+        r.option(Reader::skip_bytes)?;
+        r.skip_strings()?;
+    }
+    Ok(fields)
+}
+
+fn read_type_def(r: &mut Reader) -> Result<TypeDef> {
+    Ok(match r.u8()? {
+        0 => TypeDef::Composite(read_fields(r)?),
+        1 => {
+            let mut variants = Vec::new();
+            for _ in 0..r.compact()? {
+                let name = r.string()?;
+                let fields = read_fields(r)?;
+                let index = r.u8()?;
+                r.skip_strings()?;
+                variants.push(Variant {
+                    name,
+                    index,
+                    fields,
+                });
+            }
+            TypeDef::Variant(variants)
+        }
+        2 => TypeDef::Sequence(r.type_id()?),
+        3 => {
+            let len = r.u32()?;
+            TypeDef::Array(len, r.type_id()?)
+        }
+        4 => {
+            let mut items = Vec::new();
+            for _ in 0..r.compact()? {
+                items.push(r.type_id()?);
+            }
+            TypeDef::Tuple(items)
+        }
+        5 => TypeDef::Primitive(r.u8()?),
+        6 => {
+            r.type_id()?;
+            TypeDef::Compact
+        }
+        7 => {
+            let store = r.type_id()?;
+            r.type_id()?;
+            TypeDef::BitSequence(store)
+        }
+        other => bail!("Unknown type definition tag {}", other),
+    })
+}
+
+/// Read past one pallet's storage entries
+fn skip_storage(r: &mut Reader) -> Result<()> {
+    r.skip_bytes()?; // prefix
+    for _ in 0..r.compact()? {
+        r.skip_bytes()?; // name
+        r.u8()?; // modifier
+        match r.u8()? {
+            0 => {
+                r.type_id()?;
+            }
+            1 => {
+                r.skip_bytes()?; // hashers
+                r.type_id()?;
+                r.type_id()?;
+            }
+            other => bail!("Unknown storage entry type {}", other),
+        }
+        r.skip_bytes()?; // default
+        r.skip_strings()?;
+    }
+    Ok(())
+}
+
+/// Signed extension types, in encoding order
+fn read_signed_extensions(r: &mut Reader) -> Result<Vec<u32>> {
+    let mut types = Vec::new();
+    for _ in 0..r.compact()? {
+        r.skip_bytes()?; // identifier
+        types.push(r.type_id()?);
+        r.type_id()?; // additional signed
+    }
+    Ok(types)
+}
+
+/// Parse hex-encoded runtime metadata (`state_getMetadata`)
+pub fn parse_metadata(hex_metadata: &str) -> Result<RuntimeCalls> {
+    let bytes = hex::decode(hex_metadata.trim_start_matches("0x"))
+        .context("Failed to decode metadata hex")?;
+    let mut r = Reader::new(&bytes);
+
+    if r.take(4)? != b"meta" {
+        bail!("Not runtime metadata (missing magic number)");
+    }
+    let version = r.u8()?;
+    if !(14..=15).contains(&version) {
+        bail!(
+            "Unsupported metadata version V{} (V14 and V15 are)",
+            version
+        );
+    }
+
+    let mut types = HashMap::new();
+    for _ in 0..r.compact()? {
+        let id = r.type_id()?;
+        r.skip_strings()?; // path
+        let mut params = Vec::new();
+        for _ in 0..r.compact()? {
+            let name = r.string()?;
+            params.push((name, r.option(Reader::type_id)?));
+        }
+        let def = read_type_def(&mut r)?;
+        r.skip_strings()?;
+        types.insert(id, Type { params, def });
+    }
+
+    let mut pallets = HashMap::new();
+    for _ in 0..r.compact()? {
+        let name = r.string()?;
+        r.option(skip_storage)?;
+        let calls = r.option(Reader::type_id)?;
+        r.option(Reader::type_id)?; // events
+        for _ in 0..r.compact()? {
+            r.skip_bytes()?; // name
+            r.type_id()?;
+            r.skip_bytes()?; // value
+            r.skip_strings()?;
+        }
+        r.option(Reader::type_id)?; // errors
+        let index = r.u8()?;
+        if version >= 15 {
+            r.skip_strings()?;
+        }
+        pallets.insert(index, (name, calls));
+    }
+
+    let signed_prefix = if version == 14 {
+        // Address and signature are type parameters of the extrinsic type
+        let extrinsic_ty = r.type_id()?;
+        r.u8()?; // extrinsic version
+        let extensions = read_signed_extensions(&mut r)?;
+        let param = |name: &str| {
+            types
+                .get(&extrinsic_ty)
+                .and_then(|t: &Type| t.params.iter().find(|(n, _)| n == name))
+                .and_then(|(_, ty)| *ty)
+                .with_context(|| format!("Extrinsic type has no {} parameter", name))
+        };
+        let mut prefix = vec![param("Address")?, param("Signature")?];
+        prefix.extend(extensions);
+        prefix
+    } else {
+        r.u8()?; // extrinsic version
+        let address = r.type_id()?;
+        r.type_id()?; // call
+        let signature = r.type_id()?;
+        r.type_id()?; // extra
+        let mut prefix = vec![address, signature];
+        prefix.extend(read_signed_extensions(&mut r)?);
+        prefix
+    };
+
+    Ok(RuntimeCalls {
+        types,
+        pallets,
+        signed_prefix,
+    })
+}
+
+impl RuntimeCalls {
+    /// Skip over one encoded value of type `ty`
+    fn skip(&self, r: &mut Reader, ty: u32) -> Result<()> {
+        let def = &self
+            .types
+            .get(&ty)
+            .with_context(|| format!("Unknown type id {}", ty))?
+            .def;
+        match def {
+            TypeDef::Composite(fields) | TypeDef::Tuple(fields) => {
+                for field in fields {
+                    self.skip(r, *field)?;
+                }
+            }
+            TypeDef::Variant(variants) => {
+                let index = r.u8()?;
+                let variant = variants
+                    .iter()
+                    .find(|v| v.index == index)
+                    .with_context(|| format!("Type {} has no variant {}", ty, index))?;
+                for field in &variant.fields {
+                    self.skip(r, *field)?;
+                }
+            }
+            TypeDef::Sequence(item) => {
+                for _ in 0..r.compact()? {
+                    self.skip(r, *item)?;
+                }
+            }
+            TypeDef::Array(len, item) => {
+                for _ in 0..*len {
+                    self.skip(r, *item)?;
+                }
+            }
+            TypeDef::Primitive(primitive) => match primitive_size(*primitive) {
+                Some(size) => {
+                    r.take(size)?;
+                }
+                None => r.skip_bytes()?,
+            },
+            TypeDef::Compact => r.skip_compact()?,
+            TypeDef::BitSequence(store) => {
+                let bits = r.compact()?;
+                let word = match self.types.get(store).map(|t| &t.def) {
+                    Some(TypeDef::Primitive(p)) => primitive_size(*p).unwrap_or(1),
+                    _ => 1,
+                };
+                r.take(bits.div_ceil(8 * word) * word)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Name of a pallet's call, e.g. `("balances", "transfer_keep_alive")`
+    fn names(&self, pallet: u8, call: u8) -> (String, String) {
+        let entry = self.pallets.get(&pallet);
+        let pallet_name = entry
+            .map(|(name, _)| snake_case(name))
+            .unwrap_or_else(|| format!("#{}", pallet));
+        let call_name = entry
+            .and_then(|(_, calls)| calls.as_ref())
+            .and_then(|ty| match self.types.get(ty).map(|t| &t.def) {
+                Some(TypeDef::Variant(variants)) => variants.iter().find(|v| v.index == call),
+                _ => None,
+            })
+            .map(|v| v.name.clone())
+            .unwrap_or_else(|| format!("#{}", call));
+        (pallet_name, call_name)
+    }
+
+    /// `pallet.call` name of a hex-encoded extrinsic from `chain_getBlock`
+    pub fn call_name(&self, extrinsic_hex: &str) -> Result<String> {
+        let bytes = hex::decode(extrinsic_hex.trim_start_matches("0x"))
+            .context("Failed to decode extrinsic hex")?;
+        let mut r = Reader::new(&bytes);
+        r.compact()?; // length prefix
+        match r.u8()? {
+            // Unsigned (V4) or bare (V5): the call follows directly
+            0x04 | 0x05 => {}
+            // Signed V4: address, signature and signed extensions come first
+            0x84 => {
+                for ty in &self.signed_prefix {
+                    self.skip(&mut r, *ty)?;
+                }
+            }
+            other => bail!("Unsupported extrinsic format 0x{:02x}", other),
+        }
+        let pallet = r.u8()?;
+        let call = r.u8()?;
+        let (pallet_name, call_name) = self.names(pallet, call);
+        Ok(format!("{}.{}", pallet_name, call_name))
+    }
+}
+
+/// `ParachainSystem` -> `parachain_system`
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Count calls by name in first-seen order: "1 timestamp.set, 2 balances.transfer"
+pub fn summarize_calls(names: &[String]) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for name in names {
+        match counts.iter_mut().find(|(n, _)| n == name) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((name, 1)),
+        }
+    }
+    counts
+        .iter()
+        .map(|(name, count)| format!("{} {}", count, name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compact(n: usize) -> Vec<u8> {
+        if n < 64 {
+            vec![(n as u8) << 2]
+        } else {
+            (((n as u16) << 2) | 0b01).to_le_bytes().to_vec()
+        }
+    }
+
+    fn string(s: &str) -> Vec<u8> {
+        [compact(s.len()), s.as_bytes().to_vec()].concat()
+    }
+
+    /// Registry entry: no path, docs or field names
+    fn ty(id: usize, params: &[(&str, usize)], def: Vec<u8>) -> Vec<u8> {
+        let mut out = compact(id);
+        out.extend(compact(0));
+        out.extend(compact(params.len()));
+        for (name, ty) in params {
+            out.extend(string(name));
+            out.push(1);
+            out.extend(compact(*ty));
+        }
+        out.extend(def);
+        out.extend(compact(0));
+        out
+    }
+
+    fn field(ty: usize) -> Vec<u8> {
+        [vec![0], compact(ty), vec![0], compact(0)].concat()
+    }
+
+    fn call_variants(calls: &[(&str, u8, &[usize])]) -> Vec<u8> {
+        let mut out = vec![1];
+        out.extend(compact(calls.len()));
+        for (name, index, fields) in calls {
+            out.extend(string(name));
+            out.extend(compact(fields.len()));
+            for f in *fields {
+                out.extend(field(*f));
+            }
+            out.push(*index);
+            out.extend(compact(0));
+        }
+        out
+    }
+
+    fn pallet(name: &str, index: u8, calls: Option<usize>) -> Vec<u8> {
+        let mut out = string(name);
+        out.push(0); // storage
+        match calls {
+            Some(ty) => out.extend([vec![1], compact(ty)].concat()),
+            None => out.push(0),
+        }
+        out.push(0); // events
+        out.extend(compact(0)); // constants
+        out.push(0); // errors
+        out.push(index);
+        out
+    }
+
+    /// Minimal V14 metadata: Timestamp (3) and Balances (5) calls, a 32-byte
+    /// address and signature, and a compact tip as the only signed extension
+    fn metadata() -> String {
+        let types = [
+            ty(0, &[], vec![5, 3]),                         // u8
+            ty(1, &[], [vec![6], compact(2)].concat()),     // Compact<u64>
+            ty(2, &[], vec![5, 6]),                         // u64
+            ty(3, &[], call_variants(&[("set", 0, &[1])])), // timestamp calls
+            ty(
+                4,
+                &[],
+                call_variants(&[("transfer_keep_alive", 3, &[7, 1])]),
+            ),
+            ty(
+                5,
+                &[],
+                [vec![3], 32u32.to_le_bytes().to_vec(), compact(0)].concat(),
+            ),
+            ty(6, &[("Address", 5), ("Signature", 5)], vec![0, 0]),
+            ty(
+                7,
+                &[],
+                [vec![3], 32u32.to_le_bytes().to_vec(), compact(0)].concat(),
+            ),
+        ];
+        let mut out = b"meta".to_vec();
+        out.push(14);
+        out.extend(compact(types.len()));
+        types.iter().for_each(|t| out.extend(t));
+        out.extend(compact(2));
+        out.extend(pallet("Timestamp", 3, Some(3)));
+        out.extend(pallet("Balances", 5, Some(4)));
+        out.extend(compact(6)); // extrinsic type
+        out.push(4);
+        out.extend(compact(1));
+        out.extend(string("ChargeTransactionPayment"));
+        out.extend(compact(1));
+        out.extend(compact(0));
+        out.extend(compact(0)); // runtime type
+        format!("0x{}", hex::encode(out))
+    }
+
+    fn extrinsic(body: Vec<u8>) -> String {
+        format!("0x{}", hex::encode([compact(body.len()), body].concat()))
+    }
+
+    #[test]
+    fn test_extrinsic_call_names() {
+        let calls = parse_metadata(&metadata()).unwrap();
+
+        let timestamp = extrinsic([vec![0x04, 3, 0], compact(60)].concat());
+        assert_eq!(calls.call_name(&timestamp).unwrap(), "timestamp.set");
+
+        // Signed: address, signature and a big-integer compact tip precede the call
+        let mut signed = vec![0x84];
+        signed.extend([0xaa; 32]);
+        signed.extend([0xbb; 32]);
+        signed.extend([0b0000_0011, 1, 2, 3, 4]);
+        signed.extend([5, 3]);
+        signed.extend([0xcc; 32]);
+        signed.extend(compact(1000));
+        assert_eq!(
+            calls.call_name(&extrinsic(signed)).unwrap(),
+            "balances.transfer_keep_alive"
+        );
+
+        // Unknown indices keep their numbers
+        assert_eq!(
+            calls.call_name(&extrinsic(vec![0x04, 9, 1])).unwrap(),
+            "#9.#1"
+        );
+        assert!(calls.call_name(&extrinsic(vec![0x99, 3, 0])).is_err());
+        assert!(parse_metadata("0x00").is_err());
+
+        assert_eq!(snake_case("ParachainSystem"), "parachain_system");
+        let names: Vec<String> = ["timestamp.set", "balances.transfer", "timestamp.set"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            summarize_calls(&names),
+            "2 timestamp.set, 1 balances.transfer"
+        );
+    }
+}
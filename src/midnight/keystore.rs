@@ -1,5 +1,6 @@
+use super::validators::Validator;
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 /// Key type identifiers (hex-encoded ASCII)
@@ -88,6 +89,54 @@ impl ValidatorKeys {
     pub fn grandpa_short(&self) -> String {
         truncate_key(&self.grandpa_pub_key, 10)
     }
+
+    /// Compare these keys against this validator's on-chain registration
+    ///
+    /// The registration is found by sidechain key, falling back to the AURA key so
+    /// a rotated sidechain key is still caught. Returns an empty list when the keys
+    /// match or no registration is found (registration status covers that case).
+    pub fn registration_mismatches(&self, candidates: &[Validator]) -> Vec<KeyMismatch> {
+        let sidechain = normalize_hex(&self.sidechain_pub_key);
+        let aura = normalize_hex(&self.aura_pub_key);
+        let grandpa = normalize_hex(&self.grandpa_pub_key);
+
+        let Some(registered) = candidates
+            .iter()
+            .find(|v| normalize_hex(&v.sidechain_key) == sidechain)
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|v| normalize_hex(&v.aura_key) == aura)
+            })
+        else {
+            return Vec::new();
+        };
+
+        [
+            ("sidechain", sidechain, &registered.sidechain_key),
+            ("aura", aura, &registered.aura_key),
+            ("grandpa", grandpa, &registered.grandpa_key),
+        ]
+        .into_iter()
+        .filter(|(_, local, on_chain)| *local != normalize_hex(on_chain))
+        .map(|(key_type, local, on_chain)| KeyMismatch {
+            key_type: key_type.to_string(),
+            local,
+            on_chain: normalize_hex(on_chain),
+        })
+        .collect()
+    }
+}
+
+/// A keystore key that differs from the key registered on-chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyMismatch {
+    /// "sidechain", "aura" or "grandpa"
+    pub key_type: String,
+    /// Key found in the local keystore
+    pub local: String,
+    /// Key in the on-chain registration
+    pub on_chain: String,
 }
 
 /// Status of validator keys
@@ -162,6 +211,47 @@ mod tests {
         assert_eq!(normalize_hex("0x1234"), "0x1234");
     }
 
+    #[test]
+    fn test_registration_mismatches() {
+        let keys = ValidatorKeys {
+            sidechain_pub_key: "0xAA01".to_string(),
+            aura_pub_key: "0xbb01".to_string(),
+            grandpa_pub_key: "0xcc01".to_string(),
+        };
+        let registered = |sidechain: &str, aura: &str, grandpa: &str| Validator {
+            sidechain_key: sidechain.to_string(),
+            aura_key: aura.to_string(),
+            grandpa_key: grandpa.to_string(),
+            is_permissioned: false,
+            stake_lovelace: None,
+        };
+
+        // Matching keys (case-insensitive)
+        let candidates = vec![registered("0xaa01", "0xbb01", "0xcc01")];
+        assert!(keys.registration_mismatches(&candidates).is_empty());
+
+        // Rotated AURA key registered but keystore still has the old one
+        let candidates = vec![registered("0xaa01", "0xbb02", "0xcc01")];
+        let mismatches = keys.registration_mismatches(&candidates);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].key_type, "aura");
+        assert_eq!(mismatches[0].local, "0xbb01");
+        assert_eq!(mismatches[0].on_chain, "0xbb02");
+
+        // Sidechain key differs, found via AURA key
+        let candidates = vec![registered("0xaa02", "0xbb01", "0xcc02")];
+        let types: Vec<_> = keys
+            .registration_mismatches(&candidates)
+            .iter()
+            .map(|m| m.key_type.clone())
+            .collect();
+        assert_eq!(types, vec!["sidechain", "grandpa"]);
+
+        // Not registered at all
+        let candidates = vec![registered("0xaa09", "0xbb09", "0xcc09")];
+        assert!(keys.registration_mismatches(&candidates).is_empty());
+    }
+
     #[test]
     fn test_truncate_key() {
         assert_eq!(truncate_key("0x1234567890abcdef", 10), "0x12345678...");
@@ -2,22 +2,37 @@
 //!
 //! This module contains code specific to Midnight's blockchain implementation:
 //! - Block digest parsing (slot extraction)
+//! - Extrinsic call names from runtime metadata
 //! - Substrate keystore loading
 //! - Validator registration checking
+//! - Block production projections
+//! - Reward estimates from block production
+//! - Committee seat projection from stake
+//! - Node state-pruning detection
 //! - Validator set management and block author attribution
 
 pub mod digest;
+pub mod extrinsics;
 pub mod keystore;
 pub mod known_validators;
+pub mod prediction;
+pub mod projection;
+pub mod pruning;
 pub mod registration;
+pub mod rewards;
 pub mod scale;
 pub mod timing;
 pub mod validators;
 
 pub use digest::extract_slot_from_digest;
-pub use keystore::{KeyStatus, ValidatorKeys};
+pub use extrinsics::{parse_metadata, summarize_calls, RuntimeCalls};
+pub use keystore::{KeyMismatch, KeyStatus, ValidatorKeys};
 pub use known_validators::KnownValidators;
+pub use prediction::{format_block_estimate, project_epoch_blocks, EpochProjection};
+pub use projection::{project_seats, SeatProjection};
+pub use pruning::StatePruning;
 pub use registration::{get_key_status, RegistrationStatus};
+pub use rewards::{estimate_rewards, RewardEstimate};
 pub use scale::decode_aura_authorities;
-pub use timing::{ChainTiming, Network};
+pub use timing::{Calibration, ChainTiming, Network};
 pub use validators::ValidatorSet;
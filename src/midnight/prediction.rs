@@ -0,0 +1,100 @@
+//! Block production projections
+//!
+//! Extrapolates the current epoch's block count to an end-of-epoch total
+//! so operators get a forward-looking signal mid-epoch.
+
+/// Minimum epoch progress before projecting (early extrapolation is too noisy)
+pub const MIN_PROJECTION_PROGRESS: f64 = 10.0;
+
+/// End-of-epoch projection for our block production
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpochProjection {
+    /// Projected blocks at end of epoch at the current run rate
+    pub projected: f64,
+    /// Expected blocks for the epoch based on committee seats
+    pub expected: f64,
+}
+
+impl EpochProjection {
+    /// Deviation of projected from expected, in percent (negative = behind)
+    pub fn deviation_percent(&self) -> f64 {
+        (self.projected - self.expected) / self.expected * 100.0
+    }
+}
+
+/// Project end-of-epoch block count from blocks so far and epoch progress (0-100)
+///
+/// `expected` is the full-epoch expectation, not the pro-rated one: the
+/// projection is already scaled to the end of the epoch. Returns None when nothing is expected this epoch or the epoch is too young
+/// for the run rate to be meaningful.
+pub fn project_epoch_blocks(
+    blocks_so_far: u64,
+    progress_percent: f64,
+    expected: f64,
+) -> Option<EpochProjection> {
+    if expected <= 0.0 || progress_percent < MIN_PROJECTION_PROGRESS {
+        return None;
+    }
+
+    let elapsed_fraction = progress_percent.min(100.0) / 100.0;
+    Some(EpochProjection {
+        projected: blocks_so_far as f64 / elapsed_fraction,
+        expected,
+    })
+}
+
+/// Format a block estimate with precision suited to its magnitude
+///
+/// Small validators on large committees may expect well under one block per
+/// epoch, so rounding to whole blocks would misleadingly show "0".
+pub fn format_block_estimate(value: f64) -> String {
+    if value <= 0.0 {
+        "0".to_string()
+    } else if value < 0.1 {
+        format!("{:.2}", value)
+    } else if value < 10.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_epoch_blocks() {
+        // 7 blocks at halfway, 15 expected over the epoch -> 14 projected, ~-7%
+        let p = project_epoch_blocks(7, 50.0, 15.0).unwrap();
+        assert!((p.projected - 14.0).abs() < 1e-9);
+        assert!((p.deviation_percent() + 6.67).abs() < 0.01);
+
+        // Exactly on pace at halfway is on target, not ahead
+        let p = project_epoch_blocks(5, 50.0, 10.0).unwrap();
+        assert!(p.deviation_percent().abs() < 1e-9);
+
+        // Ahead of schedule
+        let p = project_epoch_blocks(10, 25.0, 20.0).unwrap();
+        assert!((p.deviation_percent() - 100.0).abs() < 1e-9);
+
+        // Too early, or nothing expected
+        assert!(project_epoch_blocks(1, 5.0, 15.0).is_none());
+        assert!(project_epoch_blocks(3, 50.0, 0.0).is_none());
+
+        // Progress is clamped at 100%
+        let p = project_epoch_blocks(12, 120.0, 15.0).unwrap();
+        assert!((p.projected - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_format_block_estimate() {
+        // 1 seat of 6000 on mainnet (6000 slots/epoch) = 1 block/epoch
+        assert_eq!(format_block_estimate(1.0), "1.0");
+        // 2 seats of 6000 with 1200 slots = 0.4
+        assert_eq!(format_block_estimate(2.0 / 6000.0 * 1200.0), "0.4");
+        assert_eq!(format_block_estimate(0.035), "0.04");
+        assert_eq!(format_block_estimate(14.6), "15");
+        assert_eq!(format_block_estimate(0.0), "0");
+    }
+}
@@ -0,0 +1,87 @@
+//! Committee seat projection from stake
+//!
+//! A what-if for operators deciding whether to add stake. Dynamic
+//! (non-permissioned) committee seats are filled by stake-weighted random
+//! selection, so with stake `s` in a dynamic pool totalling `T` (including `s`)
+//! each dynamic seat goes to the validator with probability `p = s / T`:
+//!
+//! - `expected_seats = dynamic_seats * p`
+//! - `P(at least one seat) = 1 - (1 - p)^dynamic_seats`
+//!
+//! Selection is random each epoch, so this is an estimate of the average, not
+//! a guarantee for any one epoch.
+
+/// Projected committee seats for a given stake
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatProjection {
+    /// Stake being projected, in lovelace
+    pub stake_lovelace: u64,
+    /// Dynamic pool stake including the projected stake, in lovelace
+    pub pool_stake_lovelace: u64,
+    /// Share of the dynamic pool, in percent
+    pub stake_share_percent: f64,
+    /// Committee seats filled by stake-weighted selection
+    pub dynamic_seats: u64,
+    /// Average seats per epoch at this stake
+    pub expected_seats: f64,
+    /// Chance of at least one seat in an epoch, in percent
+    pub selection_chance_percent: f64,
+    /// Rank by stake among dynamic validators (1 = highest)
+    pub stake_rank: u32,
+    /// Dynamic validators including the projected one
+    pub dynamic_validators: u32,
+}
+
+/// Project committee seats for `stake_lovelace` against the other dynamic stakes
+///
+/// `other_stakes` are the dynamic validators' stakes excluding the one being
+/// projected. Returns None when the epoch had no dynamic seats or no stake.
+pub fn project_seats(
+    stake_lovelace: u64,
+    other_stakes: &[u64],
+    dynamic_seats: u64,
+) -> Option<SeatProjection> {
+    let pool: u64 = other_stakes.iter().sum::<u64>() + stake_lovelace;
+    if dynamic_seats == 0 || pool == 0 {
+        return None;
+    }
+
+    let p = stake_lovelace as f64 / pool as f64;
+    let stake_rank = other_stakes.iter().filter(|s| **s > stake_lovelace).count() as u32 + 1;
+
+    Some(SeatProjection {
+        stake_lovelace,
+        pool_stake_lovelace: pool,
+        stake_share_percent: p * 100.0,
+        dynamic_seats,
+        expected_seats: dynamic_seats as f64 * p,
+        selection_chance_percent: (1.0 - (1.0 - p).powf(dynamic_seats as f64)) * 100.0,
+        stake_rank,
+        dynamic_validators: other_stakes.len() as u32 + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_seats() {
+        // 1M of a 4M pool with 100 dynamic seats
+        let projection = project_seats(1_000_000, &[2_000_000, 500_000, 500_000], 100).unwrap();
+        assert!((projection.stake_share_percent - 25.0).abs() < 1e-9);
+        assert!((projection.expected_seats - 25.0).abs() < 1e-9);
+        assert!(projection.selection_chance_percent > 99.99);
+        assert_eq!(projection.stake_rank, 2);
+        assert_eq!(projection.dynamic_validators, 4);
+
+        // Small stake: under one seat on average, selected in some epochs only
+        let projection = project_seats(1, &[999], 2).unwrap();
+        assert!((projection.expected_seats - 0.002).abs() < 1e-9);
+        assert!((projection.selection_chance_percent - 0.1999).abs() < 1e-6);
+        assert_eq!(projection.stake_rank, 2);
+
+        assert_eq!(project_seats(1_000, &[1_000], 0), None);
+        assert_eq!(project_seats(0, &[], 10), None);
+    }
+}
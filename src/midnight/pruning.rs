@@ -0,0 +1,167 @@
+//! Node state-pruning detection
+//!
+//! Author attribution needs the committee at each block's own state
+//! (`AuraApi_authorities` at the block hash). Archive nodes keep all state;
+//! pruned nodes (the Substrate default) keep only recent blocks, typically
+//! ~256. Detection asks for state at block 1: if present the node is an
+//! archive, otherwise a binary search finds the oldest block that still has
+//! state, to within `SEARCH_RESOLUTION` blocks.
+
+use crate::midnight::ValidatorSet;
+use crate::rpc::RpcClient;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Binary search stops once the pruning boundary is this narrow
+const SEARCH_RESOLUTION: u64 = 10;
+
+/// How much historical state the node retains
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatePruning {
+    /// All historical state is available
+    Archive,
+    /// State is kept only from `oldest_block` onwards
+    Pruned {
+        oldest_block: u64,
+        retained_blocks: u64,
+    },
+    /// Chain too short to tell, or the node returned an unexpected error
+    Unknown,
+}
+
+impl StatePruning {
+    /// Probe the node's retained state depth relative to `chain_tip`
+    pub async fn detect(rpc: &RpcClient, chain_tip: u64) -> Self {
+        if chain_tip <= SEARCH_RESOLUTION {
+            return StatePruning::Unknown;
+        }
+
+        match state_available(rpc, 1).await {
+            Ok(true) => {
+                debug!("Historical state available at block 1 - archive node detected");
+                return StatePruning::Archive;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                debug!("Could not determine pruning status: {}", e);
+                return StatePruning::Unknown;
+            }
+        }
+
+        // The oldest block with state lies in (low, high]
+        let (mut low, mut high) = (1, chain_tip);
+        while high - low > SEARCH_RESOLUTION {
+            let mid = low + (high - low) / 2;
+            match state_available(rpc, mid).await {
+                Ok(true) => high = mid,
+                Ok(false) => low = mid,
+                Err(e) => {
+                    debug!("Pruning search stopped at block {}: {}", mid, e);
+                    break;
+                }
+            }
+        }
+
+        debug!("State pruned before block {} (tip {})", high, chain_tip);
+        StatePruning::Pruned {
+            oldest_block: high,
+            retained_blocks: chain_tip - high,
+        }
+    }
+
+    /// Short description, e.g. "archive" or "pruned (~256 blocks)"
+    pub fn label(&self) -> String {
+        match self {
+            StatePruning::Archive => "archive".to_string(),
+            StatePruning::Pruned {
+                retained_blocks, ..
+            } => format!("pruned (~{} blocks)", retained_blocks),
+            StatePruning::Unknown => "unknown".to_string(),
+        }
+    }
+}
+
+/// Whether an RPC error means the node no longer has state for that block
+pub fn is_pruned_state_error(e: &anyhow::Error) -> bool {
+    // Check the full chain: the node's message is wrapped in our context
+    let full_error = format!("{:?}", e);
+    full_error.contains("State already discarded") || full_error.contains("UnknownBlock")
+}
+
+/// Whether committee state can be queried at `block_number`
+async fn state_available(rpc: &RpcClient, block_number: u64) -> Result<bool> {
+    let hash: String = rpc.call("chain_getBlockHash", vec![block_number]).await?;
+    match ValidatorSet::fetch_committee_at_block(rpc, Some(&hash)).await {
+        Ok(_) => Ok(true),
+        Err(e) if is_pruned_state_error(&e) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock::serve;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    /// Node whose state starts at `oldest` (block hashes are the hex block number)
+    async fn node_with_state_from(oldest: u64) -> RpcClient {
+        let endpoint = serve(Arc::new(move |request: Value| {
+            let params = &request["params"];
+            let reply = match request["method"].as_str().unwrap() {
+                "chain_getBlockHash" => Ok(json!(format!("0x{:x}", params[0].as_u64().unwrap()))),
+                "state_call" => {
+                    let hash = params[2].as_str().unwrap();
+                    let block = u64::from_str_radix(&hash[2..], 16).unwrap();
+                    if block >= oldest {
+                        Ok(json!("0x00"))
+                    } else {
+                        Err(format!("State already discarded for BlockId::Hash({})", hash))
+                    }
+                }
+                _ => Err("Method not found".to_string()),
+            };
+            match reply {
+                Ok(result) => json!({"jsonrpc": "2.0", "id": request["id"], "result": result}),
+                Err(message) => {
+                    json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": 4003, "message": message}})
+                }
+            }
+        }))
+        .await;
+        RpcClient::new(&endpoint)
+    }
+
+    #[tokio::test]
+    async fn test_detect_state_pruning() {
+        let rpc = node_with_state_from(1).await;
+        assert_eq!(
+            StatePruning::detect(&rpc, 5000).await,
+            StatePruning::Archive
+        );
+
+        let rpc = node_with_state_from(4745).await;
+        match StatePruning::detect(&rpc, 5000).await {
+            StatePruning::Pruned {
+                oldest_block,
+                retained_blocks,
+            } => {
+                assert!((4745..4745 + SEARCH_RESOLUTION).contains(&oldest_block));
+                assert_eq!(retained_blocks, 5000 - oldest_block);
+            }
+            other => panic!("expected pruned, got {:?}", other),
+        }
+
+        assert_eq!(StatePruning::detect(&rpc, 5).await, StatePruning::Unknown);
+        assert_eq!(
+            StatePruning::Pruned {
+                oldest_block: 4745,
+                retained_blocks: 255
+            }
+            .label(),
+            "pruned (~255 blocks)"
+        );
+    }
+}
@@ -1,13 +1,13 @@
 use crate::rpc::RpcClient;
 use anyhow::Result;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::keystore::{normalize_hex, CommitteeStatus, KeyStatus, ValidatorKeys};
 use super::ValidatorSet;
 
 /// Registration status for a validator
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RegistrationStatus {
     /// Registered as permissioned candidate (static)
     Permissioned,
@@ -0,0 +1,110 @@
+//! Reward estimates from block production
+//!
+//! A deliberately rough model for operators and delegators. Rewards are
+//! assumed to be proportional to blocks produced, so over the last N
+//! completed sidechain epochs with synced blocks:
+//!
+//! - `avg_blocks_per_epoch = our_blocks / N`
+//! - `block_share = our_blocks / network_blocks`
+//! - `efficiency = block_share / stake_share` (1.0 = earning in line with stake)
+//! - `blocks_per_day = avg_blocks_per_epoch * sidechain_epochs_per_day`
+//! - `rewards_per_day = blocks_per_day * reward_per_block` (when configured)
+//!
+//! The window and per-block reward come from the `[rewards]` config section
+//! so the estimate can follow changes to reward mechanics.
+
+/// Rough reward earning estimate for one validator
+#[derive(Debug, Clone, PartialEq)]
+pub struct RewardEstimate {
+    /// Epochs in the window that had synced blocks
+    pub epochs: usize,
+    /// Our average blocks per completed epoch
+    pub avg_blocks_per_epoch: f64,
+    /// Our share of all blocks in the window, in percent
+    pub block_share_percent: f64,
+    /// Our share of dynamic stake, in percent (None for permissioned validators)
+    pub stake_share_percent: Option<f64>,
+    /// Projected blocks per day at the window's rate
+    pub blocks_per_day: f64,
+    /// Projected rewards per day (None unless a per-block reward is configured)
+    pub rewards_per_day: Option<f64>,
+}
+
+impl RewardEstimate {
+    /// Block share relative to stake share (1.0 = earning in line with stake)
+    pub fn efficiency(&self) -> Option<f64> {
+        self.stake_share_percent
+            .filter(|share| *share > 0.0)
+            .map(|share| self.block_share_percent / share)
+    }
+}
+
+/// Estimate reward earning rate from per-epoch block counts
+///
+/// `our_blocks` and `network_blocks` are aligned per epoch (oldest first, as
+/// returned by the sparkline queries). Epochs with no network blocks were not
+/// synced and are left out. Returns None when no epoch in the window has data.
+pub fn estimate_rewards(
+    our_blocks: &[u64],
+    network_blocks: &[u64],
+    stake_share_percent: Option<f64>,
+    epochs_per_day: f64,
+    reward_per_block: Option<f64>,
+) -> Option<RewardEstimate> {
+    let synced: Vec<(u64, u64)> = our_blocks
+        .iter()
+        .zip(network_blocks)
+        .filter(|(_, network)| **network > 0)
+        .map(|(ours, network)| (*ours, *network))
+        .collect();
+    if synced.is_empty() {
+        return None;
+    }
+
+    let epochs = synced.len();
+    let ours: u64 = synced.iter().map(|(o, _)| o).sum();
+    let network: u64 = synced.iter().map(|(_, n)| n).sum();
+
+    let avg_blocks_per_epoch = ours as f64 / epochs as f64;
+    let blocks_per_day = avg_blocks_per_epoch * epochs_per_day;
+
+    Some(RewardEstimate {
+        epochs,
+        avg_blocks_per_epoch,
+        block_share_percent: ours as f64 / network as f64 * 100.0,
+        stake_share_percent,
+        blocks_per_day,
+        rewards_per_day: reward_per_block.map(|reward| blocks_per_day * reward),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_rewards() {
+        // Third epoch wasn't synced and is skipped
+        let estimate = estimate_rewards(
+            &[10, 14, 0, 12],
+            &[1200, 1200, 0, 1200],
+            Some(2.0),
+            12.0,
+            Some(0.5),
+        )
+        .unwrap();
+        assert_eq!(estimate.epochs, 3);
+        assert!((estimate.avg_blocks_per_epoch - 12.0).abs() < 1e-9);
+        assert!((estimate.block_share_percent - 1.0).abs() < 1e-9);
+        assert!((estimate.blocks_per_day - 144.0).abs() < 1e-9);
+        assert_eq!(estimate.rewards_per_day, Some(72.0));
+        assert!((estimate.efficiency().unwrap() - 0.5).abs() < 1e-9);
+
+        // No stake share (permissioned) or reward configured
+        let estimate = estimate_rewards(&[5], &[1000], None, 2.4, None).unwrap();
+        assert_eq!(estimate.efficiency(), None);
+        assert_eq!(estimate.rewards_per_day, None);
+
+        assert_eq!(estimate_rewards(&[0, 0], &[0, 0], None, 12.0, None), None);
+    }
+}
@@ -62,7 +62,7 @@ pub fn decode_aura_authorities(hex_response: &str) -> Result<Vec<String>> {
 /// - 0b01: Two-byte mode (64-16383)
 /// - 0b10: Four-byte mode (16384-1073741823)
 /// - 0b11: Big-integer mode (> 2^30 - not supported here)
-fn decode_compact(bytes: &[u8]) -> Result<(usize, usize)> {
+pub(crate) fn decode_compact(bytes: &[u8]) -> Result<(usize, usize)> {
     if bytes.is_empty() {
         anyhow::bail!("Cannot decode compact from empty bytes");
     }
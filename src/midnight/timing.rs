@@ -33,7 +33,6 @@ impl Network {
     }
 
     /// Get the display name for this network
-    #[allow(dead_code)]
     pub fn name(&self) -> &'static str {
         match self {
             Network::Preview => "preview",
@@ -47,7 +46,6 @@ impl Network {
 #[derive(Debug, Clone)]
 pub struct ChainTiming {
     /// Network this timing is for
-    #[allow(dead_code)]
     pub network: Network,
 
     /// Slot duration in milliseconds (6000ms = 6 seconds for all Midnight networks)
@@ -135,6 +133,34 @@ impl ChainTiming {
         self.mainchain_epoch_ms / self.sidechain_epoch_ms
     }
 
+    /// Sidechain epochs per week (84 on preview, 16.8 on mainnet)
+    pub fn sidechain_epochs_per_week(&self) -> f64 {
+        const WEEK_MS: f64 = 7.0 * 24.0 * 3600.0 * 1000.0;
+        WEEK_MS / self.sidechain_epoch_ms as f64
+    }
+
+    /// Whole sidechain epochs covering a week (84 on preview, 17 on mainnet)
+    pub fn week_epochs(&self) -> usize {
+        (self.sidechain_epochs_per_week().round() as usize).max(1)
+    }
+
+    /// Sidechain epochs per day (12 on preview, 2.4 on mainnet)
+    pub fn sidechain_epochs_per_day(&self) -> f64 {
+        self.sidechain_epochs_per_week() / 7.0
+    }
+
+    /// One line for logs, e.g. "preview: 6s slots, 2h sidechain epochs (1200 blocks), 24h mainchain epochs"
+    pub fn summary(&self) -> String {
+        format!(
+            "{}: {} slots, {} sidechain epochs ({} blocks), {} mainchain epochs",
+            self.network.name(),
+            format_duration_ms(self.slot_duration_ms),
+            format_duration_ms(self.sidechain_epoch_ms),
+            self.blocks_per_sidechain_epoch(),
+            format_duration_ms(self.mainchain_epoch_ms)
+        )
+    }
+
     /// Calculate timestamp from slot number (if genesis is known)
     pub fn slot_to_timestamp_ms(&self, slot: u64) -> Option<u64> {
         self.genesis_timestamp_ms
@@ -165,6 +191,92 @@ impl ChainTiming {
 
         epoch_progress * expected_per_seat * committee_seats as f64
     }
+
+    /// Take the genesis time (and slot duration) from a calibration
+    ///
+    /// The fitted slot duration replaces the network preset only when it is
+    /// within 1% of it; otherwise the preset is kept and false is returned, as
+    /// the samples or the configured network are likely wrong. The genesis is
+    /// anchored at the samples' centroid for whichever duration is used.
+    pub fn apply_calibration(&mut self, calibration: &Calibration) -> bool {
+        let fitted = calibration.slot_duration_ms.round() as u64;
+        let consistent = fitted.abs_diff(self.slot_duration_ms) * 100 <= self.slot_duration_ms;
+        if consistent {
+            self.slot_duration_ms = fitted;
+        }
+        self.genesis_timestamp_ms = Some(
+            calibration
+                .anchor_ms
+                .saturating_sub(calibration.anchor_slot * self.slot_duration_ms),
+        );
+        consistent
+    }
+}
+
+/// Slot timing fitted to on-chain block timestamps
+///
+/// Slot to timestamp is linear (`timestamp = genesis + slot × duration`), so
+/// a least-squares fit over several blocks gives both the effective slot
+/// duration and a genesis time free of local clock skew.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration {
+    /// Fitted milliseconds per slot
+    pub slot_duration_ms: f64,
+    /// Centroid of the samples, which the fitted line passes through
+    pub anchor_slot: u64,
+    pub anchor_ms: u64,
+    /// Blocks the fit used
+    pub samples: usize,
+}
+
+impl Calibration {
+    /// Fit (slot, timestamp_ms) samples; None without two distinct slots
+    pub fn fit(samples: &[(u64, u64)]) -> Option<Self> {
+        let n = samples.len();
+        if n < 2 {
+            return None;
+        }
+        // Offsets from the first sample keep the sums well within f64 precision
+        let (slot0, ms0) = samples[0];
+        let points: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|&(slot, ms)| (slot as f64 - slot0 as f64, ms as f64 - ms0 as f64))
+            .collect();
+        let mean_slot = points.iter().map(|p| p.0).sum::<f64>() / n as f64;
+        let mean_ms = points.iter().map(|p| p.1).sum::<f64>() / n as f64;
+        let spread: f64 = points.iter().map(|p| (p.0 - mean_slot).powi(2)).sum();
+        if spread == 0.0 {
+            return None;
+        }
+        let covariance: f64 = points
+            .iter()
+            .map(|p| (p.0 - mean_slot) * (p.1 - mean_ms))
+            .sum();
+
+        let anchor_slot = (slot0 as f64 + mean_slot).round() as u64;
+        let slot_duration_ms = covariance / spread;
+        // Move the centroid to the whole anchor slot along the fitted line
+        let anchor_ms = ms0 as f64
+            + mean_ms
+            + (anchor_slot as f64 - slot0 as f64 - mean_slot) * slot_duration_ms;
+        Some(Self {
+            slot_duration_ms,
+            anchor_slot,
+            anchor_ms: anchor_ms.round() as u64,
+            samples: n,
+        })
+    }
+}
+
+/// Largest whole unit for a duration: "2h", "90m", "6s", "1500ms"
+fn format_duration_ms(ms: u64) -> String {
+    match ms {
+        0 => "0ms".to_string(),
+        ms if ms % 3_600_000 == 0 => format!("{}h", ms / 3_600_000),
+        ms if ms % 60_000 == 0 => format!("{}m", ms / 60_000),
+        ms if ms % 1_000 == 0 => format!("{}s", ms / 1_000),
+        ms => format!("{}ms", ms),
+    }
 }
 
 impl Default for ChainTiming {
@@ -183,6 +295,45 @@ mod tests {
         assert_eq!(timing.slot_duration_ms, 6_000);
         assert_eq!(timing.blocks_per_sidechain_epoch(), 1200); // 2h / 6s = 1200
         assert_eq!(timing.sidechain_epochs_per_mainchain(), 12); // 24h / 2h = 12
+        assert_eq!(
+            timing.summary(),
+            "preview: 6s slots, 2h sidechain epochs (1200 blocks), 24h mainchain epochs"
+        );
+    }
+
+    #[test]
+    fn test_calibration_fit() {
+        // Exact 6s slots from a genesis at 1_000_000ms, with a few ms of jitter
+        let samples: Vec<(u64, u64)> = (0..8)
+            .map(|i| {
+                let slot = 290_000_000 + i * 30;
+                (
+                    slot,
+                    1_000_000 + slot * 6_000 + [0, 3, 1, 4, 2, 0, 3, 1][i as usize],
+                )
+            })
+            .collect();
+        let calibration = Calibration::fit(&samples).unwrap();
+        assert!((calibration.slot_duration_ms - 6_000.0).abs() < 0.01);
+        assert_eq!(calibration.samples, 8);
+
+        let mut timing = ChainTiming::preview();
+        assert!(timing.apply_calibration(&calibration));
+        let genesis = timing.genesis_timestamp_ms.unwrap();
+        assert!(genesis.abs_diff(1_000_000) <= 5, "genesis {}", genesis);
+
+        // A fit far from the preset keeps the preset duration
+        let slow = Calibration {
+            slot_duration_ms: 20_000.0,
+            ..calibration
+        };
+        let mut timing = ChainTiming::preview();
+        assert!(!timing.apply_calibration(&slow));
+        assert_eq!(timing.slot_duration_ms, 6_000);
+
+        // One block (or one slot) can't fix a line
+        assert!(Calibration::fit(&samples[..1]).is_none());
+        assert!(Calibration::fit(&[(5, 1), (5, 2)]).is_none());
     }
 
     #[test]
@@ -191,6 +342,9 @@ mod tests {
         assert_eq!(timing.slot_duration_ms, 6_000);
         assert_eq!(timing.blocks_per_sidechain_epoch(), 6000); // 10h / 6s = 6000
         assert_eq!(timing.sidechain_epochs_per_mainchain(), 12); // 120h / 10h = 12
+        assert!((timing.sidechain_epochs_per_week() - 16.8).abs() < 1e-9); // 168h / 10h
+        assert_eq!(timing.week_epochs(), 17);
+        assert_eq!(ChainTiming::preview().week_epochs(), 84);
     }
 
     #[test]
@@ -37,6 +37,12 @@ pub struct AriadneParameters {
     pub candidate_registrations: HashMap<String, Vec<CandidateRegistration>>,
 }
 
+/// Sidechain key prefix for committee members missing from the candidate list
+///
+/// Their blocks and seats are recorded under `unknown:<aura key>` instead of
+/// being dropped, so per-epoch totals stay complete.
+pub const PLACEHOLDER_KEY_PREFIX: &str = "unknown:";
+
 /// A validator in the active set
 #[derive(Debug, Clone)]
 pub struct Validator {
@@ -48,6 +54,25 @@ pub struct Validator {
     pub stake_lovelace: Option<u64>,
 }
 
+impl Validator {
+    /// Stand-in for a committee member whose AURA key isn't among the candidates
+    pub fn placeholder(aura_key: &str) -> Self {
+        let aura_key = normalize_hex(aura_key);
+        Validator {
+            sidechain_key: format!("{}{}", PLACEHOLDER_KEY_PREFIX, aura_key),
+            aura_key,
+            grandpa_key: String::new(),
+            is_permissioned: false,
+            stake_lovelace: None,
+        }
+    }
+
+    /// Whether this is a placeholder for an unmatched committee member
+    pub fn is_placeholder(&self) -> bool {
+        self.sidechain_key.starts_with(PLACEHOLDER_KEY_PREFIX)
+    }
+}
+
 /// Ordered validator set for a specific epoch
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -131,13 +156,7 @@ impl ValidatorSet {
                 ))
             }
             Err(e) => {
-                // Check if this is a "state discarded" error anywhere in the error chain
-                // We check the full error string which includes all context and causes
-                let full_error = format!("{:?}", e);
-                let is_pruned_state = full_error.contains("State already discarded")
-                    || full_error.contains("UnknownBlock");
-
-                if is_pruned_state {
+                if crate::midnight::pruning::is_pruned_state_error(&e) {
                     // State was pruned, fall back to current committee
                     tracing::warn!(
                         "Historical state pruned for block {}, using current committee (attribution may be inaccurate)",
@@ -165,7 +184,7 @@ impl ValidatorSet {
     }
 
     /// Fetch validator candidates from AriadneParameters
-    async fn fetch_candidates(rpc: &RpcClient, epoch: u64) -> Result<Vec<Validator>> {
+    pub async fn fetch_candidates(rpc: &RpcClient, epoch: u64) -> Result<Vec<Validator>> {
         let params: AriadneParameters = rpc
             .call("sidechain_getAriadneParameters", vec![epoch])
             .await
@@ -295,6 +314,20 @@ impl ValidatorSet {
         self.find_by_aura_key(aura_key)
     }
 
+    /// Get the block author for a given slot, falling back to a placeholder
+    ///
+    /// Unlike `get_author`, a committee member missing from the candidate list
+    /// yields `Validator::placeholder` so its block is still attributed.
+    /// Returns None only when the committee is empty.
+    pub fn get_author_or_placeholder(&self, slot_number: u64) -> Option<Validator> {
+        let aura_key = self.get_author_aura_key(slot_number)?;
+        Some(
+            self.find_by_aura_key(aura_key)
+                .cloned()
+                .unwrap_or_else(|| Validator::placeholder(aura_key)),
+        )
+    }
+
     /// Get the AURA key that should produce the block for a given slot
     ///
     /// This always works even if the validator is not in our candidate list.
@@ -307,6 +340,25 @@ impl ValidatorSet {
         self.committee.get(committee_index).map(|s| s.as_str())
     }
 
+    /// Slot leader schedule for slots in `[from_slot, to_slot)`
+    ///
+    /// Aura assigns slots round-robin over the committee, so the schedule is fully
+    /// determined by the committee. Returns `(slot, sidechain_key)` for each slot whose
+    /// committee member is among `candidates`; slots held by unknown keys are skipped.
+    pub fn schedule(&self, from_slot: u64, to_slot: u64) -> Vec<(u64, String)> {
+        if self.committee.is_empty() {
+            return Vec::new();
+        }
+
+        (from_slot..to_slot)
+            .filter_map(|slot| {
+                let aura_key = self.get_author_aura_key(slot)?;
+                self.find_by_aura_key(aura_key)
+                    .map(|v| (slot, v.sidechain_key.clone()))
+            })
+            .collect()
+    }
+
     /// Get candidate count (registered validators)
     pub fn candidate_count(&self) -> usize {
         self.candidates.len()
@@ -317,6 +369,31 @@ impl ValidatorSet {
         self.committee.len()
     }
 
+    /// Committee seats held by known candidates (equals `committee_size` when
+    /// every committee member was found in the candidate list)
+    pub fn assigned_seats(&self) -> usize {
+        self.committee
+            .iter()
+            .filter(|aura| self.candidates.iter().any(|c| &c.aura_key == *aura))
+            .count()
+    }
+
+    /// Committee members not found in the candidate list, with their seat counts
+    /// (in committee order)
+    pub fn unmatched_committee_members(&self) -> Vec<(&str, usize)> {
+        let mut unmatched: Vec<(&str, usize)> = Vec::new();
+        for aura in &self.committee {
+            if self.candidates.iter().any(|c| &c.aura_key == aura) {
+                continue;
+            }
+            match unmatched.iter_mut().find(|(key, _)| key == aura) {
+                Some((_, seats)) => *seats += 1,
+                None => unmatched.push((aura, 1)),
+            }
+        }
+        unmatched
+    }
+
     /// Legacy count method (returns candidate count for backward compatibility)
     pub fn count(&self) -> usize {
         self.candidate_count()
@@ -439,5 +516,94 @@ mod tests {
         // Test counts
         assert_eq!(validator_set.candidate_count(), 3);
         assert_eq!(validator_set.committee_size(), 4);
+        assert_eq!(validator_set.assigned_seats(), 4);
+
+        // A committee member missing from the candidate list leaves seats unassigned
+        let mut partial = validator_set.clone();
+        partial.candidates.retain(|v| v.aura_key != "0x111");
+        assert_eq!(partial.assigned_seats(), 2);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let validator = |sidechain: &str, aura: &str| Validator {
+            sidechain_key: sidechain.to_string(),
+            aura_key: aura.to_string(),
+            grandpa_key: aura.to_string(),
+            is_permissioned: false,
+            stake_lovelace: None,
+        };
+
+        // Only 0xaaa is a known candidate; 0x999 holds seats but is unknown
+        let validator_set = ValidatorSet {
+            epoch: 1000,
+            candidates: vec![validator("0xaaa", "0x111")],
+            committee: vec![
+                "0x111".to_string(),
+                "0x999".to_string(),
+                "0x999".to_string(),
+                "0x111".to_string(),
+            ],
+        };
+
+        // Slots 100..110 map to committee indices 0,1,2,3,0,1,2,3,0,1
+        assert_eq!(
+            validator_set.schedule(100, 110),
+            vec![
+                (100, "0xaaa".to_string()),
+                (103, "0xaaa".to_string()),
+                (104, "0xaaa".to_string()),
+                (107, "0xaaa".to_string()),
+                (108, "0xaaa".to_string()),
+            ]
+        );
+        assert!(validator_set.schedule(110, 110).is_empty());
+
+        let empty = ValidatorSet {
+            epoch: 1000,
+            candidates: Vec::new(),
+            committee: Vec::new(),
+        };
+        assert!(empty.schedule(0, 100).is_empty());
+    }
+
+    #[test]
+    fn test_unmatched_committee_members() {
+        let validator_set = ValidatorSet {
+            epoch: 1000,
+            candidates: vec![Validator {
+                sidechain_key: "0xaaa".to_string(),
+                aura_key: "0x111".to_string(),
+                grandpa_key: "0x111".to_string(),
+                is_permissioned: false,
+                stake_lovelace: None,
+            }],
+            committee: vec![
+                "0x111".to_string(),
+                "0x999".to_string(),
+                "0x888".to_string(),
+                "0x999".to_string(),
+            ],
+        };
+
+        assert_eq!(
+            validator_set.unmatched_committee_members(),
+            vec![("0x999", 2), ("0x888", 1)]
+        );
+
+        // Known members resolve as usual; unknown ones get a placeholder instead of None
+        assert_eq!(
+            validator_set
+                .get_author_or_placeholder(0)
+                .unwrap()
+                .sidechain_key,
+            "0xaaa"
+        );
+        assert!(validator_set.get_author(1).is_none());
+        let placeholder = validator_set.get_author_or_placeholder(1).unwrap();
+        assert_eq!(placeholder.sidechain_key, "unknown:0x999");
+        assert_eq!(placeholder.aura_key, "0x999");
+        assert!(placeholder.is_placeholder());
+        assert!(!validator_set.candidates[0].is_placeholder());
     }
 }
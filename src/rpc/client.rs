@@ -1,12 +1,33 @@
+use super::methods::{NodeProfile, RpcMethod};
+use super::ws::{self, HeadSubscription};
 use anyhow::{Context, Result};
 use reqwest::Client;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::Duration;
-use tracing::warn;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
 
 static REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 
+/// Endpoint scheme for node RPC over a Unix domain socket (`unix:///path/to/socket`)
+pub const UNIX_SCHEME: &str = "unix://";
+
+/// Request URL used when dialing a Unix socket (the node ignores the host)
+#[cfg(unix)]
+const UNIX_REQUEST_URL: &str = "http://localhost/";
+
+/// Calls at least this slow are reported by `take_slow_call` unless reconfigured
+const DEFAULT_SLOW_CALL_MS: u64 = 2000;
+
+/// The slowest RPC call over the slow threshold since it was last taken
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlowCall {
+    pub method: String,
+    pub elapsed_ms: u64,
+}
+
 /// Configuration for RPC retry behavior
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -31,6 +52,27 @@ impl Default for RetryConfig {
     }
 }
 
+/// Configuration for HTTP connection reuse and connect timeout
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// Maximum idle connections kept open to the node
+    pub max_idle_per_host: usize,
+    /// Idle connection lifetime and TCP keep-alive interval in seconds (0 = no reuse)
+    pub keepalive_secs: u64,
+    /// Timeout for establishing a connection in milliseconds
+    pub connect_timeout_ms: u64,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: 8,
+            keepalive_secs: 60,
+            connect_timeout_ms: 5000,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct JsonRpcRequest<P: Serialize> {
     jsonrpc: &'static str,
@@ -55,11 +97,33 @@ struct JsonRpcError {
     message: String,
 }
 
+/// How the endpoint answered a batch request
+enum BatchReply {
+    Results(Vec<Result<serde_json::Value>>),
+    /// A JSON-RPC error for the batch as a whole: the node doesn't do batches
+    Unsupported,
+    /// Anything else (5xx, unreadable body): worth batching again next time
+    Failed(String),
+}
+
 #[derive(Clone)]
 pub struct RpcClient {
     client: Client,
     endpoint: String,
+    /// URL requests are posted to (differs from `endpoint` for Unix sockets)
+    request_url: String,
     retry_config: RetryConfig,
+    /// Set once the endpoint has answered a batch with a JSON-RPC error
+    batch_unsupported: Arc<AtomicBool>,
+    /// Methods the node offers, set by `probe`
+    profile: Arc<OnceLock<NodeProfile>>,
+    /// Unsupported methods already logged, so each is reported once
+    unsupported_logged: Arc<Mutex<HashSet<RpcMethod>>>,
+    /// Calls taking at least this long are kept in `slowest_call` (0 = off)
+    slow_call_ms: u64,
+    slowest_call: Arc<Mutex<Option<SlowCall>>>,
+    /// Connect timeout, also applied to WebSocket subscriptions
+    connect_timeout: Duration,
 }
 
 impl RpcClient {
@@ -75,16 +139,143 @@ impl RpcClient {
     }
 
     /// Create a new RPC client with custom timeout and retry configuration
+    ///
+    /// `unix:///path/to/socket` endpoints are dialed over that Unix domain
+    /// socket instead of TCP. `ws://` and `wss://` endpoints make calls over
+    /// HTTP on the same host and port and can also `subscribe_heads`.
     pub fn with_config(endpoint: &str, timeout_ms: u64, retry_config: RetryConfig) -> Self {
-        let client = Client::builder()
+        Self::with_options(endpoint, timeout_ms, retry_config, PoolConfig::default())
+    }
+
+    /// Create a new RPC client with custom timeout, retry, and connection pool configuration
+    ///
+    /// One underlying `reqwest::Client` is shared by every clone of the
+    /// returned client, so polling and per-block sync reuse pooled keep-alive
+    /// connections instead of opening a new one per request. `timeout_ms`
+    /// bounds each whole request; `connect_timeout_ms` only the connect.
+    pub fn with_options(
+        endpoint: &str,
+        timeout_ms: u64,
+        retry_config: RetryConfig,
+        pool_config: PoolConfig,
+    ) -> Self {
+        // keepalive_secs = 0 turns connection reuse off entirely
+        let (max_idle, keepalive) = match pool_config.keepalive_secs {
+            0 => (0, None),
+            secs => (
+                pool_config.max_idle_per_host,
+                Some(Duration::from_secs(secs)),
+            ),
+        };
+        let mut builder = Client::builder()
             .timeout(Duration::from_millis(timeout_ms))
-            .build()
-            .unwrap_or_else(|_| Client::new());
+            .connect_timeout(Duration::from_millis(pool_config.connect_timeout_ms))
+            .pool_max_idle_per_host(max_idle)
+            .pool_idle_timeout(keepalive)
+            .tcp_keepalive(keepalive);
+        let mut request_url = endpoint.to_string();
+
+        #[cfg(unix)]
+        if let Some(socket_path) = endpoint.strip_prefix(UNIX_SCHEME) {
+            builder = builder.unix_socket(socket_path);
+            request_url = UNIX_REQUEST_URL.to_string();
+        }
+        if ws::is_websocket_url(endpoint) {
+            request_url = ws::http_url(endpoint);
+        }
+
+        let client = builder.build().unwrap_or_else(|_| Client::new());
 
         Self {
             client,
             endpoint: endpoint.to_string(),
+            request_url,
             retry_config,
+            batch_unsupported: Arc::new(AtomicBool::new(false)),
+            profile: Arc::new(OnceLock::new()),
+            unsupported_logged: Arc::new(Mutex::new(HashSet::new())),
+            slow_call_ms: DEFAULT_SLOW_CALL_MS,
+            slowest_call: Arc::new(Mutex::new(None)),
+            connect_timeout: Duration::from_millis(pool_config.connect_timeout_ms),
+        }
+    }
+
+    /// Detect which RPC methods the node offers and remember the profile
+    ///
+    /// Uses `rpc_methods`; if the node rejects it, every method is assumed
+    /// present under its preferred name, as before probing existed. Transport
+    /// failures leave the client unprobed so a later call can retry.
+    pub async fn probe(&self) -> Option<NodeProfile> {
+        let profile = match self
+            .call::<_, serde_json::Value>("rpc_methods", Vec::<()>::new())
+            .await
+        {
+            Ok(response) => NodeProfile::from_rpc_methods(&response),
+            Err(e) if e.to_string().starts_with("RPC error") => {
+                debug!(
+                    "rpc_methods unavailable, assuming default method names: {}",
+                    e
+                );
+                NodeProfile::default()
+            }
+            Err(e) => {
+                debug!("RPC method probe failed: {}", e);
+                return None;
+            }
+        };
+
+        if profile.is_known() {
+            info!(
+                "Node offers {} RPC methods (using {})",
+                profile.method_count(),
+                RpcMethod::ALL
+                    .iter()
+                    .filter_map(|m| profile.resolve(*m))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            for method in profile.unsupported() {
+                self.log_unsupported(method);
+            }
+        }
+
+        let _ = self.profile.set(profile);
+        self.profile.get().cloned()
+    }
+
+    /// Whether `probe` has recorded a profile for this node
+    pub fn is_probed(&self) -> bool {
+        self.profile.get().is_some()
+    }
+
+    /// Method name to call for `method` on this node, or None if it isn't offered
+    ///
+    /// Before `probe` runs, the preferred name is returned.
+    pub fn method_name(&self, method: RpcMethod) -> Option<&'static str> {
+        let name = match self.profile.get() {
+            Some(profile) => profile.resolve(method),
+            None => method.candidates().first().copied(),
+        };
+        if name.is_none() {
+            self.log_unsupported(method);
+        }
+        name
+    }
+
+    /// Warn once per method that the node doesn't offer it
+    fn log_unsupported(&self, method: RpcMethod) {
+        let first_time = self
+            .unsupported_logged
+            .lock()
+            .map(|mut logged| logged.insert(method))
+            .unwrap_or(false);
+        if first_time {
+            warn!(
+                "Node doesn't offer {} (tried {}); {} will be unavailable",
+                method.candidates()[0],
+                method.candidates().join(", "),
+                method.feature()
+            );
         }
     }
 
@@ -93,6 +284,19 @@ impl RpcClient {
         &self.endpoint
     }
 
+    /// Whether the endpoint is a WebSocket URL, so `subscribe_heads` can be used
+    pub fn is_websocket(&self) -> bool {
+        ws::is_websocket_url(&self.endpoint)
+    }
+
+    /// Subscribe to new block headers (or finalized ones only) over WebSocket
+    pub async fn subscribe_heads(&self, finalized: bool) -> Result<HeadSubscription> {
+        if !self.is_websocket() {
+            anyhow::bail!("Head subscriptions need a ws:// or wss:// endpoint");
+        }
+        HeadSubscription::connect(&self.endpoint, finalized, self.connect_timeout).await
+    }
+
     /// Set retry configuration
     #[allow(dead_code)]
     pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
@@ -100,7 +304,46 @@ impl RpcClient {
         self
     }
 
+    /// Report calls taking at least `ms` through `take_slow_call` (0 = never)
+    pub fn with_slow_call_threshold(mut self, ms: u64) -> Self {
+        self.slow_call_ms = ms;
+        self
+    }
+
+    /// The slowest call over the threshold since the last take, clearing it
+    pub fn take_slow_call(&self) -> Option<SlowCall> {
+        self.slowest_call.lock().ok()?.take()
+    }
+
+    /// Log a call's duration (visible with --verbose) and keep it if it was slow
+    fn record_timing(&self, method: &str, elapsed: Duration) {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        debug!("RPC {} took {}ms", method, elapsed_ms);
+        if self.slow_call_ms == 0 || elapsed_ms < self.slow_call_ms {
+            return;
+        }
+        if let Ok(mut slowest) = self.slowest_call.lock() {
+            if slowest.as_ref().is_none_or(|s| elapsed_ms > s.elapsed_ms) {
+                *slowest = Some(SlowCall {
+                    method: method.to_string(),
+                    elapsed_ms,
+                });
+            }
+        }
+    }
+
     pub async fn call<P, R>(&self, method: &str, params: P) -> Result<R>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let start = Instant::now();
+        let result = self.send_call(method, params).await;
+        self.record_timing(method, start.elapsed());
+        result
+    }
+
+    async fn send_call<P, R>(&self, method: &str, params: P) -> Result<R>
     where
         P: Serialize,
         R: DeserializeOwned,
@@ -114,7 +357,7 @@ impl RpcClient {
 
         let response = self
             .client
-            .post(&self.endpoint)
+            .post(&self.request_url)
             .json(&request)
             .send()
             .await
@@ -134,6 +377,116 @@ impl RpcClient {
             .context("RPC response missing result field")
     }
 
+    /// Make several RPC calls in a single JSON-RPC batch round trip
+    ///
+    /// Returns one result per call, in the same order as `calls`. If the
+    /// endpoint rejects batch requests, falls back to sequential calls (and
+    /// remembers that for subsequent batches); other batch failures, such as a
+    /// 5xx from a proxy, fall back for this call only. Only transport failures
+    /// are returned as the outer error.
+    pub async fn call_batch(
+        &self,
+        calls: &[(&str, serde_json::Value)],
+    ) -> Result<Vec<Result<serde_json::Value>>> {
+        if calls.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if !self.batch_unsupported.load(Ordering::Relaxed) {
+            let start = Instant::now();
+            let sent = self.send_batch(calls).await;
+            let label = format!("{} (batch of {})", calls[0].0, calls.len());
+            self.record_timing(&label, start.elapsed());
+            match sent? {
+                BatchReply::Results(results) => return Ok(results),
+                BatchReply::Unsupported => {
+                    debug!("RPC endpoint rejected batch request, using sequential calls");
+                    self.batch_unsupported.store(true, Ordering::Relaxed);
+                }
+                BatchReply::Failed(reason) => {
+                    debug!("RPC batch failed ({}), using sequential calls", reason);
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(calls.len());
+        for (method, params) in calls {
+            results.push(self.call(method, params.clone()).await);
+        }
+        Ok(results)
+    }
+
+    /// Send a batch request; only transport failures are errors
+    async fn send_batch(&self, calls: &[(&str, serde_json::Value)]) -> Result<BatchReply> {
+        let requests: Vec<JsonRpcRequest<&serde_json::Value>> = calls
+            .iter()
+            .map(|(method, params)| JsonRpcRequest {
+                jsonrpc: "2.0",
+                id: REQUEST_ID.fetch_add(1, Ordering::SeqCst),
+                method: method.to_string(),
+                params,
+            })
+            .collect();
+
+        let response = self
+            .client
+            .post(&self.request_url)
+            .json(&requests)
+            .send()
+            .await
+            .context("Failed to send RPC batch request")?;
+
+        let status = response.status();
+        let body: serde_json::Value = match response.json().await {
+            Ok(body) => body,
+            Err(e) => return Ok(BatchReply::Failed(format!("HTTP {}: {}", status, e))),
+        };
+        // A single error object (rather than an array) means the node rejected
+        // the batch itself; anything else may be transient
+        let Some(items) = body.as_array() else {
+            if body.get("error").is_some_and(|e| !e.is_null()) {
+                return Ok(BatchReply::Unsupported);
+            }
+            return Ok(BatchReply::Failed(format!(
+                "HTTP {}, no batch response",
+                status
+            )));
+        };
+        if !status.is_success() {
+            return Ok(BatchReply::Failed(format!("HTTP {}", status)));
+        }
+
+        // Responses may arrive in any order; match them back up by id
+        let mut by_id: std::collections::HashMap<u64, &serde_json::Value> = items
+            .iter()
+            .filter_map(|item| {
+                item.get("id")
+                    .and_then(|id| id.as_u64())
+                    .map(|id| (id, item))
+            })
+            .collect();
+
+        let results = requests
+            .iter()
+            .map(|request| {
+                let item = by_id
+                    .remove(&request.id)
+                    .with_context(|| format!("RPC batch response missing id {}", request.id))?;
+                if let Some(error) = item.get("error").filter(|e| !e.is_null()) {
+                    let error: JsonRpcError = serde_json::from_value(error.clone())
+                        .context("Failed to parse RPC error")?;
+                    anyhow::bail!("RPC error {}: {}", error.code, error.message);
+                }
+                item.get("result")
+                    .filter(|r| !r.is_null())
+                    .cloned()
+                    .context("RPC response missing result field")
+            })
+            .collect();
+
+        Ok(BatchReply::Results(results))
+    }
+
     /// Make an RPC call with automatic retry on transient failures
     pub async fn call_with_retry<P, R>(&self, method: &str, params: P) -> Result<R>
     where
@@ -200,3 +553,145 @@ impl RpcClient {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::mock::serve;
+    use serde_json::{json, Value};
+
+    /// Echo the method name back as the result
+    fn answer(request: &Value) -> Value {
+        let method = request["method"].as_str().unwrap();
+        if method == "bad_method" {
+            json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": -32601, "message": "Method not found"}})
+        } else {
+            json!({"jsonrpc": "2.0", "id": request["id"], "result": method})
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_batch() {
+        // Reply in reverse order to check responses are matched by id
+        let endpoint = serve(Arc::new(|body: Value| {
+            let mut replies: Vec<Value> = body.as_array().unwrap().iter().map(answer).collect();
+            replies.reverse();
+            Value::Array(replies)
+        }))
+        .await;
+        let rpc = RpcClient::new(&endpoint);
+
+        let results = rpc
+            .call_batch(&[
+                ("chain_getHeader", json!([])),
+                ("bad_method", json!([])),
+                ("system_health", json!([])),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), "chain_getHeader");
+        assert!(results[1]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("Method not found"));
+        assert_eq!(results[2].as_ref().unwrap(), "system_health");
+        assert!(!rpc.batch_unsupported.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_call_batch_falls_back_to_sequential() {
+        let endpoint = serve(Arc::new(|body: Value| {
+            if body.is_array() {
+                json!({"jsonrpc": "2.0", "id": null, "error": {"code": -32600, "message": "Batches not supported"}})
+            } else {
+                answer(&body)
+            }
+        }))
+        .await;
+        let rpc = RpcClient::new(&endpoint);
+
+        let results = rpc
+            .call_batch(&[("chain_getHeader", json!([])), ("system_health", json!([]))])
+            .await
+            .unwrap();
+
+        assert_eq!(results[0].as_ref().unwrap(), "chain_getHeader");
+        assert_eq!(results[1].as_ref().unwrap(), "system_health");
+        assert!(rpc.batch_unsupported.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_call_batch_retries_after_transient_failure() {
+        // The first batch hits a non-JSON proxy error; later ones succeed
+        let failed_once = Arc::new(AtomicBool::new(false));
+        let endpoint = serve(Arc::new(move |body: Value| {
+            if body.is_array() && !failed_once.swap(true, Ordering::SeqCst) {
+                json!("502 Bad Gateway")
+            } else if let Some(calls) = body.as_array() {
+                Value::Array(calls.iter().map(answer).collect())
+            } else {
+                answer(&body)
+            }
+        }))
+        .await;
+        let rpc = RpcClient::new(&endpoint);
+        let calls = [("chain_getHeader", json!([])), ("system_health", json!([]))];
+
+        let results = rpc.call_batch(&calls).await.unwrap();
+        assert_eq!(results[1].as_ref().unwrap(), "system_health");
+        assert!(!rpc.batch_unsupported.load(Ordering::Relaxed));
+
+        let results = rpc.call_batch(&calls).await.unwrap();
+        assert_eq!(results[0].as_ref().unwrap(), "chain_getHeader");
+        assert!(!rpc.batch_unsupported.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_slow_call_tracking() {
+        let endpoint = serve(Arc::new(|body: Value| {
+            std::thread::sleep(Duration::from_millis(20));
+            answer(&body)
+        }))
+        .await;
+
+        let rpc = RpcClient::new(&endpoint).with_slow_call_threshold(10);
+        let _: String = rpc.call("system_health", json!([])).await.unwrap();
+        let slow = rpc.take_slow_call().unwrap();
+        assert_eq!(slow.method, "system_health");
+        assert!(slow.elapsed_ms >= 20);
+        // Taking clears it until the next slow call
+        assert_eq!(rpc.take_slow_call(), None);
+
+        let rpc = RpcClient::new(&endpoint).with_slow_call_threshold(0);
+        let _: String = rpc.call("system_health", json!([])).await.unwrap();
+        assert_eq!(rpc.take_slow_call(), None);
+    }
+
+    #[cfg(all(unix, feature = "uds-tests"))]
+    #[tokio::test]
+    async fn test_call_over_unix_socket() {
+        let socket_path =
+            std::env::temp_dir().join(format!("mvm-rpc-test-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = tokio::net::UnixListener::bind(&socket_path).unwrap();
+
+        use crate::rpc::mock::{serve_connection, Handler};
+
+        let handler: Handler = Arc::new(|body: Value| answer(&body));
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                serve_connection(stream, &handler).await;
+            }
+        });
+
+        let rpc = RpcClient::new(&format!("{}{}", UNIX_SCHEME, socket_path.display()));
+        let result: String = rpc.call("system_health", json!([])).await.unwrap();
+        assert_eq!(result, "system_health");
+        assert_eq!(rpc.endpoint(), format!("unix://{}", socket_path.display()));
+
+        std::fs::remove_file(&socket_path).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+//! RPC method capability probing
+//!
+//! Node releases rename or stabilise RPC methods (e.g. the unstable network
+//! state call). At startup `RpcClient::probe` asks the node for its method list
+//! via `rpc_methods` and records a `NodeProfile`; callers then look up the name
+//! to use for a logical method instead of hardcoding one. A method the node
+//! doesn't offer is logged once and its feature skipped.
+
+use std::collections::HashSet;
+
+/// Logical RPC methods whose name varies between node versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RpcMethod {
+    /// Local peer ID, external addresses and connected peer details
+    NetworkState,
+    /// Connected peers with their best block
+    Peers,
+    /// Sync progress (starting, current and highest block)
+    SyncState,
+}
+
+impl RpcMethod {
+    pub const ALL: [RpcMethod; 3] = [
+        RpcMethod::NetworkState,
+        RpcMethod::Peers,
+        RpcMethod::SyncState,
+    ];
+
+    /// Known names for this method, preferred first
+    pub fn candidates(self) -> &'static [&'static str] {
+        match self {
+            RpcMethod::NetworkState => &["system_unstable_networkState", "system_networkState"],
+            RpcMethod::Peers => &["system_peers"],
+            RpcMethod::SyncState => &["system_syncState"],
+        }
+    }
+
+    /// What the method feeds in MVM, for the unsupported-method log line
+    pub fn feature(self) -> &'static str {
+        match self {
+            RpcMethod::NetworkState => "external IP, peer ID and peer addresses",
+            RpcMethod::Peers => "Peers view",
+            RpcMethod::SyncState => "node sync progress",
+        }
+    }
+}
+
+/// RPC methods a node was found to offer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeProfile {
+    /// Method names from `rpc_methods`; None when the node doesn't list them
+    methods: Option<HashSet<String>>,
+}
+
+impl NodeProfile {
+    /// Profile from the `rpc_methods` response (`{"methods": [...]}`)
+    pub fn from_rpc_methods(response: &serde_json::Value) -> Self {
+        let methods = response
+            .get("methods")
+            .and_then(|m| m.as_array())
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|n| n.as_str().map(str::to_string))
+                    .collect()
+            });
+        Self { methods }
+    }
+
+    /// Whether the node listed its methods (otherwise every method is assumed present)
+    pub fn is_known(&self) -> bool {
+        self.methods.is_some()
+    }
+
+    /// Number of methods the node listed
+    pub fn method_count(&self) -> usize {
+        self.methods.as_ref().map_or(0, HashSet::len)
+    }
+
+    /// Name to call for `method`, or None if the node offers none of its names
+    pub fn resolve(&self, method: RpcMethod) -> Option<&'static str> {
+        let candidates = method.candidates();
+        match &self.methods {
+            Some(methods) => candidates.iter().copied().find(|c| methods.contains(*c)),
+            None => candidates.first().copied(),
+        }
+    }
+
+    /// Logical methods the node doesn't offer under any known name
+    pub fn unsupported(&self) -> Vec<RpcMethod> {
+        RpcMethod::ALL
+            .into_iter()
+            .filter(|m| self.resolve(*m).is_none())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_profile_resolve() {
+        // Unknown profile: preferred names are used as before
+        let unknown = NodeProfile::default();
+        assert!(!unknown.is_known());
+        assert_eq!(
+            unknown.resolve(RpcMethod::NetworkState),
+            Some("system_unstable_networkState")
+        );
+        assert!(unknown.unsupported().is_empty());
+
+        // Renamed network state, no system_peers
+        let profile = NodeProfile::from_rpc_methods(&serde_json::json!({
+            "version": 1,
+            "methods": ["chain_getHeader", "system_networkState", "system_syncState"],
+        }));
+        assert!(profile.is_known());
+        assert_eq!(profile.method_count(), 3);
+        assert_eq!(
+            profile.resolve(RpcMethod::NetworkState),
+            Some("system_networkState")
+        );
+        assert_eq!(profile.resolve(RpcMethod::Peers), None);
+        assert_eq!(profile.unsupported(), vec![RpcMethod::Peers]);
+    }
+}
@@ -0,0 +1,152 @@
+//! Mock JSON-RPC node for tests
+//!
+//! Serves canned results over HTTP on a random localhost port, so code that
+//! talks to a node (block sync, committee fetches) can run end to end against
+//! an in-memory database. Results are keyed by method and optionally by exact
+//! params; anything unregistered gets a "Method not found" error like a real
+//! node. Batch requests are answered element by element.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Request body to response body
+pub type Handler = Arc<dyn Fn(Value) -> Value + Send + Sync>;
+
+/// Canned reply for one method call
+#[derive(Debug, Clone)]
+enum Reply {
+    Result(Value),
+    Error(String),
+}
+
+/// Canned node responses, registered builder-style then served with `start`
+#[derive(Debug, Clone, Default)]
+pub struct MockNode {
+    /// Per method: replies for exact params, checked before the catch-all (None)
+    routes: HashMap<String, Vec<(Option<Value>, Reply)>>,
+}
+
+impl MockNode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `method` with `result` whatever the params
+    pub fn result(self, method: &str, result: Value) -> Self {
+        self.route(method, None, Reply::Result(result))
+    }
+
+    /// Answer `method` with `result` when called with exactly `params`
+    pub fn result_for(self, method: &str, params: Value, result: Value) -> Self {
+        self.route(method, Some(params), Reply::Result(result))
+    }
+
+    /// Fail `method` with a JSON-RPC error when called with exactly `params`
+    pub fn error_for(self, method: &str, params: Value, message: &str) -> Self {
+        self.route(method, Some(params), Reply::Error(message.to_string()))
+    }
+
+    fn route(mut self, method: &str, params: Option<Value>, reply: Reply) -> Self {
+        self.routes
+            .entry(method.to_string())
+            .or_default()
+            .push((params, reply));
+        self
+    }
+
+    /// Response envelope for a single JSON-RPC request
+    fn answer(&self, request: &Value) -> Value {
+        let method = request["method"].as_str().unwrap_or_default();
+        let params = &request["params"];
+        let reply = self.routes.get(method).and_then(|replies| {
+            replies
+                .iter()
+                .find(|(p, _)| p.as_ref() == Some(params))
+                .or_else(|| replies.iter().find(|(p, _)| p.is_none()))
+                .map(|(_, reply)| reply)
+        });
+
+        match reply {
+            Some(Reply::Result(result)) => {
+                json!({"jsonrpc": "2.0", "id": request["id"], "result": result})
+            }
+            Some(Reply::Error(message)) => {
+                json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": 4003, "message": message}})
+            }
+            None => {
+                json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": -32601, "message": "Method not found"}})
+            }
+        }
+    }
+
+    /// Request handler for wrapping in a custom `serve` (e.g. to vary replies per call)
+    pub fn into_handler(self) -> Handler {
+        Arc::new(move |body: Value| match body {
+            Value::Array(requests) => {
+                Value::Array(requests.iter().map(|r| self.answer(r)).collect())
+            }
+            request => self.answer(&request),
+        })
+    }
+
+    /// Start serving and return the endpoint URL
+    pub async fn start(self) -> String {
+        serve(self.into_handler()).await
+    }
+}
+
+/// Minimal HTTP server answering each request body with `handler(body)`
+pub async fn serve(handler: Handler) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move { serve_connection(stream, &handler).await });
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+/// Answer one HTTP request on `stream` with `handler(body)`
+pub async fn serve_connection<S>(mut stream: S, handler: &Handler)
+where
+    S: AsyncReadExt + AsyncWriteExt + Unpin,
+{
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let body = loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        let text = String::from_utf8_lossy(&buf);
+        if let Some(split) = text.find("\r\n\r\n") {
+            let length = text[..split]
+                .lines()
+                .find_map(|l| {
+                    l.to_ascii_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or(0);
+            if buf.len() >= split + 4 + length {
+                break buf[split + 4..split + 4 + length].to_vec();
+            }
+        }
+    };
+
+    let reply = handler(serde_json::from_slice(&body).unwrap()).to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        reply.len(),
+        reply
+    );
+    stream.write_all(response.as_bytes()).await.unwrap();
+}
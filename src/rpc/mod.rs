@@ -1,5 +1,11 @@
 mod client;
+mod methods;
+#[cfg(test)]
+pub mod mock;
 mod types;
+mod ws;
 
-pub use client::{RetryConfig, RpcClient};
+pub use client::{PoolConfig, RetryConfig, RpcClient, SlowCall, UNIX_SCHEME};
+pub use methods::RpcMethod;
 pub use types::*;
+pub use ws::HeadSubscription;
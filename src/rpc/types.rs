@@ -1,22 +1,79 @@
-use serde::Deserialize;
+//! RPC response types
+//!
+//! Midnight's RPC is still changing between node releases, so these structs
+//! are deliberately tolerant: non-essential fields default when missing, and
+//! numbers are accepted as JSON numbers or as decimal/hex strings. Only the
+//! fields MVM cannot work without (block number, epochs) are required.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use tracing::debug;
+
+/// Accept a u64 as a JSON number or a decimal/0x-hex string
+fn lenient_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Int(u64),
+        Float(f64),
+        Text(String),
+    }
+
+    match Number::deserialize(deserializer)? {
+        Number::Int(n) => Ok(n),
+        Number::Float(f) if f >= 0.0 && f.fract() == 0.0 => Ok(f as u64),
+        Number::Text(t) if t.starts_with("0x") => parse_hex_number(&t)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid hex number: {}", t))),
+        Number::Text(t) => t
+            .parse()
+            .map_err(|_| serde::de::Error::custom(format!("invalid number: {}", t))),
+        Number::Float(f) => Err(serde::de::Error::custom(format!("invalid number: {}", f))),
+    }
+}
+
+/// Optional variant of `lenient_u64`; null or unparseable values become None
+fn lenient_opt_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|v| lenient_u64(v).ok()))
+}
+
+/// Decode a non-critical RPC result, logging the raw JSON at debug on schema drift
+pub fn decode_lenient<T: DeserializeOwned>(method: &str, value: serde_json::Value) -> Option<T> {
+    match serde_json::from_value::<T>(value.clone()) {
+        Ok(decoded) => Some(decoded),
+        Err(e) => {
+            debug!("Unexpected {} response ({}): {}", method, e, value);
+            None
+        }
+    }
+}
 
 /// Response from system_health RPC call
+///
+/// `peers` is required: a default of 0 would trip the peer-floor alert.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct SystemHealth {
     pub peers: u32,
+    #[serde(default)]
     pub is_syncing: bool,
+    #[serde(default)]
     pub should_have_peers: bool,
 }
 
 /// Response from system_syncState RPC call
+///
+/// Current and highest block are required, so a drifted response can't read as synced.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct SyncState {
+    #[serde(deserialize_with = "lenient_u64")]
     pub current_block: u64,
+    #[serde(deserialize_with = "lenient_u64")]
     pub highest_block: u64,
+    #[serde(default, deserialize_with = "lenient_u64")]
     pub starting_block: u64,
 }
 
@@ -24,10 +81,14 @@ pub struct SyncState {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockHeader {
+    #[serde(default)]
     pub parent_hash: String,
     pub number: String,
+    #[serde(default)]
     pub state_root: String,
+    #[serde(default)]
     pub extrinsics_root: String,
+    #[serde(default)]
     pub digest: Option<Digest>,
 }
 
@@ -38,8 +99,9 @@ impl BlockHeader {
 }
 
 /// Block digest containing consensus logs
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct Digest {
+    #[serde(default)]
     pub logs: Vec<String>,
 }
 
@@ -48,6 +110,7 @@ pub struct Digest {
 #[allow(dead_code)]
 pub struct SignedBlock {
     pub block: Block,
+    #[serde(default)]
     pub justifications: Option<serde_json::Value>,
 }
 
@@ -55,21 +118,25 @@ pub struct SignedBlock {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Block {
     pub header: BlockHeader,
+    #[serde(default)]
     pub extrinsics: Vec<String>,
 }
 
 /// Epoch/slot info for a chain
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct ChainStatus {
+    #[serde(deserialize_with = "lenient_u64")]
     pub epoch: u64,
+    #[serde(default, deserialize_with = "lenient_u64")]
     pub slot: u64,
+    #[serde(default, deserialize_with = "lenient_opt_u64")]
     pub next_epoch_timestamp: Option<u64>,
 }
 
 /// Response from sidechain_getStatus RPC call
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct SidechainStatus {
     pub sidechain: ChainStatus,
     pub mainchain: ChainStatus,
@@ -92,4 +159,60 @@ mod tests {
         assert_eq!(parse_hex_number("332534"), Some(3351860));
         assert_eq!(parse_hex_number("0x1"), Some(1));
     }
+
+    #[test]
+    fn test_sidechain_status_tolerates_drift() {
+        // Extra fields, string and hex numbers, missing slot/nextEpochTimestamp
+        let value = serde_json::json!({
+            "sidechain": {"epoch": "245001", "slot": "0x10", "nextEpochTimestamp": null, "new": 1},
+            "mainchain": {"epoch": 1000, "nextEpochTimestamp": "1700000000000"},
+            "partnerChain": {}
+        });
+        let status: SidechainStatus = decode_lenient("sidechain_getStatus", value).unwrap();
+        assert_eq!(status.sidechain.epoch, 245001);
+        assert_eq!(status.sidechain.slot, 16);
+        assert_eq!(status.sidechain.next_epoch_timestamp, None);
+        assert_eq!(status.mainchain.slot, 0);
+        assert_eq!(
+            status.mainchain.next_epoch_timestamp,
+            Some(1_700_000_000_000)
+        );
+
+        // Epoch is essential: a renamed field fails decoding rather than reading 0
+        let value = serde_json::json!({"sidechain": {"epochNumber": 1}, "mainchain": {"epoch": 1}});
+        assert!(decode_lenient::<SidechainStatus>("sidechain_getStatus", value).is_none());
+    }
+
+    #[test]
+    fn test_header_and_health_defaults() {
+        let header: BlockHeader =
+            serde_json::from_value(serde_json::json!({"number": "0x10"})).unwrap();
+        assert_eq!(header.block_number(), 16);
+        assert!(header.digest.is_none());
+
+        let health: SystemHealth =
+            decode_lenient("system_health", serde_json::json!({"peers": 7})).unwrap();
+        assert_eq!(health.peers, 7);
+        assert!(!health.is_syncing);
+        // No peer count is not zero peers
+        assert!(
+            decode_lenient::<SystemHealth>("system_health", serde_json::json!("oops")).is_none()
+        );
+        assert!(decode_lenient::<SystemHealth>(
+            "system_health",
+            serde_json::json!({"isSyncing": false})
+        )
+        .is_none());
+
+        let sync: SyncState = decode_lenient(
+            "system_syncState",
+            serde_json::json!({"currentBlock": "0x20", "highestBlock": 40}),
+        )
+        .unwrap();
+        assert_eq!(
+            (sync.current_block, sync.highest_block, sync.starting_block),
+            (32, 40, 0)
+        );
+        assert!(decode_lenient::<SyncState>("system_syncState", serde_json::json!({})).is_none());
+    }
 }
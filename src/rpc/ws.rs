@@ -0,0 +1,185 @@
+//! WebSocket head subscriptions
+//!
+//! Polling `chain_getHeader` only notices a block on the next tick. Over a
+//! WebSocket the node pushes each imported (or finalized) header instead, so
+//! the sync daemon can fetch it straight away. Ordinary calls still go over
+//! HTTP: Substrate nodes serve both protocols on the same RPC port.
+
+use super::types::BlockHeader;
+use anyhow::{bail, Context, Result};
+use futures_util::SinkExt;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_stream::StreamExt;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+/// Request id of the subscribe call (each subscription has its own connection)
+const SUBSCRIBE_ID: u64 = 1;
+
+/// Whether an endpoint is a WebSocket URL (`ws://` or `wss://`)
+pub fn is_websocket_url(url: &str) -> bool {
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
+
+/// The HTTP URL serving the same node as a WebSocket URL (others unchanged)
+pub fn http_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else {
+        url.to_string()
+    }
+}
+
+/// A live `chain_subscribeNewHeads` or `chain_subscribeFinalizedHeads` stream
+pub struct HeadSubscription {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    id: Value,
+}
+
+impl HeadSubscription {
+    /// Connect to `url` and subscribe to new heads, or finalized heads only
+    pub async fn connect(url: &str, finalized: bool, timeout: Duration) -> Result<Self> {
+        let method = if finalized {
+            "chain_subscribeFinalizedHeads"
+        } else {
+            "chain_subscribeNewHeads"
+        };
+
+        let (mut stream, _) = tokio::time::timeout(timeout, connect_async(url))
+            .await
+            .with_context(|| format!("Timed out connecting to {}", url))?
+            .with_context(|| format!("Failed to connect to {}", url))?;
+
+        let request = json!({"jsonrpc": "2.0", "id": SUBSCRIBE_ID, "method": method, "params": []});
+        stream
+            .send(Message::text(request.to_string()))
+            .await
+            .context("Failed to send subscribe request")?;
+
+        // The node may start pushing headers before we've seen the reply, but
+        // those can only belong to this subscription, so waiting for it is safe
+        loop {
+            let message = tokio::time::timeout(timeout, read_json(&mut stream))
+                .await
+                .with_context(|| format!("Timed out waiting for {} reply", method))??;
+            if message.get("id").and_then(Value::as_u64) != Some(SUBSCRIBE_ID) {
+                continue;
+            }
+            if let Some(error) = message.get("error").filter(|e| !e.is_null()) {
+                bail!(
+                    "RPC error {}: {}",
+                    error["code"],
+                    error["message"].as_str().unwrap_or_default()
+                );
+            }
+            let id = message
+                .get("result")
+                .filter(|r| !r.is_null())
+                .cloned()
+                .context("Subscribe response missing result field")?;
+            return Ok(Self { stream, id });
+        }
+    }
+
+    /// Wait for the next header the node pushes
+    ///
+    /// Fails once the connection drops; callers fall back to polling and
+    /// subscribe again later.
+    pub async fn next(&mut self) -> Result<BlockHeader> {
+        loop {
+            let message = read_json(&mut self.stream).await?;
+            let params = &message["params"];
+            if params["subscription"] != self.id {
+                continue;
+            }
+            return serde_json::from_value(params["result"].clone())
+                .context("Failed to parse pushed header");
+        }
+    }
+}
+
+/// Read the next JSON text message, skipping control frames
+async fn read_json(stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>) -> Result<Value> {
+    loop {
+        let message = stream
+            .next()
+            .await
+            .context("Connection closed by node")?
+            .context("WebSocket read failed")?;
+        match message {
+            Message::Text(text) => {
+                return serde_json::from_str(&text).context("Failed to parse WebSocket message")
+            }
+            Message::Close(_) => bail!("Connection closed by node"),
+            // Pings are answered by the library on the next read
+            _ => continue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn test_http_url() {
+        assert!(is_websocket_url("ws://localhost:9944"));
+        assert!(is_websocket_url("wss://rpc.example.com"));
+        assert!(!is_websocket_url("http://localhost:9944"));
+        assert_eq!(http_url("ws://localhost:9944"), "http://localhost:9944");
+        assert_eq!(
+            http_url("wss://rpc.example.com/"),
+            "https://rpc.example.com/"
+        );
+        assert_eq!(http_url("http://localhost:9944"), "http://localhost:9944");
+    }
+
+    #[tokio::test]
+    async fn test_head_subscription() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let request: Value = match ws.next().await.unwrap().unwrap() {
+                Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                other => panic!("unexpected message {:?}", other),
+            };
+            assert_eq!(request["method"], "chain_subscribeFinalizedHeads");
+
+            let push = |subscription: &str, number: &str| {
+                Message::text(
+                    json!({
+                        "jsonrpc": "2.0",
+                        "method": "chain_finalizedHead",
+                        "params": {"subscription": subscription, "result": {"number": number}}
+                    })
+                    .to_string(),
+                )
+            };
+            ws.send(Message::text(
+                json!({"jsonrpc": "2.0", "id": request["id"], "result": "sub-1"}).to_string(),
+            ))
+            .await
+            .unwrap();
+            // Headers for other subscriptions are ignored
+            ws.send(push("sub-0", "0x63")).await.unwrap();
+            ws.send(push("sub-1", "0x64")).await.unwrap();
+            ws.send(push("sub-1", "0x65")).await.unwrap();
+            ws.close(None).await.unwrap();
+        });
+
+        let url = format!("ws://{}", addr);
+        let mut heads = HeadSubscription::connect(&url, true, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(heads.next().await.unwrap().block_number(), 100);
+        assert_eq!(heads.next().await.unwrap().block_number(), 101);
+        assert!(heads.next().await.is_err());
+    }
+}
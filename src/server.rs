@@ -0,0 +1,465 @@
+//! Built-in HTTP server for health probes and MVM's own metrics
+//!
+//! Serves lightweight endpoints for Kubernetes, load balancers and Prometheus:
+//! - `/healthz` - process is alive (always 200)
+//! - `/readyz`  - last successful RPC fetch is recent AND the node is healthy
+//!   per `[thresholds]` (503 with a JSON reason when degraded)
+//! - `/metrics` - MVM's derived stats as Prometheus gauges (only those the
+//!   running command knows; `sync` has no validator view, so just its lag)
+//!
+//! Disabled unless `server.listen_addr` (or `--listen`) is set.
+//!
+//! A metrics-only listener (`server.exporter_addr` or `--exporter-bind`)
+//! serves the same `/metrics` for Prometheus without exposing the probes.
+
+use anyhow::{Context, Result};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info};
+
+/// Maximum request header size we are willing to read
+const MAX_REQUEST_BYTES: usize = 8192;
+
+/// Readiness inputs published by the running command
+#[derive(Debug, Clone)]
+pub struct HealthState {
+    /// True until the first successful data fetch
+    pub is_loading: bool,
+    /// Time of the last successful RPC fetch
+    pub last_success: Option<Instant>,
+    /// Why the node is unhealthy (None when synced and within thresholds)
+    pub node_issue: Option<String>,
+    /// Most recent error, if any
+    pub last_error: Option<String>,
+}
+
+impl Default for HealthState {
+    fn default() -> Self {
+        Self {
+            is_loading: true,
+            last_success: None,
+            node_issue: Some("node is not synced".to_string()),
+            last_error: None,
+        }
+    }
+}
+
+impl HealthState {
+    /// Check readiness, returning the reason when degraded
+    pub fn readiness(&self, max_age: Duration) -> std::result::Result<(), String> {
+        if self.is_loading {
+            return Err("initial data fetch has not completed".to_string());
+        }
+
+        match self.last_success {
+            None => return Err("no successful RPC fetch yet".to_string()),
+            Some(at) if at.elapsed() > max_age => {
+                return Err(format!(
+                    "last successful RPC fetch was {}s ago (limit {}s)",
+                    at.elapsed().as_secs(),
+                    max_age.as_secs()
+                ));
+            }
+            Some(_) => {}
+        }
+
+        if let Some(ref issue) = self.node_issue {
+            return Err(issue.clone());
+        }
+
+        Ok(())
+    }
+}
+
+/// Health state shared between the command loop and the HTTP server
+pub type SharedHealth = Arc<Mutex<HealthState>>;
+
+/// Stats computed by the running command, exported as Prometheus gauges
+///
+/// Unknown values are None and left out of `/metrics` rather than shown as 0.
+#[derive(Debug, Clone, Default)]
+pub struct ExporterState {
+    /// Committee seats our validators hold this sidechain epoch
+    pub our_seats: Option<u64>,
+    /// Blocks our validators produced this sidechain epoch
+    pub blocks_this_epoch: Option<u64>,
+    /// Blocks expected so far this epoch from our seats
+    pub expected_blocks: Option<f64>,
+    /// Total committee seats this epoch
+    pub committee_size: Option<u64>,
+    /// Blocks MVM's database trails the chain tip by
+    pub sync_lag_blocks: Option<u64>,
+    /// Share of tracked epochs each of our dynamic validators was selected in (0-1), by sidechain key
+    pub selection_rates: Vec<(String, f64)>,
+}
+
+impl ExporterState {
+    /// Prometheus text exposition of the gauges
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, samples: &[(String, f64)]| {
+            if samples.is_empty() {
+                return;
+            }
+            out.push_str(&format!(
+                "# HELP {} {}\n# TYPE {} gauge\n",
+                name, help, name
+            ));
+            for (labels, value) in samples {
+                out.push_str(&format!("{}{} {}\n", name, labels, value));
+            }
+        };
+        let single = |value: Option<f64>| -> Vec<(String, f64)> {
+            value.map(|v| (String::new(), v)).into_iter().collect()
+        };
+        gauge(
+            "mvm_our_seats",
+            "Committee seats held by our validators this sidechain epoch",
+            &single(self.our_seats.map(|v| v as f64)),
+        );
+        gauge(
+            "mvm_blocks_this_epoch",
+            "Blocks produced by our validators this sidechain epoch",
+            &single(self.blocks_this_epoch.map(|v| v as f64)),
+        );
+        gauge(
+            "mvm_expected_blocks",
+            "Blocks expected from our seats so far this sidechain epoch",
+            &single(self.expected_blocks),
+        );
+        gauge(
+            "mvm_committee_size",
+            "Total committee seats this sidechain epoch",
+            &single(self.committee_size.map(|v| v as f64)),
+        );
+        gauge(
+            "mvm_sync_lag_blocks",
+            "Blocks the MVM database trails the chain tip by",
+            &single(self.sync_lag_blocks.map(|v| v as f64)),
+        );
+        let rates: Vec<(String, f64)> = self
+            .selection_rates
+            .iter()
+            .map(|(key, rate)| (format!("{{sidechain_key=\"{}\"}}", key), *rate))
+            .collect();
+        gauge(
+            "mvm_selection_rate",
+            "Share of tracked epochs our dynamic validator held committee seats in",
+            &rates,
+        );
+        out
+    }
+}
+
+/// Exporter state shared between the command loop and the HTTP server
+pub type SharedExporter = Arc<Mutex<ExporterState>>;
+
+/// Response status, content type and body
+type Response = (u16, &'static str, String);
+
+/// Maps a request method and path to a response
+type Responder = Arc<dyn Fn(&str, &str) -> Response + Send + Sync>;
+
+const JSON: &str = "application/json";
+const PROMETHEUS_TEXT: &str = "text/plain; version=0.0.4";
+
+/// Bind the HTTP server and serve requests in the background
+///
+/// Binding happens before this returns so address errors surface immediately.
+pub async fn start(
+    addr: &str,
+    health: SharedHealth,
+    exporter: SharedExporter,
+    ready_max_age: Duration,
+) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind health server to {}", addr))?;
+    info!(
+        "Health and metrics endpoints listening on http://{} (/healthz, /readyz, /metrics)",
+        addr
+    );
+
+    serve(
+        listener,
+        Arc::new(move |method, path| {
+            if path.split('?').next() == Some("/metrics") {
+                let state = exporter
+                    .lock()
+                    .map(|s| s.clone())
+                    .unwrap_or_else(|poisoned| poisoned.into_inner().clone());
+                return route_metrics(method, path, &state);
+            }
+            let state = health
+                .lock()
+                .map(|s| s.clone())
+                .unwrap_or_else(|poisoned| poisoned.into_inner().clone());
+            let (status, body) = route(method, path, &state, ready_max_age);
+            (status, JSON, body)
+        }),
+    );
+    Ok(())
+}
+
+/// Bind the Prometheus exporter and serve `/metrics` in the background
+///
+/// Returns the bound address (useful with port 0).
+pub async fn start_exporter(addr: &str, exporter: SharedExporter) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind exporter to {}", addr))?;
+    let local_addr = listener.local_addr()?;
+    info!(
+        "Prometheus exporter listening on http://{}/metrics",
+        local_addr
+    );
+
+    serve(
+        listener,
+        Arc::new(move |method, path| {
+            let state = exporter
+                .lock()
+                .map(|s| s.clone())
+                .unwrap_or_else(|poisoned| poisoned.into_inner().clone());
+            route_metrics(method, path, &state)
+        }),
+    );
+    Ok(local_addr)
+}
+
+/// Accept connections until the process exits, answering each with `respond`
+fn serve(listener: TcpListener, respond: Responder) {
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let respond = respond.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, respond).await {
+                            debug!("HTTP request failed: {}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    debug!("Failed to accept HTTP connection: {}", e);
+                }
+            }
+        }
+    });
+}
+
+async fn handle_connection(mut stream: TcpStream, respond: Responder) -> Result<()> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+
+    // Read until end of headers; we never need the body
+    loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if buf.windows(4).any(|w| w == b"\r\n\r\n") || buf.len() >= MAX_REQUEST_BYTES {
+            break;
+        }
+    }
+
+    let request = String::from_utf8_lossy(&buf);
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    let (status, content_type, body) = respond(method, path);
+
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason_phrase(status),
+        content_type,
+        body.len(),
+        if method == "HEAD" { "" } else { body.as_str() }
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await.ok();
+
+    Ok(())
+}
+
+/// Map a request to a status code and JSON body
+fn route(method: &str, path: &str, state: &HealthState, ready_max_age: Duration) -> (u16, String) {
+    if method != "GET" && method != "HEAD" {
+        return (405, r#"{"error":"method not allowed"}"#.to_string());
+    }
+
+    // Ignore any query string
+    let path = path.split('?').next().unwrap_or(path);
+
+    match path {
+        "/healthz" => (200, r#"{"status":"ok"}"#.to_string()),
+        "/readyz" => match state.readiness(ready_max_age) {
+            Ok(()) => (200, r#"{"status":"ready"}"#.to_string()),
+            Err(reason) => (
+                503,
+                serde_json::json!({
+                    "status": "not_ready",
+                    "reason": reason,
+                    "last_error": state.last_error,
+                })
+                .to_string(),
+            ),
+        },
+        _ => (404, r#"{"error":"not found"}"#.to_string()),
+    }
+}
+
+/// Map a `/metrics` request to a Prometheus text response
+fn route_metrics(method: &str, path: &str, state: &ExporterState) -> Response {
+    if method != "GET" && method != "HEAD" {
+        return (405, PROMETHEUS_TEXT, "method not allowed\n".to_string());
+    }
+    match path.split('?').next().unwrap_or(path) {
+        "/metrics" => (200, PROMETHEUS_TEXT, state.render()),
+        _ => (404, PROMETHEUS_TEXT, "not found\n".to_string()),
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ready_state() -> HealthState {
+        HealthState {
+            is_loading: false,
+            last_success: Some(Instant::now()),
+            node_issue: None,
+            last_error: None,
+        }
+    }
+
+    #[test]
+    fn test_healthz_always_ok() {
+        let state = HealthState::default();
+        let (status, _) = route("GET", "/healthz", &state, Duration::from_secs(60));
+        assert_eq!(status, 200);
+    }
+
+    #[test]
+    fn test_readyz() {
+        let max_age = Duration::from_secs(60);
+
+        let (status, _) = route("GET", "/readyz", &ready_state(), max_age);
+        assert_eq!(status, 200);
+
+        // Still loading
+        let (status, body) = route("GET", "/readyz", &HealthState::default(), max_age);
+        assert_eq!(status, 503);
+        assert!(body.contains("not_ready"));
+
+        // Node behind
+        let mut state = ready_state();
+        state.node_issue = Some("node is not synced".to_string());
+        state.last_error = Some("RPC error: timeout".to_string());
+        let (status, body) = route("GET", "/readyz", &state, max_age);
+        assert_eq!(status, 503);
+        assert!(body.contains("node is not synced"));
+        assert!(body.contains("RPC error: timeout"));
+
+        // Finality lagging beyond threshold
+        let mut state = ready_state();
+        state.node_issue = crate::config::ThresholdsConfig::default()
+            .assess_node(true, Some(8), 500)
+            .err();
+        let (status, body) = route("GET", "/readyz", &state, max_age);
+        assert_eq!(status, 503);
+        assert!(body.contains("500 behind"));
+
+        // Stale fetch
+        let mut state = ready_state();
+        state.last_success = Instant::now().checked_sub(Duration::from_secs(120));
+        let (status, _) = route("GET", "/readyz", &state, max_age);
+        assert_eq!(status, 503);
+    }
+
+    #[test]
+    fn test_exporter_metrics() {
+        let state = ExporterState {
+            our_seats: Some(3),
+            blocks_this_epoch: Some(2),
+            expected_blocks: Some(2.5),
+            committee_size: Some(300),
+            sync_lag_blocks: Some(1),
+            selection_rates: vec![("0xabc".to_string(), 0.25)],
+        };
+        let (status, content_type, body) = route_metrics("GET", "/metrics", &state);
+        assert_eq!(status, 200);
+        assert_eq!(content_type, PROMETHEUS_TEXT);
+        assert!(body.contains("# TYPE mvm_our_seats gauge\nmvm_our_seats 3\n"));
+        assert!(body.contains("mvm_expected_blocks 2.5\n"));
+        assert!(body.contains("mvm_committee_size 300\n"));
+        assert!(body.contains("mvm_sync_lag_blocks 1\n"));
+        assert!(body.contains("mvm_selection_rate{sidechain_key=\"0xabc\"} 0.25\n"));
+
+        assert_eq!(route_metrics("GET", "/healthz", &state).0, 404);
+        assert_eq!(route_metrics("POST", "/metrics", &state).0, 405);
+
+        // `mvm sync` only knows its lag; unknown gauges are left out, not zero
+        let state = ExporterState {
+            sync_lag_blocks: Some(0),
+            ..ExporterState::default()
+        };
+        let body = route_metrics("GET", "/metrics", &state).2;
+        assert!(body.contains("mvm_sync_lag_blocks 0\n"));
+        assert!(!body.contains("mvm_our_seats"));
+        assert!(!body.contains("mvm_selection_rate"));
+    }
+
+    #[tokio::test]
+    async fn test_start_exporter() {
+        let exporter: SharedExporter = Arc::new(Mutex::new(ExporterState {
+            our_seats: Some(4),
+            ..ExporterState::default()
+        }));
+        let addr = start_exporter("127.0.0.1:0", exporter.clone())
+            .await
+            .unwrap();
+        let get = |path: &'static str| async move {
+            let response = reqwest::get(format!("http://{}{}", addr, path))
+                .await
+                .unwrap();
+            (response.status().as_u16(), response.text().await.unwrap())
+        };
+
+        let (status, body) = get("/metrics").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("mvm_our_seats 4\n"));
+
+        // Updates from the command loop show on the next scrape
+        exporter.lock().unwrap().our_seats = Some(5);
+        assert!(get("/metrics").await.1.contains("mvm_our_seats 5\n"));
+
+        // Only /metrics; the probes stay on the health server
+        assert_eq!(get("/healthz").await.0, 404);
+    }
+
+    #[test]
+    fn test_unknown_routes() {
+        let state = ready_state();
+        let max_age = Duration::from_secs(60);
+        assert_eq!(route("GET", "/nope", &state, max_age).0, 404);
+        assert_eq!(route("POST", "/healthz", &state, max_age).0, 405);
+        assert_eq!(route("GET", "/readyz?verbose=1", &state, max_age).0, 200);
+    }
+}
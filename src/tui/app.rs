@@ -1,15 +1,31 @@
 //! Application state management for TUI
 
+use crate::alerts::{GrandpaVoterAlert, GrandpaVoterMonitor, PeerFloorAlert, PeerFloorMonitor};
+use crate::config::{
+    Charset, CommitteeRef, DisplayTimeZone, IpFilter, RewardsConfig, ThresholdsConfig,
+};
 use crate::db::{
-    BlockRecord, CommitteeSelectionStats, Database, ValidatorEpochHistoryRecord,
-    ValidatorEpochRecord, ValidatorRecord,
+    audit_epoch, explain_busy, AuditIssue, BlockRecord, CommitteeChurn, CommitteeSelectionStats,
+    Database, DynamicValidatorRank, EpochAuthorShare, EpochRank, LivenessScore, SlotCollision,
+    StakeChange, ValidatorEpochHistoryRecord, ValidatorEpochRecord, ValidatorRecord,
+};
+use crate::metrics::{
+    BreakerTransition, MetricsClient, NodeExporterClient, ScrapeBreaker, BREAKER_RETRY_INTERVAL,
 };
-use crate::metrics::{MetricsClient, NodeExporterClient};
-use crate::midnight::{ChainTiming, KnownValidators, ValidatorSet};
-use crate::rpc::{RpcClient, SidechainStatus};
+use crate::midnight::validators::Validator;
+use crate::midnight::{
+    estimate_rewards, extract_slot_from_digest, get_key_status, parse_metadata,
+    project_epoch_blocks, summarize_calls, ChainTiming, EpochProjection, KeyMismatch,
+    KnownValidators, RegistrationStatus, RewardEstimate, RuntimeCalls, StatePruning, ValidatorKeys,
+    ValidatorSet,
+};
+use crate::rpc::{decode_lenient, RpcClient, RpcMethod, SidechainStatus, SignedBlock, SlowCall};
+use crate::server::{ExporterState, HealthState};
 use crate::tui::Theme;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::time::{Duration, Instant};
 
 /// View modes for the TUI
@@ -20,23 +36,220 @@ pub enum ViewMode {
     Validators,
     Performance,
     Peers,
+    /// Reverse-chronological log of alerts and state transitions
+    Events,
     Help,
     /// Drill-down view for validator epoch history
     ValidatorEpochDetail,
+    /// Full-screen summary of our validator's key metrics (wall displays)
+    Focus,
+}
+
+/// Maximum number of events kept in the Events view
+const MAX_EVENTS: usize = 500;
+
+/// Largest host clock offset believed; beyond this the tip is stale, not the clock
+const MAX_CLOCK_SKEW_MS: u64 = 10 * 60 * 1000;
+
+/// Completed epochs the identity popup's liveness score covers
+const LIVENESS_WINDOW_EPOCHS: usize = 12;
+
+/// Epochs of stake history searched for a previous snapshot to compare against
+const STAKE_HISTORY_EPOCHS: usize = 10;
+
+/// Completed epochs shown in the identity popup's rank history
+const RANK_HISTORY_EPOCHS: usize = 8;
+
+/// Newest slot collisions turned into Events entries per refresh
+const SLOT_COLLISION_EVENTS: u32 = 10;
+
+/// Sidechain epochs (current and preceding) covered by the seats-vs-blocks audit
+const AUDIT_EPOCHS: u64 = 3;
+
+/// Format a lovelace amount as tADA with a K/M suffix
+pub fn format_stake(lovelace: u64) -> String {
+    // Convert lovelace to ADA (1 ADA = 1,000,000 lovelace)
+    let ada = lovelace as f64 / 1_000_000.0;
+    if ada >= 1_000_000.0 {
+        format!("{:.2}M tADA", ada / 1_000_000.0)
+    } else if ada >= 1_000.0 {
+        format!("{:.2}K tADA", ada / 1_000.0)
+    } else {
+        format!("{:.2} tADA", ada)
+    }
+}
+
+/// Severity of a monitor event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl EventSeverity {
+    /// Short fixed-width label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            EventSeverity::Info => "INFO ",
+            EventSeverity::Warning => "WARN ",
+            EventSeverity::Error => "ERROR",
+        }
+    }
+}
+
+/// An alert or state transition shown in the Events view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorEvent {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    pub severity: EventSeverity,
+    /// Event source (e.g. "epoch", "sync", "peers")
+    pub category: String,
+    pub message: String,
+}
+
+/// State compared across refreshes to detect transitions
+struct TransitionSnapshot {
+    is_loading: bool,
+    chain_phase: ChainPhase,
+    sidechain_epoch: u64,
+    mainchain_epoch: u64,
+    is_synced: bool,
+    peer_count: u64,
+    node_version: String,
+    committee_size: usize,
+    committee_elected: bool,
+    last_error: Option<String>,
+}
+
+impl TransitionSnapshot {
+    fn capture(state: &AppState) -> Self {
+        Self {
+            is_loading: state.is_loading,
+            chain_phase: state.chain_phase,
+            sidechain_epoch: state.sidechain_epoch,
+            mainchain_epoch: state.mainchain_epoch,
+            is_synced: state.sync_progress.is_synced,
+            peer_count: state.peer_count,
+            node_version: state.node_version.clone(),
+            committee_size: state.committee_size,
+            committee_elected: state.committee_elected,
+            last_error: state.last_error.clone(),
+        }
+    }
+}
+
+/// Sort order for validator lists (Validators and Performance views)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// Permissioned validators first, then by seats descending
+    Permissioned,
+    /// Committee seats in the current epoch, descending
+    Seats,
+    /// Blocks produced in the current epoch, descending
+    EpochBlocks,
+    /// All-time blocks produced, descending
+    TotalBlocks,
+    /// Stake delegation, descending
+    Stake,
+    /// Sidechain key, ascending
+    Key,
+}
+
+impl SortKey {
+    /// Next sort key in the cycle
+    pub fn next(self) -> Self {
+        match self {
+            SortKey::Permissioned => SortKey::Seats,
+            SortKey::Seats => SortKey::EpochBlocks,
+            SortKey::EpochBlocks => SortKey::TotalBlocks,
+            SortKey::TotalBlocks => SortKey::Stake,
+            SortKey::Stake => SortKey::Key,
+            SortKey::Key => SortKey::Permissioned,
+        }
+    }
+
+    /// Short label for view titles
+    pub fn label(self) -> &'static str {
+        match self {
+            SortKey::Permissioned => "permissioned",
+            SortKey::Seats => "seats",
+            SortKey::EpochBlocks => "epoch blocks",
+            SortKey::TotalBlocks => "all-time blocks",
+            SortKey::Stake => "stake",
+            SortKey::Key => "key",
+        }
+    }
+
+    /// Default sort key for a view
+    pub fn default_for(view: ViewMode) -> Self {
+        match view {
+            ViewMode::Performance => SortKey::TotalBlocks,
+            _ => SortKey::Permissioned,
+        }
+    }
+}
+
+/// Compare two validators by the given sort key
+///
+/// Ties are broken by sidechain key so the ordering is stable between refreshes.
+pub fn compare_validators(
+    a: &ValidatorRecord,
+    b: &ValidatorRecord,
+    key: SortKey,
+    epoch_data: &HashMap<String, ValidatorEpochRecord>,
+    epoch_blocks: &HashMap<String, u64>,
+) -> std::cmp::Ordering {
+    let seats = |v: &ValidatorRecord| {
+        epoch_data
+            .get(&v.sidechain_key)
+            .map(|e| e.committee_seats)
+            .unwrap_or(0)
+    };
+    let stake = |v: &ValidatorRecord| {
+        epoch_data
+            .get(&v.sidechain_key)
+            .and_then(|e| e.stake_lovelace)
+            .unwrap_or(0)
+    };
+    let blocks = |v: &ValidatorRecord| epoch_blocks.get(&v.sidechain_key).copied().unwrap_or(0);
+
+    let ordering = match key {
+        SortKey::Permissioned => {
+            let a_perm = a.registration_status.as_deref() == Some("permissioned");
+            let b_perm = b.registration_status.as_deref() == Some("permissioned");
+            b_perm.cmp(&a_perm).then_with(|| seats(b).cmp(&seats(a)))
+        }
+        SortKey::Seats => seats(b).cmp(&seats(a)),
+        SortKey::EpochBlocks => blocks(b).cmp(&blocks(a)),
+        SortKey::TotalBlocks => b.total_blocks.cmp(&a.total_blocks),
+        SortKey::Stake => stake(b).cmp(&stake(a)),
+        SortKey::Key => std::cmp::Ordering::Equal,
+    };
+
+    ordering.then_with(|| a.sidechain_key.cmp(&b.sidechain_key))
 }
 
 /// Popup content for modal overlays
 #[derive(Debug, Clone)]
 pub enum PopupContent {
     /// Block detail popup showing full block information
-    BlockDetail { block: BlockRecord },
+    BlockDetail {
+        block: BlockRecord,
+        /// Call breakdown ("1 timestamp.set, ..."), once decoded (view.decode_extrinsics)
+        calls: Option<String>,
+    },
     /// Peer detail popup showing peer connection details
     PeerDetail { peer: PeerInfo },
     /// Validator detail popup showing epoch history (from Performance view)
     ValidatorDetail {
         validator: ValidatorRecord,
+        /// Most recent epoch first
         epoch_history: Vec<ValidatorEpochHistoryRecord>,
         scroll_index: usize,
+        /// Older epochs may exist beyond the loaded set
+        has_more: bool,
     },
     /// Validator identity card (from Validators view)
     ValidatorIdentity {
@@ -45,9 +258,85 @@ pub enum PopupContent {
         current_epoch_seats: u32,
         committee_size: u32,
         blocks_this_epoch: u64,
+        /// Recency-weighted blocks vs expected over recent completed epochs
+        liveness: Option<LivenessScore>,
         stake_display: Option<String>,
-        selection_stats: Option<CommitteeSelectionStats>,
+        /// Stake movement between the two most recent snapshots
+        stake_change: Option<StakeChange>,
+        /// This validator's remaining slots in the current epoch
+        upcoming_slots: Vec<u64>,
+        selection_stats: Option<Box<CommitteeSelectionStats>>,
+        /// Rough reward earning estimate over recent epochs
+        reward_estimate: Option<Box<RewardEstimate>>,
+        /// Rank by blocks produced in the current epoch
+        rank: Option<EpochRank>,
+        /// Ranks in recent completed epochs, oldest first
+        rank_history: Vec<EpochRank>,
+    },
+    /// Side-by-side comparison of two marked validators (from Performance view)
+    ValidatorCompare {
+        sides: Box<[ValidatorComparison; 2]>,
     },
+    /// Block production by author for one sidechain epoch (from Performance view)
+    EpochDistribution {
+        epoch: u64,
+        shares: Vec<EpochAuthorShare>,
+    },
+    /// Symbol and color legend for a view (`?` outside the Help view)
+    Legend { view: ViewMode },
+}
+
+/// One side of the validator comparison popup
+#[derive(Debug, Clone)]
+pub struct ValidatorComparison {
+    pub validator: ValidatorRecord,
+    pub selection_stats: Option<CommitteeSelectionStats>,
+    /// All tracked epochs, most recent first
+    pub epoch_history: Vec<ValidatorEpochHistoryRecord>,
+    pub stake_lovelace: Option<u64>,
+}
+
+impl ValidatorComparison {
+    /// Seats over the most recent `epochs` tracked epochs, oldest first
+    pub fn seats_trend(&self, epochs: usize) -> Vec<u64> {
+        self.epoch_history
+            .iter()
+            .take(epochs)
+            .rev()
+            .map(|r| r.seats as u64)
+            .collect()
+    }
+
+    /// Average blocks per completed tracked epoch
+    pub fn blocks_per_epoch(&self, current_epoch: u64) -> Option<f64> {
+        let completed: Vec<_> = self
+            .epoch_history
+            .iter()
+            .filter(|r| r.epoch < current_epoch)
+            .collect();
+        if completed.is_empty() {
+            return None;
+        }
+        let blocks: u64 = completed.iter().map(|r| r.blocks_produced).sum();
+        Some(blocks as f64 / completed.len() as f64)
+    }
+
+    /// Blocks produced as a percentage of expected across completed tracked epochs
+    ///
+    /// None when the validator never held seats in a completed epoch.
+    pub fn production_rate(&self, timing: &ChainTiming, current_epoch: u64) -> Option<f64> {
+        let (produced, expected) = self
+            .epoch_history
+            .iter()
+            .filter(|r| r.epoch < current_epoch)
+            .fold((0u64, 0.0), |(produced, expected), r| {
+                (
+                    produced + r.blocks_produced,
+                    expected + timing.expected_blocks(r.seats as u64, r.committee_size as u64, 1.0),
+                )
+            });
+        (expected > 0.0).then(|| produced as f64 / expected * 100.0)
+    }
 }
 
 /// Entry in the view stack for drill-down navigation
@@ -71,6 +360,8 @@ pub struct App {
     pub should_quit: bool,
     /// Filter to show only our validators
     pub show_ours_only: bool,
+    /// Validators view as a leaderboard of dynamic validators by stake
+    pub show_dynamic_only: bool,
     /// Per-view selection indices (preserved when switching views)
     pub view_selections: HashMap<ViewMode, usize>,
     /// Popup overlay (Block/Peer detail)
@@ -87,24 +378,80 @@ pub struct App {
     pub state: AppState,
     /// Last update timestamp
     pub last_update: Instant,
+    /// Manual refresh (`r`) pending or in flight; cleared when `update` finishes
+    pub refresh_requested: bool,
+    /// Decode extrinsic call names in the block popup (view.decode_extrinsics)
+    pub decode_extrinsics: bool,
+    /// The open block popup is waiting for its call breakdown (`take_block_calls_request`)
+    pub block_calls_requested: bool,
+    /// Metadata-derived call names, keyed by runtime spec version
+    runtime_calls: Option<(u64, RuntimeCalls)>,
     /// Previous sync block for rate calculation
     prev_sync_block: u64,
     /// Previous sync check time for rate calculation
     prev_sync_time: Instant,
     /// Smoothed sync rate (exponential moving average)
     smoothed_sync_rate: f64,
+    /// Previous database block count for insert rate calculation
+    prev_db_blocks: u64,
+    /// Previous database check time for insert rate calculation
+    prev_db_time: Instant,
+    /// Smoothed database insert rate (exponential moving average)
+    smoothed_db_rate: f64,
+    /// When MVM started (Unix seconds), the restart baseline until node metrics arrive
+    started_at: i64,
+    /// Tip slot at the previous refresh, so skew is only sampled from fresh blocks
+    prev_tip_slot: Option<u64>,
     /// Color theme
     pub theme: Theme,
-    /// Expected IP for filtering external addresses (from config)
-    pub expected_ip: Option<String>,
+    /// Sparkline and progress-bar characters (view.charset)
+    pub charset: Charset,
+    /// Filter for external addresses (view.expected_ip, exact or CIDR)
+    pub expected_ip: Option<IpFilter>,
+    /// Pinned external address for the Identity row (network.public_ip)
+    pub public_ip: Option<String>,
+    /// P2P port whose addresses are preferred for display (network.p2p_port)
+    pub p2p_port: u16,
     /// Chain timing parameters (network-specific)
     pub chain_timing: ChainTiming,
     /// Known validators registry (optional labels)
     pub known_validators: KnownValidators,
+    /// Per-view sort order for validator lists
+    pub sort_keys: HashMap<ViewMode, SortKey>,
+    /// Validators marked for side-by-side comparison (Performance view, at most two)
+    pub compare_marks: Vec<String>,
+    /// Block the committee election check queries at (from config)
+    pub committee_ref: CommitteeRef,
+    /// Finalization lag samples to keep (from config)
+    pub finality_history_len: usize,
+    /// Epochs loaded per step in the validator detail popup
+    pub history_epochs: usize,
+    /// Node health thresholds (from config)
+    pub thresholds: ThresholdsConfig,
+    /// Debounces the critical peer floor alert
+    peer_floor: PeerFloorMonitor,
+    /// Alert when the node stops being a GRANDPA voter (from config)
+    pub grandpa_voter_alert: bool,
+    grandpa_voter_monitor: GrandpaVoterMonitor,
+    /// Backs off the Prometheus / node_exporter scrapes while they fail
+    metrics_breaker: ScrapeBreaker,
+    node_exporter_breaker: ScrapeBreaker,
+    /// Keys from the local keystore (from config), compared against on-chain registration
+    pub local_keys: Option<ValidatorKeys>,
+    /// Mainchain epoch the registered keys were last checked in
+    key_check_epoch: Option<u64>,
+    /// Time zone for displayed timestamps (from config)
+    pub time_zone: DisplayTimeZone,
+    /// Reward estimate settings (from config)
+    pub rewards: RewardsConfig,
+    /// This node's place in the node switcher as (1-based index, count), when monitoring several
+    pub node_position: Option<(usize, usize)>,
+    /// State dump being shown by `mvm view --replay` (no live data)
+    pub replay_source: Option<String>,
 }
 
 /// Epoch progress information
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct EpochProgress {
     /// Current slot within the epoch
@@ -129,8 +476,37 @@ pub struct EpochProgress {
     pub mainchain_time_remaining_secs: u64,
 }
 
+/// Whether the chain has anything to monitor yet
+///
+/// A freshly started network (or a node connected before genesis) reports a
+/// zero tip and no sidechain epoch, which would otherwise render as "epoch 0"
+/// bars and 100% sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChainPhase {
+    /// Blocks are being produced and the sidechain epoch is known
+    #[default]
+    Running,
+    /// Node is up but nothing beyond genesis has been produced
+    NoBlocks,
+    /// Blocks exist but the sidechain hasn't reported an epoch yet
+    SidechainNotStarted,
+}
+
+impl ChainPhase {
+    /// Classify from the chain tip and the sidechain epoch (None when unavailable)
+    pub fn detect(chain_tip: u64, sidechain_epoch: Option<u64>) -> Self {
+        if chain_tip == 0 {
+            ChainPhase::NoBlocks
+        } else if sidechain_epoch.unwrap_or(0) == 0 {
+            ChainPhase::SidechainNotStarted
+        } else {
+            ChainPhase::Running
+        }
+    }
+}
+
 /// Node sync progress information
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct SyncProgress {
     /// Current block the node has synced to
@@ -151,7 +527,140 @@ pub struct SyncProgress {
     pub eta_seconds: Option<u64>,
 }
 
+/// Current Unix time in milliseconds
+fn unix_now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Host clock offset from chain time in ms (positive = host ahead)
+///
+/// The tip was authored in its AURA slot, so a correct host clock reads between
+/// the slot start and one slot later; offsets inside that window count as zero.
+pub fn clock_skew_ms(tip_slot: u64, slot_duration_ms: u64, now_ms: u64) -> i64 {
+    let delta = now_ms as i64 - (tip_slot * slot_duration_ms) as i64;
+    if delta < 0 {
+        delta
+    } else {
+        (delta - slot_duration_ms as i64).max(0)
+    }
+}
+
+/// Order our public `ip:port` addresses for display, preferred first
+///
+/// Addresses outside `filter` are dropped. A pinned `public_ip` comes first
+/// (added if the node didn't report it), then addresses on the P2P port, then
+/// the rest in sorted order so the choice is stable between refreshes.
+fn rank_external_addrs(
+    mut addrs: Vec<String>,
+    filter: Option<&IpFilter>,
+    public_ip: Option<&str>,
+    p2p_port: u16,
+) -> Vec<String> {
+    let parsed = |addr: &str| addr.parse::<SocketAddr>().ok();
+    if let Some(filter) = filter {
+        addrs.retain(|addr| parsed(addr).is_some_and(|a| filter.matches(a.ip())));
+    }
+    addrs.sort();
+    addrs.dedup();
+    addrs.sort_by_key(|addr| parsed(addr).is_none_or(|a| a.port() != p2p_port));
+
+    let pinned = public_ip.and_then(|ip| ip.trim().trim_matches(['[', ']']).parse::<IpAddr>().ok());
+    if let Some(pinned) = pinned {
+        match addrs
+            .iter()
+            .position(|addr| parsed(addr).is_some_and(|a| a.ip() == pinned))
+        {
+            Some(index) => {
+                let addr = addrs.remove(index);
+                addrs.insert(0, addr);
+            }
+            None => addrs.insert(0, pinned.to_string()),
+        }
+    }
+    addrs
+}
+
+/// Lowercase a hex key and ensure the 0x prefix
+fn normalize_hex_key(key: &str) -> String {
+    let key = key.trim().to_lowercase();
+    if key.starts_with("0x") {
+        key
+    } else {
+        format!("0x{}", key)
+    }
+}
+
+/// Format a public multiaddr as `ip:port` (IPv4) or `[ip]:port` (IPv6)
+///
+/// Accepts `/ip4/203.0.113.1/tcp/30333` and `/ip6/2001:db8::1/tcp/30333`; returns
+/// None for loopback, private, link-local and unspecified addresses. Used for both
+/// our external addresses and peer addresses so the filtering stays identical.
+fn public_socket_addr(multiaddr: &str) -> Option<String> {
+    let parts: Vec<&str> = multiaddr.split('/').collect();
+    // parts: ["", "ip4", "203.0.113.1", "tcp", "30333"]
+    if parts.len() < 5 {
+        return None;
+    }
+    let (ip, port) = (parts[2], parts[4]);
+
+    match parts[1] {
+        "ip4" => {
+            let addr: Ipv4Addr = ip.parse().ok()?;
+            let internal = addr.is_private()
+                || addr.is_loopback()
+                || addr.is_link_local()
+                || addr.octets()[0] == 0;
+            (!internal).then(|| format!("{}:{}", addr, port))
+        }
+        "ip6" => {
+            let addr: Ipv6Addr = ip.parse().ok()?;
+            let first = addr.segments()[0];
+            let internal = addr.is_loopback()
+                || addr.is_unspecified()
+                || (first & 0xffc0) == 0xfe80 // link-local fe80::/10
+                || (first & 0xfe00) == 0xfc00; // unique local fc00::/7
+            (!internal).then(|| format!("[{}]:{}", addr, port))
+        }
+        _ => None,
+    }
+}
+
+/// MVM's own database catch-up progress (separate from the node's sync)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DbSyncProgress {
+    /// Highest block number stored in the database
+    pub last_block: u64,
+    /// Blocks between the database and the chain tip
+    pub blocks_remaining: u64,
+    /// Percentage of the chain tip reached by the database (0-100)
+    pub percent: f64,
+    /// Block insert rate in blocks per second (smoothed)
+    pub insert_rate_bps: f64,
+    /// Estimated time remaining in seconds
+    pub eta_seconds: Option<u64>,
+    /// Trailing by more than `thresholds.max_db_lag_blocks` with the gap growing
+    pub lagging: bool,
+}
+
+impl DbSyncProgress {
+    /// Whether the database is far enough behind to be worth showing
+    pub fn is_catching_up(&self) -> bool {
+        self.blocks_remaining > DB_SYNC_TOLERANCE_BLOCKS
+    }
+}
+
+/// Blocks the database may trail the tip by during normal polling
+const DB_SYNC_TOLERANCE_BLOCKS: u64 = 10;
+
 /// Application state data
+///
+/// Serializable so a user's exact view can be dumped (`w`) and re-rendered
+/// with `mvm view --replay`; missing fields in older dumps take their defaults.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppState {
     // Network status
     pub chain_tip: u64,
@@ -167,6 +676,8 @@ pub struct AppState {
 
     // Node sync progress
     pub sync_progress: SyncProgress,
+    /// Database catch-up progress relative to the chain tip
+    pub db_sync: DbSyncProgress,
 
     // Node identity
     pub node_name: String,
@@ -192,16 +703,39 @@ pub struct AppState {
     pub committee_elected: bool,
     pub committee_seats: usize,
     pub committee_size: usize,
+    /// Elected this epoch but no blocks from us past
+    /// `thresholds.alert_missed_block_epoch_percent` of it
+    pub block_gap: bool,
 
     // Sidechain epoch timing (for block counting)
     /// Next sidechain epoch timestamp in ms (used to calculate epoch start)
     pub sidechain_next_epoch_ms: Option<u64>,
+    /// Our assigned slots for the rest of the sidechain epoch, as (slot, sidechain_key)
+    pub upcoming_slots: Vec<(u64, String)>,
+    /// Committee membership change for the latest snapshotted epoch
+    pub committee_churn: Option<CommitteeChurn>,
+    /// Seat/block inconsistencies found in recent epochs, newest epoch first
+    pub audit_issues: Vec<(u64, AuditIssue)>,
+    /// Recorded slot collisions (None until first loaded) and the newest one
+    pub slot_collision_count: Option<u64>,
+    pub latest_slot_collision: Option<SlotCollision>,
+    /// Keystore keys that differ from our on-chain registration
+    pub key_mismatches: Vec<KeyMismatch>,
+    /// On-chain registration of our keystore's sidechain key (None until checked)
+    pub registration: Option<RegistrationStatus>,
+    /// Host clock offset from chain time in ms (positive = host ahead), None until measured
+    pub clock_skew_ms: Option<i64>,
 
     // Validator epoch data (seats per validator in current epoch)
     /// Maps sidechain_key -> ValidatorEpochRecord for current sidechain epoch
     pub validator_epoch_data: HashMap<String, ValidatorEpochRecord>,
+    /// Whether sync has captured the seats snapshot for the current sidechain epoch
+    /// (false right after an epoch boundary, so missing seats aren't shown as zero)
+    pub has_epoch_snapshot: bool,
     /// Maps sidechain_key -> blocks produced this epoch
     pub validator_epoch_blocks: HashMap<String, u64>,
+    /// Maps sidechain_key -> stake rank among dynamic validators this epoch
+    pub dynamic_ranks: HashMap<String, DynamicValidatorRank>,
 
     // Block production sparkline (for dashboard)
     /// Block counts per sidechain epoch for our validators (last 24 epochs = 48h)
@@ -211,12 +745,24 @@ pub struct AppState {
     pub our_seats_sparkline: Vec<u64>,
     /// Total committee seats for our validators over the sparkline period
     pub sparkline_total_seats: u64,
+    /// Our blocks and seats over the last week of completed sidechain epochs
+    pub our_week_blocks: u64,
+    pub our_week_seats: u64,
+    /// Blocks per sidechain epoch from all authors, same window as our sparkline
+    pub network_blocks_sparkline: Vec<u64>,
 
     // Status
     pub last_error: Option<String>,
     pub update_duration: Duration,
+    /// Slowest RPC call of the last refresh, if over `rpc.slow_call_ms`
+    pub slow_rpc: Option<SlowCall>,
     /// True until the first successful data fetch
     pub is_loading: bool,
+    /// Pre-genesis / not-yet-started detection, refreshed on every RPC fetch
+    pub chain_phase: ChainPhase,
+    /// Time of the last successful RPC fetch
+    #[serde(skip)]
+    pub last_rpc_success: Option<Instant>,
 
     // Node metrics (from Prometheus endpoint)
     pub bandwidth_in: u64,
@@ -224,18 +770,33 @@ pub struct AppState {
     pub txpool_ready: u64,
     pub txpool_validations: u64,
     pub uptime_secs: u64,
+    /// Node process start (Unix seconds, from Prometheus process_start_time)
+    pub node_started_at: Option<i64>,
+    /// Blocks our validators produced since the node (or MVM) started
+    pub our_blocks_since_restart: Option<u64>,
     pub grandpa_voter: bool,
+    /// The node was a GRANDPA voter and stopped (cleared when it votes again)
+    pub grandpa_voter_lost: bool,
 
     // Network state (from system_unstable_networkState)
     pub local_peer_id: String,
+    /// Our public addresses, the one shown in the Identity row first
     pub external_ips: Vec<String>,
     pub external_ip_fetched: bool, // Flag to prevent re-fetching (IP order varies)
+    /// Node's retained state depth (None until probed)
+    pub state_pruning: Option<StatePruning>,
     pub connected_peers: Vec<PeerInfo>,
+    /// Per-peer best-block lag, keyed by peer ID
+    pub peer_lag: HashMap<String, PeerLagStats>,
 
     // Prometheus-based peer metrics (supplemental info)
     pub peers_discovered: u64,
     pub pending_connections: u64,
 
+    /// Scrapes backed off after repeated failures (see `ScrapeBreaker`)
+    pub metrics_paused: bool,
+    pub node_exporter_paused: bool,
+
     // System resource metrics (from node_exporter)
     pub system_load1: f64,
     pub system_memory_used_bytes: u64,
@@ -246,10 +807,16 @@ pub struct AppState {
     pub memory_history: Vec<u64>,
     /// Memory trend: positive = rising, negative = falling, zero = stable
     pub memory_trend: MemoryTrend,
+
+    /// Finalization lag (tip - finalized) per refresh, oldest first
+    pub finality_lag_history: Vec<u64>,
+
+    /// Alerts and transitions for the Events view, oldest first
+    pub events: Vec<MonitorEvent>,
 }
 
 /// Memory usage trend
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum MemoryTrend {
     #[default]
     Stable,
@@ -258,7 +825,7 @@ pub enum MemoryTrend {
 }
 
 /// Information about a connected peer
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct PeerInfo {
     pub peer_id: String,
@@ -266,6 +833,78 @@ pub struct PeerInfo {
     pub best_number: u64,
     pub address: Option<String>, // IP:port if available
     pub is_outbound: bool,       // true = we dialed them, false = they dialed us
+    /// Smoothed blocks behind our chain tip across refreshes
+    pub avg_blocks_behind: f64,
+    /// Refresh cycles the average is based on
+    pub lag_samples: u64,
+}
+
+impl PeerInfo {
+    /// Whether this peer has stayed far behind our tip (disconnection candidate)
+    pub fn is_lagging(&self) -> bool {
+        self.lag_samples >= PEER_LAG_MIN_SAMPLES && self.avg_blocks_behind >= PEER_LAGGING_BLOCKS
+    }
+}
+
+/// The rows of a list view as rendered, for `e` exports
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewTable {
+    /// View name used in the export file name
+    pub name: &'static str,
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Running best-block lag for one peer
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PeerLagStats {
+    pub samples: u64,
+    pub avg_blocks_behind: f64,
+}
+
+impl PeerLagStats {
+    /// Fold in one refresh cycle's lag (exponential moving average)
+    fn record(&mut self, blocks_behind: u64) {
+        let lag = blocks_behind as f64;
+        self.avg_blocks_behind = if self.samples == 0 {
+            lag
+        } else {
+            PEER_LAG_ALPHA * lag + (1.0 - PEER_LAG_ALPHA) * self.avg_blocks_behind
+        };
+        self.samples += 1;
+    }
+}
+
+/// Weight of the newest sample in a peer's lag average
+const PEER_LAG_ALPHA: f64 = 0.2;
+
+/// Average lag at which a peer is flagged as consistently behind
+const PEER_LAGGING_BLOCKS: f64 = 10.0;
+
+/// Refresh cycles observed before a peer can be flagged as lagging
+const PEER_LAG_MIN_SAMPLES: u64 = 5;
+
+/// Record each peer's lag behind `chain_tip`, forget disconnected peers,
+/// and rank peers most-current first
+fn update_peer_lag(
+    stats: &mut HashMap<String, PeerLagStats>,
+    peers: &mut [PeerInfo],
+    chain_tip: u64,
+) {
+    stats.retain(|id, _| peers.iter().any(|p| &p.peer_id == id));
+
+    for peer in peers.iter_mut() {
+        let entry = stats.entry(peer.peer_id.clone()).or_default();
+        entry.record(chain_tip.saturating_sub(peer.best_number));
+        peer.avg_blocks_behind = entry.avg_blocks_behind;
+        peer.lag_samples = entry.samples;
+    }
+
+    peers.sort_by(|a, b| {
+        a.avg_blocks_behind
+            .total_cmp(&b.avg_blocks_behind)
+            .then(b.best_number.cmp(&a.best_number))
+    });
 }
 
 impl Default for AppState {
@@ -288,6 +927,7 @@ impl Default for AppState {
             peers_outbound: 0,
             node_health: true,
             sync_progress: SyncProgress::default(),
+            db_sync: DbSyncProgress::default(),
             node_name,
             chain_name: String::new(),
             node_version: String::new(),
@@ -301,27 +941,51 @@ impl Default for AppState {
             committee_elected: false,
             committee_seats: 0,
             committee_size: 0,
+            block_gap: false,
             sidechain_next_epoch_ms: None,
+            upcoming_slots: Vec::new(),
+            committee_churn: None,
+            audit_issues: Vec::new(),
+            slot_collision_count: None,
+            latest_slot_collision: None,
+            key_mismatches: Vec::new(),
+            registration: None,
+            clock_skew_ms: None,
             validator_epoch_data: HashMap::new(),
+            dynamic_ranks: HashMap::new(),
+            has_epoch_snapshot: false,
             validator_epoch_blocks: HashMap::new(),
             our_blocks_sparkline: Vec::new(),
             our_seats_sparkline: Vec::new(),
             sparkline_total_seats: 0,
+            our_week_blocks: 0,
+            our_week_seats: 0,
+            network_blocks_sparkline: Vec::new(),
             last_error: None,
             update_duration: Duration::from_secs(0),
+            slow_rpc: None,
             is_loading: true,
+            chain_phase: ChainPhase::Running,
+            last_rpc_success: None,
             bandwidth_in: 0,
             bandwidth_out: 0,
             txpool_ready: 0,
             txpool_validations: 0,
             uptime_secs: 0,
+            node_started_at: None,
+            our_blocks_since_restart: None,
             grandpa_voter: false,
+            grandpa_voter_lost: false,
             local_peer_id: String::new(),
             external_ips: Vec::new(),
             external_ip_fetched: false,
+            state_pruning: None,
             connected_peers: Vec::new(),
+            peer_lag: HashMap::new(),
             peers_discovered: 0,
             pending_connections: 0,
+            metrics_paused: false,
+            node_exporter_paused: false,
             system_load1: 0.0,
             system_memory_used_bytes: 0,
             system_memory_total_bytes: 0,
@@ -329,6 +993,8 @@ impl Default for AppState {
             system_disk_total_bytes: 0,
             memory_history: Vec::new(),
             memory_trend: MemoryTrend::default(),
+            finality_lag_history: Vec::new(),
+            events: Vec::new(),
         }
     }
 }
@@ -340,6 +1006,7 @@ impl App {
             view_mode: ViewMode::Dashboard,
             should_quit: false,
             show_ours_only: false,
+            show_dynamic_only: false,
             view_selections: HashMap::new(),
             popup: None,
             view_stack: Vec::new(),
@@ -348,13 +1015,42 @@ impl App {
             drill_down_validator: None,
             state: AppState::default(),
             last_update: Instant::now(),
+            refresh_requested: false,
+            decode_extrinsics: false,
+            block_calls_requested: false,
+            runtime_calls: None,
             prev_sync_block: 0,
             prev_sync_time: Instant::now(),
             smoothed_sync_rate: 0.0,
+            prev_db_blocks: 0,
+            prev_db_time: Instant::now(),
+            smoothed_db_rate: 0.0,
+            started_at: chrono::Utc::now().timestamp(),
+            prev_tip_slot: None,
             theme: Theme::default(),
+            charset: Charset::default(),
             expected_ip: None,
+            public_ip: None,
+            p2p_port: 30333,
             chain_timing: ChainTiming::default(),
             known_validators: KnownValidators::load(),
+            sort_keys: HashMap::new(),
+            compare_marks: Vec::new(),
+            committee_ref: CommitteeRef::default(),
+            finality_history_len: 30,
+            history_epochs: 50,
+            thresholds: ThresholdsConfig::default(),
+            peer_floor: PeerFloorMonitor::default(),
+            grandpa_voter_alert: true,
+            grandpa_voter_monitor: GrandpaVoterMonitor::default(),
+            metrics_breaker: ScrapeBreaker::default(),
+            node_exporter_breaker: ScrapeBreaker::default(),
+            local_keys: None,
+            key_check_epoch: None,
+            time_zone: DisplayTimeZone::default(),
+            rewards: RewardsConfig::default(),
+            node_position: None,
+            replay_source: None,
         }
     }
 
@@ -374,6 +1070,12 @@ impl App {
         self
     }
 
+    /// Set the block reference used for committee election checks
+    pub fn with_committee_ref(mut self, committee_ref: CommitteeRef) -> Self {
+        self.committee_ref = committee_ref;
+        self
+    }
+
     /// Update application state from RPC and database
     pub async fn update(
         &mut self,
@@ -383,11 +1085,13 @@ impl App {
         db: &Database,
     ) -> Result<()> {
         let start = Instant::now();
+        let previous = TransitionSnapshot::capture(&self.state);
 
         // Fetch RPC data
         let rpc_ok = match self.fetch_rpc_data(rpc).await {
             Ok(_) => {
                 self.state.last_error = None;
+                self.state.last_rpc_success = Some(Instant::now());
                 true
             }
             Err(e) => {
@@ -413,7 +1117,7 @@ impl App {
                 true
             }
             Err(e) => {
-                self.state.last_error = Some(format!("DB error: {}", e));
+                self.state.last_error = Some(format!("DB error: {}", explain_busy(e)));
                 false
             }
         };
@@ -423,22 +1127,149 @@ impl App {
             self.state.is_loading = false;
         }
 
+        self.record_transitions(&previous);
+        if rpc_ok && !self.state.is_loading {
+            self.check_peer_floor(Instant::now());
+        }
+
         self.state.update_duration = start.elapsed();
+        self.state.slow_rpc = rpc.take_slow_call();
         self.last_update = Instant::now();
+        self.refresh_requested = false;
 
         Ok(())
     }
 
+    /// Copy of the state after `update`, for the app the UI draws from
+    ///
+    /// Events recorded since the last call move into the copy, so each one
+    /// reaches the UI once.
+    pub fn take_refreshed_state(&mut self) -> AppState {
+        let events = std::mem::take(&mut self.state.events);
+        let mut state = self.state.clone();
+        state.events = events;
+        state
+    }
+
+    /// Show a state fetched by a background refresher, keeping this app's events
+    pub fn apply_refresh(&mut self, mut state: AppState) {
+        let events = std::mem::replace(&mut state.events, std::mem::take(&mut self.state.events));
+        self.state = state;
+        for event in events {
+            self.push_event(event);
+        }
+        self.last_update = Instant::now();
+        self.refresh_requested = false;
+    }
+
+    /// Readiness inputs for the health server
+    pub fn health_state(&self) -> HealthState {
+        HealthState {
+            is_loading: self.state.is_loading,
+            last_success: self.state.last_rpc_success,
+            node_issue: self.node_health().err(),
+            last_error: self.state.last_error.clone(),
+        }
+    }
+
+    /// Run-rate projection of our blocks this epoch against the full-epoch expectation
+    ///
+    /// `epoch_progress.expected_blocks` is pro-rated to the current progress, so
+    /// the projection is compared with the expectation for the whole epoch.
+    pub fn epoch_projection(&self) -> Option<EpochProjection> {
+        if !self.state.committee_elected || self.state.committee_size == 0 {
+            return None;
+        }
+        let expected = self.chain_timing.expected_blocks(
+            self.state.committee_seats as u64,
+            self.state.committee_size as u64,
+            1.0,
+        );
+        let progress = &self.state.epoch_progress;
+        project_epoch_blocks(
+            progress.our_blocks_this_epoch,
+            progress.progress_percent,
+            expected,
+        )
+    }
+
+    /// Dashboard stats for the Prometheus exporter
+    pub fn exporter_state(&self) -> ExporterState {
+        ExporterState {
+            our_seats: Some(self.state.committee_seats as u64),
+            blocks_this_epoch: Some(self.state.epoch_progress.our_blocks_this_epoch),
+            expected_blocks: Some(self.state.epoch_progress.expected_blocks),
+            committee_size: Some(self.state.committee_size as u64),
+            sync_lag_blocks: Some(self.state.db_sync.blocks_remaining),
+            selection_rates: self
+                .state
+                .our_validators
+                .iter()
+                .filter_map(|v| {
+                    let rate = self
+                        .state
+                        .dynamic_ranks
+                        .get(&v.sidechain_key)?
+                        .selection_rate()?;
+                    Some((v.sidechain_key.clone(), rate / 100.0))
+                })
+                .collect(),
+        }
+    }
+
+    /// Composite node health (sync, peers, finalized lag) per configured thresholds
+    pub fn node_health(&self) -> std::result::Result<(), String> {
+        self.thresholds.assess_node(
+            self.state.sync_progress.is_synced,
+            Some(self.state.peer_count),
+            self.state
+                .chain_tip
+                .saturating_sub(self.state.finalized_block),
+        )
+    }
+
     async fn fetch_rpc_data(&mut self, rpc: &RpcClient) -> Result<()> {
+        // Fetch tip, finalized head, sidechain status, sync state and health in one round trip
+        let no_params = serde_json::json!([]);
+        let results: [Result<serde_json::Value>; 5] = rpc
+            .call_batch(&[
+                ("chain_getHeader", no_params.clone()),
+                ("chain_getFinalizedHead", no_params.clone()),
+                ("sidechain_getStatus", no_params.clone()),
+                (
+                    rpc.method_name(RpcMethod::SyncState)
+                        .unwrap_or("system_syncState"),
+                    no_params.clone(),
+                ),
+                ("system_health", no_params),
+            ])
+            .await?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unexpected RPC batch result count"))?;
+        let [header_result, finalized_hash_result, status_result, sync_state_result, health_result] =
+            results;
+
         // Get chain tip
-        let header: crate::rpc::BlockHeader = rpc.call("chain_getHeader", Vec::<()>::new()).await?;
+        let header: crate::rpc::BlockHeader =
+            serde_json::from_value(header_result?).context("Failed to parse block header")?;
         self.state.chain_tip = header.block_number();
 
+        // Host clock vs chain time from the tip's slot
+        if let Some(slot) = header
+            .digest
+            .as_ref()
+            .and_then(|d| extract_slot_from_digest(&d.logs))
+        {
+            self.sample_clock_skew(slot, unix_now_ms());
+        }
+
         // Get finalized block
-        let finalized_hash: String = rpc.call("chain_getFinalizedHead", Vec::<()>::new()).await?;
+        let finalized_hash: String = serde_json::from_value(finalized_hash_result?)
+            .context("Failed to parse finalized head")?;
         let finalized_header: crate::rpc::BlockHeader =
             rpc.call("chain_getHeader", vec![&finalized_hash]).await?;
         self.state.finalized_block = finalized_header.block_number();
+        self.record_finality_lag();
 
         // Get chain name (network identifier)
         if self.state.chain_name.is_empty() {
@@ -461,19 +1292,20 @@ impl App {
         }
 
         // Get sidechain status and calculate epoch progress
-        if let Ok(status) = rpc
-            .call::<_, SidechainStatus>("sidechain_getStatus", Vec::<()>::new())
-            .await
-        {
+        let status = status_result
+            .ok()
+            .and_then(|v| decode_lenient::<SidechainStatus>("sidechain_getStatus", v));
+        self.state.chain_phase = ChainPhase::detect(
+            self.state.chain_tip,
+            status.as_ref().map(|s| s.sidechain.epoch),
+        );
+        if let Some(status) = status {
             self.state.mainchain_epoch = status.mainchain.epoch;
             self.state.sidechain_epoch = status.sidechain.epoch;
             self.state.sidechain_slot = status.sidechain.slot;
 
-            // Get current time for progress calculations
-            let now_ms = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_millis() as u64)
-                .unwrap_or(0);
+            // Chain time for progress calculations, so host clock drift doesn't skew them
+            let now_ms = self.chain_now_ms();
 
             // Calculate SIDECHAIN epoch progress using nextEpochTimestamp
             // Sidechain epoch duration varies by network (2h preview, 10h mainnet)
@@ -510,10 +1342,7 @@ impl App {
         }
 
         // Get sync state with detailed progress
-        if let Ok(sync_state) = rpc
-            .call::<_, serde_json::Value>("system_syncState", Vec::<()>::new())
-            .await
-        {
+        if let Ok(sync_state) = sync_state_result {
             let current_block = sync_state
                 .get("currentBlock")
                 .and_then(|v| v.as_u64())
@@ -585,11 +1414,11 @@ impl App {
         }
 
         // Get system health (includes peer count)
-        if let Ok(health) = rpc
-            .call::<_, serde_json::Value>("system_health", Vec::<()>::new())
-            .await
-        {
-            self.state.peer_count = health.get("peers").and_then(|v| v.as_u64()).unwrap_or(0);
+        if let Ok(health) = health_result {
+            // Keep the last count rather than reading a missing field as zero peers
+            if let Some(peers) = health.get("peers").and_then(|v| v.as_u64()) {
+                self.state.peer_count = peers;
+            }
             self.state.node_health = !health
                 .get("isSyncing")
                 .and_then(|v| v.as_bool())
@@ -597,11 +1426,16 @@ impl App {
         }
 
         // Get network state (external IPs, peer ID, connected peers)
-        // This requires --rpc-methods=unsafe on the node
-        if let Ok(network_state) = rpc
-            .call::<_, serde_json::Value>("system_unstable_networkState", Vec::<()>::new())
-            .await
-        {
+        // This requires --rpc-methods=unsafe on the node; the method name
+        // depends on the node version (see RpcClient::probe)
+        let network_state = match rpc.method_name(RpcMethod::NetworkState) {
+            Some(method) => rpc
+                .call::<_, serde_json::Value>(method, Vec::<()>::new())
+                .await
+                .ok(),
+            None => None,
+        };
+        if let Some(network_state) = &network_state {
             // Extract local peer ID (only once)
             if self.state.local_peer_id.is_empty() {
                 if let Some(peer_id) = network_state.get("peerId").and_then(|v| v.as_str()) {
@@ -609,8 +1443,9 @@ impl App {
                 }
             }
 
-            // Extract external addresses - collect all unique public IPs
-            // Use flag to prevent re-fetching since array order varies between RPC calls
+            // Extract external addresses - collect all unique public IPs, the
+            // displayed one first. Use flag to prevent re-fetching since the
+            // node's list grows with peer-reported addresses
             if !self.state.external_ip_fetched {
                 self.state.external_ip_fetched = true; // Mark as attempted regardless of result
 
@@ -618,51 +1453,16 @@ impl App {
                     .get("externalAddresses")
                     .and_then(|v| v.as_array())
                 {
-                    // Collect all public IPs (not just the first one)
-                    let mut public_ips: Vec<String> = external
-                        .iter()
-                        .filter_map(|addr| addr.as_str())
-                        .filter_map(|addr| {
-                            // Parse multiaddr format like /ip4/203.0.113.1/tcp/30333
-                            if addr.starts_with("/ip4/") {
-                                let parts: Vec<&str> = addr.split('/').collect();
-                                // parts: ["", "ip4", "203.0.113.1", "tcp", "30333"]
-                                if parts.len() >= 5 {
-                                    let ip = parts[2];
-                                    let port = parts[4];
-                                    // Filter out private/internal IPs
-                                    if !ip.starts_with("127.")
-                                        && !ip.starts_with("10.")
-                                        && !ip.starts_with("192.168.")
-                                        && !ip.starts_with("172.16.")
-                                        && !ip.starts_with("172.17.")
-                                        && !ip.starts_with("172.18.")
-                                        && !ip.starts_with("172.19.")
-                                        && !ip.starts_with("172.2")
-                                        && !ip.starts_with("172.30.")
-                                        && !ip.starts_with("172.31.")
-                                        && !ip.starts_with("0.")
-                                    {
-                                        return Some(format!("{}:{}", ip, port));
-                                    }
-                                }
-                            }
-                            None
-                        })
-                        .collect();
-
-                    // Deduplicate and sort for consistent display
-                    public_ips.sort();
-                    public_ips.dedup();
-
-                    // Filter by expected IP if configured
-                    if let Some(ref expected) = self.expected_ip {
-                        public_ips.retain(|addr| addr.starts_with(expected));
-                    }
-
-                    // Only keep first IP - API may return multiple and order can vary
-                    // between calls, so we truncate to avoid display cycling
-                    public_ips.truncate(1);
+                    let public_ips = rank_external_addrs(
+                        external
+                            .iter()
+                            .filter_map(|addr| addr.as_str())
+                            .filter_map(public_socket_addr)
+                            .collect(),
+                        self.expected_ip.as_ref(),
+                        self.public_ip.as_deref(),
+                        self.p2p_port,
+                    );
 
                     if !public_ips.is_empty() {
                         self.state.external_ips = public_ips;
@@ -672,18 +1472,19 @@ impl App {
         }
 
         // Get connected peers with sync info
-        if let Ok(peers) = rpc
-            .call::<_, Vec<serde_json::Value>>("system_peers", Vec::<()>::new())
-            .await
-        {
-            // Also get network state to extract peer addresses and connection direction
+        let peers = match rpc.method_name(RpcMethod::Peers) {
+            Some(method) => rpc
+                .call::<_, Vec<serde_json::Value>>(method, Vec::<()>::new())
+                .await
+                .ok(),
+            None => None,
+        };
+        if let Some(peers) = peers {
+            // Network state gives peer addresses and connection direction
             let (peer_addresses, peer_directions): (
                 HashMap<String, String>,
                 HashMap<String, bool>,
-            ) = if let Ok(net_state) = rpc
-                .call::<_, serde_json::Value>("system_unstable_networkState", Vec::<()>::new())
-                .await
-            {
+            ) = if let Some(net_state) = &network_state {
                 let peers_obj = net_state.get("connectedPeers").and_then(|v| v.as_object());
 
                 let addresses = peers_obj
@@ -694,66 +1495,20 @@ impl App {
                                 let addr = info
                                     .get("knownAddresses")
                                     .and_then(|v| v.as_array())
-                                    .and_then(|addrs| {
-                                        // First try to find a public IPv4
-                                        let ipv4 = addrs
+                                    .map(|addrs| {
+                                        addrs
                                             .iter()
                                             .filter_map(|a| a.as_str())
-                                            .find_map(|addr| {
-                                                if addr.starts_with("/ip4/") {
-                                                    let parts: Vec<&str> =
-                                                        addr.split('/').collect();
-                                                    if parts.len() >= 5 {
-                                                        let ip = parts[2];
-                                                        let port = parts[4];
-                                                        // Filter out private IPs
-                                                        if !ip.starts_with("127.")
-                                                            && !ip.starts_with("10.")
-                                                            && !ip.starts_with("192.168.")
-                                                            && !ip.starts_with("172.16.")
-                                                            && !ip.starts_with("172.17.")
-                                                            && !ip.starts_with("172.18.")
-                                                            && !ip.starts_with("172.19.")
-                                                            && !ip.starts_with("172.2")
-                                                            && !ip.starts_with("172.30.")
-                                                            && !ip.starts_with("172.31.")
-                                                            && !ip.starts_with("0.")
-                                                        {
-                                                            return Some(format!(
-                                                                "{}:{}",
-                                                                ip, port
-                                                            ));
-                                                        }
-                                                    }
-                                                }
-                                                None
-                                            });
-
-                                        // If no IPv4, try IPv6
-                                        ipv4.or_else(|| {
-                                            addrs.iter().filter_map(|a| a.as_str()).find_map(
-                                                |addr| {
-                                                    if addr.starts_with("/ip6/") {
-                                                        let parts: Vec<&str> =
-                                                            addr.split('/').collect();
-                                                        if parts.len() >= 5 {
-                                                            let ip = parts[2];
-                                                            let port = parts[4];
-                                                            // Filter out localhost
-                                                            if ip != "::1"
-                                                                && !ip.starts_with("fe80:")
-                                                            {
-                                                                return Some(format!(
-                                                                    "[{}]:{}",
-                                                                    ip, port
-                                                                ));
-                                                            }
-                                                        }
-                                                    }
-                                                    None
-                                                },
-                                            )
-                                        })
+                                            .filter_map(public_socket_addr)
+                                            .collect::<Vec<_>>()
+                                    })
+                                    .and_then(|public| {
+                                        // Prefer IPv4, fall back to IPv6
+                                        public
+                                            .iter()
+                                            .find(|a| !a.starts_with('['))
+                                            .or(public.first())
+                                            .cloned()
                                     });
                                 addr.map(|a| (peer_id.clone(), a))
                             })
@@ -802,14 +1557,17 @@ impl App {
                         best_number,
                         address,
                         is_outbound,
+                        ..Default::default()
                     })
                 })
                 .collect();
 
-            // Sort by best_number descending (most synced peers first)
-            self.state
-                .connected_peers
-                .sort_by(|a, b| b.best_number.cmp(&a.best_number));
+            // Rank by average lag (most current peers first)
+            update_peer_lag(
+                &mut self.state.peer_lag,
+                &mut self.state.connected_peers,
+                self.state.chain_tip,
+            );
 
             // Count inbound/outbound
             self.state.peers_outbound = self
@@ -829,7 +1587,21 @@ impl App {
         // Check committee election status for our validators
         // Only check if we have validators marked as ours
         if !self.state.our_validators.is_empty() {
-            if let Ok(committee) = ValidatorSet::fetch_committee_at_block(rpc, None).await {
+            // Query behind tip so a boundary block or short reorg doesn't flip the result
+            let committee_block = match self.committee_ref {
+                CommitteeRef::Tip => None,
+                CommitteeRef::Finalized => Some(finalized_hash.clone()),
+                CommitteeRef::Offset(n) => rpc
+                    .call::<_, String>(
+                        "chain_getBlockHash",
+                        vec![self.state.chain_tip.saturating_sub(n)],
+                    )
+                    .await
+                    .ok(),
+            };
+            if let Ok(committee) =
+                ValidatorSet::fetch_committee_at_block(rpc, committee_block.as_deref()).await
+            {
                 self.state.committee_size = committee.len();
 
                 // Count how many seats our validators have in the committee
@@ -852,20 +1624,313 @@ impl App {
 
                 self.state.committee_seats = total_seats;
                 self.state.committee_elected = total_seats > 0;
+                self.state.upcoming_slots = self.our_slot_schedule(&committee, unix_now_ms());
             }
         }
 
+        self.check_registered_keys(rpc).await;
+
+        // Retained state depth: probed once, after the first full fetch has
+        // drawn (a binary search of ~20 state queries on pruned nodes)
+        if self.state.state_pruning.is_none()
+            && !self.state.is_loading
+            && self.state.chain_phase == ChainPhase::Running
+        {
+            self.state.state_pruning = Some(StatePruning::detect(rpc, self.state.chain_tip).await);
+        }
+
         Ok(())
     }
 
+    /// Compare keystore keys with our on-chain registration and check we are registered
+    /// at all (once per mainchain epoch)
+    async fn check_registered_keys(&mut self, rpc: &RpcClient) {
+        let epoch = self.state.mainchain_epoch;
+        let Some(ref keys) = self.local_keys else {
+            return;
+        };
+        if epoch == 0 || self.key_check_epoch == Some(epoch) {
+            return;
+        }
+        let Ok(candidates) = ValidatorSet::fetch_candidates(rpc, epoch).await else {
+            return;
+        };
+
+        let mismatches = keys.registration_mismatches(&candidates);
+        let registration = get_key_status(rpc, keys, epoch).await.registration;
+        self.key_check_epoch = Some(epoch);
+        if !mismatches.is_empty() && self.state.key_mismatches.is_empty() {
+            let types: Vec<_> = mismatches.iter().map(|m| m.key_type.as_str()).collect();
+            self.record_event(
+                EventSeverity::Error,
+                "keys",
+                format!(
+                    "Keystore {} key(s) differ from on-chain registration - run `mvm keys verify`",
+                    types.join("/")
+                ),
+            );
+        } else if mismatches.is_empty() && !self.state.key_mismatches.is_empty() {
+            self.record_event(
+                EventSeverity::Info,
+                "keys",
+                "Keystore keys match on-chain registration again",
+            );
+        }
+        self.state.key_mismatches = mismatches;
+
+        let was_unregistered = self.is_unregistered();
+        self.state.registration = registration;
+        if self.is_unregistered() && !was_unregistered {
+            self.record_event(
+                EventSeverity::Error,
+                "keys",
+                "Validator NOT registered on-chain - see `mvm guide registration`",
+            );
+        }
+    }
+
+    /// Our slots from now until the end of the current sidechain epoch
+    fn our_slot_schedule(&self, committee: &[String], now_ms: u64) -> Vec<(u64, String)> {
+        let slot_ms = self.chain_timing.slot_duration_ms;
+        let Some(epoch_end_ms) = self.state.sidechain_next_epoch_ms else {
+            return Vec::new();
+        };
+        if slot_ms == 0 {
+            return Vec::new();
+        }
+
+        // Only our validators are candidates, so the schedule lists just our slots
+        let candidates = self
+            .state
+            .our_validators
+            .iter()
+            .filter_map(|v| {
+                let aura_key = v.aura_key.as_ref()?;
+                Some(Validator {
+                    sidechain_key: v.sidechain_key.clone(),
+                    aura_key: normalize_hex_key(aura_key),
+                    grandpa_key: v.grandpa_key.clone().unwrap_or_default(),
+                    is_permissioned: false,
+                    stake_lovelace: None,
+                })
+            })
+            .collect();
+        let validator_set = ValidatorSet {
+            epoch: self.state.sidechain_epoch,
+            candidates,
+            committee: committee.iter().map(|k| normalize_hex_key(k)).collect(),
+        };
+
+        // Aura slot = unix time / slot duration
+        validator_set.schedule(now_ms / slot_ms + 1, epoch_end_ms.div_ceil(slot_ms))
+    }
+
+    /// Seconds until the given Aura slot starts (0 if it has started)
+    pub fn slot_countdown_secs(&self, slot: u64) -> u64 {
+        (slot * self.chain_timing.slot_duration_ms).saturating_sub(unix_now_ms()) / 1000
+    }
+
+    /// Log an event when the peer count stays below the critical floor (and on recovery)
+    fn check_peer_floor(&mut self, now: Instant) {
+        let alert = self.peer_floor.observe(
+            self.state.peer_count,
+            self.thresholds.peers_crit,
+            Duration::from_secs(self.thresholds.peers_alert_after_secs),
+            now,
+        );
+        match alert {
+            Some(alert @ PeerFloorAlert::Below { .. }) => {
+                self.record_event(EventSeverity::Error, "peers", alert.message())
+            }
+            Some(alert @ PeerFloorAlert::Recovered { .. }) => {
+                self.record_event(EventSeverity::Info, "peers", alert.message())
+            }
+            None => {}
+        }
+    }
+
+    /// Warn when the node stops voting in GRANDPA while it keeps importing blocks
+    fn check_grandpa_voter(&mut self) {
+        match self.grandpa_voter_monitor.observe(self.state.grandpa_voter) {
+            Some(alert @ GrandpaVoterAlert::Stopped) => {
+                self.state.grandpa_voter_lost = true;
+                self.record_event(EventSeverity::Error, "grandpa", alert.message());
+            }
+            Some(alert @ GrandpaVoterAlert::Resumed) => {
+                self.state.grandpa_voter_lost = false;
+                self.record_event(EventSeverity::Info, "grandpa", alert.message());
+            }
+            None => {}
+        }
+    }
+
+    /// Flag an epoch where we hold seats but have produced nothing well into it
+    ///
+    /// Catches a validator that was elected but silently stopped producing
+    /// (e.g. a key problem) before it shows up as an empty sparkline bar.
+    /// Clears once a block lands or the next epoch starts.
+    fn check_block_gap(&mut self) {
+        let progress = &self.state.epoch_progress;
+        let gap = self.state.committee_elected
+            && self.state.committee_seats > 0
+            && progress.our_blocks_this_epoch == 0
+            && progress.progress_percent > self.thresholds.alert_missed_block_epoch_percent;
+        if gap && !self.state.block_gap {
+            let message = format!(
+                "No blocks this epoch with {} seats, {:.0}% elapsed",
+                self.state.committee_seats, progress.progress_percent
+            );
+            self.record_event(EventSeverity::Error, "blocks", message);
+        } else if !gap && self.state.block_gap && progress.our_blocks_this_epoch > 0 {
+            self.record_event(
+                EventSeverity::Info,
+                "blocks",
+                "Block produced, production gap cleared".to_string(),
+            );
+        }
+        self.state.block_gap = gap;
+    }
+
+    /// Track how far the database trails the chain tip and how fast it is catching up
+    fn update_db_sync_progress(&mut self, last_block: u64, now: Instant) {
+        let total_blocks = self.state.total_blocks;
+        let chain_tip = self.state.chain_tip;
+        let blocks_remaining = chain_tip.saturating_sub(last_block);
+        let percent = if chain_tip > 0 {
+            (last_block as f64 / chain_tip as f64 * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        };
+
+        // Rate from rows inserted since the last refresh (same smoothing as node sync)
+        let elapsed_secs = now.duration_since(self.prev_db_time).as_secs_f64();
+        if elapsed_secs > 0.5 && self.prev_db_blocks > 0 {
+            let instant_rate =
+                total_blocks.saturating_sub(self.prev_db_blocks) as f64 / elapsed_secs;
+            let alpha = 0.3;
+            self.smoothed_db_rate = if self.smoothed_db_rate > 0.0 {
+                alpha * instant_rate + (1.0 - alpha) * self.smoothed_db_rate
+            } else {
+                instant_rate
+            };
+        }
+        self.prev_db_blocks = total_blocks;
+        self.prev_db_time = now;
+
+        let eta_seconds = if self.smoothed_db_rate > 0.1 {
+            Some((blocks_remaining as f64 / self.smoothed_db_rate) as u64)
+        } else {
+            None
+        };
+
+        // A stalled `mvm sync` shows as a gap that keeps widening. Blocks arrive
+        // slower than refreshes, so an unchanged gap keeps the previous verdict
+        let prev = &self.state.db_sync;
+        let lagging = blocks_remaining > self.thresholds.max_db_lag_blocks
+            && match blocks_remaining.cmp(&prev.blocks_remaining) {
+                std::cmp::Ordering::Greater => prev.last_block > 0,
+                std::cmp::Ordering::Equal => prev.lagging,
+                std::cmp::Ordering::Less => false,
+            };
+        if lagging && !prev.lagging {
+            self.record_event(
+                EventSeverity::Warning,
+                "sync",
+                format!(
+                    "MVM sync lagging: database {} blocks behind the chain tip and falling further behind",
+                    blocks_remaining
+                ),
+            );
+        }
+
+        self.state.db_sync = DbSyncProgress {
+            last_block,
+            blocks_remaining,
+            percent,
+            insert_rate_bps: self.smoothed_db_rate,
+            eta_seconds,
+            lagging,
+        };
+    }
+
     fn fetch_db_data(&mut self, db: &Database) -> Result<()> {
         // Get database stats
         self.state.total_blocks = db.count_blocks()?;
         self.state.total_validators = db.count_validators()?;
         self.state.our_validators_count = db.count_our_validators()?;
 
+        // Committee churn for this epoch (or the last one, until sync snapshots this one)
+        let epoch = self.state.sidechain_epoch;
+        let churn = match db.get_committee_churn(epoch)? {
+            Some(churn) => Some(churn),
+            None => db.get_committee_churn(epoch.saturating_sub(1))?,
+        };
+        if let Some(ref c) = churn {
+            let is_new = self
+                .state
+                .committee_churn
+                .as_ref()
+                .is_some_and(|prev| prev.epoch != c.epoch);
+            if is_new {
+                self.record_event(
+                    EventSeverity::Info,
+                    "committee",
+                    format!("Committee churn in epoch {}: {}", c.epoch, c.summary()),
+                );
+            }
+        }
+        self.state.committee_churn = churn;
+
+        // Seats-vs-blocks self-audit over the current and recent epochs
+        let slots_per_epoch = self.chain_timing.blocks_per_sidechain_epoch();
+        let mut audit_issues = Vec::new();
+        for e in (epoch.saturating_sub(AUDIT_EPOCHS - 1)..=epoch).rev() {
+            let input = db.get_epoch_audit_input(e, slots_per_epoch)?;
+            audit_issues.extend(audit_epoch(&input).into_iter().map(|issue| (e, issue)));
+        }
+        self.state.audit_issues = audit_issues;
+
+        // Slot collisions recorded by sync (possible equivocation)
+        let collision_count = db.count_slot_collisions()?;
+        let previous = self.state.slot_collision_count;
+        if collision_count > previous.unwrap_or(0) {
+            let recent = db.get_recent_slot_collisions(SLOT_COLLISION_EVENTS)?;
+            match previous {
+                // Already on disk at startup: one summary rather than a flood
+                None => self.record_event(
+                    EventSeverity::Warning,
+                    "consensus",
+                    format!(
+                        "{} slot collision(s) recorded, latest at slot {}",
+                        collision_count,
+                        recent.first().map(|c| c.slot_number).unwrap_or(0)
+                    ),
+                ),
+                Some(prev) => {
+                    let new = (collision_count - prev).min(recent.len() as u64) as usize;
+                    for c in recent[..new].iter().rev() {
+                        self.record_event(
+                            EventSeverity::Warning,
+                            "consensus",
+                            format!(
+                                "Slot {} claimed by two blocks: #{} {} and #{} {}",
+                                c.slot_number,
+                                c.other_block_number,
+                                c.other_block_hash,
+                                c.block_number,
+                                c.block_hash
+                            ),
+                        );
+                    }
+                }
+            }
+            self.state.latest_slot_collision = recent.into_iter().next();
+        }
+        self.state.slot_collision_count = Some(collision_count);
+
         // Get recent blocks - fetch enough to fill most terminal heights
         let max_block = db.get_max_block_number()?.unwrap_or(0);
+        self.update_db_sync_progress(max_block, Instant::now());
         if max_block > 0 {
             let blocks_to_fetch = 50; // Enough for tall terminals
             let start = max_block.saturating_sub(blocks_to_fetch - 1);
@@ -911,6 +1976,18 @@ impl App {
                     );
                 }
             }
+            self.state.has_epoch_snapshot = db
+                .has_validator_epoch_snapshot(self.state.sidechain_epoch)
+                .unwrap_or(false);
+            match db.get_dynamic_leaderboard(self.state.sidechain_epoch) {
+                Ok(ranks) => {
+                    self.state.dynamic_ranks = ranks
+                        .into_iter()
+                        .map(|r| (r.sidechain_key.clone(), r))
+                        .collect();
+                }
+                Err(e) => tracing::debug!("Failed to load dynamic leaderboard: {}", e),
+            }
         }
 
         // Calculate blocks in current SIDECHAIN epoch for all validators
@@ -968,11 +2045,23 @@ impl App {
                 // Not in committee - no blocks expected
                 self.state.epoch_progress.expected_blocks = 0.0;
             }
+            self.check_block_gap();
         }
 
-        // Fetch sparkline data for our validators (block production over last 24 sidechain epochs)
-        // Using epoch-based counting ensures alignment between blocks and seats
-        let num_epochs = 24;
+        // Blocks since the node last restarted (MVM's own start without metrics),
+        // so a restarted node can be seen producing again before epoch stats move
+        if !self.state.our_validators.is_empty() {
+            let since = self.restart_baseline();
+            let mut since_restart = 0;
+            for v in &self.state.our_validators {
+                since_restart += db.count_blocks_by_author_since(&v.sidechain_key, since)?;
+            }
+            self.state.our_blocks_since_restart = Some(since_restart);
+        }
+
+        // Fetch sparkline data for our validators (block production over last 24 sidechain epochs)
+        // Using epoch-based counting ensures alignment between blocks and seats
+        let num_epochs = 24;
         if !self.state.our_validators.is_empty() {
             let author_keys: Vec<String> = self
                 .state
@@ -1018,23 +2107,80 @@ impl App {
                     self.state.sparkline_total_seats = 0;
                 }
             }
+
+            // Rolling week: a stable figure whether epochs are 2h (preview) or 10h (mainnet)
+            let (week_blocks, week_seats) = db.get_blocks_by_author_in_window(
+                &author_keys,
+                self.state.sidechain_epoch,
+                self.chain_timing.week_epochs(),
+            )?;
+            self.state.our_week_blocks = week_blocks;
+            self.state.our_week_seats = week_seats;
         } else {
             self.state.our_blocks_sparkline = vec![0; num_epochs];
             self.state.our_seats_sparkline = vec![0; num_epochs];
             self.state.sparkline_total_seats = 0;
+            self.state.our_week_blocks = 0;
+            self.state.our_week_seats = 0;
+        }
+
+        // Network-wide production over the same epochs (chain liveness)
+        match db.get_total_block_counts_by_epoch(self.state.sidechain_epoch, num_epochs) {
+            Ok(counts) => {
+                self.state.network_blocks_sparkline = counts;
+            }
+            Err(e) => {
+                tracing::debug!("Failed to fetch network sparkline data: {}", e);
+                self.state.network_blocks_sparkline = vec![0; num_epochs];
+            }
         }
 
         Ok(())
     }
 
+    /// Report a scrape breaker transition in the Events view
+    fn record_breaker_transition(&mut self, endpoint: &str, transition: BreakerTransition) {
+        match transition {
+            BreakerTransition::Opened { failures } => self.record_event(
+                EventSeverity::Warning,
+                "metrics",
+                format!(
+                    "{} endpoint failed {} times in a row, retrying every {}s",
+                    endpoint,
+                    failures,
+                    BREAKER_RETRY_INTERVAL.as_secs()
+                ),
+            ),
+            BreakerTransition::Closed => self.record_event(
+                EventSeverity::Info,
+                "metrics",
+                format!("{} endpoint reachable again", endpoint),
+            ),
+        }
+    }
+
     async fn fetch_metrics_data(&mut self, metrics: &MetricsClient) {
-        // Metrics are non-critical - don't fail the update if they're unavailable
-        if let Ok(m) = metrics.fetch_metrics().await {
+        // Metrics are non-critical - don't fail the update if they're unavailable,
+        // and stop scraping every refresh while the endpoint keeps failing
+        let now = Instant::now();
+        if !self.metrics_breaker.should_attempt(now) {
+            return;
+        }
+        let result = metrics.fetch_metrics().await;
+        if let Some(transition) = self.metrics_breaker.record(result.is_ok(), now) {
+            self.record_breaker_transition("Metrics", transition);
+        }
+        self.state.metrics_paused = self.metrics_breaker.is_open();
+
+        if let Ok(m) = result {
             self.state.bandwidth_in = m.bandwidth_in;
             self.state.bandwidth_out = m.bandwidth_out;
             self.state.txpool_ready = m.txpool_ready;
             self.state.txpool_validations = m.txpool_validations_finished;
             self.state.grandpa_voter = m.grandpa_voter;
+            if self.grandpa_voter_alert {
+                self.check_grandpa_voter();
+            }
 
             // Calculate uptime from process start time
             if m.process_start_time > 0.0 {
@@ -1043,6 +2189,7 @@ impl App {
                     .map(|d| d.as_secs_f64())
                     .unwrap_or(0.0);
                 self.state.uptime_secs = (now - m.process_start_time) as u64;
+                self.state.node_started_at = Some(m.process_start_time as i64);
             }
 
             // Prometheus provides additional peer network info (don't override RPC counts)
@@ -1053,7 +2200,17 @@ impl App {
 
     async fn fetch_node_exporter_data(&mut self, node_exporter: &NodeExporterClient) {
         // Node exporter metrics are non-critical - don't fail the update if unavailable
-        if let Ok(m) = node_exporter.fetch_metrics().await {
+        let now = Instant::now();
+        if !self.node_exporter_breaker.should_attempt(now) {
+            return;
+        }
+        let result = node_exporter.fetch_metrics().await;
+        if let Some(transition) = self.node_exporter_breaker.record(result.is_ok(), now) {
+            self.record_breaker_transition("node_exporter", transition);
+        }
+        self.state.node_exporter_paused = self.node_exporter_breaker.is_open();
+
+        if let Ok(m) = result {
             self.state.system_load1 = m.load1;
 
             // Calculate memory used = total - available
@@ -1133,9 +2290,11 @@ impl App {
             ViewMode::Blocks => ViewMode::Validators,
             ViewMode::Validators => ViewMode::Performance,
             ViewMode::Performance => ViewMode::Peers,
-            ViewMode::Peers => ViewMode::Help,
+            ViewMode::Peers => ViewMode::Events,
+            ViewMode::Events => ViewMode::Help,
             ViewMode::Help => ViewMode::Dashboard,
             ViewMode::ValidatorEpochDetail => ViewMode::Performance, // Should not happen
+            ViewMode::Focus => ViewMode::Dashboard,
         };
         // Selection is preserved in view_selections HashMap
     }
@@ -1158,8 +2317,10 @@ impl App {
             ViewMode::Validators => ViewMode::Blocks,
             ViewMode::Performance => ViewMode::Validators,
             ViewMode::Peers => ViewMode::Performance,
-            ViewMode::Help => ViewMode::Peers,
+            ViewMode::Events => ViewMode::Peers,
+            ViewMode::Help => ViewMode::Events,
             ViewMode::ValidatorEpochDetail => ViewMode::Performance, // Should not happen
+            ViewMode::Focus => ViewMode::Dashboard,
         };
         // Selection is preserved in view_selections HashMap
     }
@@ -1179,13 +2340,12 @@ impl App {
 
     /// Get max scroll index for current view
     fn max_scroll_index(&self) -> usize {
-        // Help screen item count (count of ListItems in render_help)
-        const HELP_ITEM_COUNT: usize = 73; // About + Keyboard Shortcuts + Reference sections
-
         match self.view_mode {
             ViewMode::Blocks => self.state.recent_blocks.len().saturating_sub(1),
             ViewMode::Validators => {
-                if self.show_ours_only {
+                if self.show_dynamic_only {
+                    self.get_sorted_validators().len().saturating_sub(1)
+                } else if self.show_ours_only {
                     self.state.our_validators.len().saturating_sub(1)
                 } else {
                     self.state.validators.len().saturating_sub(1)
@@ -1199,7 +2359,10 @@ impl App {
                 }
             }
             ViewMode::Peers => self.state.connected_peers.len().saturating_sub(1),
-            ViewMode::Help => HELP_ITEM_COUNT.saturating_sub(1),
+            ViewMode::Events => self.state.events.len().saturating_sub(1),
+            ViewMode::Help => crate::tui::ui::help_items(self.theme)
+                .len()
+                .saturating_sub(1),
             ViewMode::ValidatorEpochDetail => self.validator_epoch_history.len().saturating_sub(1),
             _ => 0,
         }
@@ -1245,41 +2408,391 @@ impl App {
         self.set_selected_index(0);
     }
 
-    /// Get validators sorted for display (permissioned first, then by seats desc)
-    /// This is the single source of truth for validator ordering
+    /// Toggle the dynamic-validator leaderboard (Validators view)
+    pub fn toggle_dynamic_filter(&mut self) {
+        if self.view_mode == ViewMode::Validators {
+            self.show_dynamic_only = !self.show_dynamic_only;
+            self.set_selected_index(0);
+        }
+    }
+
+    /// Get validators sorted for the Validators view
     pub fn get_sorted_validators(&self) -> Vec<ValidatorRecord> {
+        self.sorted_validators(ViewMode::Validators)
+    }
+
+    /// Get validators sorted by the sort key chosen for a view
+    /// This is the single source of truth for validator ordering
+    pub fn sorted_validators(&self, view: ViewMode) -> Vec<ValidatorRecord> {
         let mut validators: Vec<_> = if self.show_ours_only {
             self.state.our_validators.clone()
         } else {
             self.state.validators.clone()
         };
 
-        let epoch_data = &self.state.validator_epoch_data;
+        // Leaderboard: dynamic validators with known stake, ranked by stake
+        if view == ViewMode::Validators && self.show_dynamic_only {
+            let ranks = &self.state.dynamic_ranks;
+            validators.retain(|v| ranks.contains_key(&v.sidechain_key));
+            validators.sort_by(|a, b| {
+                let rank = |v: &ValidatorRecord| ranks[&v.sidechain_key].stake_rank;
+                rank(a)
+                    .cmp(&rank(b))
+                    .then_with(|| a.sidechain_key.cmp(&b.sidechain_key))
+            });
+            return validators;
+        }
+
+        let key = self.sort_key(view);
         validators.sort_by(|a, b| {
-            let a_perm = a.registration_status.as_deref() == Some("permissioned");
-            let b_perm = b.registration_status.as_deref() == Some("permissioned");
-            match (a_perm, b_perm) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => {
-                    let a_seats = epoch_data
-                        .get(&a.sidechain_key)
-                        .map(|e| e.committee_seats)
-                        .unwrap_or(0);
-                    let b_seats = epoch_data
-                        .get(&b.sidechain_key)
-                        .map(|e| e.committee_seats)
-                        .unwrap_or(0);
-                    b_seats
-                        .cmp(&a_seats)
-                        .then_with(|| a.sidechain_key.cmp(&b.sidechain_key))
-                }
-            }
+            compare_validators(
+                a,
+                b,
+                key,
+                &self.state.validator_epoch_data,
+                &self.state.validator_epoch_blocks,
+            )
         });
 
         validators
     }
 
+    /// Get the sort key for a view
+    pub fn sort_key(&self, view: ViewMode) -> SortKey {
+        self.sort_keys
+            .get(&view)
+            .copied()
+            .unwrap_or_else(|| SortKey::default_for(view))
+    }
+
+    /// Cycle the sort key for the current view (Validators/Performance only)
+    ///
+    /// The dynamic leaderboard is always ranked by stake, so it has no sort key.
+    pub fn cycle_sort_key(&mut self) {
+        let leaderboard = self.view_mode == ViewMode::Validators && self.show_dynamic_only;
+        if matches!(self.view_mode, ViewMode::Validators | ViewMode::Performance) && !leaderboard {
+            let next = self.sort_key(self.view_mode).next();
+            self.sort_keys.insert(self.view_mode, next);
+            // Ordering changed - start from the top
+            self.set_selected_index(0);
+        }
+    }
+
+    /// Append an event to the bounded event log
+    pub fn record_event(
+        &mut self,
+        severity: EventSeverity,
+        category: &'static str,
+        message: impl Into<String>,
+    ) {
+        self.push_event(MonitorEvent {
+            timestamp: chrono::Utc::now().timestamp(),
+            severity,
+            category: category.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn push_event(&mut self, event: MonitorEvent) {
+        let events = &mut self.state.events;
+        events.push(event);
+        if events.len() > MAX_EVENTS {
+            let excess = events.len() - MAX_EVENTS;
+            events.drain(..excess);
+        }
+    }
+
+    /// Record events for anything that changed since the previous refresh
+    fn record_transitions(&mut self, prev: &TransitionSnapshot) {
+        // The first successful fetch populates everything; nothing to compare
+        if prev.is_loading {
+            return;
+        }
+
+        let state = &self.state;
+        let mut events: Vec<(EventSeverity, &'static str, String)> = Vec::new();
+
+        match (&prev.last_error, &state.last_error) {
+            (None, Some(err)) => events.push((EventSeverity::Error, "monitor", err.clone())),
+            (Some(_), None) => events.push((
+                EventSeverity::Info,
+                "monitor",
+                "Data refresh recovered".to_string(),
+            )),
+            _ => {}
+        }
+
+        if prev.chain_phase != ChainPhase::Running && state.chain_phase == ChainPhase::Running {
+            events.push((
+                EventSeverity::Info,
+                "chain",
+                format!(
+                    "Chain started: block #{}, sidechain epoch {}",
+                    state.chain_tip, state.sidechain_epoch
+                ),
+            ));
+        }
+
+        if state.sidechain_epoch > prev.sidechain_epoch && prev.sidechain_epoch > 0 {
+            events.push((
+                EventSeverity::Info,
+                "epoch",
+                format!("Sidechain epoch {} started", state.sidechain_epoch),
+            ));
+        }
+        if state.mainchain_epoch > prev.mainchain_epoch && prev.mainchain_epoch > 0 {
+            events.push((
+                EventSeverity::Info,
+                "epoch",
+                format!("Mainchain epoch {} started", state.mainchain_epoch),
+            ));
+        }
+
+        let is_synced = state.sync_progress.is_synced;
+        if prev.is_synced && !is_synced {
+            events.push((
+                EventSeverity::Warning,
+                "sync",
+                format!(
+                    "Node fell behind ({} blocks remaining)",
+                    state.sync_progress.blocks_remaining
+                ),
+            ));
+        } else if !prev.is_synced && is_synced {
+            events.push((
+                EventSeverity::Info,
+                "sync",
+                "Node synced to chain tip".to_string(),
+            ));
+        }
+
+        if prev.peer_count > 0 && state.peer_count == 0 {
+            events.push((
+                EventSeverity::Error,
+                "peers",
+                "All peers disconnected".to_string(),
+            ));
+        } else if prev.peer_count == 0 && state.peer_count > 0 {
+            events.push((
+                EventSeverity::Info,
+                "peers",
+                format!("Peers reconnected ({})", state.peer_count),
+            ));
+        }
+
+        if !prev.node_version.is_empty()
+            && !state.node_version.is_empty()
+            && prev.node_version != state.node_version
+        {
+            events.push((
+                EventSeverity::Info,
+                "node",
+                format!(
+                    "Node version changed: {} -> {}",
+                    prev.node_version, state.node_version
+                ),
+            ));
+        }
+
+        if prev.committee_size > 0
+            && state.committee_size > 0
+            && prev.committee_elected != state.committee_elected
+        {
+            if state.committee_elected {
+                events.push((
+                    EventSeverity::Info,
+                    "committee",
+                    format!(
+                        "Elected to committee ({} seats / {})",
+                        state.committee_seats, state.committee_size
+                    ),
+                ));
+            } else {
+                events.push((
+                    EventSeverity::Warning,
+                    "committee",
+                    "No longer in committee".to_string(),
+                ));
+            }
+        }
+
+        for (severity, category, message) in events {
+            self.record_event(severity, category, message);
+        }
+    }
+
+    /// Unix seconds from which "blocks since restart" counts: the node's
+    /// process start when metrics report it, otherwise when MVM started
+    pub fn restart_baseline(&self) -> i64 {
+        self.state.node_started_at.unwrap_or(self.started_at)
+    }
+
+    /// Epoch whose seats snapshot sync hasn't captured yet (seats unknown, not zero)
+    pub fn pending_seats_epoch(&self) -> Option<u64> {
+        (self.state.sidechain_epoch > 0 && !self.state.has_epoch_snapshot)
+            .then_some(self.state.sidechain_epoch)
+    }
+
+    /// Our keystore's validator is neither a registered candidate nor in the committee
+    ///
+    /// More urgent than "not elected": an unregistered validator can never be selected.
+    pub fn is_unregistered(&self) -> bool {
+        self.state.registration == Some(RegistrationStatus::NotRegistered)
+            && !self.state.committee_elected
+    }
+
+    /// Conditions needing operator attention right now, most severe first
+    ///
+    /// Gathers the warnings the dashboard spreads across panels (node health,
+    /// registration, keystore, sync lag, clock, paused scrapes, audit) into
+    /// one list for focus mode.
+    pub fn active_alerts(&self) -> Vec<(EventSeverity, String)> {
+        let mut alerts = Vec::new();
+        if let Err(issue) = self.node_health() {
+            alerts.push((EventSeverity::Error, format!("Node: {}", issue)));
+        }
+        if self.is_unregistered() {
+            alerts.push((EventSeverity::Error, "Validator not registered".to_string()));
+        }
+        for mismatch in &self.state.key_mismatches {
+            alerts.push((
+                EventSeverity::Error,
+                format!(
+                    "Keystore {} key differs from on-chain registration",
+                    mismatch.key_type
+                ),
+            ));
+        }
+        if self.state.block_gap {
+            alerts.push((
+                EventSeverity::Error,
+                format!(
+                    "No blocks this epoch despite {} seats",
+                    self.state.committee_seats
+                ),
+            ));
+        }
+        if self.state.db_sync.lagging {
+            alerts.push((EventSeverity::Error, "MVM sync lagging".to_string()));
+        }
+        if self.state.grandpa_voter_lost {
+            alerts.push((
+                EventSeverity::Error,
+                "Node stopped voting in GRANDPA".to_string(),
+            ));
+        }
+        if let Some(secs) = self.clock_skew_warning() {
+            alerts.push((
+                EventSeverity::Warning,
+                format!(
+                    "Host clock {}s {} chain time",
+                    secs.abs(),
+                    if secs > 0 { "ahead of" } else { "behind" }
+                ),
+            ));
+        }
+        if self.state.metrics_paused || self.state.node_exporter_paused {
+            alerts.push((EventSeverity::Warning, "Metrics scrapes paused".to_string()));
+        }
+        for (epoch, issue) in &self.state.audit_issues {
+            alerts.push((
+                EventSeverity::Warning,
+                format!("Epoch {}: {}", epoch, issue),
+            ));
+        }
+        alerts
+    }
+
+    /// Current Unix time in ms corrected by the measured host clock skew
+    pub fn chain_now_ms(&self) -> u64 {
+        let skew = self.state.clock_skew_ms.unwrap_or(0);
+        (unix_now_ms() as i64 - skew).max(0) as u64
+    }
+
+    /// Clock skew in seconds when it exceeds `thresholds.max_clock_skew_secs`
+    pub fn clock_skew_warning(&self) -> Option<i64> {
+        let skew_secs = self.state.clock_skew_ms? / 1000;
+        (skew_secs.unsigned_abs() > self.thresholds.max_clock_skew_secs).then_some(skew_secs)
+    }
+
+    /// Measure skew from the tip slot, only when it's a fresh block on a synced node
+    ///
+    /// A stalled chain or node leaves the tip behind, and the growing gap would
+    /// read as the host clock running ahead, so stale tips keep the previous
+    /// measurement and implausibly large offsets are dropped.
+    fn sample_clock_skew(&mut self, tip_slot: u64, now_ms: u64) {
+        let advanced = self.prev_tip_slot.is_some_and(|prev| tip_slot > prev);
+        self.prev_tip_slot = Some(tip_slot);
+        if !advanced || self.state.sync_state_syncing {
+            return;
+        }
+        let skew = clock_skew_ms(tip_slot, self.chain_timing.slot_duration_ms, now_ms);
+        if skew.unsigned_abs() > MAX_CLOCK_SKEW_MS {
+            tracing::debug!("Ignoring implausible clock skew of {}ms", skew);
+            return;
+        }
+        self.record_clock_skew(skew);
+    }
+
+    /// Store a skew measurement, logging an event when it crosses the threshold
+    fn record_clock_skew(&mut self, skew_ms: i64) {
+        let was_skewed = self.clock_skew_warning().is_some();
+        self.state.clock_skew_ms = Some(skew_ms);
+        match (was_skewed, self.clock_skew_warning()) {
+            (false, Some(secs)) => self.record_event(
+                EventSeverity::Warning,
+                "clock",
+                format!(
+                    "Host clock is {}s {} chain time - check NTP on this host",
+                    secs.abs(),
+                    if secs > 0 { "ahead of" } else { "behind" }
+                ),
+            ),
+            (true, None) => self.record_event(
+                EventSeverity::Info,
+                "clock",
+                "Host clock back in step with chain time",
+            ),
+            _ => {}
+        }
+    }
+
+    /// Append the current finalization lag to the bounded history
+    fn record_finality_lag(&mut self) {
+        let lag = self
+            .state
+            .chain_tip
+            .saturating_sub(self.state.finalized_block);
+        let history = &mut self.state.finality_lag_history;
+        history.push(lag);
+        if history.len() > self.finality_history_len {
+            let excess = history.len() - self.finality_history_len;
+            history.drain(..excess);
+        }
+    }
+
+    /// Jump to the next of our validators in the current sorted list
+    ///
+    /// Repeated calls cycle through our validators, wrapping at the end.
+    pub fn jump_to_ours(&mut self) {
+        if !matches!(self.view_mode, ViewMode::Validators | ViewMode::Performance) {
+            return;
+        }
+
+        let ours: Vec<usize> = self
+            .sorted_validators(self.view_mode)
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_ours)
+            .map(|(i, _)| i)
+            .collect();
+
+        let current = self.selected_index();
+        if let Some(&next) = ours.iter().find(|&&i| i > current).or(ours.first()) {
+            self.set_selected_index(next);
+        }
+    }
+
     // ========================================
     // Popup Management
     // ========================================
@@ -1289,10 +2802,94 @@ impl App {
         let index = self.selected_index();
         if index < self.state.recent_blocks.len() {
             let block = self.state.recent_blocks[index].clone();
-            self.popup = Some(PopupContent::BlockDetail { block });
+            self.popup = Some(PopupContent::BlockDetail { block, calls: None });
+            self.block_calls_requested = self.decode_extrinsics;
+        }
+    }
+
+    /// Hash of the open block popup's block when it is waiting for its call breakdown
+    pub fn take_block_calls_request(&mut self) -> Option<String> {
+        if !std::mem::take(&mut self.block_calls_requested) {
+            return None;
+        }
+        match &self.popup {
+            Some(PopupContent::BlockDetail { block, .. }) => Some(block.block_hash.clone()),
+            _ => None,
         }
     }
 
+    /// Call breakdown of a block from `chain_getBlock`, or why it wasn't decoded
+    pub async fn block_calls(&mut self, rpc: &RpcClient, hash: &str) -> String {
+        match self.decode_block_calls(rpc, hash).await {
+            Ok(breakdown) => breakdown,
+            Err(e) => format!("not decoded: {:#}", e),
+        }
+    }
+
+    /// Fill in the block popup's call breakdown if it still shows that block
+    pub fn set_block_calls(&mut self, hash: &str, breakdown: String) {
+        if let Some(PopupContent::BlockDetail { block, calls }) = &mut self.popup {
+            if block.block_hash == hash {
+                *calls = Some(breakdown);
+            }
+        }
+    }
+
+    /// Call names of a block's extrinsics, counted by name
+    ///
+    /// Metadata is fetched once per runtime version (looked up at the block)
+    /// and cached. When the node has pruned the block's state, the cached or
+    /// latest metadata is used instead.
+    async fn decode_block_calls(&mut self, rpc: &RpcClient, hash: &str) -> Result<String> {
+        let spec_version = rpc
+            .call::<_, serde_json::Value>("state_getRuntimeVersion", vec![hash])
+            .await
+            .ok()
+            .and_then(|v| v["specVersion"].as_u64());
+        let cached = match (&self.runtime_calls, spec_version) {
+            (Some((cached, _)), Some(spec)) => *cached == spec,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if !cached {
+            let params: Vec<&str> = spec_version.map(|_| hash).into_iter().collect();
+            let metadata: String = rpc
+                .call("state_getMetadata", params)
+                .await
+                .context("Failed to fetch runtime metadata")?;
+            self.runtime_calls = Some((spec_version.unwrap_or(0), parse_metadata(&metadata)?));
+        }
+
+        let signed: SignedBlock = rpc
+            .call("chain_getBlock", vec![hash])
+            .await
+            .context("Failed to fetch block")?;
+        let Some((_, runtime)) = &self.runtime_calls else {
+            anyhow::bail!("No runtime metadata");
+        };
+        let names: Vec<String> = signed
+            .block
+            .extrinsics
+            .iter()
+            .map(|x| {
+                runtime
+                    .call_name(x)
+                    .unwrap_or_else(|_| "undecodable".to_string())
+            })
+            .collect();
+        Ok(summarize_calls(&names))
+    }
+
+    /// Open the legend for the current view (the Help view is its own legend)
+    pub fn open_legend(&mut self) {
+        let view = match self.view_mode {
+            ViewMode::Help => return,
+            ViewMode::ValidatorEpochDetail => ViewMode::Performance,
+            view => view,
+        };
+        self.popup = Some(PopupContent::Legend { view });
+    }
+
     /// Open peer detail popup
     pub fn open_peer_popup(&mut self) {
         let index = self.selected_index();
@@ -1330,19 +2927,36 @@ impl App {
             .copied()
             .unwrap_or(0);
 
+        let liveness = db.and_then(|database| {
+            database
+                .get_liveness_score(
+                    sidechain_key,
+                    self.state.sidechain_epoch,
+                    LIVENESS_WINDOW_EPOCHS,
+                    self.chain_timing.blocks_per_sidechain_epoch(),
+                )
+                .ok()?
+        });
+
         // Format stake if available
-        let stake_display = epoch_data.and_then(|d| d.stake_lovelace).map(|stake| {
-            // Convert lovelace to ADA (1 ADA = 1,000,000 lovelace)
-            let ada = stake as f64 / 1_000_000.0;
-            if ada >= 1_000_000.0 {
-                format!("{:.2}M tADA", ada / 1_000_000.0)
-            } else if ada >= 1_000.0 {
-                format!("{:.2}K tADA", ada / 1_000.0)
-            } else {
-                format!("{:.2} tADA", ada)
-            }
+        let stake_display = epoch_data.and_then(|d| d.stake_lovelace).map(format_stake);
+
+        // Compare against the previous epoch's stake snapshot
+        let stake_change = db.and_then(|database| {
+            database
+                .get_stake_history(sidechain_key, STAKE_HISTORY_EPOCHS)
+                .ok()
+                .and_then(|history| StakeChange::from_history(&history))
         });
 
+        let upcoming_slots = self
+            .state
+            .upcoming_slots
+            .iter()
+            .filter(|(_, key)| key == sidechain_key)
+            .map(|(slot, _)| *slot)
+            .collect();
+
         // Load committee selection statistics if database is available
         let selection_stats = db.and_then(|database| {
             database
@@ -1350,14 +2964,49 @@ impl App {
                 .ok()
         });
 
+        // Estimate reward earning rate from recent completed epochs
+        let reward_estimate = db.and_then(|database| {
+            let window = self.rewards.window_epochs as usize;
+            let epoch = self.state.sidechain_epoch;
+            let ours = database
+                .get_block_counts_by_epoch(std::slice::from_ref(sidechain_key), epoch, window)
+                .ok()?;
+            let network = database
+                .get_total_block_counts_by_epoch(epoch, window)
+                .ok()?;
+            estimate_rewards(
+                &ours,
+                &network,
+                selection_stats.as_ref().and_then(|s| s.stake_share_percent),
+                self.chain_timing.sidechain_epochs_per_day(),
+                self.rewards.reward_per_block,
+            )
+        });
+
+        let epoch = self.state.sidechain_epoch;
+        let rank = db.and_then(|database| database.get_rank_in_epoch(sidechain_key, epoch).ok()?);
+        let rank_history = db
+            .and_then(|database| {
+                database
+                    .get_rank_history(sidechain_key, epoch, RANK_HISTORY_EPOCHS)
+                    .ok()
+            })
+            .unwrap_or_default();
+
         self.popup = Some(PopupContent::ValidatorIdentity {
             validator,
             aura_key,
             current_epoch_seats,
             committee_size,
             blocks_this_epoch,
+            liveness,
             stake_display,
-            selection_stats,
+            stake_change,
+            upcoming_slots,
+            selection_stats: selection_stats.map(Box::new),
+            reward_estimate: reward_estimate.map(Box::new),
+            rank,
+            rank_history,
         });
     }
 
@@ -1377,13 +3026,8 @@ impl App {
 
     /// Open validator detail popup (from Performance view)
     pub fn open_validator_popup(&mut self, db: &Database) {
-        // Use the same sorting as render_performance: by total_blocks descending
-        let mut validators: Vec<_> = if self.show_ours_only {
-            self.state.our_validators.clone()
-        } else {
-            self.state.validators.clone()
-        };
-        validators.sort_by(|a, b| b.total_blocks.cmp(&a.total_blocks));
+        // Use the same ordering as render_performance
+        let validators = self.sorted_validators(ViewMode::Performance);
 
         let index = self.selected_index();
         if index >= validators.len() {
@@ -1393,23 +3037,155 @@ impl App {
         let validator = validators[index].clone();
         let sidechain_key = validator.sidechain_key.clone();
 
-        // Load epoch history from database
-        let epoch_history = match db.get_validator_epoch_history(&sidechain_key, 50) {
-            Ok(history) => history,
-            Err(e) => {
-                tracing::warn!("Failed to load validator epoch history: {}", e);
-                Vec::new()
-            }
-        };
+        // Load the most recent epochs; older ones load on scrolling past the end
+        let epoch_history =
+            match db.get_validator_epoch_history(&sidechain_key, self.history_epochs) {
+                Ok(history) => history,
+                Err(e) => {
+                    tracing::warn!("Failed to load validator epoch history: {}", e);
+                    Vec::new()
+                }
+            };
+        let has_more = epoch_history.len() >= self.history_epochs;
 
         // Open as popup
         self.popup = Some(PopupContent::ValidatorDetail {
             validator,
             epoch_history,
             scroll_index: 0,
+            has_more,
         });
     }
 
+    /// Load older epochs into the validator detail popup when at its last row
+    pub fn load_more_history(&mut self, db: &Database) {
+        let step = self.history_epochs;
+        let Some(PopupContent::ValidatorDetail {
+            validator,
+            epoch_history,
+            scroll_index,
+            has_more,
+        }) = &mut self.popup
+        else {
+            return;
+        };
+        if !*has_more || *scroll_index + 1 < epoch_history.len() {
+            return;
+        }
+
+        let limit = epoch_history.len() + step;
+        match db.get_validator_epoch_history(&validator.sidechain_key, limit) {
+            Ok(history) => {
+                *has_more = history.len() >= limit;
+                *epoch_history = history;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load more validator epoch history: {}", e);
+                *has_more = false;
+            }
+        }
+    }
+
+    /// Mark or unmark the selected validator for comparison (Performance view)
+    ///
+    /// At most two validators stay marked; marking a third drops the oldest.
+    pub fn toggle_compare_mark(&mut self) {
+        if self.view_mode != ViewMode::Performance {
+            return;
+        }
+        let validators = self.sorted_validators(ViewMode::Performance);
+        let Some(validator) = validators.get(self.selected_index()) else {
+            return;
+        };
+
+        let key = &validator.sidechain_key;
+        if let Some(pos) = self.compare_marks.iter().position(|k| k == key) {
+            self.compare_marks.remove(pos);
+        } else {
+            if self.compare_marks.len() == 2 {
+                self.compare_marks.remove(0);
+            }
+            self.compare_marks.push(key.clone());
+        }
+    }
+
+    /// Open the side-by-side comparison popup for the two marked validators
+    pub fn open_compare_popup(&mut self, db: &Database) {
+        let [first, second] = self.compare_marks.as_slice() else {
+            return;
+        };
+
+        let load = |key: &str| -> Option<ValidatorComparison> {
+            let validator = self
+                .state
+                .validators
+                .iter()
+                .find(|v| v.sidechain_key == key)?
+                .clone();
+            let selection_stats = db
+                .get_committee_selection_stats(key, self.state.sidechain_epoch)
+                .ok();
+            let tracked = selection_stats
+                .as_ref()
+                .map(|s| s.epochs_tracked as usize)
+                .unwrap_or(0);
+            let epoch_history = match db.get_validator_epoch_history(key, tracked) {
+                Ok(history) => history,
+                Err(e) => {
+                    tracing::warn!("Failed to load validator epoch history: {}", e);
+                    Vec::new()
+                }
+            };
+            let stake_lovelace = self
+                .state
+                .validator_epoch_data
+                .get(key)
+                .and_then(|d| d.stake_lovelace);
+            Some(ValidatorComparison {
+                validator,
+                selection_stats,
+                epoch_history,
+                stake_lovelace,
+            })
+        };
+
+        if let (Some(a), Some(b)) = (load(first), load(second)) {
+            self.popup = Some(PopupContent::ValidatorCompare {
+                sides: Box::new([a, b]),
+            });
+        }
+    }
+
+    /// Open the block distribution popup for the last completed epoch
+    pub fn open_epoch_distribution_popup(&mut self, db: &Database) {
+        let epoch = self.state.sidechain_epoch.saturating_sub(1);
+        self.load_epoch_distribution(db, epoch);
+    }
+
+    /// Move the open distribution popup to an earlier (-) or later (+) epoch
+    pub fn step_distribution_epoch(&mut self, db: &Database, delta: i64) {
+        let Some(PopupContent::EpochDistribution { epoch, .. }) = self.popup else {
+            return;
+        };
+        let target = epoch
+            .saturating_add_signed(delta)
+            .min(self.state.sidechain_epoch);
+        if target != epoch {
+            self.load_epoch_distribution(db, target);
+        }
+    }
+
+    fn load_epoch_distribution(&mut self, db: &Database, epoch: u64) {
+        let shares = match db.get_epoch_author_distribution(epoch) {
+            Ok(shares) => shares,
+            Err(e) => {
+                tracing::warn!("Failed to load block distribution: {}", e);
+                Vec::new()
+            }
+        };
+        self.popup = Some(PopupContent::EpochDistribution { epoch, shares });
+    }
+
     /// Scroll down within validator detail popup
     pub fn popup_scroll_down(&mut self) {
         if let Some(PopupContent::ValidatorDetail {
@@ -1476,6 +3252,248 @@ impl App {
         !self.view_stack.is_empty()
     }
 
+    /// Take over the view and theme of the node being switched away from
+    ///
+    /// Drill-downs and popups are node-specific, so they fall back to their base view.
+    pub fn follow_view(&mut self, other: &App) {
+        let view = match other.view_mode {
+            ViewMode::ValidatorEpochDetail => ViewMode::Performance,
+            view => view,
+        };
+        self.set_view(view);
+        self.theme = other.theme;
+        self.show_ours_only = other.show_ours_only;
+        self.show_dynamic_only = other.show_dynamic_only;
+    }
+
+    /// Ask for an immediate data refresh instead of waiting for the interval
+    ///
+    /// Returns false when a refresh is already pending or in flight, so
+    /// repeated presses don't queue overlapping updates.
+    pub fn request_refresh(&mut self) -> bool {
+        if self.refresh_requested {
+            return false;
+        }
+        self.refresh_requested = true;
+        true
+    }
+
+    /// The current list view's rows in on-screen order, with the active filter and sort
+    ///
+    /// Uses the same sources as the renderer (`sorted_validators`, the leaderboard
+    /// ranks, recent blocks, connected peers) but with full keys instead of the
+    /// width-dependent short forms. None for views that are not a list.
+    pub fn export_current_view(&self) -> Option<ViewTable> {
+        let state = &self.state;
+        let flag = |b: bool| if b { "yes" } else { "" }.to_string();
+        let table = match self.view_mode {
+            ViewMode::Blocks => ViewTable {
+                name: "blocks",
+                header: vec![
+                    "block_number",
+                    "slot",
+                    "epoch",
+                    "extrinsics",
+                    "finalized",
+                    "author",
+                ],
+                rows: state
+                    .recent_blocks
+                    .iter()
+                    .map(|b| {
+                        vec![
+                            b.block_number.to_string(),
+                            b.slot_number.to_string(),
+                            b.sidechain_epoch.to_string(),
+                            b.extrinsics_count.to_string(),
+                            flag(b.is_finalized),
+                            b.author_key.clone().unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            },
+            ViewMode::Validators if self.show_dynamic_only => ViewTable {
+                name: "leaderboard",
+                header: vec![
+                    "rank",
+                    "ours",
+                    "sidechain_key",
+                    "label",
+                    "stake_lovelace",
+                    "stake_share_percent",
+                    "seats",
+                    "times_selected",
+                    "epochs_tracked",
+                ],
+                rows: self
+                    .get_sorted_validators()
+                    .iter()
+                    .filter_map(|v| {
+                        let rank = state.dynamic_ranks.get(&v.sidechain_key)?;
+                        Some(vec![
+                            rank.stake_rank.to_string(),
+                            flag(v.is_ours),
+                            v.sidechain_key.clone(),
+                            v.label.clone().unwrap_or_default(),
+                            rank.stake_lovelace.to_string(),
+                            format!("{:.2}", rank.stake_share_percent),
+                            rank.committee_seats.to_string(),
+                            rank.times_selected.to_string(),
+                            rank.epochs_tracked.to_string(),
+                        ])
+                    })
+                    .collect(),
+            },
+            ViewMode::Validators => ViewTable {
+                name: "validators",
+                header: vec![
+                    "ours",
+                    "sidechain_key",
+                    "label",
+                    "status",
+                    "seats",
+                    "epoch_blocks",
+                ],
+                rows: self
+                    .get_sorted_validators()
+                    .iter()
+                    .map(|v| {
+                        // Empty seats while the epoch has no snapshot, like the "-" on screen
+                        let seats = match state.validator_epoch_data.get(&v.sidechain_key) {
+                            Some(epoch) => epoch.committee_seats.to_string(),
+                            None if self.pending_seats_epoch().is_some() => String::new(),
+                            None => "0".to_string(),
+                        };
+                        vec![
+                            flag(v.is_ours),
+                            v.sidechain_key.clone(),
+                            v.label.clone().unwrap_or_default(),
+                            v.registration_status
+                                .clone()
+                                .unwrap_or_else(|| "unknown".to_string()),
+                            seats,
+                            state
+                                .validator_epoch_blocks
+                                .get(&v.sidechain_key)
+                                .copied()
+                                .unwrap_or(0)
+                                .to_string(),
+                        ]
+                    })
+                    .collect(),
+            },
+            ViewMode::Performance => ViewTable {
+                name: "performance",
+                header: vec![
+                    "position",
+                    "ours",
+                    "sidechain_key",
+                    "total_blocks",
+                    "share_percent",
+                ],
+                rows: self
+                    .sorted_validators(ViewMode::Performance)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        let share = if state.total_blocks > 0 {
+                            (v.total_blocks as f64 / state.total_blocks as f64) * 100.0
+                        } else {
+                            0.0
+                        };
+                        vec![
+                            (i + 1).to_string(),
+                            flag(v.is_ours),
+                            v.sidechain_key.clone(),
+                            v.total_blocks.to_string(),
+                            format!("{:.3}", share),
+                        ]
+                    })
+                    .collect(),
+            },
+            ViewMode::Peers => ViewTable {
+                name: "peers",
+                header: vec![
+                    "peer_id",
+                    "address",
+                    "direction",
+                    "best_block",
+                    "sync",
+                    "avg_blocks_behind",
+                ],
+                rows: state
+                    .connected_peers
+                    .iter()
+                    .map(|p| {
+                        let sync = if p.best_number >= state.chain_tip {
+                            "synced"
+                        } else if state.chain_tip.saturating_sub(p.best_number) < 10 {
+                            "near"
+                        } else {
+                            "behind"
+                        };
+                        vec![
+                            p.peer_id.clone(),
+                            p.address.clone().unwrap_or_default(),
+                            if p.is_outbound { "outbound" } else { "inbound" }.to_string(),
+                            p.best_number.to_string(),
+                            sync.to_string(),
+                            format!("{:.1}", p.avg_blocks_behind),
+                        ]
+                    })
+                    .collect(),
+            },
+            _ => return None,
+        };
+        Some(table)
+    }
+
+    /// Export the current list view as CSV to the current directory
+    pub fn export_view(&mut self) {
+        let Some(table) = self.export_current_view() else {
+            self.record_event(
+                EventSeverity::Warning,
+                "export",
+                "Nothing to export here (Blocks, Validators, Performance and Peers can be exported)"
+                    .to_string(),
+            );
+            return;
+        };
+        let csv = crate::commands::output::csv_table(&table.header, &table.rows);
+        match crate::tui::snapshot::write_export(std::path::Path::new("."), table.name, &csv) {
+            Ok(path) => self.record_event(
+                EventSeverity::Info,
+                "export",
+                format!("{} rows written to {}", table.rows.len(), path.display()),
+            ),
+            Err(e) => self.record_event(
+                EventSeverity::Error,
+                "export",
+                format!("Export failed: {:#}", e),
+            ),
+        }
+    }
+
+    /// Dump the full state to the current directory for `mvm view --replay`
+    pub fn dump_state(&mut self) {
+        match crate::tui::snapshot::dump_state(std::path::Path::new("."), &self.state) {
+            Ok(path) => self.record_event(
+                EventSeverity::Info,
+                "replay",
+                format!(
+                    "State written to {} (mvm view --replay {})",
+                    path.display(),
+                    path.display()
+                ),
+            ),
+            Err(e) => self.record_event(
+                EventSeverity::Error,
+                "replay",
+                format!("State dump failed: {:#}", e),
+            ),
+        }
+    }
+
     /// Toggle theme
     pub fn toggle_theme(&mut self) {
         self.theme = self.theme.toggle();
@@ -1486,3 +3504,728 @@ impl App {
         self.should_quit = true;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_phase() {
+        assert_eq!(ChainPhase::detect(0, None), ChainPhase::NoBlocks);
+        assert_eq!(ChainPhase::detect(0, Some(0)), ChainPhase::NoBlocks);
+        assert_eq!(ChainPhase::detect(5, None), ChainPhase::SidechainNotStarted);
+        assert_eq!(
+            ChainPhase::detect(5, Some(0)),
+            ChainPhase::SidechainNotStarted
+        );
+        assert_eq!(ChainPhase::detect(5, Some(1234)), ChainPhase::Running);
+
+        // Leaving the not-started phase is recorded once
+        let mut app = App::new();
+        app.state.is_loading = false;
+        app.state.chain_phase = ChainPhase::NoBlocks;
+        let previous = TransitionSnapshot::capture(&app.state);
+        app.state.chain_phase = ChainPhase::Running;
+        app.state.chain_tip = 1;
+        app.state.sidechain_epoch = 1234;
+        app.record_transitions(&previous);
+        let event = app.state.events.last().unwrap();
+        assert_eq!(event.category, "chain");
+        assert_eq!(
+            event.message,
+            "Chain started: block #1, sidechain epoch 1234"
+        );
+    }
+
+    #[test]
+    fn test_help_scroll_bound_matches_content() {
+        let mut app = App::new();
+        app.set_view(ViewMode::Help);
+
+        // Page down well past the end; selection must stop on the last item
+        for _ in 0..50 {
+            app.scroll_page_down();
+        }
+
+        let item_count = crate::tui::ui::help_items(app.theme).len();
+        assert!(item_count > 0);
+        assert_eq!(app.selected_index(), item_count - 1);
+    }
+
+    #[test]
+    fn test_request_refresh() {
+        let mut app = App::new();
+        assert!(app.request_refresh());
+        // A second press while pending doesn't queue another update
+        assert!(!app.request_refresh());
+        assert!(app.refresh_requested);
+
+        app.refresh_requested = false;
+        let r = crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('r'),
+            crossterm::event::KeyModifiers::NONE,
+        );
+        assert!(crate::tui::event::handle_key_event(r, &mut app, None));
+        assert!(app.refresh_requested);
+    }
+
+    #[test]
+    fn test_follow_view() {
+        let mut from = App::new();
+        from.set_view(ViewMode::ValidatorEpochDetail);
+        from.toggle_theme();
+        from.show_ours_only = true;
+
+        let mut to = App::new();
+        to.popup = Some(PopupContent::EpochDistribution {
+            epoch: 1,
+            shares: Vec::new(),
+        });
+        to.follow_view(&from);
+        assert_eq!(to.view_mode, ViewMode::Performance);
+        assert_eq!(to.theme, from.theme);
+        assert!(to.show_ours_only);
+        assert!(to.popup.is_none());
+    }
+
+    fn validator(key: &str, status: &str, total_blocks: u64) -> ValidatorRecord {
+        ValidatorRecord {
+            sidechain_key: key.to_string(),
+            aura_key: None,
+            grandpa_key: None,
+            label: None,
+            is_ours: false,
+            registration_status: Some(status.to_string()),
+            first_seen_epoch: None,
+            total_blocks,
+        }
+    }
+
+    #[test]
+    fn test_our_slot_schedule() {
+        let mut app = App::new();
+        let slot_ms = app.chain_timing.slot_duration_ms;
+        let now_ms = 1_000 * slot_ms;
+
+        let mut ours = validator("0xours", "Active", 0);
+        ours.aura_key = Some("0xABC".to_string());
+        app.state.our_validators = vec![ours];
+        let committee = vec!["0xabc".to_string(), "0xdef".to_string()];
+
+        // Unknown epoch end -> no schedule
+        assert!(app.our_slot_schedule(&committee, now_ms).is_empty());
+
+        // Epoch ends at slot 1006: remaining slots 1001..1005, ours are the even ones
+        app.state.sidechain_next_epoch_ms = Some(1_006 * slot_ms);
+        let slots: Vec<u64> = app
+            .our_slot_schedule(&committee, now_ms)
+            .into_iter()
+            .map(|(slot, key)| {
+                assert_eq!(key, "0xours");
+                slot
+            })
+            .collect();
+        assert_eq!(slots, vec![1002, 1004]);
+    }
+
+    #[test]
+    fn test_sort_key_cycle_per_view() {
+        let mut app = App::new();
+        app.state.validators = vec![
+            validator("0xccc", "registered", 5),
+            validator("0xaaa", "registered", 20),
+            validator("0xbbb", "permissioned", 10),
+        ];
+        app.state
+            .validator_epoch_blocks
+            .insert("0xccc".to_string(), 7);
+
+        let keys =
+            |vs: Vec<ValidatorRecord>| vs.into_iter().map(|v| v.sidechain_key).collect::<Vec<_>>();
+
+        // Defaults: permissioned first on Validators, all-time blocks on Performance
+        assert_eq!(
+            keys(app.sorted_validators(ViewMode::Validators)),
+            vec!["0xbbb", "0xaaa", "0xccc"]
+        );
+        assert_eq!(
+            keys(app.sorted_validators(ViewMode::Performance)),
+            vec!["0xaaa", "0xbbb", "0xccc"]
+        );
+
+        // Cycling on Validators does not affect Performance
+        app.set_view(ViewMode::Validators);
+        app.cycle_sort_key();
+        app.cycle_sort_key();
+        assert_eq!(app.sort_key(ViewMode::Validators), SortKey::EpochBlocks);
+        assert_eq!(
+            keys(app.sorted_validators(ViewMode::Validators)),
+            vec!["0xccc", "0xaaa", "0xbbb"]
+        );
+        assert_eq!(app.sort_key(ViewMode::Performance), SortKey::TotalBlocks);
+
+        // Cycle wraps back to the start
+        for _ in 0..4 {
+            app.cycle_sort_key();
+        }
+        assert_eq!(app.sort_key(ViewMode::Validators), SortKey::Permissioned);
+    }
+
+    #[test]
+    fn test_dynamic_leaderboard_filter() {
+        let mut app = App::new();
+        app.state.validators = vec![
+            validator("0xccc", "registered", 5),
+            validator("0xaaa", "registered", 20),
+            validator("0xbbb", "permissioned", 10),
+        ];
+        for (key, stake_rank) in [("0xccc", 1), ("0xaaa", 2)] {
+            app.state.dynamic_ranks.insert(
+                key.to_string(),
+                DynamicValidatorRank {
+                    sidechain_key: key.to_string(),
+                    stake_rank,
+                    stake_lovelace: 1_000 / stake_rank as u64,
+                    stake_share_percent: 50.0,
+                    committee_seats: 0,
+                    epochs_tracked: 0,
+                    times_selected: 0,
+                },
+            );
+        }
+        let keys =
+            |vs: Vec<ValidatorRecord>| vs.into_iter().map(|v| v.sidechain_key).collect::<Vec<_>>();
+
+        // Only toggles on the Validators view
+        app.toggle_dynamic_filter();
+        assert!(!app.show_dynamic_only);
+
+        app.set_view(ViewMode::Validators);
+        app.toggle_dynamic_filter();
+        assert_eq!(keys(app.get_sorted_validators()), vec!["0xccc", "0xaaa"]);
+        // Ranked by stake regardless of the sort key, which stays put
+        app.cycle_sort_key();
+        assert_eq!(app.sort_key(ViewMode::Validators), SortKey::Permissioned);
+        // Performance keeps every validator
+        assert_eq!(app.sorted_validators(ViewMode::Performance).len(), 3);
+
+        app.toggle_dynamic_filter();
+        assert_eq!(app.get_sorted_validators().len(), 3);
+    }
+
+    #[test]
+    fn test_export_current_view() {
+        let mut app = App::new();
+        app.state.validators = vec![
+            validator("0xaaa", "registered", 20),
+            validator("0xbbb", "permissioned", 60),
+        ];
+        app.state.our_validators = vec![validator("0xaaa", "registered", 20)];
+        app.state.total_blocks = 80;
+
+        // Same order as the screen (all-time blocks)
+        app.set_view(ViewMode::Performance);
+        let table = app.export_current_view().unwrap();
+        assert_eq!(table.name, "performance");
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0][2], "0xbbb");
+        assert_eq!(table.rows[0][4], "75.000");
+
+        // The active filter applies
+        app.toggle_ours_filter();
+        let table = app.export_current_view().unwrap();
+        assert_eq!(table.rows.len(), 1);
+        assert_eq!(table.rows[0][2], "0xaaa");
+
+        app.set_view(ViewMode::Dashboard);
+        assert!(app.export_current_view().is_none());
+    }
+
+    #[test]
+    fn test_public_socket_addr() {
+        assert_eq!(
+            public_socket_addr("/ip4/203.0.113.1/tcp/30333").as_deref(),
+            Some("203.0.113.1:30333")
+        );
+        assert_eq!(
+            public_socket_addr("/ip6/2001:db8::1/tcp/30444").as_deref(),
+            Some("[2001:db8::1]:30444")
+        );
+        assert_eq!(
+            public_socket_addr("/ip6/2a01:4f8:0:0:0:0:0:1/tcp/30333/ws").as_deref(),
+            Some("[2a01:4f8::1]:30333")
+        );
+
+        for internal in [
+            "/ip4/127.0.0.1/tcp/30333",
+            "/ip4/10.0.0.5/tcp/30333",
+            "/ip4/172.20.1.1/tcp/30333",
+            "/ip4/192.168.1.10/tcp/30333",
+            "/ip4/169.254.0.1/tcp/30333",
+            "/ip6/::1/tcp/30333",
+            "/ip6/fe80::1/tcp/30333",
+            "/ip6/fd00::1/tcp/30333",
+            "/dns/example.com/tcp/30333",
+            "/ip4/203.0.113.1",
+        ] {
+            assert_eq!(public_socket_addr(internal), None, "{}", internal);
+        }
+
+        // 172.2.x.x is public (only 172.16.0.0/12 is private)
+        assert!(public_socket_addr("/ip4/172.2.0.1/tcp/30333").is_some());
+    }
+
+    #[test]
+    fn test_rank_external_addrs() {
+        let addrs = || {
+            vec![
+                "198.51.100.7:41234".to_string(),
+                "203.0.113.10:30333".to_string(),
+                "[2001:db8::1]:30333".to_string(),
+                "203.0.113.10:30333".to_string(),
+            ]
+        };
+
+        // P2P port first, then sorted; duplicates removed
+        assert_eq!(
+            rank_external_addrs(addrs(), None, None, 30333),
+            vec![
+                "203.0.113.10:30333",
+                "[2001:db8::1]:30333",
+                "198.51.100.7:41234"
+            ]
+        );
+
+        // Pinned address moves to the front, or is added if not reported
+        assert_eq!(
+            rank_external_addrs(addrs(), None, Some("198.51.100.7"), 30333)[0],
+            "198.51.100.7:41234"
+        );
+        assert_eq!(
+            rank_external_addrs(addrs(), None, Some("192.0.2.1"), 30333)[0],
+            "192.0.2.1"
+        );
+
+        // CIDR filter drops addresses outside the block
+        let filter = IpFilter::from_str("203.0.113.0/24").unwrap();
+        assert_eq!(
+            rank_external_addrs(addrs(), Some(&filter), None, 30333),
+            vec!["203.0.113.10:30333"]
+        );
+    }
+
+    #[test]
+    fn test_db_sync_progress() {
+        let mut app = App::new();
+        let start = Instant::now();
+        app.state.chain_tip = 10_000;
+
+        app.state.total_blocks = 4_000;
+        app.update_db_sync_progress(4_000, start);
+        assert!(app.state.db_sync.is_catching_up());
+        assert_eq!(app.state.db_sync.blocks_remaining, 6_000);
+        assert!((app.state.db_sync.percent - 40.0).abs() < 0.01);
+        assert_eq!(app.state.db_sync.eta_seconds, None);
+
+        // 1000 blocks inserted over 10s -> 100 blk/s, 50s to cover the remaining 5000
+        app.state.total_blocks = 5_000;
+        app.update_db_sync_progress(5_000, start + Duration::from_secs(10));
+        assert!((app.state.db_sync.insert_rate_bps - 100.0).abs() < 0.01);
+        assert_eq!(app.state.db_sync.eta_seconds, Some(50));
+
+        // Trailing by a few blocks during normal polling is not catching up
+        app.state.total_blocks = 9_995;
+        app.update_db_sync_progress(9_995, start + Duration::from_secs(20));
+        assert!(!app.state.db_sync.is_catching_up());
+        assert!(!app.state.db_sync.lagging);
+
+        // Sync stalls: the gap grows past the threshold, holds between blocks, then closes
+        app.thresholds.max_db_lag_blocks = 20;
+        for (tip, lagging) in [
+            (10_010, false),
+            (10_030, true),
+            (10_030, true),
+            (10_020, false),
+        ] {
+            app.state.chain_tip = tip;
+            app.update_db_sync_progress(9_995, start + Duration::from_secs(30));
+            assert_eq!(app.state.db_sync.lagging, lagging, "tip {}", tip);
+        }
+    }
+
+    #[test]
+    fn test_jump_to_ours_cycles() {
+        let mut app = App::new();
+        let mut ours_a = validator("0xaaa", "registered", 20);
+        ours_a.is_ours = true;
+        let mut ours_c = validator("0xccc", "registered", 5);
+        ours_c.is_ours = true;
+        app.state.validators = vec![
+            ours_c,
+            validator("0xbbb", "permissioned", 10),
+            ours_a,
+            validator("0xddd", "registered", 1),
+        ];
+
+        // Sorted: 0xbbb (permissioned), 0xaaa, 0xccc, 0xddd
+        app.set_view(ViewMode::Validators);
+        app.jump_to_ours();
+        assert_eq!(app.selected_index(), 1);
+        app.jump_to_ours();
+        assert_eq!(app.selected_index(), 2);
+        app.jump_to_ours();
+        assert_eq!(app.selected_index(), 1);
+
+        // No-op outside validator lists
+        app.set_view(ViewMode::Blocks);
+        app.jump_to_ours();
+        assert_eq!(app.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_finality_lag_history_bounded() {
+        let mut app = App::new();
+        app.finality_history_len = 3;
+        for (tip, finalized) in [(10, 8), (11, 8), (12, 8), (13, 12)] {
+            app.state.chain_tip = tip;
+            app.state.finalized_block = finalized;
+            app.record_finality_lag();
+        }
+        assert_eq!(app.state.finality_lag_history, vec![3, 4, 1]);
+    }
+
+    #[test]
+    fn test_restart_baseline() {
+        let mut app = App::new();
+        app.started_at = 1_700_000_500;
+        assert_eq!(app.restart_baseline(), 1_700_000_500);
+
+        // A node restart reported by metrics moves the baseline
+        app.state.node_started_at = Some(1_700_000_900);
+        assert_eq!(app.restart_baseline(), 1_700_000_900);
+    }
+
+    #[test]
+    fn test_pending_seats_epoch() {
+        let mut app = App::new();
+        // Epoch not known yet: nothing to call pending
+        assert_eq!(app.pending_seats_epoch(), None);
+
+        app.state.sidechain_epoch = 245_001;
+        assert_eq!(app.pending_seats_epoch(), Some(245_001));
+
+        app.state.has_epoch_snapshot = true;
+        assert_eq!(app.pending_seats_epoch(), None);
+    }
+
+    #[test]
+    fn test_is_unregistered() {
+        let mut app = App::new();
+        // Not checked yet
+        assert!(!app.is_unregistered());
+
+        app.state.registration = Some(RegistrationStatus::NotRegistered);
+        assert!(app.is_unregistered());
+
+        // Still seated from an earlier registration: not elected is the lesser problem
+        app.state.committee_elected = true;
+        assert!(!app.is_unregistered());
+
+        app.state.committee_elected = false;
+        app.state.registration = Some(RegistrationStatus::RegisteredValid);
+        assert!(!app.is_unregistered());
+    }
+
+    #[test]
+    fn test_block_gap() {
+        let mut app = App::new();
+        app.thresholds.alert_missed_block_epoch_percent = 50.0;
+        app.state.committee_elected = true;
+        app.state.committee_seats = 3;
+        app.state.epoch_progress.progress_percent = 40.0;
+        app.check_block_gap();
+        assert!(!app.state.block_gap);
+
+        app.state.epoch_progress.progress_percent = 60.0;
+        app.check_block_gap();
+        assert!(app.state.block_gap);
+        assert!(app.state.events.last().unwrap().message.contains("3 seats"));
+        assert!(app
+            .active_alerts()
+            .iter()
+            .any(|(_, a)| a.contains("No blocks this epoch")));
+
+        app.state.epoch_progress.our_blocks_this_epoch = 1;
+        app.check_block_gap();
+        assert!(!app.state.block_gap);
+        assert!(app.state.events.last().unwrap().message.contains("cleared"));
+
+        // Not elected: nothing is expected of us
+        app.state.epoch_progress.our_blocks_this_epoch = 0;
+        app.state.committee_elected = false;
+        app.check_block_gap();
+        assert!(!app.state.block_gap);
+    }
+
+    #[test]
+    fn test_epoch_projection() {
+        let mut app = App::new();
+        assert!(app.epoch_projection().is_none());
+
+        // Preview: 1200 blocks per epoch, 2 of 12 seats -> 200 expected
+        app.state.committee_elected = true;
+        app.state.committee_seats = 2;
+        app.state.committee_size = 12;
+        app.state.epoch_progress.progress_percent = 50.0;
+        app.state.epoch_progress.expected_blocks = 100.0;
+        app.state.epoch_progress.our_blocks_this_epoch = 100;
+
+        // On pace at halfway projects to the full-epoch expectation
+        let p = app.epoch_projection().unwrap();
+        assert!((p.expected - 200.0).abs() < 1e-9);
+        assert!(p.deviation_percent().abs() < 1e-9);
+
+        app.state.epoch_progress.our_blocks_this_epoch = 60;
+        assert!((app.epoch_projection().unwrap().deviation_percent() + 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        // Slot 100 starts at 600_000ms with 6s slots
+        assert_eq!(clock_skew_ms(100, 6000, 603_000), 0);
+        assert_eq!(clock_skew_ms(100, 6000, 606_000), 0);
+        assert_eq!(clock_skew_ms(100, 6000, 614_000), 8_000);
+        assert_eq!(clock_skew_ms(100, 6000, 590_000), -10_000);
+
+        let mut app = App::new();
+        app.thresholds.max_clock_skew_secs = 5;
+        app.record_clock_skew(3_000);
+        assert_eq!(app.clock_skew_warning(), None);
+        app.record_clock_skew(-9_000);
+        assert_eq!(app.clock_skew_warning(), Some(-9));
+        assert!(app
+            .state
+            .events
+            .last()
+            .unwrap()
+            .message
+            .contains("9s behind"));
+        app.record_clock_skew(0);
+        assert_eq!(app.clock_skew_warning(), None);
+
+        // Only fresh tips are sampled: a stalled tip keeps the last measurement
+        let mut app = App::new();
+        app.sample_clock_skew(100, 620_000);
+        assert_eq!(app.state.clock_skew_ms, None);
+        app.sample_clock_skew(101, 620_000);
+        assert_eq!(app.state.clock_skew_ms, Some(8_000));
+        app.sample_clock_skew(101, 900_000);
+        assert_eq!(app.state.clock_skew_ms, Some(8_000));
+
+        // Nor while syncing, nor when the offset is implausible
+        app.state.sync_state_syncing = true;
+        app.sample_clock_skew(102, 700_000);
+        assert_eq!(app.state.clock_skew_ms, Some(8_000));
+        app.state.sync_state_syncing = false;
+        app.sample_clock_skew(103, 3_000_000);
+        assert_eq!(app.state.clock_skew_ms, Some(8_000));
+    }
+
+    #[test]
+    fn test_apply_refresh() {
+        // The refresher's app hands each event over once
+        let mut fetched = App::new();
+        fetched.state.chain_tip = 42;
+        fetched.record_event(EventSeverity::Warning, "peers", "Peer count dropped");
+        let state = fetched.take_refreshed_state();
+        assert!(fetched.state.events.is_empty());
+        assert_eq!(fetched.state.chain_tip, 42);
+
+        // The UI's app takes the data and keeps its own events
+        let mut app = App::new();
+        app.record_event(EventSeverity::Info, "export", "Wrote validators.csv");
+        app.refresh_requested = true;
+        app.apply_refresh(state);
+        assert_eq!(app.state.chain_tip, 42);
+        assert!(!app.refresh_requested);
+        let categories: Vec<_> = app
+            .state
+            .events
+            .iter()
+            .map(|e| e.category.as_str())
+            .collect();
+        assert_eq!(categories, vec!["export", "peers"]);
+    }
+
+    #[test]
+    fn test_record_transitions() {
+        let mut app = App::new();
+        app.state.sidechain_epoch = 100;
+        app.state.peer_count = 5;
+        app.state.sync_progress.is_synced = true;
+
+        // No events while the first fetch is still loading
+        let previous = TransitionSnapshot::capture(&app.state);
+        app.state.sidechain_epoch = 101;
+        app.record_transitions(&previous);
+        assert!(app.state.events.is_empty());
+
+        app.state.is_loading = false;
+        let previous = TransitionSnapshot::capture(&app.state);
+        app.state.sidechain_epoch = 102;
+        app.state.peer_count = 0;
+        app.state.sync_progress.is_synced = false;
+        app.record_transitions(&previous);
+
+        let categories: Vec<_> = app
+            .state
+            .events
+            .iter()
+            .map(|e| e.category.as_str())
+            .collect();
+        assert_eq!(categories, vec!["epoch", "sync", "peers"]);
+        assert_eq!(app.state.events[2].severity, EventSeverity::Error);
+
+        // Steady state produces nothing new
+        let previous = TransitionSnapshot::capture(&app.state);
+        app.record_transitions(&previous);
+        assert_eq!(app.state.events.len(), 3);
+    }
+
+    #[test]
+    fn test_update_peer_lag() {
+        let peer = |id: &str, best_number| PeerInfo {
+            peer_id: id.to_string(),
+            best_number,
+            ..Default::default()
+        };
+        let mut stats = HashMap::new();
+
+        // "slow" is always 20 behind, "fast" is at the tip
+        for tip in 100..106 {
+            let mut peers = vec![peer("slow", tip - 20), peer("fast", tip)];
+            update_peer_lag(&mut stats, &mut peers, tip);
+            assert_eq!(peers[0].peer_id, "fast");
+            if tip == 105 {
+                assert_eq!(peers[1].lag_samples, 6);
+                assert!((peers[1].avg_blocks_behind - 20.0).abs() < 1e-9);
+                assert!(peers[1].is_lagging());
+                assert!(!peers[0].is_lagging());
+            }
+        }
+
+        // One good refresh only nudges the average
+        let mut peers = vec![peer("slow", 110)];
+        update_peer_lag(&mut stats, &mut peers, 110);
+        assert!((peers[0].avg_blocks_behind - 16.0).abs() < 1e-9);
+
+        // Disconnected peers are forgotten
+        assert_eq!(stats.len(), 1);
+        assert!(!stats.contains_key("fast"));
+    }
+
+    #[test]
+    fn test_toggle_compare_mark() {
+        let mut app = App::new();
+        app.state.validators = vec![
+            validator("0xaaa", "registered", 30),
+            validator("0xbbb", "registered", 20),
+            validator("0xccc", "registered", 10),
+        ];
+        app.set_view(ViewMode::Performance);
+
+        app.toggle_compare_mark();
+        app.scroll_down();
+        app.toggle_compare_mark();
+        assert_eq!(app.compare_marks, vec!["0xaaa", "0xbbb"]);
+
+        // A third mark replaces the oldest; marking again unmarks
+        app.scroll_down();
+        app.toggle_compare_mark();
+        assert_eq!(app.compare_marks, vec!["0xbbb", "0xccc"]);
+        app.toggle_compare_mark();
+        assert_eq!(app.compare_marks, vec!["0xbbb"]);
+    }
+
+    #[test]
+    fn test_validator_popup_loads_more_history() {
+        let db = Database::open_in_memory().unwrap();
+        for epoch in 1..=5 {
+            db.store_validator_epoch(&ValidatorEpochRecord {
+                sidechain_epoch: epoch,
+                sidechain_key: "0xaaa".to_string(),
+                aura_key: "0xaura".to_string(),
+                committee_seats: 1,
+                committee_size: 10,
+                is_permissioned: false,
+                stake_lovelace: None,
+                captured_at: 0,
+            })
+            .unwrap();
+        }
+
+        let mut app = App::new();
+        app.history_epochs = 2;
+        app.state.validators = vec![validator("0xaaa", "registered", 0)];
+        app.set_view(ViewMode::Performance);
+        app.open_validator_popup(&db);
+
+        let loaded = |app: &App| match &app.popup {
+            Some(PopupContent::ValidatorDetail {
+                epoch_history,
+                has_more,
+                ..
+            }) => (
+                epoch_history.iter().map(|r| r.epoch).collect::<Vec<_>>(),
+                *has_more,
+            ),
+            _ => panic!("validator popup not open"),
+        };
+        // Newest first; not at the last row yet, so nothing more loads
+        assert_eq!(loaded(&app), (vec![5, 4], true));
+        app.load_more_history(&db);
+        assert_eq!(loaded(&app).0.len(), 2);
+
+        app.popup_scroll_down();
+        app.load_more_history(&db);
+        assert_eq!(loaded(&app), (vec![5, 4, 3, 2], true));
+        app.popup_page_down();
+        app.load_more_history(&db);
+        assert_eq!(loaded(&app), (vec![5, 4, 3, 2, 1], false));
+    }
+
+    #[test]
+    fn test_validator_comparison_metrics() {
+        let record = |epoch, seats, blocks_produced| ValidatorEpochHistoryRecord {
+            epoch,
+            seats,
+            committee_size: 1200,
+            blocks_produced,
+        };
+        let side = ValidatorComparison {
+            validator: validator("0xaaa", "registered", 0),
+            selection_stats: None,
+            // Most recent first; epoch 12 is still in progress
+            epoch_history: vec![
+                record(12, 4, 1),
+                record(11, 2, 2),
+                record(10, 0, 0),
+                record(9, 2, 1),
+            ],
+            stake_lovelace: None,
+        };
+        let timing = ChainTiming::preview();
+
+        assert_eq!(side.seats_trend(3), vec![0, 2, 4]);
+        assert_eq!(side.blocks_per_epoch(12), Some(1.0));
+        // 3 blocks produced of 4 expected (2 seats x 1 block/seat, twice)
+        assert_eq!(side.production_rate(&timing, 12), Some(75.0));
+
+        let new_side = ValidatorComparison {
+            epoch_history: vec![record(12, 4, 1)],
+            ..side
+        };
+        assert_eq!(new_side.blocks_per_epoch(12), None);
+        assert_eq!(new_side.production_rate(&timing, 12), None);
+    }
+}
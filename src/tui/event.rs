@@ -42,6 +42,20 @@ impl EventHandler {
     }
 }
 
+/// Node switcher keys: `n` next node, `N` previous (ignored while a popup is open)
+///
+/// Handled by the caller, which owns one `App` per monitored node.
+pub fn node_switch_delta(key: KeyEvent, app: &crate::tui::App) -> Option<isize> {
+    if app.node_position.is_none() || app.has_popup() {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('n') => Some(1),
+        KeyCode::Char('N') => Some(-1),
+        _ => None,
+    }
+}
+
 /// Parse keyboard event and return whether to continue running
 /// The `db` parameter is optional - if not provided, validator detail won't work
 pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Database>) -> bool {
@@ -51,6 +65,8 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
     if app.has_popup() {
         // Check if it's a scrollable popup (ValidatorDetail)
         let is_scrollable = matches!(app.popup, Some(PopupContent::ValidatorDetail { .. }));
+        let is_distribution = matches!(app.popup, Some(PopupContent::EpochDistribution { .. }));
+        let is_legend = matches!(app.popup, Some(PopupContent::Legend { .. }));
 
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') => {
@@ -59,6 +75,9 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
             }
             // Scrolling for validator detail popup
             KeyCode::Down | KeyCode::Char('j') if is_scrollable => {
+                if let Some(db) = db {
+                    app.load_more_history(db);
+                }
                 app.popup_scroll_down();
                 return true;
             }
@@ -67,6 +86,9 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
                 return true;
             }
             KeyCode::Char('J') | KeyCode::PageDown if is_scrollable => {
+                if let Some(db) = db {
+                    app.load_more_history(db);
+                }
                 app.popup_page_down();
                 return true;
             }
@@ -74,6 +96,25 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
                 app.popup_page_up();
                 return true;
             }
+            // From the legend straight to the full help
+            KeyCode::Char('?') | KeyCode::Char('h') | KeyCode::F(1) if is_legend => {
+                app.close_popup();
+                app.set_view(ViewMode::Help);
+                return true;
+            }
+            // Step through epochs in the block distribution popup
+            KeyCode::Left | KeyCode::Char('h') if is_distribution => {
+                if let Some(db) = db {
+                    app.step_distribution_epoch(db, -1);
+                }
+                return true;
+            }
+            KeyCode::Right | KeyCode::Char('l') if is_distribution => {
+                if let Some(db) = db {
+                    app.step_distribution_epoch(db, 1);
+                }
+                return true;
+            }
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 app.quit();
                 return false;
@@ -144,7 +185,20 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
             app.set_view(ViewMode::Peers);
             true
         }
-        KeyCode::Char('?') | KeyCode::Char('h') | KeyCode::F(1) => {
+        KeyCode::Char('6') => {
+            app.set_view(ViewMode::Events);
+            true
+        }
+        KeyCode::Char('0') => {
+            app.set_view(ViewMode::Focus);
+            true
+        }
+        // Legend for the current view; h/F1 for the full help
+        KeyCode::Char('?') => {
+            app.open_legend();
+            true
+        }
+        KeyCode::Char('h') | KeyCode::F(1) => {
             app.set_view(ViewMode::Help);
             true
         }
@@ -187,6 +241,54 @@ pub fn handle_key_event(key: KeyEvent, app: &mut crate::tui::App, db: Option<&Da
             app.toggle_ours_filter();
             true
         }
+        // Dynamic-validator leaderboard by stake (Validators view)
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            app.toggle_dynamic_filter();
+            true
+        }
+        // Mark validators and compare them side by side (Performance view)
+        KeyCode::Char(' ') => {
+            app.toggle_compare_mark();
+            true
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') if app.view_mode == ViewMode::Performance => {
+            if let Some(db) = db {
+                app.open_compare_popup(db);
+            }
+            true
+        }
+        // Block distribution across authors for an epoch (Performance view)
+        KeyCode::Char('d') | KeyCode::Char('D') if app.view_mode == ViewMode::Performance => {
+            if let Some(db) = db {
+                app.open_epoch_distribution_popup(db);
+            }
+            true
+        }
+        // Cycle sort order
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            app.cycle_sort_key();
+            true
+        }
+        // Jump to (next) own validator
+        KeyCode::Char('g') => {
+            app.jump_to_ours();
+            true
+        }
+        // Refresh data now (the view loop runs the update)
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.request_refresh();
+            true
+        }
+        // Export the current list as CSV
+        KeyCode::Char('e') | KeyCode::Char('E') => {
+            app.export_view();
+            true
+        }
+        // Write the state to a file for `mvm view --replay` (bug reports)
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.dump_state();
+            true
+        }
         // Toggle theme
         KeyCode::Char('t') | KeyCode::Char('T') => {
             app.toggle_theme();
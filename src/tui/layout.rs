@@ -1,18 +1,42 @@
 //! Responsive layout system for TUI
 //!
-//! Provides dynamic terminal scaling with two size categories:
+//! Provides dynamic terminal scaling with three size categories:
+//! - Small: Mini dashboard for narrow or short panes (< 80 cols or < 24 rows)
 //! - Medium: Standard layouts for typical terminals (< 120 cols) - truncated keys
 //! - Large: Expanded layouts with full keys (>= 120 cols)
 //!
 //! The 120 col threshold is based on the widest content line (block list):
 //! `#12345678  slot 123456789012  epoch 1234  ✓ author: 0x...66_char_key`
 //! which requires 118 chars + 2 for borders = 120 cols to fit without truncation.
+//!
+//! Below `MIN_WIDTH` x `MIN_HEIGHT` the views can't fit their fixed rows, so
+//! the renderer switches to a single-column mini dashboard (sync, epoch,
+//! seats/blocks, peers) for tmux splits and side panes. Below
+//! `MINI_MIN_WIDTH` x `MINI_MIN_HEIGHT` even that doesn't fit and a
+//! "terminal too small" notice is shown instead.
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+/// Smallest terminal width the views are laid out for
+pub const MIN_WIDTH: u16 = 80;
+
+/// Smallest terminal height (title + status bars and the dashboard panels)
+pub const MIN_HEIGHT: u16 = 24;
+
+/// Smallest terminal width for the mini dashboard (label + short value)
+pub const MINI_MIN_WIDTH: u16 = 30;
+
+/// Smallest terminal height for the mini dashboard (title + 5 rows + hint)
+pub const MINI_MIN_HEIGHT: u16 = 7;
+
+/// Label column width in the mini dashboard ("Epoch:  ")
+pub const MINI_LABEL_WIDTH: u16 = 8;
+
 /// Screen size categories for responsive layouts
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ScreenSize {
+    /// Small: < 80 cols or < 24 rows - single-column mini dashboard
+    Small,
     /// Medium: < 120 cols - truncated keys (23 chars: 12...8)
     Medium,
     /// Large: >= 120 cols - full keys (66 chars)
@@ -22,8 +46,10 @@ pub enum ScreenSize {
 impl ScreenSize {
     /// Determine screen size from terminal dimensions
     /// Threshold at 120 cols where full 66-char keys fit in block list
-    pub fn from_dimensions(width: u16, _height: u16) -> Self {
-        if width >= 120 {
+    pub fn from_dimensions(width: u16, height: u16) -> Self {
+        if width < MIN_WIDTH || height < MIN_HEIGHT {
+            ScreenSize::Small
+        } else if width >= 120 {
             ScreenSize::Large
         } else {
             ScreenSize::Medium
@@ -50,6 +76,35 @@ impl ResponsiveLayout {
         }
     }
 
+    /// Whether the terminal is below the minimum usable size, even for the mini dashboard
+    pub fn is_too_small(&self) -> bool {
+        self.width < MINI_MIN_WIDTH || self.height < MINI_MIN_HEIGHT
+    }
+
+    /// Whether to render the single-column mini dashboard instead of the full views
+    pub fn is_mini(&self) -> bool {
+        self.size == ScreenSize::Small && !self.is_too_small()
+    }
+
+    /// Get the mini dashboard layout (title line, content rows, hint line)
+    pub fn mini_layout(&self, area: Rect) -> Vec<Rect> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Title
+                Constraint::Min(0),    // Content rows
+                Constraint::Length(1), // Hint
+            ])
+            .split(area)
+            .to_vec()
+    }
+
+    /// Width of the epoch progress bar in the mini dashboard
+    /// Leaves room for the label column and a " 100.0%" suffix, capped at 30
+    pub fn mini_bar_width(&self) -> usize {
+        self.width.saturating_sub(MINI_LABEL_WIDTH + 7).min(30) as usize
+    }
+
     /// Get the main layout (title bar, content, status bar)
     pub fn main_layout(&self, area: Rect) -> Vec<Rect> {
         Layout::default()
@@ -65,14 +120,14 @@ impl ResponsiveLayout {
 
     /// Get the dashboard layout constraints
     /// Heights are fixed based on content: Network Status (8 lines + 2 border = 10),
-    /// Our Validator (7 lines for 1 validator with 3 keys + 2 border = 9)
+    /// Our Validator (8 lines for 1 validator with 3 keys + 2 border = 10)
     pub fn dashboard_layout(&self, area: Rect, network_status_rows: u16) -> Vec<Rect> {
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(network_status_rows + 2), // Content rows + 2 for border
-                Constraint::Length(9), // Our validator: 4 header + 3 keys + 2 border
-                Constraint::Min(0),    // Recent blocks: fills remaining space
+                Constraint::Length(10), // Our validator: 5 header + 3 keys + 2 border
+                Constraint::Min(0),     // Recent blocks: fills remaining space
             ])
             .split(area)
             .to_vec()
@@ -82,6 +137,7 @@ impl ResponsiveLayout {
     #[allow(dead_code)]
     pub fn blocks_to_display(&self) -> usize {
         match self.size {
+            ScreenSize::Small => 10,
             ScreenSize::Medium => 20,
             ScreenSize::Large => 30,
         }
@@ -91,6 +147,7 @@ impl ResponsiveLayout {
     #[allow(dead_code)]
     pub fn validators_to_display(&self) -> usize {
         match self.size {
+            ScreenSize::Small => 10,
             ScreenSize::Medium => 25,
             ScreenSize::Large => 50,
         }
@@ -99,8 +156,8 @@ impl ResponsiveLayout {
     /// Determine if we should show the full key or truncated version
     pub fn key_display_length(&self) -> KeyDisplayMode {
         match self.size {
-            ScreenSize::Medium => KeyDisplayMode::Short, // 12...8
-            ScreenSize::Large => KeyDisplayMode::Full,   // Full key
+            ScreenSize::Small | ScreenSize::Medium => KeyDisplayMode::Short, // 12...8
+            ScreenSize::Large => KeyDisplayMode::Full,                       // Full key
         }
     }
 
@@ -113,6 +170,12 @@ impl ResponsiveLayout {
     /// Get column widths for block list
     pub fn block_list_columns(&self) -> BlockListColumns {
         match self.size {
+            ScreenSize::Small => BlockListColumns {
+                show_slot: false,
+                show_epoch: false,
+                show_extrinsics: false,
+                author_width: 20,
+            },
             ScreenSize::Medium => BlockListColumns {
                 show_slot: true,
                 show_epoch: true,
@@ -131,6 +194,11 @@ impl ResponsiveLayout {
     /// Get column widths for validator list
     pub fn validator_list_columns(&self) -> ValidatorListColumns {
         match self.size {
+            ScreenSize::Small => ValidatorListColumns {
+                key_width: 23,
+                show_status: false,
+                show_registration: false,
+            },
             ScreenSize::Medium => ValidatorListColumns {
                 key_width: 66,
                 show_status: true,
@@ -196,6 +264,11 @@ mod tests {
 
     #[test]
     fn test_screen_size_detection() {
+        // Small: narrow or short panes get the mini dashboard
+        assert_eq!(ScreenSize::from_dimensions(40, 40), ScreenSize::Small);
+        assert_eq!(ScreenSize::from_dimensions(79, 40), ScreenSize::Small);
+        assert_eq!(ScreenSize::from_dimensions(120, 23), ScreenSize::Small);
+
         // Medium screens (< 120 cols) - truncated keys
         assert_eq!(ScreenSize::from_dimensions(80, 24), ScreenSize::Medium);
         assert_eq!(ScreenSize::from_dimensions(100, 40), ScreenSize::Medium);
@@ -207,6 +280,47 @@ mod tests {
         assert_eq!(ScreenSize::from_dimensions(200, 60), ScreenSize::Large);
     }
 
+    #[test]
+    fn test_too_small() {
+        assert!(!ResponsiveLayout::new(Rect::new(0, 0, 80, 24)).is_too_small());
+        assert!(!ResponsiveLayout::new(Rect::new(0, 0, 200, 60)).is_too_small());
+        // Narrow and short panes fall back to the mini dashboard, not the notice
+        assert!(!ResponsiveLayout::new(Rect::new(0, 0, 79, 40)).is_too_small());
+        assert!(!ResponsiveLayout::new(Rect::new(0, 0, 120, 23)).is_too_small());
+        assert!(ResponsiveLayout::new(Rect::new(0, 0, 29, 40)).is_too_small());
+        assert!(ResponsiveLayout::new(Rect::new(0, 0, 60, 6)).is_too_small());
+    }
+
+    #[test]
+    fn test_mini_layout() {
+        // (width, height, is_mini, bar width)
+        for (width, height, mini, bar) in [
+            (40, 20, true, 25),
+            (60, 20, true, 30),
+            (80, 20, true, 30),
+            (80, 24, false, 30),
+        ] {
+            let area = Rect::new(0, 0, width, height);
+            let layout = ResponsiveLayout::new(area);
+            assert_eq!(layout.is_mini(), mini, "{}x{}", width, height);
+            assert_eq!(layout.mini_bar_width(), bar, "{}x{}", width, height);
+
+            let chunks = layout.mini_layout(area);
+            assert_eq!(chunks.len(), 3);
+            assert_eq!(chunks[0].height, 1);
+            assert_eq!(chunks[1].height, height - 2);
+            assert_eq!(chunks[2].y, height - 1);
+            assert!(chunks.iter().all(|c| c.width == width));
+        }
+
+        // Smallest mini pane still fits all five content rows
+        let area = Rect::new(0, 0, MINI_MIN_WIDTH, MINI_MIN_HEIGHT);
+        let layout = ResponsiveLayout::new(area);
+        assert!(layout.is_mini());
+        assert_eq!(layout.mini_layout(area)[1].height, 5);
+        assert_eq!(layout.mini_bar_width(), 15);
+    }
+
     #[test]
     fn test_key_display_mode() {
         let test_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
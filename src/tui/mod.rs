@@ -3,10 +3,11 @@
 mod app;
 pub mod event;
 mod layout;
+pub mod snapshot;
 mod theme;
 mod ui;
 
-pub use app::{App, PopupContent, ViewMode};
+pub use app::{App, AppState, ChainPhase, EventSeverity, PopupContent, ViewMode};
 pub use event::{Event, EventHandler};
 pub use layout::ScreenSize;
 pub use theme::Theme;
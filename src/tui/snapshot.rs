@@ -0,0 +1,236 @@
+//! Headless dashboard snapshots and state dumps
+//!
+//! Renders the dashboard to plain text and dumps key state as JSON so
+//! operators without a terminal session can `cat` the files over SSH or
+//! serve them from a web server. A full `AppState` dump (the `w` key) can be
+//! loaded by `mvm view --replay` to reproduce exactly what a user saw.
+
+use crate::tui::{App, AppState};
+use anyhow::{Context, Result};
+use ratatui::{backend::TestBackend, Terminal};
+use std::path::{Path, PathBuf};
+
+/// Dimensions used for the text rendering (fits the full dashboard layout)
+const SNAPSHOT_WIDTH: u16 = 140;
+const SNAPSHOT_HEIGHT: u16 = 45;
+
+/// Render the current view to plain text (no colors)
+pub fn render_text(app: &App) -> Result<String> {
+    let backend = TestBackend::new(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT);
+    let mut terminal = Terminal::new(backend).context("Failed to create snapshot terminal")?;
+    terminal
+        .draw(|f| crate::tui::render(f, app))
+        .context("Failed to render snapshot")?;
+
+    let buffer = terminal.backend().buffer();
+    let width = buffer.area.width as usize;
+    let mut text = String::new();
+    for row in buffer.content.chunks(width) {
+        let line: String = row.iter().map(|cell| cell.symbol()).collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Summarize the current state as JSON
+pub fn state_json(app: &App) -> serde_json::Value {
+    let s = &app.state;
+    serde_json::json!({
+        "generated_at": chrono::Utc::now().to_rfc3339(),
+        "node": {
+            "name": s.node_name,
+            "chain": s.chain_name,
+            "version": s.node_version,
+            "synced": s.sync_progress.is_synced,
+            "sync_percent": s.sync_progress.sync_percent,
+            "peers": s.peer_count,
+        },
+        "chain": {
+            "tip": s.chain_tip,
+            "finalized": s.finalized_block,
+            "finality_lag": s.chain_tip.saturating_sub(s.finalized_block),
+            "sidechain_epoch": s.sidechain_epoch,
+            "mainchain_epoch": s.mainchain_epoch,
+            "epoch_progress_percent": s.epoch_progress.progress_percent,
+        },
+        "validator": {
+            "count": s.our_validators_count,
+            "committee_elected": s.committee_elected,
+            "committee_seats": s.committee_seats,
+            "committee_size": s.committee_size,
+            "blocks_this_epoch": s.epoch_progress.our_blocks_this_epoch,
+            "expected_blocks": s.epoch_progress.expected_blocks,
+        },
+        "database": {
+            "total_blocks": s.total_blocks,
+            "total_validators": s.total_validators,
+            "last_block": s.db_sync.last_block,
+            "blocks_behind": s.db_sync.blocks_remaining,
+            "eta_seconds": s.db_sync.eta_seconds,
+        },
+        "last_error": s.last_error,
+    })
+}
+
+/// Write `dashboard.txt` and `state.json` into `dir`
+///
+/// Files are written to a temporary name and renamed so readers never see
+/// a partially written snapshot.
+pub fn write_snapshot(dir: &Path, app: &App) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create output directory {}", dir.display()))?;
+
+    write_atomic(&dir.join("dashboard.txt"), &render_text(app)?)?;
+    let json = serde_json::to_string_pretty(&state_json(app))?;
+    write_atomic(&dir.join("state.json"), &json)?;
+    Ok(())
+}
+
+/// Write the full state to `mvm-state-<unix time>.json` in `dir`, returning the path
+pub fn dump_state(dir: &Path, state: &AppState) -> Result<PathBuf> {
+    let path = dir.join(format!("mvm-state-{}.json", chrono::Utc::now().timestamp()));
+    write_atomic(&path, &serde_json::to_string_pretty(state)?)?;
+    Ok(path)
+}
+
+/// Write an exported view table to `mvm-<view>-<unix time>.csv` in `dir`, returning the path
+pub fn write_export(dir: &Path, view: &str, csv: &str) -> Result<PathBuf> {
+    let path = dir.join(format!(
+        "mvm-{}-{}.csv",
+        view,
+        chrono::Utc::now().timestamp()
+    ));
+    write_atomic(&path, csv)?;
+    Ok(path)
+}
+
+/// Load a state dump written by `dump_state`
+pub fn load_state(path: &Path) -> Result<AppState> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state dump {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("Invalid state dump {}", path.display()))
+}
+
+fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, contents).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, path)
+        .with_context(|| format!("Failed to move snapshot into place at {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_snapshot() {
+        let mut app = App::new();
+        app.state.is_loading = false;
+        app.state.chain_tip = 1000;
+        app.state.finalized_block = 997;
+
+        let dir = std::env::temp_dir().join(format!("mvm-snapshot-test-{}", std::process::id()));
+        write_snapshot(&dir, &app).unwrap();
+
+        let text = std::fs::read_to_string(dir.join("dashboard.txt")).unwrap();
+        assert!(text.contains("Network Status"));
+        assert!(text.contains("#1000"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("state.json")).unwrap())
+                .unwrap();
+        assert_eq!(json["chain"]["finality_lag"], 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_dump_and_replay_state() {
+        let mut app = App::new();
+        app.state.is_loading = false;
+        app.state.chain_tip = 5000;
+        app.state.finalized_block = 4998;
+        app.state.sidechain_epoch = 245_001;
+        app.state.committee_elected = true;
+        app.state.committee_seats = 3;
+        app.record_event(
+            crate::tui::app::EventSeverity::Warning,
+            "peers",
+            "Peer count low",
+        );
+
+        let dir = std::env::temp_dir().join(format!("mvm-replay-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dump_state(&dir, &app.state).unwrap();
+
+        let mut replay = App::new();
+        replay.state = load_state(&path).unwrap();
+        assert_eq!(replay.state.chain_tip, 5000);
+        assert_eq!(replay.state.committee_seats, 3);
+        assert_eq!(replay.state.events[0].category, "peers");
+        // The replayed state renders the same dashboard
+        assert_eq!(render_text(&replay).unwrap(), render_text(&app).unwrap());
+
+        // Fields missing from an older dump take their defaults
+        std::fs::write(&path, r#"{"chain_tip": 42}"#).unwrap();
+        assert_eq!(load_state(&path).unwrap().chain_tip, 42);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_view_legend_popup() {
+        let mut app = App::new();
+        app.state.is_loading = false;
+        app.set_view(crate::tui::ViewMode::Peers);
+        app.open_legend();
+
+        let text = render_text(&app).unwrap();
+        assert!(text.contains("Legend"));
+        assert!(text.contains("Inbound: the peer dialed us"));
+        assert!(text.contains("full help"));
+
+        // The Help view needs no legend
+        app.close_popup();
+        app.set_view(crate::tui::ViewMode::Help);
+        app.open_legend();
+        assert!(!app.has_popup());
+    }
+
+    #[test]
+    fn test_focus_mode_render() {
+        let mut app = App::new();
+        app.state.is_loading = false;
+        app.state.sync_progress.is_synced = true;
+        app.state.peer_count = 12;
+        app.state.chain_tip = 1000;
+        app.state.finalized_block = 998;
+        app.state.our_validators_count = 1;
+        app.state.committee_elected = true;
+        app.state.committee_seats = 4;
+        app.state.committee_size = 1200;
+        app.set_view(crate::tui::ViewMode::Focus);
+
+        let text = render_text(&app).unwrap();
+        assert!(text.contains("SYNCED"));
+        assert!(text.contains("Finalization"));
+        assert!(text.contains("of 1200"));
+        assert!(text.contains("Alerts"));
+        // Everything else is hidden
+        assert!(!text.contains("Network Status"));
+        assert_eq!(app.active_alerts().len(), 0);
+
+        // A stalled finality shows up as a tile and an alert
+        app.state.finalized_block = 100;
+        let alerts = app.active_alerts();
+        assert_eq!(alerts.len(), 1);
+        assert!(alerts[0]
+            .1
+            .starts_with("Node: finalized block is 900 behind"));
+        let text = render_text(&app).unwrap();
+        assert!(text.contains("STALLED"));
+        assert!(text.contains("1 ACTIVE"));
+    }
+}
@@ -1,6 +1,6 @@
 //! Color themes for TUI
 
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
 
 /// Theme for the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -8,6 +8,8 @@ pub enum Theme {
     #[default]
     Midnight,
     Midday,
+    /// No colors, for NO_COLOR / --no-color (selection shown in reverse video)
+    Monochrome,
 }
 
 impl Theme {
@@ -16,6 +18,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(138, 102, 255), // Purple/Violet
             Theme::Midday => Color::Rgb(0, 150, 200),     // Vibrant teal/cyan
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -24,6 +27,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(102, 153, 255), // Light blue
             Theme::Midday => Color::Rgb(0, 80, 180),      // Vivid blue (readable on light bg)
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -32,6 +36,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(120, 255, 180), // Mint green
             Theme::Midday => Color::Rgb(0, 180, 100),     // Vibrant emerald
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -40,6 +45,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(255, 200, 100), // Warm yellow/orange
             Theme::Midday => Color::Rgb(255, 140, 0),     // Bright orange
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -48,6 +54,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(255, 100, 120), // Soft red
             Theme::Midday => Color::Rgb(220, 50, 80),     // Vibrant coral red
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -56,6 +63,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(150, 150, 170), // Light gray-purple
             Theme::Midday => Color::Rgb(100, 115, 140),   // Medium slate (brighter)
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -64,6 +72,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(60, 45, 90),  // Dark purple
             Theme::Midday => Color::Rgb(210, 235, 255), // Light sky blue
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -72,6 +81,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(100, 80, 140), // Purple-gray
             Theme::Midday => Color::Rgb(140, 170, 200),  // Light steel blue
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -80,6 +90,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(180, 150, 255), // Bright purple
             Theme::Midday => Color::Rgb(0, 140, 200),     // Bright teal
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -88,6 +99,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(255, 215, 100), // Gold
             Theme::Midday => Color::Rgb(230, 140, 0),     // Amber orange
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -96,6 +108,7 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(150, 200, 255), // Light cyan-blue
             Theme::Midday => Color::Rgb(130, 80, 200),    // Bright purple (readable)
+            Theme::Monochrome => Color::Reset,
         }
     }
 
@@ -109,14 +122,24 @@ impl Theme {
         match self {
             Theme::Midnight => Color::Rgb(220, 220, 230), // Light gray
             Theme::Midday => Color::Rgb(50, 60, 80),      // Medium navy (brighter)
+            Theme::Monochrome => Color::Reset,
         }
     }
 
-    /// Toggle to the other theme
+    /// Extra modifier for selected items (reverse video when colors are off)
+    pub fn highlight_modifier(&self) -> Modifier {
+        match self {
+            Theme::Monochrome => Modifier::REVERSED,
+            _ => Modifier::empty(),
+        }
+    }
+
+    /// Toggle to the other theme (monochrome stays monochrome)
     pub fn toggle(&self) -> Theme {
         match self {
             Theme::Midnight => Theme::Midday,
             Theme::Midday => Theme::Midnight,
+            Theme::Monochrome => Theme::Monochrome,
         }
     }
 
@@ -125,6 +148,7 @@ impl Theme {
         match self {
             Theme::Midnight => "Midnight Theme",
             Theme::Midday => "Midday Theme",
+            Theme::Monochrome => "Monochrome",
         }
     }
 }
@@ -1,8 +1,13 @@
 //! UI rendering for TUI
 
-use crate::db::CommitteeSelectionStats;
-use crate::tui::layout::ResponsiveLayout;
-use crate::tui::{App, ScreenSize, ViewMode};
+use crate::config::Charset;
+use crate::db::{CommitteeSelectionStats, EpochRank, LivenessScore, StakeChange};
+use crate::midnight::{format_block_estimate, RewardEstimate, StatePruning};
+use crate::tui::app::{format_stake, EventSeverity, ValidatorComparison};
+use crate::tui::layout::{
+    KeyDisplayMode, ResponsiveLayout, MINI_LABEL_WIDTH, MINI_MIN_HEIGHT, MINI_MIN_WIDTH,
+};
+use crate::tui::{App, ChainPhase, ScreenSize, Theme, ViewMode};
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -14,11 +19,35 @@ use ratatui::{
     Frame,
 };
 
-/// Convert a slice of values to Unicode sparkline bars
-/// Uses block characters: ▁▂▃▄▅▆▇█ (8 levels)
-#[allow(dead_code)]
-fn sparkline_bars(values: &[u64]) -> String {
-    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Sparkline levels, lowest to highest
+fn sparkline_levels(charset: Charset) -> [char; 8] {
+    match charset {
+        Charset::Unicode => ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'],
+        Charset::Ascii => ['_', '.', ',', '-', '~', '=', '*', '#'],
+    }
+}
+
+/// Filled and empty progress-bar characters
+fn bar_glyphs(charset: Charset) -> (&'static str, &'static str) {
+    match charset {
+        Charset::Unicode => ("━", "░"),
+        Charset::Ascii => ("#", "."),
+    }
+}
+
+/// `width`-character progress bar with `filled` characters done
+fn progress_bar(charset: Charset, filled: usize, width: usize) -> String {
+    let (done, todo) = bar_glyphs(charset);
+    format!(
+        "{}{}",
+        done.repeat(filled.min(width)),
+        todo.repeat(width.saturating_sub(filled))
+    )
+}
+
+/// Convert a slice of values to sparkline bars (8 levels, see `sparkline_levels`)
+fn sparkline_bars(values: &[u64], charset: Charset) -> String {
+    let bars = sparkline_levels(charset);
 
     if values.is_empty() {
         return String::new();
@@ -27,7 +56,7 @@ fn sparkline_bars(values: &[u64]) -> String {
     let max = *values.iter().max().unwrap_or(&1);
     if max == 0 {
         // All zeros - show flat line
-        return values.iter().map(|_| BARS[0]).collect();
+        return values.iter().map(|_| bars[0]).collect();
     }
 
     values
@@ -39,7 +68,7 @@ fn sparkline_bars(values: &[u64]) -> String {
                 // Scale to 0-7 range, with max value getting index 7
                 ((v as f64 / max as f64) * 7.0).round() as usize
             };
-            BARS[idx.min(7)]
+            bars[idx.min(7)]
         })
         .collect()
 }
@@ -51,8 +80,9 @@ fn sparkline_colored_spans<'a>(
     seats: &[u64],
     normal_color: Color,
     error_color: Color,
+    charset: Charset,
 ) -> Vec<Span<'a>> {
-    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let bars = sparkline_levels(charset);
 
     if blocks.is_empty() {
         return vec![];
@@ -65,10 +95,10 @@ fn sparkline_colored_spans<'a>(
         .zip(seats.iter().chain(std::iter::repeat(&0u64)))
         .map(|(&block_count, &seat_count)| {
             let bar_char = if max == 0 || block_count == 0 {
-                BARS[0]
+                bars[0]
             } else {
                 let idx = ((block_count as f64 / max as f64) * 7.0).round() as usize;
-                BARS[idx.min(7)]
+                bars[idx.min(7)]
             };
 
             // Red only for missed blocks, purple for everything else
@@ -84,8 +114,26 @@ fn sparkline_colored_spans<'a>(
 }
 
 /// Render the UI with responsive layout
+///
+/// The layout is recomputed from the frame area on every draw, so resizing
+/// the terminal takes effect on the next frame.
 pub fn render(f: &mut Frame, app: &App) {
     let layout = ResponsiveLayout::new(f.area());
+    if layout.is_too_small() {
+        render_too_small(f, app, &layout);
+        return;
+    }
+    if app.view_mode == ViewMode::Focus {
+        render_focus(f, app);
+        if let Some(ref popup) = app.popup {
+            render_popup(f, app, popup);
+        }
+        return;
+    }
+    if layout.is_mini() {
+        render_mini_dashboard(f, app, &layout);
+        return;
+    }
     let chunks = layout.main_layout(f.area());
 
     // Render title bar (compact for small screens)
@@ -100,7 +148,9 @@ pub fn render(f: &mut Frame, app: &App) {
             render_performance(f, app, chunks[1], &layout)
         }
         ViewMode::Peers => render_peers(f, app, chunks[1], &layout),
+        ViewMode::Events => render_events(f, app, chunks[1]),
         ViewMode::Help => render_help(f, app, chunks[1]),
+        ViewMode::Focus => unreachable!("focus mode is rendered full screen"),
     }
 
     // Render status bar (compact for small screens)
@@ -112,6 +162,463 @@ pub fn render(f: &mut Frame, app: &App) {
     }
 }
 
+/// Notice shown instead of the views when the terminal is below the minimum size
+fn render_too_small(f: &mut Frame, app: &App, layout: &ResponsiveLayout) {
+    let theme = app.theme;
+    let area = f.area();
+    let text = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(theme.warning())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "{}x{} (need {}x{})",
+                layout.width, layout.height, MINI_MIN_WIDTH, MINI_MIN_HEIGHT
+            ),
+            Style::default().fg(theme.text()),
+        )),
+        Line::from(Span::styled(
+            "Resize, or press q to quit",
+            Style::default().fg(theme.muted()),
+        )),
+    ];
+    // Vertically centre the notice when there is room
+    let top = area.height.saturating_sub(text.len() as u16) / 2;
+    let area = Rect::new(
+        area.x,
+        area.y + top,
+        area.width,
+        area.height.saturating_sub(top),
+    );
+    f.render_widget(Paragraph::new(text).alignment(Alignment::Center), area);
+}
+
+/// Single-column dashboard for narrow or short terminals (tmux splits, side panes)
+///
+/// Shows only what answers "is the validator OK": sync, epoch progress, our
+/// seats/blocks and peers. Sparklines and two-column rows are dropped; widen
+/// the pane past `MIN_WIDTH` x `MIN_HEIGHT` for the full views.
+fn render_mini_dashboard(f: &mut Frame, app: &App, layout: &ResponsiveLayout) {
+    let theme = app.theme;
+    let chunks = layout.mini_layout(f.area());
+    let label = |text: &str| {
+        Span::styled(
+            format!("{:<width$}", text, width = MINI_LABEL_WIDTH as usize),
+            Style::default().fg(theme.muted()),
+        )
+    };
+
+    let chain = if app.state.chain_name.is_empty() {
+        "MVM".to_string()
+    } else {
+        format!("MVM · {}", app.state.chain_name)
+    };
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            chain,
+            Style::default()
+                .fg(theme.primary())
+                .add_modifier(Modifier::BOLD),
+        )),
+        chunks[0],
+    );
+
+    let rows = if app.state.is_loading {
+        vec![Line::from(Span::styled(
+            "◌ Connecting...",
+            Style::default().fg(theme.warning()),
+        ))]
+    } else if app.state.chain_phase != ChainPhase::Running {
+        let waiting = match app.state.chain_phase {
+            ChainPhase::NoBlocks => "◌ No blocks yet",
+            _ => "◌ Sidechain not started",
+        };
+        vec![
+            Line::from(Span::styled(waiting, Style::default().fg(theme.warning()))),
+            Line::from(vec![
+                label("Peers:"),
+                Span::styled(
+                    app.state.peer_count.to_string(),
+                    Style::default().fg(theme.text()),
+                ),
+            ]),
+        ]
+    } else {
+        let sync = &app.state.sync_progress;
+        let sync_row = if sync.is_synced {
+            Line::from(vec![
+                label("Sync:"),
+                Span::styled("✓ ", Style::default().fg(theme.success())),
+                Span::styled(
+                    format!("#{}", app.state.chain_tip),
+                    Style::default().fg(theme.block_number()),
+                ),
+                Span::styled(
+                    format!(" fin {}", app.state.finalized_block),
+                    Style::default().fg(theme.muted()),
+                ),
+            ])
+        } else {
+            Line::from(vec![
+                label("Sync:"),
+                Span::styled(
+                    format!("⟳ {:.1}%", sync.sync_percent),
+                    Style::default().fg(theme.warning()),
+                ),
+                Span::styled(
+                    format!(" {} left", sync.blocks_remaining),
+                    Style::default().fg(theme.muted()),
+                ),
+            ])
+        };
+
+        let epoch_progress = &app.state.epoch_progress;
+        let bar_width = layout.mini_bar_width();
+        let filled = ((epoch_progress.progress_percent / 100.0) * bar_width as f64) as usize;
+        let epoch_row = Line::from(vec![
+            label("Epoch:"),
+            Span::styled(
+                progress_bar(app.charset, filled, bar_width),
+                Style::default().fg(theme.primary()),
+            ),
+            Span::styled(
+                format!(" {:>5.1}%", epoch_progress.progress_percent),
+                Style::default().fg(theme.text()),
+            ),
+        ]);
+        let next_row = Line::from(vec![
+            label(""),
+            Span::styled(
+                format!("{} ", app.state.sidechain_epoch),
+                Style::default().fg(theme.epoch()),
+            ),
+            Span::styled(
+                format!(
+                    "next {}",
+                    format_countdown(epoch_progress.sidechain_time_remaining_secs)
+                ),
+                Style::default().fg(theme.muted()),
+            ),
+        ]);
+
+        let ours_row = if app.state.our_validators_count == 0 {
+            Line::from(vec![
+                label("Ours:"),
+                Span::styled("no validator", Style::default().fg(theme.muted())),
+            ])
+        } else if app.state.committee_elected {
+            Line::from(vec![
+                label("Ours:"),
+                Span::styled(
+                    format!("{} seats", app.state.committee_seats),
+                    Style::default().fg(theme.ours()),
+                ),
+                Span::styled(
+                    format!(
+                        " {}/{:.0} blk",
+                        epoch_progress.our_blocks_this_epoch, epoch_progress.expected_blocks
+                    ),
+                    Style::default().fg(theme.text()),
+                ),
+            ])
+        } else {
+            Line::from(vec![
+                label("Ours:"),
+                Span::styled("not elected", Style::default().fg(theme.warning())),
+            ])
+        };
+
+        let peer_count = app.state.peer_count;
+        let peer_color = if peer_count < app.thresholds.peers_crit {
+            theme.error()
+        } else if peer_count < app.thresholds.peers_warn {
+            theme.warning()
+        } else {
+            theme.success()
+        };
+        let peers_row = Line::from(vec![
+            label("Peers:"),
+            Span::styled(peer_count.to_string(), Style::default().fg(peer_color)),
+        ]);
+
+        vec![sync_row, epoch_row, next_row, ours_row, peers_row]
+    };
+    f.render_widget(Paragraph::new(rows), chunks[1]);
+
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            if app.refresh_requested {
+                "⟳ Refreshing…"
+            } else {
+                "mini view · r refresh · q quit"
+            },
+            Style::default().fg(theme.muted()),
+        )),
+        chunks[2],
+    );
+}
+
+/// One focus-mode tile: a headline value and supporting detail lines
+struct FocusTile {
+    title: &'static str,
+    value: String,
+    detail: Vec<String>,
+    color: Color,
+}
+
+/// The six focus-mode tiles, in display order
+fn focus_tiles(app: &App) -> Vec<FocusTile> {
+    let theme = app.theme;
+    let state = &app.state;
+    let tile = |title, value: String, detail: Vec<String>, color| FocusTile {
+        title,
+        value,
+        detail,
+        color,
+    };
+
+    let sync = &state.sync_progress;
+    let node = if state.is_loading {
+        tile("Node", "CONNECTING".into(), vec![], theme.warning())
+    } else if sync.is_synced {
+        tile(
+            "Node",
+            "SYNCED".into(),
+            vec![format!("#{} · {} peers", state.chain_tip, state.peer_count)],
+            if state.peer_count < app.thresholds.peers_crit {
+                theme.warning()
+            } else {
+                theme.success()
+            },
+        )
+    } else {
+        tile(
+            "Node",
+            format!("SYNCING {:.1}%", sync.sync_percent),
+            vec![format!("{} blocks left", sync.blocks_remaining)],
+            theme.warning(),
+        )
+    };
+
+    let lag = state.chain_tip.saturating_sub(state.finalized_block);
+    let finalization = if state.is_loading {
+        tile("Finalization", "—".into(), vec![], theme.muted())
+    } else if lag <= app.thresholds.max_finalized_lag {
+        tile(
+            "Finalization",
+            "OK".into(),
+            vec![format!("#{} (lag {})", state.finalized_block, lag)],
+            theme.success(),
+        )
+    } else {
+        tile(
+            "Finalization",
+            "STALLED".into(),
+            vec![format!(
+                "{} behind best (limit {})",
+                lag, app.thresholds.max_finalized_lag
+            )],
+            theme.error(),
+        )
+    };
+
+    let epoch = format!("epoch {}", state.sidechain_epoch);
+    let seats = if state.our_validators_count == 0 {
+        tile("Our Seats", "NO VALIDATOR".into(), vec![], theme.muted())
+    } else if app.is_unregistered() {
+        tile(
+            "Our Seats",
+            "UNREGISTERED".into(),
+            vec!["mvm guide registration".into()],
+            theme.error(),
+        )
+    } else if state.committee_elected {
+        tile(
+            "Our Seats",
+            state.committee_seats.to_string(),
+            vec![format!("of {} · {}", state.committee_size, epoch)],
+            theme.ours(),
+        )
+    } else {
+        tile(
+            "Our Seats",
+            "NOT ELECTED".into(),
+            vec![epoch],
+            theme.warning(),
+        )
+    };
+
+    let progress = &state.epoch_progress;
+    let blocks = if !state.committee_elected {
+        tile("Blocks", "—".into(), vec![], theme.muted())
+    } else {
+        // Judge by the run-rate projection, not the raw count, so early epochs aren't red
+        let (color, projected) = match app.epoch_projection() {
+            Some(p) => {
+                let deviation = p.deviation_percent();
+                let color = if deviation >= -10.0 {
+                    theme.success()
+                } else if deviation >= -30.0 {
+                    theme.warning()
+                } else {
+                    theme.error()
+                };
+                (
+                    color,
+                    format!("projected {:.0} ({:+.0}%)", p.projected, deviation),
+                )
+            }
+            None => (
+                theme.text(),
+                format!("{:.0}% of epoch", progress.progress_percent),
+            ),
+        };
+        tile(
+            "Blocks",
+            format!(
+                "{} / {}",
+                progress.our_blocks_this_epoch,
+                format_block_estimate(progress.expected_blocks)
+            ),
+            vec!["produced / expected".into(), projected],
+            color,
+        )
+    };
+
+    let next_slot = match state.upcoming_slots.first() {
+        Some((slot, _)) => tile(
+            "Next Slot",
+            format_countdown(app.slot_countdown_secs(*slot)),
+            vec![format!(
+                "slot {} · {} left this epoch",
+                slot,
+                state.upcoming_slots.len()
+            )],
+            theme.epoch(),
+        ),
+        None => tile(
+            "Next Slot",
+            "NONE".into(),
+            vec!["no slots left this epoch".into()],
+            theme.muted(),
+        ),
+    };
+
+    let active = app.active_alerts();
+    let alerts = if active.is_empty() {
+        tile("Alerts", "NONE".into(), vec![], theme.success())
+    } else {
+        let color = if active.iter().any(|(s, _)| *s == EventSeverity::Error) {
+            theme.error()
+        } else {
+            theme.warning()
+        };
+        tile(
+            "Alerts",
+            format!("{} ACTIVE", active.len()),
+            active.into_iter().map(|(_, message)| message).collect(),
+            color,
+        )
+    };
+
+    vec![node, finalization, seats, blocks, next_slot, alerts]
+}
+
+/// Full-screen focus mode: our validator's key metrics in large, high-contrast tiles
+///
+/// Meant for a wall display or a glance from across the room, so everything
+/// else is hidden. Tiles are laid out 2x3 on wide terminals and stacked on
+/// narrow ones.
+fn render_focus(f: &mut Frame, app: &App) {
+    use ratatui::layout::{Constraint, Direction, Layout};
+
+    let theme = app.theme;
+    let area = f.area();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    let mut header = vec![Span::styled(
+        " MVM focus",
+        Style::default()
+            .fg(theme.primary())
+            .add_modifier(Modifier::BOLD),
+    )];
+    if !app.state.chain_name.is_empty() {
+        header.push(Span::styled(
+            format!(" · {}", app.state.chain_name),
+            Style::default().fg(theme.text()),
+        ));
+    }
+    header.push(Span::styled(
+        "  (1 dashboard · q quit)",
+        Style::default().fg(theme.muted()),
+    ));
+    f.render_widget(Paragraph::new(Line::from(header)), chunks[0]);
+
+    let tiles = focus_tiles(app);
+    let columns = if area.width >= 60 { 2 } else { 1 };
+    let rows = tiles.len().div_ceil(columns);
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(chunks[1]);
+    let cells: Vec<Rect> = row_areas
+        .iter()
+        .flat_map(|row| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+                .split(*row)
+                .to_vec()
+        })
+        .collect();
+
+    for (tile, cell) in tiles.into_iter().zip(cells) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(tile.color))
+            .title(Span::styled(
+                format!(" {} ", tile.title),
+                Style::default()
+                    .fg(theme.text())
+                    .add_modifier(Modifier::BOLD),
+            ));
+        let inner = block.inner(cell);
+        f.render_widget(block, cell);
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!("  {}  ", tile.value),
+            Style::default()
+                .fg(tile.color)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        ))];
+        lines.extend(
+            tile.detail
+                .into_iter()
+                .map(|d| Line::from(Span::styled(d, Style::default().fg(theme.text())))),
+        );
+        // Vertically centre what fits; the headline always shows
+        let shown = lines.len().min(inner.height as usize);
+        lines.truncate(shown.max(1));
+        let top = inner.height.saturating_sub(lines.len() as u16) / 2;
+        let text_area = Rect::new(
+            inner.x,
+            inner.y + top,
+            inner.width,
+            inner.height.saturating_sub(top),
+        );
+        f.render_widget(
+            Paragraph::new(lines).alignment(Alignment::Center),
+            text_area,
+        );
+    }
+}
+
 fn render_title_bar(f: &mut Frame, app: &App, area: Rect, _layout: &ResponsiveLayout) {
     use ratatui::layout::{Constraint, Direction, Layout};
 
@@ -142,7 +649,9 @@ fn render_title_bar(f: &mut Frame, app: &App, area: Rect, _layout: &ResponsiveLa
         ViewMode::Validators => "[3] Validators",
         ViewMode::Performance | ViewMode::ValidatorEpochDetail => "[4] Performance",
         ViewMode::Peers => "[5] Peers",
-        ViewMode::Help => "[?] Help",
+        ViewMode::Events => "[6] Events",
+        ViewMode::Help => "[H] Help",
+        ViewMode::Focus => "[0] Focus",
     };
 
     let left_text = vec![
@@ -164,30 +673,37 @@ fn render_title_bar(f: &mut Frame, app: &App, area: Rect, _layout: &ResponsiveLa
     let left_paragraph = Paragraph::new(Line::from(left_text)).alignment(Alignment::Left);
     f.render_widget(left_paragraph, chunks[0]);
 
-    // Right side: chain name and hostname (bold)
-    let right_text = if app.state.chain_name.is_empty() {
-        Line::from(vec![
-            Span::styled(
-                &app.state.node_name,
-                Style::default()
-                    .fg(theme.secondary())
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" "),
-        ])
-    } else {
-        Line::from(vec![
-            Span::styled(&app.state.chain_name, Style::default().fg(theme.epoch())),
-            Span::styled(" @ ", Style::default().fg(theme.muted())),
-            Span::styled(
-                &app.state.node_name,
-                Style::default()
-                    .fg(theme.secondary())
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" "),
-        ])
-    };
+    // Right side: chain name and hostname (bold), with the node switcher position
+    let mut right_spans = Vec::new();
+    if !app.state.chain_name.is_empty() {
+        right_spans.push(Span::styled(
+            &app.state.chain_name,
+            Style::default().fg(theme.epoch()),
+        ));
+        right_spans.push(Span::styled(" @ ", Style::default().fg(theme.muted())));
+    }
+    right_spans.push(Span::styled(
+        &app.state.node_name,
+        Style::default()
+            .fg(theme.secondary())
+            .add_modifier(Modifier::BOLD),
+    ));
+    if let Some((index, count)) = app.node_position {
+        right_spans.push(Span::styled(
+            format!(" [{}/{}]", index, count),
+            Style::default().fg(theme.muted()),
+        ));
+    }
+    if let Some(ref source) = app.replay_source {
+        right_spans.push(Span::styled(
+            format!(" [replay: {}]", source),
+            Style::default()
+                .fg(theme.warning())
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    right_spans.push(Span::raw(" "));
+    let right_text = Line::from(right_spans);
     let right_paragraph = Paragraph::new(right_text).alignment(Alignment::Right);
     f.render_widget(right_paragraph, chunks[1]);
 }
@@ -212,20 +728,27 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
 
     // Split inner area: left for status, right for MVM/version/theme
     let right_width = match layout.size {
-        ScreenSize::Medium => 24, // MVM: 12345 ☽ Night
-        ScreenSize::Large => 45,  // MVM: 12345  Node: v0.5.6  ☽ Midnight
+        ScreenSize::Small | ScreenSize::Medium => 24, // MVM: 12345 ☽ Night
+        ScreenSize::Large => 45,                      // MVM: 12345  Node: v0.5.6  ☽ Midnight
     };
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Min(0), Constraint::Length(right_width)])
         .split(inner_area);
 
+    // Health dot: green when synced, peered and finality within threshold
+    let health_color = if app.node_health().is_ok() {
+        theme.success()
+    } else {
+        theme.warning()
+    };
+
     // Left side: status info - compact for narrow screens
     // Show contextual hints based on current view/state
     let left_text = if app.has_popup() {
         // Popup is open - show dismiss hint
         vec![
-            Span::styled("●", Style::default().fg(theme.success())),
+            Span::styled("●", Style::default().fg(health_color)),
             Span::styled(
                 format!(" {}s ago  |  ", since_update),
                 Style::default().fg(theme.text()),
@@ -244,6 +767,11 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
                 Span::raw("")
             },
         ]
+    } else if app.refresh_requested {
+        vec![
+            Span::styled("⟳ ", Style::default().fg(theme.warning())),
+            Span::styled("Refreshing…", Style::default().fg(theme.text())),
+        ]
     } else if app.state.is_loading {
         vec![
             Span::styled("◌ ", Style::default().fg(theme.warning())),
@@ -259,26 +787,56 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
             ),
             Span::styled(err.clone(), Style::default().fg(theme.error())),
         ]
+    } else if app.state.db_sync.lagging {
+        // The dashboard is reading a database nobody is updating
+        vec![
+            Span::styled(
+                "⚠ MVM sync lagging",
+                Style::default()
+                    .fg(theme.error())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    " ({} behind) - is mvm sync running?",
+                    app.state.db_sync.blocks_remaining
+                ),
+                Style::default().fg(theme.error()),
+            ),
+        ]
+    } else if let Some(ref slow) = app.state.slow_rpc {
+        // Points at the call that made the last refresh drag
+        vec![
+            Span::styled("⚠ slow RPC: ", Style::default().fg(theme.warning())),
+            Span::styled(
+                format!(
+                    "{} took {:.1}s",
+                    slow.method,
+                    slow.elapsed_ms as f64 / 1000.0
+                ),
+                Style::default().fg(theme.text()),
+            ),
+        ]
     } else {
         // Show contextual hints based on view
         let enter_hint = match app.view_mode {
             ViewMode::Blocks => Some("Block details"),
             ViewMode::Validators => Some("Identity"),
-            ViewMode::Performance => Some("Epoch history"),
+            ViewMode::Performance => Some("Epoch history (Space/C compare)"),
             ViewMode::Peers => Some("Peer details"),
             _ => None,
         };
 
         match layout.size {
-            ScreenSize::Medium => {
+            ScreenSize::Small | ScreenSize::Medium => {
                 // Compact status for narrow screens
                 let mut spans = vec![
-                    Span::styled("●", Style::default().fg(theme.success())),
+                    Span::styled("●", Style::default().fg(health_color)),
                     Span::styled(
                         format!(" {}s ago | ", since_update),
                         Style::default().fg(theme.text()),
                     ),
-                    Span::styled("[1-5]", Style::default().fg(theme.primary())),
+                    Span::styled("[1-6]", Style::default().fg(theme.primary())),
                     Span::styled(" Views | ", Style::default().fg(theme.muted())),
                 ];
                 if let Some(hint) = enter_hint {
@@ -289,18 +847,18 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
                     ));
                 }
                 spans.push(Span::styled("?", Style::default().fg(theme.primary())));
-                spans.push(Span::styled(" Help", Style::default().fg(theme.muted())));
+                spans.push(Span::styled(" Legend", Style::default().fg(theme.muted())));
                 spans
             }
             ScreenSize::Large => {
                 // Full status for wide screens
                 let mut spans = vec![
-                    Span::styled("●", Style::default().fg(theme.success())),
+                    Span::styled("●", Style::default().fg(health_color)),
                     Span::styled(
                         format!(" Connected  |  Updated {}s ago  |  ", since_update),
                         Style::default().fg(theme.text()),
                     ),
-                    Span::styled("[1-5]", Style::default().fg(theme.primary())),
+                    Span::styled("[1-6]", Style::default().fg(theme.primary())),
                     Span::styled(" Views  ", Style::default().fg(theme.muted())),
                 ];
                 if let Some(hint) = enter_hint {
@@ -316,6 +874,11 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
                 spans.push(Span::styled("[Q]", Style::default().fg(theme.primary())));
                 spans.push(Span::styled(" Quit  ", Style::default().fg(theme.muted())));
                 spans.push(Span::styled("[?]", Style::default().fg(theme.primary())));
+                spans.push(Span::styled(
+                    " Legend  ",
+                    Style::default().fg(theme.muted()),
+                ));
+                spans.push(Span::styled("[H]", Style::default().fg(theme.primary())));
                 spans.push(Span::styled(" Help", Style::default().fg(theme.muted())));
                 spans
             }
@@ -355,11 +918,19 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
         &app.state.node_version
     };
 
-    let (theme_icon, theme_display) = if theme.name().contains("Midnight") {
+    let (theme_icon, theme_display) = if theme == Theme::Monochrome {
+        (
+            "◐ ",
+            match layout.size {
+                ScreenSize::Small | ScreenSize::Medium => "Mono",
+                ScreenSize::Large => "Monochrome",
+            },
+        )
+    } else if theme.name().contains("Midnight") {
         (
             "☽ ",
             match layout.size {
-                ScreenSize::Medium => "Night",
+                ScreenSize::Small | ScreenSize::Medium => "Night",
                 ScreenSize::Large => "Midnight",
             },
         )
@@ -367,7 +938,7 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
         (
             "☀ ",
             match layout.size {
-                ScreenSize::Medium => "Day",
+                ScreenSize::Small | ScreenSize::Medium => "Day",
                 ScreenSize::Large => "Midday",
             },
         )
@@ -385,7 +956,7 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
     };
 
     let right_text = match layout.size {
-        ScreenSize::Medium => Line::from(vec![
+        ScreenSize::Small | ScreenSize::Medium => Line::from(vec![
             Span::styled("MVM:", Style::default().fg(theme.muted())),
             Span::styled(format!("{} ", mvm_display), Style::default().fg(mvm_color)),
             Span::styled(theme_icon, Style::default().fg(theme.primary())),
@@ -409,44 +980,132 @@ fn render_status_bar(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
     f.render_widget(right_paragraph, chunks[1]);
 }
 
+/// Placeholder for a chain that hasn't started, or None once it is running
+fn chain_not_started_lines(app: &App) -> Option<Vec<Line<'static>>> {
+    let theme = app.theme;
+    let (headline, detail) = match app.state.chain_phase {
+        ChainPhase::Running => return None,
+        ChainPhase::NoBlocks => (
+            "Chain not started: no blocks yet",
+            "The node is up and waiting for the first block after genesis.",
+        ),
+        ChainPhase::SidechainNotStarted => (
+            "Sidechain not started: no epoch yet",
+            "Blocks are arriving but sidechain_getStatus reports no epoch.",
+        ),
+    };
+    let node = if app.state.chain_name.is_empty() {
+        "unknown chain".to_string()
+    } else {
+        app.state.chain_name.clone()
+    };
+    Some(vec![
+        Line::from(vec![
+            Span::styled("◌ ", Style::default().fg(theme.warning())),
+            Span::styled(headline, Style::default().fg(theme.text())),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {}", detail),
+            Style::default().fg(theme.muted()),
+        )),
+        Line::from(vec![
+            Span::styled("  Chain: ", Style::default().fg(theme.muted())),
+            Span::styled(node, Style::default().fg(theme.text())),
+            Span::styled(
+                format!("  block #{}", app.state.chain_tip),
+                Style::default().fg(theme.block_number()),
+            ),
+            Span::styled("  peers ", Style::default().fg(theme.muted())),
+            Span::styled(
+                app.state.peer_count.to_string(),
+                Style::default().fg(theme.text()),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "  The dashboard fills in automatically once the chain is running.",
+            Style::default().fg(theme.muted()),
+        )),
+    ])
+}
+
 fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout) {
     let theme = app.theme;
     let key_mode = layout.key_display_length();
 
+    // Elected but producing nothing: a full-width row above every panel
+    let area = if app.state.block_gap {
+        use ratatui::layout::{Constraint, Direction, Layout};
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        let progress = &app.state.epoch_progress;
+        let banner = Paragraph::new(Line::from(Span::styled(
+            format!(
+                " ⚠ NO BLOCKS THIS EPOCH - elected with {} seats, {:.0}% of the epoch gone (expected ~{:.1}); check keys and node logs ",
+                app.state.committee_seats, progress.progress_percent, progress.expected_blocks
+            ),
+            Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )));
+        f.render_widget(banner, rows[0]);
+        rows[1]
+    } else {
+        area
+    };
+
     // Calculate dynamic row count for Network Status panel
     // Base: 7 rows (Node, Block, Sidechain, Mainchain, Identity, Bandwidth/Peers, Tx Pool)
     let mut network_rows: u16 = 7;
     if !app.state.sync_progress.is_synced {
         network_rows += 1; // Sync detail row
     }
+    if app.state.db_sync.is_catching_up() {
+        network_rows += 1; // Database catch-up row
+    }
+    if known_state_pruning(app).is_some() {
+        network_rows += 1; // State pruning row
+    }
     if app.state.system_memory_total_bytes > 0 {
         network_rows += 1; // System row
                            // Check if memory warning will be shown
         let mem_percent = (app.state.system_memory_used_bytes as f64
             / app.state.system_memory_total_bytes as f64)
             * 100.0;
-        if mem_percent > 85.0 {
+        if mem_percent > app.thresholds.memory_warn_percent {
             network_rows += 1; // Memory warning row
         }
     }
+    if app.state.latest_slot_collision.is_some() {
+        network_rows += 1; // Equivocation row
+    }
+    if app.clock_skew_warning().is_some() {
+        network_rows += 1; // Clock skew row
+    }
     let chunks = layout.dashboard_layout(area, network_rows);
 
-    // Show loading state if still loading initial data
-    if app.state.is_loading {
-        let loading_text = vec![
-            Line::from(vec![
-                Span::styled("◌ ", Style::default().fg(theme.warning())),
-                Span::styled(
-                    "Connecting to node and loading data...",
-                    Style::default().fg(theme.text()),
-                ),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "  This may take a few seconds on first startup.",
-                Style::default().fg(theme.muted()),
-            )]),
-        ];
+    // Show loading state if still loading initial data, or a placeholder
+    // while the chain hasn't started (zeros would read as "epoch 0, 100% synced")
+    let not_started = chain_not_started_lines(app);
+    if app.state.is_loading || not_started.is_some() {
+        let loading_text = not_started.unwrap_or_else(|| {
+            vec![
+                Line::from(vec![
+                    Span::styled("◌ ", Style::default().fg(theme.warning())),
+                    Span::styled(
+                        "Connecting to node and loading data...",
+                        Style::default().fg(theme.text()),
+                    ),
+                ]),
+                Line::from(""),
+                Line::from(vec![Span::styled(
+                    "  This may take a few seconds on first startup.",
+                    Style::default().fg(theme.muted()),
+                )]),
+            ]
+        });
         let loading_widget = Paragraph::new(loading_text).block(
             Block::default()
                 .borders(Borders::ALL)
@@ -494,28 +1153,16 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
     let epoch_bar_width = 30; // Wider bars for full-width epoch rows
     let sidechain_filled =
         ((epoch_progress.progress_percent / 100.0) * epoch_bar_width as f64) as usize;
-    let sidechain_bar: String = format!(
-        "{}{}",
-        "━".repeat(sidechain_filled.min(epoch_bar_width)),
-        "░".repeat(epoch_bar_width.saturating_sub(sidechain_filled))
-    );
+    let sidechain_bar: String = progress_bar(app.charset, sidechain_filled, epoch_bar_width);
     let mainchain_filled =
         ((epoch_progress.mainchain_progress_percent / 100.0) * epoch_bar_width as f64) as usize;
-    let mainchain_bar: String = format!(
-        "{}{}",
-        "━".repeat(mainchain_filled.min(epoch_bar_width)),
-        "░".repeat(epoch_bar_width.saturating_sub(mainchain_filled))
-    );
+    let mainchain_bar: String = progress_bar(app.charset, mainchain_filled, epoch_bar_width);
 
     // Build sync progress bar (12 chars + space + percentage fits in 22 char column)
     let sync = &app.state.sync_progress;
     let sync_bar_width = 12;
     let sync_filled = ((sync.sync_percent / 100.0) * sync_bar_width as f64) as usize;
-    let sync_bar: String = format!(
-        "{}{}",
-        "━".repeat(sync_filled.min(sync_bar_width)),
-        "░".repeat(sync_bar_width.saturating_sub(sync_filled))
-    );
+    let sync_bar: String = progress_bar(app.charset, sync_filled, sync_bar_width);
     let (sync_icon, sync_color) = if sync.is_synced {
         ("✓", theme.success())
     } else {
@@ -543,6 +1190,15 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         format!("#{} (-{})", app.state.finalized_block, finalized_lag)
     };
 
+    // Finalization lag sparkline: warn when the latest sample is well above the window's low
+    let finality_trend_color = match (
+        app.state.finality_lag_history.iter().min(),
+        app.state.finality_lag_history.last(),
+    ) {
+        (Some(&min), Some(&last)) if last > min.max(1) * 3 && last > 10 => theme.warning(),
+        _ => theme.muted(),
+    };
+
     // Row 1: Node sync + Uptime
     let mut network_text = vec![];
     if sync.is_synced {
@@ -590,6 +1246,37 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         ]));
     }
 
+    // Row 1c: MVM database catch-up (only while the local DB trails the tip)
+    let db_sync = &app.state.db_sync;
+    if db_sync.is_catching_up() {
+        let db_filled = ((db_sync.percent / 100.0) * sync_bar_width as f64) as usize;
+        let db_bar: String = progress_bar(app.charset, db_filled, sync_bar_width);
+        let db_rate_str = if db_sync.insert_rate_bps > 0.1 {
+            format!("{:.0} blk/s", db_sync.insert_rate_bps)
+        } else {
+            "-- blk/s".to_string()
+        };
+        let db_eta_str = match db_sync.eta_seconds {
+            Some(eta_secs) => format!("ETA {}", format_uptime(eta_secs)),
+            None => "ETA --".to_string(),
+        };
+        network_text.push(Line::from(vec![
+            Span::styled("Database:     ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!("{:<22}", format!("{} {:.1}%", db_bar, db_sync.percent)),
+                Style::default().fg(theme.secondary()),
+            ),
+            Span::styled(
+                format!("{} behind", db_sync.blocks_remaining),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled("  ", Style::default()),
+            Span::styled(db_rate_str, Style::default().fg(theme.muted())),
+            Span::styled("  ", Style::default()),
+            Span::styled(db_eta_str, Style::default().fg(theme.muted())),
+        ]));
+    }
+
     // Row 2: Block + Finalized
     network_text.push(Line::from(vec![
         Span::styled("Block:        ", Style::default().fg(theme.muted())),
@@ -599,6 +1286,11 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         ),
         Span::styled("Finalized:    ", Style::default().fg(theme.muted())),
         Span::styled(finalized_str, Style::default().fg(theme.text())),
+        Span::styled("  ", Style::default()),
+        Span::styled(
+            sparkline_bars(&app.state.finality_lag_history, app.charset),
+            Style::default().fg(finality_trend_color),
+        ),
     ]));
 
     // Row 3: Sidechain epoch (full width with longer bar + countdown)
@@ -653,11 +1345,12 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
     ]));
 
     // Row 5: Network identity (external IP + peer ID)
-    let external_ip = if app.state.external_ips.is_empty() {
-        "unknown".to_string()
-    } else {
-        app.state.external_ips.join(", ")
-    };
+    // Only the preferred address fits here; the Peers view lists the others
+    let external_ip = app
+        .state
+        .external_ips
+        .first()
+        .map_or("unknown", String::as_str);
     let peer_id_display = if app.state.local_peer_id.len() > 16 {
         format!(
             "{}...{}",
@@ -678,6 +1371,22 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         Span::styled(peer_id_display, Style::default().fg(theme.secondary())),
     ]));
 
+    // Row 5b: Node state pruning (once detected) - whether past blocks can be attributed
+    if let Some(pruning) = known_state_pruning(app) {
+        let (color, note) = match pruning {
+            StatePruning::Archive => (theme.success(), "full history attributable"),
+            _ => (theme.warning(), "older blocks sync without authors"),
+        };
+        network_text.push(Line::from(vec![
+            Span::styled("State:        ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!("{:<22}", pruning.label()),
+                Style::default().fg(color),
+            ),
+            Span::styled(note, Style::default().fg(theme.muted())),
+        ]));
+    }
+
     // Row 6: Bandwidth + Peers (network I/O grouped, color-coded like Peers view)
     // ↓ inbound = green (success), ↑ outbound = muted
     network_text.push(Line::from(vec![
@@ -707,12 +1416,39 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         ),
     ]));
 
-    // Row 7: Tx Pool
+    // Row 7: Tx Pool + network-wide blocks per epoch (all authors)
+    // Epochs under 80% of the window's median are flagged: a chain-wide dip rather
+    // than a problem specific to our validator
     let txpool_str = format!("{} ready", app.state.txpool_ready);
-    network_text.push(Line::from(vec![
+    let network_blocks = &app.state.network_blocks_sparkline;
+    let dip_floor = {
+        let mut sorted = network_blocks.clone();
+        sorted.sort_unstable();
+        sorted
+            .get(sorted.len() / 2)
+            .map_or(0, |median| median * 4 / 5)
+    };
+    let mut txpool_spans = vec![
         Span::styled("Tx Pool:      ", Style::default().fg(theme.muted())),
-        Span::styled(txpool_str, Style::default().fg(theme.text())),
-    ]));
+        Span::styled(
+            format!("{:<22}", txpool_str),
+            Style::default().fg(theme.text()),
+        ),
+    ];
+    if network_blocks.iter().any(|&n| n > 0) {
+        txpool_spans.push(Span::styled(
+            "Network:      ",
+            Style::default().fg(theme.muted()),
+        ));
+        txpool_spans.extend(sparkline_colored_spans(
+            network_blocks,
+            &vec![dip_floor; network_blocks.len()],
+            theme.primary(),
+            theme.warning(),
+            app.charset,
+        ));
+    }
+    network_text.push(Line::from(txpool_spans));
 
     // Row 8: System resources (from node_exporter if configured) - infrastructure last
     if app.state.system_memory_total_bytes > 0 {
@@ -763,7 +1499,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         ]));
 
         // Add memory warning if high usage
-        if mem_percent > 85.0 {
+        if mem_percent > app.thresholds.memory_warn_percent {
             network_text.push(Line::from(vec![
                 Span::styled("              ", Style::default()),
                 Span::styled("⚠ ", Style::default().fg(theme.warning())),
@@ -778,17 +1514,93 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         }
     }
 
-    let network_widget = Paragraph::new(network_text).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(theme.border()))
-            .title(Span::styled(
-                "Network Status",
-                Style::default()
-                    .fg(theme.primary())
-                    .add_modifier(Modifier::BOLD),
-            )),
-    );
+    // Host clock drift breaks time-windowed block counts
+    if let Some(secs) = app.clock_skew_warning() {
+        network_text.push(Line::from(vec![
+            Span::styled("Clock:        ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!(
+                    "⚠ Host clock {}s {} chain time - check NTP",
+                    secs.abs(),
+                    if secs > 0 { "ahead of" } else { "behind" }
+                ),
+                Style::default().fg(theme.warning()),
+            ),
+        ]));
+    }
+
+    // Voting stopped while blocks keep coming: easy to miss from the static indicator
+    if app.state.grandpa_voter_lost {
+        network_text.push(Line::from(vec![
+            Span::styled("GRANDPA:      ", Style::default().fg(theme.muted())),
+            Span::styled(
+                "⚠ Node stopped voting - out of the authority set or finality stalled locally",
+                Style::default().fg(theme.error()),
+            ),
+        ]));
+    }
+
+    // Scrapes backed off after repeated failures; values above are stale
+    let paused: Vec<&str> = [
+        (app.state.metrics_paused, "metrics"),
+        (app.state.node_exporter_paused, "node_exporter"),
+    ]
+    .iter()
+    .filter(|(paused, _)| *paused)
+    .map(|(_, name)| *name)
+    .collect();
+    if !paused.is_empty() {
+        network_text.push(Line::from(vec![
+            Span::styled("Metrics:      ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!(
+                    "⚠ {} unreachable, disabled temporarily (retry every {}s)",
+                    paused.join(" + "),
+                    crate::metrics::BREAKER_RETRY_INTERVAL.as_secs()
+                ),
+                Style::default().fg(theme.warning()),
+            ),
+        ]));
+    }
+
+    // Slot collisions recorded by sync (details in the Events view)
+    if let Some(c) = &app.state.latest_slot_collision {
+        network_text.push(Line::from(vec![
+            Span::styled("Equivocation: ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!(
+                    "⚠ {} slot collision(s), latest slot {} (#{} / #{})",
+                    app.state.slot_collision_count.unwrap_or(0),
+                    c.slot_number,
+                    c.other_block_number,
+                    c.block_number
+                ),
+                Style::default().fg(theme.warning()),
+            ),
+        ]));
+    }
+
+    let mut network_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border()))
+        .title(Span::styled(
+            "Network Status",
+            Style::default()
+                .fg(theme.primary())
+                .add_modifier(Modifier::BOLD),
+        ));
+    // Seats-vs-blocks audit banner (details: `mvm db verify`)
+    if let Some((epoch, issue)) = app.state.audit_issues.first() {
+        let more = match app.state.audit_issues.len() - 1 {
+            0 => String::new(),
+            n => format!(" (+{} more)", n),
+        };
+        network_block = network_block.title_bottom(Span::styled(
+            format!(" ⚠ epoch {}: {}{} ", epoch, issue, more),
+            Style::default().fg(theme.warning()),
+        ));
+    }
+    let network_widget = Paragraph::new(network_text).block(network_block);
     f.render_widget(network_widget, chunks[0]);
 
     // Our validators - responsive with epoch block predictions
@@ -828,18 +1640,42 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
             theme.muted()
         };
 
-        // Panel height (9) fits 1 validator with all 3 keys (4 header + 3 key lines + 2 border)
+        // Run-rate projection to end of epoch, colored by deviation from expected
+        let (projection_text, projection_color) = match app.epoch_projection() {
+            Some(p) => {
+                let deviation = p.deviation_percent();
+                let color = if deviation >= -10.0 {
+                    theme.success()
+                } else if deviation >= -30.0 {
+                    theme.warning()
+                } else {
+                    theme.error()
+                };
+                (
+                    // Expected is already shown alongside, so only the projection is added
+                    format!("  → projected {:.0} ({:+.0}%)", p.projected, deviation),
+                    color,
+                )
+            }
+            None => (String::new(), theme.muted()),
+        };
+
+        // Panel height (10) fits 1 validator with all 3 keys (5 header + 3 key lines + 2 border)
         let max_validators = 1;
 
         // Committee election status
         let (committee_icon, committee_color) = if app.state.committee_elected {
             ("✓", theme.success())
+        } else if app.is_unregistered() {
+            ("✗", theme.error())
         } else {
             ("✗", theme.warning())
         };
 
         // Committee status - compact format
-        let committee_status = if app.state.committee_elected {
+        let committee_status = if app.is_unregistered() {
+            format!("{} NOT registered", committee_icon)
+        } else if app.state.committee_elected {
             format!(
                 "{} Elected ({} / {})",
                 committee_icon, app.state.committee_seats, app.state.committee_size
@@ -851,6 +1687,11 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         };
 
         let blocks_str = format!("{} blocks", total_our_blocks);
+        let since_restart_text = app
+            .state
+            .our_blocks_since_restart
+            .map(|n| format!("  ({} since restart)", n))
+            .unwrap_or_default();
         let epoch_str = format!("{} blocks", epoch_blocks);
 
         // Sparkline performance coloring: green >= 90%, warning 70-90%, error < 70%
@@ -872,6 +1713,8 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         // GRANDPA voter status
         let (grandpa_icon, grandpa_color) = if app.state.grandpa_voter {
             ("✓ Voting", theme.success())
+        } else if app.state.grandpa_voter_lost {
+            ("✗ Stopped voting", theme.error())
         } else {
             ("○ Not voting", theme.muted())
         };
@@ -899,6 +1742,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                     format!("{:.3}%", share),
                     Style::default().fg(theme.success()),
                 ),
+                Span::styled(since_restart_text, Style::default().fg(theme.muted())),
             ]),
             // Row 3: This epoch + Expected
             Line::from(vec![
@@ -913,6 +1757,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                     Style::default().fg(theme.text()),
                 ),
                 Span::styled(performance_indicator, Style::default().fg(perf_color)),
+                Span::styled(projection_text, Style::default().fg(projection_color)),
             ]),
             // Row 4: Sparkline (24 epoch trend, spans both columns)
             // Each bar colored individually: normal for met expectations, red for missed blocks
@@ -926,6 +1771,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                     &app.state.our_seats_sparkline,
                     theme.primary(), // Purple - normal
                     theme.error(),   // Red - missed blocks only
+                    app.charset,
                 ));
                 sparkline_spans.push(Span::styled(
                     format!(
@@ -936,6 +1782,31 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                 ));
                 Line::from(sparkline_spans)
             },
+            // Row 5: Rolling 7-day production (epoch-aligned, independent of epoch length)
+            {
+                let (blocks, seats) = (app.state.our_week_blocks, app.state.our_week_seats);
+                let (pct_text, pct_color) = if seats > 0 {
+                    let pct = blocks as f64 / seats as f64 * 100.0;
+                    let color = if pct >= 90.0 {
+                        theme.success()
+                    } else if pct >= 70.0 {
+                        theme.warning()
+                    } else {
+                        theme.error()
+                    };
+                    (format!(" ({:.0}%)", pct), color)
+                } else {
+                    (String::new(), theme.muted())
+                };
+                Line::from(vec![
+                    Span::styled("7 Days:       ", Style::default().fg(theme.muted())),
+                    Span::styled(
+                        format!("{} blocks / {} seats", blocks, seats),
+                        Style::default().fg(theme.text()),
+                    ),
+                    Span::styled(pct_text, Style::default().fg(pct_color)),
+                ])
+            },
         ];
 
         // Show validators with all three public keys (14-char labels)
@@ -947,7 +1818,7 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                 .map(|l| format!(" ({})", l))
                 .unwrap_or_default();
 
-            // Row 5: Sidechain key
+            // Row 6: Sidechain key
             lines.push(Line::from(vec![
                 Span::styled("* Sidechain:  ", Style::default().fg(theme.ours())),
                 Span::styled(
@@ -957,21 +1828,39 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
                 Span::styled(label.clone(), Style::default().fg(theme.muted())),
             ]));
 
-            // Row 6: AURA key
+            // Keys that differ from the on-chain registration are flagged in place
+            let key_style = |key_type: &str| {
+                if app
+                    .state
+                    .key_mismatches
+                    .iter()
+                    .any(|m| m.key_type == key_type)
+                {
+                    (Style::default().fg(theme.error()), " ✗ not registered")
+                } else {
+                    (Style::default().fg(theme.text()), "")
+                }
+            };
+
+            // Row 7: AURA key
             if let Some(ref aura_key) = v.aura_key {
                 let aura_display = key_mode.format(aura_key);
+                let (style, flag) = key_style("aura");
                 lines.push(Line::from(vec![
                     Span::styled("  AURA:       ", Style::default().fg(theme.muted())),
-                    Span::styled(aura_display, Style::default().fg(theme.text())),
+                    Span::styled(aura_display, style),
+                    Span::styled(flag, style),
                 ]));
             }
 
-            // Row 7: Grandpa key
+            // Row 8: Grandpa key
             if let Some(ref grandpa_key) = v.grandpa_key {
                 let grandpa_display = key_mode.format(grandpa_key);
+                let (style, flag) = key_style("grandpa");
                 lines.push(Line::from(vec![
                     Span::styled("  Grandpa:    ", Style::default().fg(theme.muted())),
-                    Span::styled(grandpa_display, Style::default().fg(theme.text())),
+                    Span::styled(grandpa_display, style),
+                    Span::styled(flag, style),
                 ]));
             }
         }
@@ -1005,17 +1894,54 @@ fn render_dashboard(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLay
         ]
     };
 
-    let our_validators_widget = Paragraph::new(our_validators_text).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(theme.border()))
-            .title(Span::styled(
-                "Our Validator",
-                Style::default()
-                    .fg(theme.ours())
-                    .add_modifier(Modifier::BOLD),
-            )),
-    );
+    // Countdown to our next assigned slot, for planning maintenance windows
+    let mut our_validator_title = vec![Span::styled(
+        "Our Validator",
+        Style::default()
+            .fg(theme.ours())
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some((next_slot, _)) = app.state.upcoming_slots.first() {
+        our_validator_title.push(Span::styled(
+            format!(
+                " · next slot in {} ({} left this epoch) ",
+                format_countdown(app.slot_countdown_secs(*next_slot)),
+                app.state.upcoming_slots.len()
+            ),
+            Style::default().fg(theme.epoch()),
+        ));
+    }
+
+    let mut our_validators_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border()))
+        .title(Line::from(our_validator_title));
+    if !app.state.key_mismatches.is_empty() {
+        let types: Vec<_> = app
+            .state
+            .key_mismatches
+            .iter()
+            .map(|m| m.key_type.as_str())
+            .collect();
+        our_validators_block = our_validators_block.title_bottom(Span::styled(
+            format!(
+                " ⚠ keystore {} key differs from on-chain registration ",
+                types.join("/")
+            ),
+            Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.is_unregistered() {
+        our_validators_block = our_validators_block.title_bottom(Span::styled(
+            " ⚠ Validator NOT registered - run registration (mvm guide registration) ",
+            Style::default()
+                .fg(theme.error())
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let our_validators_widget = Paragraph::new(our_validators_text).block(our_validators_block);
     f.render_widget(our_validators_widget, chunks[1]);
 
     // Recent blocks - fill available space (panel height minus 2 for borders)
@@ -1163,6 +2089,7 @@ fn render_blocks(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout
         .highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         );
@@ -1179,6 +2106,8 @@ fn render_validators(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
 
     // Use the shared sorted validator list
     let validators = app.get_sorted_validators();
+    let seats_pending = app.pending_seats_epoch();
+    let leaderboard = app.show_dynamic_only;
 
     let validator_items: Vec<ListItem> = validators
         .iter()
@@ -1187,13 +2116,12 @@ fn render_validators(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
             let ours = if v.is_ours { "★" } else { " " };
             let key_display = key_mode.format(&v.sidechain_key);
 
-            // Get seats from epoch data if available
-            let seats_display = app
-                .state
-                .validator_epoch_data
-                .get(&v.sidechain_key)
-                .map(|epoch| format!("{:>3}", epoch.committee_seats))
-                .unwrap_or_else(|| "  0".to_string());
+            // Get seats from epoch data; "-" while the epoch has no snapshot yet
+            let seats_display = match app.state.validator_epoch_data.get(&v.sidechain_key) {
+                Some(epoch) => format!("{:>3}", epoch.committee_seats),
+                None if seats_pending.is_some() => "  -".to_string(),
+                None => "  0".to_string(),
+            };
 
             // Standard validator format (same for Medium and Large)
             // Fixed-width label column (5 chars for pool ticker)
@@ -1215,6 +2143,53 @@ fn render_validators(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
                 Span::raw(" "),
             ];
 
+            // Leaderboard row: stake rank, stake, share, seats, selection rate
+            if let Some(rank) = leaderboard
+                .then(|| app.state.dynamic_ranks.get(&v.sidechain_key))
+                .flatten()
+            {
+                spans.insert(
+                    0,
+                    Span::styled(
+                        format!("{:>3}. ", rank.stake_rank),
+                        Style::default().fg(theme.warning()),
+                    ),
+                );
+                spans.extend([
+                    Span::styled(
+                        format!("{:>8}", format_stake(rank.stake_lovelace)),
+                        Style::default().fg(theme.text()),
+                    ),
+                    Span::styled(
+                        format!(" {:>6.2}%", rank.stake_share_percent),
+                        Style::default().fg(theme.muted()),
+                    ),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("{:>3}", rank.committee_seats),
+                        Style::default().fg(if rank.committee_seats > 0 {
+                            theme.success()
+                        } else {
+                            theme.muted()
+                        }),
+                    ),
+                    Span::styled(" seats", Style::default().fg(theme.muted())),
+                    Span::raw("  "),
+                    Span::styled(
+                        match rank.selection_rate() {
+                            Some(rate) => format!("{:>3.0}% selected", rate),
+                            None => "   - selected".to_string(),
+                        },
+                        Style::default().fg(theme.text()),
+                    ),
+                    Span::styled(
+                        format!(" ({}/{} epochs)", rank.times_selected, rank.epochs_tracked),
+                        Style::default().fg(theme.muted()),
+                    ),
+                ]);
+                return ListItem::new(Line::from(spans));
+            }
+
             if val_cols.show_status {
                 spans.push(Span::styled(
                     format!("{:<12}", status),
@@ -1265,12 +2240,41 @@ fn render_validators(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
     } else {
         String::new()
     };
-    let title = format!(
-        "Validators ({} total{}{}) - [F] filter, j/k or ↑/↓ scroll",
-        validators.len(),
-        filter_text,
-        epoch_label
-    );
+    let title = if leaderboard {
+        format!(
+            "Dynamic validators by stake ({} ranked{}{}) - [L] all validators, [F] filter, j/k or ↑/↓ scroll",
+            validators.len(),
+            filter_text,
+            epoch_label
+        )
+    } else {
+        format!(
+            "Validators ({} total{}{}) - [S] sort: {}, [F] filter, [L] dynamic leaderboard, j/k or ↑/↓ scroll",
+            validators.len(),
+            filter_text,
+            epoch_label,
+            app.sort_key(ViewMode::Validators).label()
+        )
+    };
+
+    // Pending seats note and epoch-over-epoch committee churn along the bottom border
+    let mut bottom_spans = Vec::new();
+    if let Some(epoch) = seats_pending {
+        bottom_spans.push(Span::styled(
+            format!(
+                " seats data pending for epoch {} (capturing on next block) ",
+                epoch
+            ),
+            Style::default().fg(theme.warning()),
+        ));
+    }
+    if let Some(c) = app.state.committee_churn.as_ref() {
+        bottom_spans.push(Span::styled(
+            format!(" committee churn (epoch {}): {} ", c.epoch, c.summary()),
+            Style::default().fg(theme.muted()),
+        ));
+    }
+    let churn_title = Line::from(bottom_spans);
 
     let validators_list = List::new(validator_items)
         .block(
@@ -1282,11 +2286,13 @@ fn render_validators(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLa
                     Style::default()
                         .fg(theme.primary())
                         .add_modifier(Modifier::BOLD),
-                )),
+                ))
+                .title_bottom(churn_title),
         )
         .highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         );
@@ -1300,14 +2306,8 @@ fn render_performance(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveL
     let theme = app.theme;
     let key_mode = layout.key_display_length();
 
-    let mut validators = if app.show_ours_only {
-        app.state.our_validators.clone()
-    } else {
-        app.state.validators.clone()
-    };
-
-    // Sort by block count descending
-    validators.sort_by(|a, b| b.total_blocks.cmp(&a.total_blocks));
+    // Use the shared sorted validator list (all-time blocks by default)
+    let validators = app.sorted_validators(ViewMode::Performance);
 
     // Always use total blocks from all validators for percentage (not filtered total)
     let total_blocks = app.state.total_blocks;
@@ -1323,6 +2323,11 @@ fn render_performance(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveL
             };
 
             let ours = if v.is_ours { "★" } else { " " };
+            let marked = if app.compare_marks.contains(&v.sidechain_key) {
+                "◆"
+            } else {
+                " "
+            };
             let key_display = key_mode.format(&v.sidechain_key);
 
             // Standard performance format (same for Medium and Large)
@@ -1331,7 +2336,7 @@ fn render_performance(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveL
                     format!("{:>3}.", i + 1),
                     Style::default().fg(theme.warning()),
                 ),
-                Span::raw(" "),
+                Span::styled(marked, Style::default().fg(theme.primary())),
                 Span::styled(ours, Style::default().fg(theme.ours())),
                 Span::raw(" "),
                 Span::styled(key_display, Style::default().fg(theme.secondary())),
@@ -1357,8 +2362,10 @@ fn render_performance(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveL
 
     let filter_text = if app.show_ours_only { " (ours)" } else { "" };
     let title = format!(
-        "Performance Rankings{} - [F] filter, j/k or ↑/↓ scroll",
-        filter_text
+        "Performance Rankings{} - [S] sort: {}, [F] filter, [Space] mark {}/2, [C] compare, [D] distribution",
+        filter_text,
+        app.sort_key(ViewMode::Performance).label(),
+        app.compare_marks.len()
     );
 
     let performance_list = List::new(validator_items)
@@ -1376,6 +2383,7 @@ fn render_performance(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveL
         .highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         );
@@ -1394,14 +2402,16 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
 
     // Peer health analysis
     let peer_count = app.state.connected_peers.len();
+    let peers_warn = app.thresholds.peers_warn as usize;
+    let peers_crit = app.thresholds.peers_crit as usize;
     let (health_status, health_color) = if peer_count == 0 {
         ("CRITICAL: No peers connected!", theme.error())
-    } else if peer_count < 3 {
+    } else if peer_count < peers_crit {
         (
             "WARNING: Very few peers - network isolation risk",
             theme.error(),
         )
-    } else if peer_count < 8 {
+    } else if peer_count < peers_warn {
         ("CAUTION: Low peer count", theme.warning())
     } else {
         ("Healthy", theme.success())
@@ -1424,9 +2434,10 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
         .filter(|p| app.state.chain_tip.saturating_sub(p.best_number) < 10)
         .count();
 
-    // Split area: header info + peer list
-    let has_warnings = peer_count < 8 || diversity_warning.is_some();
-    let header_height = if has_warnings { 4 } else { 2 };
+    // Split area: header info + peer list (plus a line for our addresses)
+    let has_warnings = peer_count < peers_warn || diversity_warning.is_some();
+    let has_addresses = app.state.external_ips.len() > 1;
+    let header_height = if has_warnings { 4 } else { 2 } + u16::from(has_addresses);
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -1462,12 +2473,26 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
         ),
     ])];
 
+    if has_addresses {
+        header_lines.push(Line::from(vec![
+            Span::styled("Our addresses: ", Style::default().fg(theme.muted())),
+            Span::styled(
+                app.state.external_ips[0].clone(),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled(
+                format!("  also {}", app.state.external_ips[1..].join(", ")),
+                Style::default().fg(theme.muted()),
+            ),
+        ]));
+    }
+
     if let Some(warning) = diversity_warning {
         header_lines.push(Line::from(vec![
             Span::styled("⚠ ", Style::default().fg(theme.warning())),
             Span::styled(warning, Style::default().fg(theme.warning())),
         ]));
-    } else if peer_count < 8 {
+    } else if peer_count < peers_warn {
         header_lines.push(Line::from(vec![
             Span::styled("⚠ ", Style::default().fg(theme.warning())),
             Span::styled(
@@ -1532,6 +2557,14 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
                     format!("#{}", peer.best_number),
                     Style::default().fg(theme.block_number()),
                 ),
+                Span::styled(
+                    format!("  avg -{:<5.1}", peer.avg_blocks_behind),
+                    Style::default().fg(if peer.is_lagging() {
+                        theme.warning()
+                    } else {
+                        theme.muted()
+                    }),
+                ),
                 Span::styled(addr_display, Style::default().fg(theme.muted())),
             ]);
 
@@ -1541,7 +2574,7 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
 
     // Build title with count
     let title = format!(
-        "{} connected peers - j/k or ↑/↓ scroll, Enter for details",
+        "{} connected peers (most current first) - j/k or ↑/↓ scroll, Enter for details",
         peer_count
     );
 
@@ -1555,6 +2588,7 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
         .highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         );
@@ -1564,11 +2598,120 @@ fn render_peers(f: &mut Frame, app: &App, area: Rect, layout: &ResponsiveLayout)
     f.render_stateful_widget(peers_list, chunks[1], &mut list_state);
 }
 
+fn render_events(f: &mut Frame, app: &App, area: Rect) {
+    let theme = app.theme;
+
+    // Newest first
+    let event_items: Vec<ListItem> = app
+        .state
+        .events
+        .iter()
+        .rev()
+        .map(|event| {
+            let color = match event.severity {
+                EventSeverity::Info => theme.text(),
+                EventSeverity::Warning => theme.warning(),
+                EventSeverity::Error => theme.error(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    app.time_zone.format(event.timestamp),
+                    Style::default().fg(theme.muted()),
+                ),
+                Span::raw("  "),
+                Span::styled(event.severity.label(), Style::default().fg(color)),
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:<10}", event.category),
+                    Style::default().fg(theme.secondary()),
+                ),
+                Span::styled(event.message.clone(), Style::default().fg(color)),
+            ]))
+        })
+        .collect();
+
+    let title = if event_items.is_empty() {
+        "Events - none since startup".to_string()
+    } else {
+        format!(
+            "Events ({}) - newest first, j/k or ↑/↓ scroll",
+            event_items.len()
+        )
+    };
+
+    let events_list = List::new(event_items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border()))
+                .title(Span::styled(
+                    title,
+                    Style::default()
+                        .fg(theme.primary())
+                        .add_modifier(Modifier::BOLD),
+                )),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
+                .add_modifier(Modifier::BOLD)
+                .fg(theme.text()),
+        );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.selected_index()));
+    f.render_stateful_widget(events_list, area, &mut list_state);
+}
+
 fn render_help(f: &mut Frame, app: &App, area: Rect) {
     let theme = app.theme;
 
     // Build help items as ListItems for scrolling support
-    let help_items: Vec<ListItem> = vec![
+    let help_items = help_items(theme);
+    let item_count = help_items.len();
+
+    let help_list = List::new(help_items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border()))
+                .title(Span::styled(
+                    "Help - Use j/k/J/K or ↑/↓ to scroll",
+                    Style::default()
+                        .fg(theme.primary())
+                        .add_modifier(Modifier::BOLD),
+                )),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
+                .add_modifier(Modifier::BOLD)
+                .fg(theme.text()),
+        );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(app.selected_index()));
+    f.render_stateful_widget(help_list, area, &mut list_state);
+
+    // Render scrollbar
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(Some("↑"))
+        .end_symbol(Some("↓"));
+
+    let mut scrollbar_state = ScrollbarState::new(item_count).position(app.selected_index());
+
+    // Render scrollbar in the same area (it will appear on the right edge)
+    f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+}
+
+/// Build the help screen content
+///
+/// Shared by `render_help` and `App::max_scroll_index` so the scroll bound
+/// always matches the rendered list.
+pub(crate) fn help_items(theme: Theme) -> Vec<ListItem<'static>> {
+    vec![
         ListItem::new(Line::from(vec![Span::styled(
             "About",
             Style::default()
@@ -1616,11 +2759,15 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
                 .add_modifier(Modifier::BOLD),
         )])),
         ListItem::new(Line::from(vec![
-            Span::styled("    1-5       ", Style::default().fg(theme.text())),
+            Span::styled("    1-6       ", Style::default().fg(theme.text())),
             Span::raw(
-                "Switch to view (1=Dashboard, 2=Blocks, 3=Validators, 4=Performance, 5=Peers)",
+                "Switch to view (1=Dashboard, 2=Blocks, 3=Validators, 4=Performance, 5=Peers, 6=Events)",
             ),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    0         ", Style::default().fg(theme.text())),
+            Span::raw("Focus mode: full-screen key metrics for our validator (--focus)"),
+        ])),
         ListItem::new(Line::from(vec![
             Span::styled("    Tab       ", Style::default().fg(theme.text())),
             Span::raw("Next view"),
@@ -1630,8 +2777,12 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
             Span::raw("Previous view"),
         ])),
         ListItem::new(Line::from(vec![
-            Span::styled("    ? / h / F1", Style::default().fg(theme.text())),
-            Span::raw("  Show this help"),
+            Span::styled("    h / F1    ", Style::default().fg(theme.text())),
+            Span::raw("Show this help"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    ?         ", Style::default().fg(theme.text())),
+            Span::raw("Legend for the current view's symbols and colors (Esc closes)"),
         ])),
         ListItem::new(Line::from("")),
         ListItem::new(Line::from(vec![Span::styled(
@@ -1667,6 +2818,14 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("    Enter     ", Style::default().fg(theme.text())),
             Span::raw("Open details (Blocks: popup, Performance: drill-down, Peers: popup)"),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    Space / c ", Style::default().fg(theme.text())),
+            Span::raw("Mark two validators, then compare side by side (Performance)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    d         ", Style::default().fg(theme.text())),
+            Span::raw("Block distribution by author for an epoch; ←/→ to step (Performance)"),
+        ])),
         ListItem::new(Line::from(vec![
             Span::styled("    Esc       ", Style::default().fg(theme.text())),
             Span::raw("Close popup or return from drill-down"),
@@ -1686,10 +2845,38 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("    f / F     ", Style::default().fg(theme.text())),
             Span::raw("Toggle 'ours only' filter (Validators/Performance views)"),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    l / L     ", Style::default().fg(theme.text())),
+            Span::raw("Dynamic validators ranked by stake, with share, seats and selection rate (Validators view)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    s / S     ", Style::default().fg(theme.text())),
+            Span::raw("Cycle sort order (Validators/Performance views)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    g         ", Style::default().fg(theme.text())),
+            Span::raw("Jump to our validator; repeat to cycle (Validators/Performance)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    r / R     ", Style::default().fg(theme.text())),
+            Span::raw("Refresh data now"),
+        ])),
         ListItem::new(Line::from(vec![
             Span::styled("    t / T     ", Style::default().fg(theme.text())),
             Span::raw("Toggle theme (Midnight ⟷ Midday)"),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    e / E     ", Style::default().fg(theme.text())),
+            Span::raw("Export the current list (with filter and sort) to mvm-<view>-<time>.csv"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    w / W     ", Style::default().fg(theme.text())),
+            Span::raw("Write state to mvm-state-<time>.json (for mvm view --replay)"),
+        ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    n / N     ", Style::default().fg(theme.text())),
+            Span::raw("Next / previous node (when monitoring several)"),
+        ])),
         ListItem::new(Line::from("")),
         ListItem::new(Line::from(vec![Span::styled(
             "  Quit:",
@@ -1732,6 +2919,10 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("    [5] Peers       ", Style::default().fg(theme.text())),
             Span::raw("Connected peers with sync status and IPs"),
         ])),
+        ListItem::new(Line::from(vec![
+            Span::styled("    [6] Events      ", Style::default().fg(theme.text())),
+            Span::raw("Alerts and state transitions, newest first"),
+        ])),
         ListItem::new(Line::from("")),
         ListItem::new(Line::from(vec![Span::styled(
             "Reference",
@@ -1832,42 +3023,14 @@ fn render_help(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("    State Pruning    ", Style::default().fg(theme.text())),
             Span::raw("Removal of old blockchain state to save disk space"),
         ])),
-    ];
-
-    let item_count = help_items.len();
-
-    let help_list = List::new(help_items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(theme.border()))
-                .title(Span::styled(
-                    "Help - Use j/k/J/K or ↑/↓ to scroll",
-                    Style::default()
-                        .fg(theme.primary())
-                        .add_modifier(Modifier::BOLD),
-                )),
-        )
-        .highlight_style(
-            Style::default()
-                .bg(theme.highlight())
-                .add_modifier(Modifier::BOLD)
-                .fg(theme.text()),
-        );
-
-    let mut list_state = ListState::default();
-    list_state.select(Some(app.selected_index()));
-    f.render_stateful_widget(help_list, area, &mut list_state);
-
-    // Render scrollbar
-    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-        .begin_symbol(Some("↑"))
-        .end_symbol(Some("↓"));
-
-    let mut scrollbar_state = ScrollbarState::new(item_count).position(app.selected_index());
+    ]
+}
 
-    // Render scrollbar in the same area (it will appear on the right edge)
-    f.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+/// The node's state pruning once detected (the State row is shown)
+fn known_state_pruning(app: &App) -> Option<StatePruning> {
+    app.state
+        .state_pruning
+        .filter(|pruning| *pruning != StatePruning::Unknown)
 }
 
 /// Format bytes into human-readable string (KB, MB, GB)
@@ -1913,16 +3076,6 @@ fn format_countdown(secs: u64) -> String {
     format!("{:02}:{:02}:{:02}", hours, mins, s)
 }
 
-/// Format timestamp as human-readable date/time
-fn format_timestamp(timestamp: i64) -> String {
-    use chrono::{TimeZone, Utc};
-    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
-        dt.format("%Y-%m-%d %H:%M:%S UTC").to_string()
-    } else {
-        "Unknown".to_string()
-    }
-}
-
 // ========================================
 // Popup Rendering
 // ========================================
@@ -1932,14 +3085,24 @@ use crate::tui::PopupContent;
 /// Render popup overlay
 fn render_popup(f: &mut Frame, app: &App, popup: &PopupContent) {
     match popup {
-        PopupContent::BlockDetail { block } => render_block_detail_popup(f, app, block),
+        PopupContent::BlockDetail { block, calls } => {
+            render_block_detail_popup(f, app, block, calls.as_deref())
+        }
         PopupContent::PeerDetail { peer } => render_peer_detail_popup(f, app, peer),
         PopupContent::ValidatorDetail {
             validator,
             epoch_history,
             scroll_index,
+            has_more,
         } => {
-            render_validator_detail_popup(f, app, validator, epoch_history, *scroll_index);
+            render_validator_detail_popup(
+                f,
+                app,
+                validator,
+                epoch_history,
+                *scroll_index,
+                *has_more,
+            );
         }
         PopupContent::ValidatorIdentity {
             validator,
@@ -1947,8 +3110,14 @@ fn render_popup(f: &mut Frame, app: &App, popup: &PopupContent) {
             current_epoch_seats,
             committee_size,
             blocks_this_epoch,
+            liveness,
             stake_display,
+            stake_change,
+            upcoming_slots,
             selection_stats,
+            reward_estimate,
+            rank,
+            rank_history,
         } => {
             render_validator_identity_popup(
                 f,
@@ -1958,13 +3127,193 @@ fn render_popup(f: &mut Frame, app: &App, popup: &PopupContent) {
                 *current_epoch_seats,
                 *committee_size,
                 *blocks_this_epoch,
+                liveness.as_ref(),
                 stake_display.as_deref(),
-                selection_stats.as_ref(),
+                stake_change.as_ref(),
+                upcoming_slots,
+                selection_stats.as_deref(),
+                reward_estimate.as_deref(),
+                rank.as_ref(),
+                rank_history,
             );
         }
+        PopupContent::ValidatorCompare { sides } => {
+            render_validator_compare_popup(f, app, sides);
+        }
+        PopupContent::EpochDistribution { epoch, shares } => {
+            render_epoch_distribution_popup(f, app, *epoch, shares);
+        }
+        PopupContent::Legend { view } => render_legend_popup(f, app, *view),
+    }
+}
+
+/// Symbols and colors used by a view, with what they mean
+fn legend_entries(view: ViewMode, theme: Theme) -> Vec<(&'static str, Color, &'static str)> {
+    match view {
+        ViewMode::Dashboard => vec![
+            ("✓ ⟳", theme.success(), "Node synced / still syncing"),
+            (
+                "▆",
+                theme.primary(),
+                "Sparkline: blocks per epoch (purple: all seats filled)",
+            ),
+            (
+                "▆",
+                theme.error(),
+                "Sparkline: epoch with missed blocks (fewer blocks than seats)",
+            ),
+            (
+                "✓ ○ !",
+                theme.success(),
+                "This epoch: ≥90% / ≥50% / <50% of expected blocks",
+            ),
+            (
+                "✗",
+                theme.error(),
+                "Not registered (yellow: registered, not elected)",
+            ),
+            (
+                "↓ ↑",
+                theme.success(),
+                "Inbound (green) / outbound (grey) peers and bandwidth",
+            ),
+            ("↑ ↓ ─", theme.warning(), "Memory rising / falling / stable"),
+            (
+                "⚠",
+                theme.warning(),
+                "Warning banner (clock skew, collisions, lagging sync)",
+            ),
+            (
+                "⚠",
+                theme.error(),
+                "Red top row: elected but no blocks yet this epoch",
+            ),
+        ],
+        ViewMode::Blocks => vec![
+            ("#", theme.block_number(), "Block number"),
+            ("slot", theme.muted(), "AURA slot the block was authored in"),
+            ("epoch", theme.epoch(), "Sidechain epoch"),
+            ("extr", theme.text(), "Extrinsics in the block"),
+            ("✓", theme.success(), "Finalized (blank: not yet finalized)"),
+        ],
+        ViewMode::Validators => vec![
+            ("★", theme.ours(), "Our validator"),
+            (
+                "seats",
+                theme.text(),
+                "Committee seats this epoch (-: snapshot pending)",
+            ),
+            (
+                "%",
+                theme.muted(),
+                "Leaderboard [L]: stake share, selection rate",
+            ),
+        ],
+        ViewMode::Performance | ViewMode::ValidatorEpochDetail => vec![
+            ("★", theme.ours(), "Our validator"),
+            (
+                "◆",
+                theme.primary(),
+                "Marked for comparison [Space], compare with [C]",
+            ),
+            ("blocks", theme.text(), "Blocks produced (all time)"),
+            ("%", theme.success(), "Share of all blocks (green: ours)"),
+        ],
+        ViewMode::Peers => vec![
+            ("✓", theme.success(), "Peer at or ahead of our best block"),
+            (
+                "~",
+                theme.warning(),
+                "Peer within 10 blocks of our best block",
+            ),
+            ("○", theme.muted(), "Peer 10+ blocks behind"),
+            ("↓", theme.success(), "Inbound: the peer dialed us"),
+            ("↑", theme.muted(), "Outbound: we dialed the peer"),
+            (
+                "⚠",
+                theme.warning(),
+                "Below the peer floor or consistently lagging",
+            ),
+        ],
+        ViewMode::Events => vec![
+            ("INFO", theme.text(), "A state change"),
+            ("WARN", theme.warning(), "Needs attention"),
+            ("ERROR", theme.error(), "Something is broken"),
+            (
+                "category",
+                theme.secondary(),
+                "Source: node, sync, committee, peers, ...",
+            ),
+        ],
+        ViewMode::Focus => vec![
+            ("■", theme.success(), "Healthy / on track"),
+            ("■", theme.warning(), "Degraded or behind expectations"),
+            ("■", theme.error(), "Failing: act now"),
+            ("■", theme.muted(), "No data yet"),
+        ],
+        ViewMode::Help => vec![],
     }
 }
 
+/// Render the compact legend for a view
+fn render_legend_popup(f: &mut Frame, app: &App, view: ViewMode) {
+    use ratatui::widgets::Clear;
+
+    let theme = app.theme;
+    let entries = legend_entries(view, theme);
+    let symbol_width = entries
+        .iter()
+        .map(|(symbol, _, _)| symbol.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut content = vec![Line::from("")];
+    content.extend(entries.iter().map(|(symbol, color, meaning)| {
+        Line::from(vec![
+            Span::raw(" "),
+            Span::styled(
+                format!("{:<width$}", symbol, width = symbol_width),
+                Style::default().fg(*color).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  "),
+            Span::styled(*meaning, Style::default().fg(theme.text())),
+        ])
+    }));
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled(" Esc", Style::default().fg(theme.primary())),
+        Span::styled(" close  ", Style::default().fg(theme.muted())),
+        Span::styled("h", Style::default().fg(theme.primary())),
+        Span::styled(" full help", Style::default().fg(theme.muted())),
+    ]));
+
+    // Sized to the content, centered
+    let screen = f.area();
+    let content_width = content.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+    let width = (content_width + 3).min(screen.width);
+    let height = (content.len() as u16 + 2).min(screen.height);
+    let area = Rect::new(
+        screen.x + (screen.width - width) / 2,
+        screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    );
+
+    f.render_widget(Clear, area);
+    let popup = Paragraph::new(content).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary()))
+            .title(Span::styled(
+                " Legend ",
+                Style::default()
+                    .fg(theme.title())
+                    .add_modifier(Modifier::BOLD),
+            )),
+    );
+    f.render_widget(popup, area);
+}
+
 /// Calculate centered popup area with adaptive sizing
 /// On narrow screens (<100 cols), uses nearly full width with small margins
 /// On wider screens, uses percentage-based sizing
@@ -1999,7 +3348,12 @@ fn centered_popup(min_width: u16, percent_x: u16, percent_y: u16, area: Rect) ->
 }
 
 /// Render block detail popup
-fn render_block_detail_popup(f: &mut Frame, app: &App, block: &crate::db::BlockRecord) {
+fn render_block_detail_popup(
+    f: &mut Frame,
+    app: &App,
+    block: &crate::db::BlockRecord,
+    calls: Option<&str>,
+) {
     use ratatui::widgets::Clear;
 
     let theme = app.theme;
@@ -2079,7 +3433,7 @@ fn render_block_detail_popup(f: &mut Frame, app: &App, block: &crate::db::BlockR
         Line::from(vec![
             Span::styled(" Timestamp:        ", Style::default().fg(theme.muted())),
             Span::styled(
-                format_timestamp(block.timestamp),
+                app.time_zone.format(block.timestamp),
                 Style::default().fg(theme.text()),
             ),
         ]),
@@ -2091,7 +3445,10 @@ fn render_block_detail_popup(f: &mut Frame, app: &App, block: &crate::db::BlockR
         Line::from(vec![
             Span::styled(" Extrinsics:       ", Style::default().fg(theme.muted())),
             Span::styled(
-                format!("{}", block.extrinsics_count),
+                match calls {
+                    Some(calls) => format!("{} ({})", block.extrinsics_count, calls),
+                    None => format!("{}", block.extrinsics_count),
+                },
                 Style::default().fg(theme.text()),
             ),
         ]),
@@ -2114,17 +3471,20 @@ fn render_block_detail_popup(f: &mut Frame, app: &App, block: &crate::db::BlockR
         ]),
     ];
 
-    let popup = Paragraph::new(content).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(theme.primary()))
-            .title(Span::styled(
-                format!(" Block #{} ", block.block_number),
-                Style::default()
-                    .fg(theme.title())
-                    .add_modifier(Modifier::BOLD),
-            )),
-    );
+    // Long call breakdowns wrap onto the following lines
+    let popup = Paragraph::new(content)
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.primary()))
+                .title(Span::styled(
+                    format!(" Block #{} ", block.block_number),
+                    Style::default()
+                        .fg(theme.title())
+                        .add_modifier(Modifier::BOLD),
+                )),
+        );
 
     f.render_widget(popup, area);
 }
@@ -2205,6 +3565,28 @@ fn render_peer_detail_popup(f: &mut Frame, app: &App, peer: &crate::tui::app::Pe
                 Span::raw("")
             },
         ]),
+        Line::from(vec![
+            Span::styled(" Average Lag:      ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!(
+                    "{:.1} blocks over {} refreshes",
+                    peer.avg_blocks_behind, peer.lag_samples
+                ),
+                Style::default().fg(if peer.is_lagging() {
+                    theme.warning()
+                } else {
+                    theme.text()
+                }),
+            ),
+            if peer.is_lagging() {
+                Span::styled(
+                    " - consistently behind, consider disconnecting",
+                    Style::default().fg(theme.warning()),
+                )
+            } else {
+                Span::raw("")
+            },
+        ]),
         Line::from(""),
         Line::from(vec![
             Span::styled(" Press ", Style::default().fg(theme.muted())),
@@ -2228,6 +3610,41 @@ fn render_peer_detail_popup(f: &mut Frame, app: &App, peer: &crate::tui::app::Pe
     f.render_widget(popup, area);
 }
 
+/// Identity popup headline: liveness score colored by how close to 100 it is
+fn liveness_line<'a>(app: &App, liveness: Option<&LivenessScore>) -> Line<'a> {
+    let theme = app.theme;
+    let Some(l) = liveness else {
+        return Line::from(Span::styled(
+            " Liveness:       no seats in recent epochs",
+            Style::default().fg(theme.muted()),
+        ));
+    };
+    let color = if l.score >= 90.0 {
+        theme.success()
+    } else if l.score >= 70.0 {
+        theme.warning()
+    } else {
+        theme.error()
+    };
+    Line::from(vec![
+        Span::styled(" Liveness:       ", Style::default().fg(theme.muted())),
+        Span::styled(
+            format!("{:.0}/100", l.score),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  {} of ~{} expected blocks over {} seated epoch{}",
+                l.blocks,
+                format_block_estimate(l.expected),
+                l.epochs,
+                if l.epochs == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(theme.muted()),
+        ),
+    ])
+}
+
 /// Render validator identity card popup
 #[allow(clippy::too_many_arguments)]
 fn render_validator_identity_popup(
@@ -2238,8 +3655,14 @@ fn render_validator_identity_popup(
     current_epoch_seats: u32,
     committee_size: u32,
     blocks_this_epoch: u64,
+    liveness: Option<&LivenessScore>,
     stake_display: Option<&str>,
+    stake_change: Option<&StakeChange>,
+    upcoming_slots: &[u64],
     selection_stats: Option<&CommitteeSelectionStats>,
+    reward_estimate: Option<&RewardEstimate>,
+    rank: Option<&EpochRank>,
+    rank_history: &[EpochRank],
 ) {
     use ratatui::widgets::Clear;
 
@@ -2271,14 +3694,12 @@ fn render_validator_identity_popup(
         0.0
     };
 
-    // Expected blocks this epoch (rough estimate)
+    // Expected blocks for a full epoch and a week at this seat share.
     // Slots per epoch varies by network (1200 preview, 6000 mainnet)
-    let slots_per_epoch = app.chain_timing.blocks_per_sidechain_epoch() as f64;
-    let expected_blocks = if committee_size > 0 {
-        (current_epoch_seats as f64 / committee_size as f64) * slots_per_epoch
-    } else {
-        0.0
-    };
+    let expected_blocks =
+        app.chain_timing
+            .expected_blocks(current_epoch_seats as u64, committee_size as u64, 1.0);
+    let expected_per_week = expected_blocks * app.chain_timing.sidechain_epochs_per_week();
 
     // Build content
     let mut content = vec![
@@ -2296,6 +3717,7 @@ fn render_validator_identity_popup(
             ),
             Span::styled(&label_display, Style::default().fg(theme.muted())),
         ]),
+        liveness_line(app, liveness),
         Line::from(""),
         Line::from(vec![
             Span::styled(" Sidechain Key:  ", Style::default().fg(theme.muted())),
@@ -2330,42 +3752,120 @@ fn render_validator_identity_popup(
 
     // Stake if available
     if let Some(stake) = stake_display {
-        content.push(Line::from(vec![
+        let mut spans = vec![
             Span::styled(" Stake:          ", Style::default().fg(theme.muted())),
             Span::styled(stake, Style::default().fg(theme.success())),
-        ]));
+        ];
+        if let Some(change) = stake_change {
+            let magnitude = format_stake(change.delta_lovelace.unsigned_abs());
+            let (text, color) = match change.delta_lovelace {
+                d if d > 0 => (format!("  ↑ {}", magnitude), theme.success()),
+                d if d < 0 => (format!("  ↓ {}", magnitude), theme.warning()),
+                _ => ("  unchanged".to_string(), theme.muted()),
+            };
+            spans.push(Span::styled(text, Style::default().fg(color)));
+            spans.push(Span::styled(
+                format!(" since epoch {}", change.previous_epoch),
+                Style::default().fg(theme.muted()),
+            ));
+        }
+        content.push(Line::from(spans));
     }
 
     content.push(Line::from(""));
 
     // Current epoch info
-    content.push(Line::from(vec![
-        Span::styled(" Seats:          ", Style::default().fg(theme.muted())),
-        Span::styled(
-            format!("{}", current_epoch_seats),
-            Style::default().fg(if current_epoch_seats > 0 {
-                theme.success()
-            } else {
-                theme.muted()
-            }),
-        ),
-        Span::styled(
-            format!(" / {} ({:.2}%)", committee_size, committee_pct),
-            Style::default().fg(theme.muted()),
-        ),
-    ]));
+    if let Some(epoch) = app.pending_seats_epoch() {
+        content.push(Line::from(vec![
+            Span::styled(" Seats:          ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!("pending (no snapshot for epoch {} yet)", epoch),
+                Style::default().fg(theme.warning()),
+            ),
+        ]));
+    } else {
+        content.push(Line::from(vec![
+            Span::styled(" Seats:          ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!("{}", current_epoch_seats),
+                Style::default().fg(if current_epoch_seats > 0 {
+                    theme.success()
+                } else {
+                    theme.muted()
+                }),
+            ),
+            Span::styled(
+                format!(" / {} ({:.2}%)", committee_size, committee_pct),
+                Style::default().fg(theme.muted()),
+            ),
+        ]));
+    }
 
-    content.push(Line::from(vec![
+    let mut block_spans = vec![
         Span::styled(" Blocks:         ", Style::default().fg(theme.muted())),
         Span::styled(
             format!("{} produced", blocks_this_epoch),
             Style::default().fg(theme.text()),
         ),
-        Span::styled(
-            format!(" / {:.0} expected", expected_blocks),
+    ];
+    // Expectations need the seat count, unknown until the snapshot lands
+    if app.pending_seats_epoch().is_none() {
+        block_spans.push(Span::styled(
+            format!(
+                " / ~{}/epoch, ~{}/week expected",
+                format_block_estimate(expected_blocks),
+                format_block_estimate(expected_per_week)
+            ),
             Style::default().fg(theme.muted()),
-        ),
-    ]));
+        ));
+    }
+    content.push(Line::from(block_spans));
+
+    // Standing among this epoch's block authors, plus the recent trend
+    let rank_text = match rank {
+        Some(r) => format!("#{} of {}", r.rank, r.authors),
+        None => "no blocks yet".to_string(),
+    };
+    let mut rank_spans = vec![
+        Span::styled(" Rank:           ", Style::default().fg(theme.muted())),
+        Span::styled(rank_text, Style::default().fg(theme.text())),
+        Span::styled(" this epoch", Style::default().fg(theme.muted())),
+    ];
+    if !rank_history.is_empty() {
+        let history: Vec<String> = rank_history
+            .iter()
+            .map(|r| format!("#{}", r.rank))
+            .collect();
+        rank_spans.push(Span::styled(
+            format!("  (prev: {} → now)", history.join(" ")),
+            Style::default().fg(theme.muted()),
+        ));
+    }
+    content.push(Line::from(rank_spans));
+
+    // Remaining slot assignments this epoch (only known for our validators)
+    if validator.is_ours && current_epoch_seats > 0 {
+        const MAX_SLOTS_SHOWN: usize = 4;
+        let slots_text = if upcoming_slots.is_empty() {
+            "none left this epoch".to_string()
+        } else {
+            let countdowns: Vec<String> = upcoming_slots
+                .iter()
+                .take(MAX_SLOTS_SHOWN)
+                .map(|slot| format!("in {}", format_countdown(app.slot_countdown_secs(*slot))))
+                .collect();
+            let more = upcoming_slots.len().saturating_sub(MAX_SLOTS_SHOWN);
+            if more > 0 {
+                format!("{} (+{} more)", countdowns.join(", "), more)
+            } else {
+                countdowns.join(", ")
+            }
+        };
+        content.push(Line::from(vec![
+            Span::styled(" Next Slots:     ", Style::default().fg(theme.muted())),
+            Span::styled(slots_text, Style::default().fg(theme.epoch())),
+        ]));
+    }
 
     content.push(Line::from(vec![
         Span::styled(" Total Blocks:   ", Style::default().fg(theme.muted())),
@@ -2400,6 +3900,14 @@ fn render_validator_identity_popup(
             ),
         ]));
 
+        // Flag incomplete snapshot history so the rate isn't taken at face value
+        if let Some(note) = stats.coverage_note() {
+            content.push(Line::from(vec![
+                Span::styled(" Coverage:       ", Style::default().fg(theme.muted())),
+                Span::styled(note, Style::default().fg(theme.warning())),
+            ]));
+        }
+
         // Total seats received
         content.push(Line::from(vec![
             Span::styled(" Total Seats:    ", Style::default().fg(theme.muted())),
@@ -2505,6 +4013,66 @@ fn render_validator_identity_popup(
         }
     }
 
+    // Rough reward estimate from recent block production
+    if let Some(estimate) = reward_estimate {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            " ── Reward Estimate ──",
+            Style::default()
+                .fg(theme.secondary())
+                .add_modifier(Modifier::BOLD),
+        )]));
+        content.push(Line::from(""));
+
+        content.push(Line::from(vec![
+            Span::styled(" Blocks/Epoch:   ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format_block_estimate(estimate.avg_blocks_per_epoch),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled(
+                format!(" avg over last {} epochs", estimate.epochs),
+                Style::default().fg(theme.muted()),
+            ),
+        ]));
+
+        let mut share_spans = vec![
+            Span::styled(" Block Share:    ", Style::default().fg(theme.muted())),
+            Span::styled(
+                format!("{:.2}%", estimate.block_share_percent),
+                Style::default().fg(theme.text()),
+            ),
+        ];
+        if let (Some(stake_share), Some(efficiency)) =
+            (estimate.stake_share_percent, estimate.efficiency())
+        {
+            share_spans.push(Span::styled(
+                format!(" vs {:.2}% of stake (", stake_share),
+                Style::default().fg(theme.muted()),
+            ));
+            share_spans.push(Span::styled(
+                format!("{:.2}x", efficiency),
+                Style::default().fg(if efficiency >= 1.0 {
+                    theme.success()
+                } else {
+                    theme.warning()
+                }),
+            ));
+            share_spans.push(Span::styled(")", Style::default().fg(theme.muted())));
+        }
+        content.push(Line::from(share_spans));
+
+        let mut projection = format!("~{} blocks", format_block_estimate(estimate.blocks_per_day));
+        if let Some(rewards) = estimate.rewards_per_day {
+            projection.push_str(&format!(", ~{:.2} {}", rewards, app.rewards.unit));
+        }
+        content.push(Line::from(vec![
+            Span::styled(" Per Day:        ", Style::default().fg(theme.muted())),
+            Span::styled(projection, Style::default().fg(theme.success())),
+            Span::styled(" (rough estimate)", Style::default().fg(theme.muted())),
+        ]));
+    }
+
     content.push(Line::from(""));
     content.push(Line::from(vec![
         Span::styled(" Press ", Style::default().fg(theme.muted())),
@@ -2533,6 +4101,324 @@ fn render_validator_identity_popup(
     f.render_widget(popup, area);
 }
 
+/// Render side-by-side comparison of two validators
+fn render_validator_compare_popup(f: &mut Frame, app: &App, sides: &[ValidatorComparison; 2]) {
+    use ratatui::layout::Constraint;
+    use ratatui::widgets::Clear;
+
+    /// Epochs shown in the seats trend sparkline
+    const TREND_EPOCHS: usize = 20;
+
+    let theme = app.theme;
+    let area = centered_popup(90, 75, 50, f.area());
+    f.render_widget(Clear, area);
+
+    let current_epoch = app.state.sidechain_epoch;
+    let label_style = Style::default().fg(theme.muted());
+    let value_style = Style::default().fg(theme.text());
+
+    // One row per metric: label, then a cell per validator
+    let row = |label: &'static str, cells: [Cell<'static>; 2]| {
+        let [a, b] = cells;
+        Row::new(vec![Cell::from(label).style(label_style), a, b])
+    };
+    let cells = |value: &dyn Fn(&ValidatorComparison) -> Cell<'static>| {
+        [value(&sides[0]), value(&sides[1])]
+    };
+
+    let header = Row::new(vec![
+        Cell::from(""),
+        Cell::from(compare_header(&sides[0])),
+        Cell::from(compare_header(&sides[1])),
+    ])
+    .style(
+        Style::default()
+            .fg(theme.secondary())
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows = vec![
+        row(
+            "Selected",
+            cells(&|side| {
+                let text = side
+                    .selection_stats
+                    .as_ref()
+                    .map(|s| s.selection_rate_display())
+                    .unwrap_or_else(|| "—".to_string());
+                Cell::from(text).style(value_style)
+            }),
+        ),
+        row(
+            "Avg Seats",
+            cells(&|side| {
+                let text = side
+                    .selection_stats
+                    .as_ref()
+                    .and_then(|s| s.avg_seats_when_selected())
+                    .map(|avg| format!("{:.1} when selected", avg))
+                    .unwrap_or_else(|| "—".to_string());
+                Cell::from(text).style(value_style)
+            }),
+        ),
+        row(
+            "Seats Trend",
+            cells(&|side| {
+                Cell::from(sparkline_bars(&side.seats_trend(TREND_EPOCHS), app.charset))
+                    .style(Style::default().fg(theme.epoch()))
+            }),
+        ),
+        row(
+            "Blocks/Epoch",
+            cells(&|side| {
+                let text = side
+                    .blocks_per_epoch(current_epoch)
+                    .map(format_block_estimate)
+                    .unwrap_or_else(|| "—".to_string());
+                Cell::from(text).style(value_style)
+            }),
+        ),
+        row(
+            "Stake",
+            cells(&|side| {
+                let mut text = side
+                    .stake_lovelace
+                    .map(format_stake)
+                    .unwrap_or_else(|| "—".to_string());
+                if let Some(share) = side
+                    .selection_stats
+                    .as_ref()
+                    .and_then(|s| s.stake_share_percent)
+                {
+                    text.push_str(&format!(" ({:.2}%)", share));
+                }
+                Cell::from(text).style(value_style)
+            }),
+        ),
+        row(
+            "Production",
+            cells(
+                &|side| match side.production_rate(&app.chain_timing, current_epoch) {
+                    Some(rate) => {
+                        let color = if rate >= 90.0 {
+                            theme.success()
+                        } else if rate >= 70.0 {
+                            theme.warning()
+                        } else {
+                            theme.error()
+                        };
+                        Cell::from(format!("{:.1}% of expected", rate))
+                            .style(Style::default().fg(color))
+                    }
+                    None => Cell::from("—").style(label_style),
+                },
+            ),
+        ),
+        row(
+            "Total Blocks",
+            cells(&|side| {
+                Cell::from(format!("{} (all time)", side.validator.total_blocks)).style(value_style)
+            }),
+        ),
+    ];
+
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Percentage(50),
+        Constraint::Percentage(50),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary()))
+            .title(Span::styled(
+                " Compare Validators ",
+                Style::default()
+                    .fg(theme.title())
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Span::styled(
+                " Production over completed tracked epochs · Esc to close ",
+                Style::default().fg(theme.muted()),
+            )),
+    );
+
+    f.render_widget(table, area);
+}
+
+/// Render block production by author for one epoch as a horizontal bar chart
+fn render_epoch_distribution_popup(
+    f: &mut Frame,
+    app: &App,
+    epoch: u64,
+    shares: &[crate::db::EpochAuthorShare],
+) {
+    use ratatui::widgets::Clear;
+
+    /// Authors listed (the rest are summarised on one line)
+    const TOP_AUTHORS: usize = 20;
+    /// Top authors whose combined share is shown as a concentration figure
+    const CONCENTRATION_TOP: usize = 5;
+
+    let theme = app.theme;
+    let area = centered_popup(90, 75, 80, f.area());
+    f.render_widget(Clear, area);
+
+    let total: u64 = shares.iter().map(|s| s.blocks).sum();
+    let max = shares.first().map(|s| s.blocks).unwrap_or(0);
+    let bar_width = (area.width as usize).saturating_sub(70).clamp(10, 40);
+
+    // The current epoch is judged against the slots elapsed so far
+    let progress = if epoch == app.state.sidechain_epoch {
+        app.state.epoch_progress.progress_percent / 100.0
+    } else {
+        1.0
+    };
+
+    let mut content = vec![Line::from("")];
+    if total == 0 {
+        content.push(Line::from(Span::styled(
+            " No attributed blocks stored for this epoch",
+            Style::default().fg(theme.muted()),
+        )));
+    } else {
+        let top: u64 = shares
+            .iter()
+            .take(CONCENTRATION_TOP)
+            .map(|s| s.blocks)
+            .sum();
+        content.push(Line::from(vec![
+            Span::styled(
+                format!(" {} blocks from {} authors", total, shares.len()),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled(
+                format!(
+                    " · top {} produced {:.1}%",
+                    CONCENTRATION_TOP.min(shares.len()),
+                    top as f64 / total as f64 * 100.0
+                ),
+                Style::default().fg(theme.muted()),
+            ),
+        ]));
+    }
+    content.push(Line::from(""));
+
+    for (i, share) in shares.iter().take(TOP_AUTHORS).enumerate() {
+        let is_ours = app
+            .state
+            .our_validators
+            .iter()
+            .any(|v| v.sidechain_key == share.sidechain_key);
+        let filled = if max > 0 {
+            ((share.blocks as f64 / max as f64) * bar_width as f64).round() as usize
+        } else {
+            0
+        };
+        let pct = if total > 0 {
+            share.blocks as f64 / total as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        // Production vs what the seats entitle this author to
+        let expected = app.chain_timing.expected_blocks(
+            share.seats as u64,
+            share.committee_size as u64,
+            progress,
+        );
+        let (ratio_text, color) = if expected > 0.0 {
+            let ratio = share.blocks as f64 / expected * 100.0;
+            let color = if ratio < 70.0 {
+                theme.error()
+            } else if ratio > 130.0 {
+                theme.warning()
+            } else {
+                theme.success()
+            };
+            (format!("{:>4.0}% of expected", ratio), color)
+        } else {
+            ("no seats".to_string(), theme.muted())
+        };
+
+        content.push(Line::from(vec![
+            Span::styled(
+                format!(" {:>3}. ", i + 1),
+                Style::default().fg(theme.muted()),
+            ),
+            Span::styled(
+                if is_ours { "★" } else { " " },
+                Style::default().fg(theme.ours()),
+            ),
+            Span::styled(
+                format!(
+                    "{:<22} ",
+                    KeyDisplayMode::Short.format(&share.sidechain_key)
+                ),
+                Style::default().fg(theme.secondary()),
+            ),
+            Span::styled(
+                // Full-height sparkline level doubles as the solid bar
+                sparkline_levels(app.charset)[7].to_string().repeat(filled),
+                Style::default().fg(color),
+            ),
+            Span::raw(" ".repeat(bar_width - filled.min(bar_width))),
+            Span::styled(
+                format!(" {:>5} {:>6.2}%", share.blocks, pct),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled(
+                format!("  {:>3} seats  ", share.seats),
+                Style::default().fg(theme.muted()),
+            ),
+            Span::styled(ratio_text, Style::default().fg(color)),
+        ]));
+    }
+
+    let hidden = shares.len().saturating_sub(TOP_AUTHORS);
+    if hidden > 0 {
+        content.push(Line::from(Span::styled(
+            format!("       … and {} more authors", hidden),
+            Style::default().fg(theme.muted()),
+        )));
+    }
+
+    let current_note = if epoch == app.state.sidechain_epoch {
+        " (in progress)"
+    } else {
+        ""
+    };
+    let popup = Paragraph::new(content).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary()))
+            .title(Span::styled(
+                format!(" Block Distribution · Epoch {}{} ", epoch, current_note),
+                Style::default()
+                    .fg(theme.title())
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .title_bottom(Span::styled(
+                " ←/→ change epoch · Esc to close ",
+                Style::default().fg(theme.muted()),
+            )),
+    );
+
+    f.render_widget(popup, area);
+}
+
+/// Column header for one side of the comparison popup
+fn compare_header(side: &ValidatorComparison) -> String {
+    let short = KeyDisplayMode::Short.format(&side.validator.sidechain_key);
+    let ours = if side.validator.is_ours { "★ " } else { "" };
+    match &side.validator.label {
+        Some(label) => format!("{}{} ({})", ours, short, label),
+        None => format!("{}{}", ours, short),
+    }
+}
+
 /// Render validator detail popup with epoch history table
 fn render_validator_detail_popup(
     f: &mut Frame,
@@ -2540,6 +4426,7 @@ fn render_validator_detail_popup(
     validator: &crate::db::ValidatorRecord,
     epoch_history: &[crate::db::ValidatorEpochHistoryRecord],
     scroll_index: usize,
+    has_more: bool,
 ) {
     use ratatui::layout::{Constraint, Direction, Layout};
     use ratatui::widgets::Clear;
@@ -2630,11 +4517,19 @@ fn render_validator_detail_popup(
     // Build table rows
     // Slots per epoch varies by network (1200 preview, 6000 mainnet)
     let slots_per_epoch = app.chain_timing.blocks_per_sidechain_epoch() as f64;
+    // The in-progress epoch is measured against the slots elapsed so far and
+    // left out of the summary, like the sparklines (completed epochs only)
+    let current_epoch = app.state.sidechain_epoch;
+    let current_fraction = app.state.epoch_progress.progress_percent / 100.0;
     let rows: Vec<Row> = epoch_history
         .iter()
         .map(|record| {
+            let is_current = record.epoch == current_epoch;
+            let epoch_fraction = if is_current { current_fraction } else { 1.0 };
             let expected = if record.committee_size > 0 {
-                (record.seats as f64 / record.committee_size as f64) * slots_per_epoch
+                (record.seats as f64 / record.committee_size as f64)
+                    * slots_per_epoch
+                    * epoch_fraction
             } else {
                 0.0
             };
@@ -2651,7 +4546,8 @@ fn render_validator_detail_popup(
                 theme.success()
             } else if ratio >= 70.0 {
                 theme.warning()
-            } else if record.seats == 0 {
+            } else if record.seats == 0 || is_current {
+                // Too few slots elapsed in the current epoch to call it low
                 theme.muted()
             } else {
                 theme.error()
@@ -2671,18 +4567,37 @@ fn render_validator_detail_popup(
                     .style(Style::default().fg(theme.text())),
                 Cell::from(format!("{:>6.0}", expected)).style(Style::default().fg(theme.muted())),
                 Cell::from(format!("{:>6.1}%", ratio)).style(Style::default().fg(ratio_color)),
+                Cell::from(if is_current {
+                    format!("(current, {:.0}% elapsed)", current_fraction * 100.0)
+                } else {
+                    String::new()
+                })
+                .style(Style::default().fg(theme.muted())),
             ])
         })
         .collect();
 
-    // Summary stats
-    let total_epochs = epoch_history.len();
-    let total_blocks: u64 = epoch_history.iter().map(|r| r.blocks_produced).sum();
-    let epochs_with_seats = epoch_history.iter().filter(|r| r.seats > 0).count();
+    // Summary stats (completed epochs only)
+    let completed: Vec<_> = epoch_history
+        .iter()
+        .filter(|r| r.epoch != current_epoch)
+        .collect();
+    let total_epochs = completed.len();
+    let total_blocks: u64 = completed.iter().map(|r| r.blocks_produced).sum();
+    let epochs_with_seats = completed.iter().filter(|r| r.seats > 0).count();
 
+    // Say when older epochs exist so the loaded window isn't mistaken for all history
     let title = format!(
-        " Epoch History ({} epochs, {} with seats, {} blocks) ",
-        total_epochs, epochs_with_seats, total_blocks
+        " Epoch History, newest first ({} {}completed epochs, {} with seats, {} blocks{}) ",
+        total_epochs,
+        if has_more { "most recent " } else { "" },
+        epochs_with_seats,
+        total_blocks,
+        if has_more {
+            " - ↓ at end loads more"
+        } else {
+            ""
+        }
     );
 
     // Table header
@@ -2695,6 +4610,7 @@ fn render_validator_detail_popup(
         Cell::from(format!("{:>6}", "Blocks")).style(header_style),
         Cell::from(format!("{:>8}", "Expected")).style(header_style),
         Cell::from(format!("{:>7}", "Ratio")).style(header_style),
+        Cell::from(""),
     ])
     .height(1)
     .bottom_margin(0);
@@ -2705,6 +4621,7 @@ fn render_validator_detail_popup(
         Constraint::Length(8),
         Constraint::Length(10),
         Constraint::Length(9),
+        Constraint::Min(0),
     ];
 
     let table = Table::new(rows, widths)
@@ -2723,6 +4640,7 @@ fn render_validator_detail_popup(
         .row_highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         )
@@ -2934,6 +4852,7 @@ fn render_validator_epoch_detail(f: &mut Frame, app: &App, area: Rect, layout: &
         .row_highlight_style(
             Style::default()
                 .bg(theme.highlight())
+                .add_modifier(theme.highlight_modifier())
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.text()),
         )